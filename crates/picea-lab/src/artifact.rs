@@ -16,7 +16,10 @@ use picea::{debug::DebugAabb, prelude::*};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    scenario::{build_scenario, CompoundProvenance, RunConfig, ScenarioId},
+    scenario::{
+        build_run_scenario, CompoundProvenance, FixtureProvenance, FixtureSource, RunConfig,
+        ScenarioId, ScenarioOverrides,
+    },
     LabError, LabResult,
 };
 
@@ -74,10 +77,18 @@ pub struct ArtifactEntry {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunManifest {
     pub run_id: String,
-    pub scenario_id: ScenarioId,
+    /// Builtin scenario of the run; `None` for fixture runs.
+    #[serde(default)]
+    pub scenario_id: Option<ScenarioId>,
     pub frame_count: usize,
     pub final_state_hash: String,
     pub artifacts: Vec<ArtifactEntry>,
+    /// Source fixture of a fixture run.
+    #[serde(default)]
+    pub fixture: Option<FixtureProvenance>,
+    /// Overrides the run was made with, so golden runs can be replayed.
+    #[serde(default)]
+    pub overrides: ScenarioOverrides,
 }
 
 /// One line in `frames.jsonl`. A frame is the stable view of one fixed
@@ -95,13 +106,20 @@ pub struct FrameRecord {
     /// not need a side channel or web-side fixture reconstruction.
     #[serde(default)]
     pub compound_provenance: Vec<CompoundProvenance>,
+    /// Wheel states of a scenario vehicle after its pre-step update; wheels
+    /// are not colliders, so the debug snapshot cannot show them.
+    #[serde(default)]
+    pub vehicle_wheels: Vec<WheelState>,
 }
 
 /// Viewer-oriented, compact render summary derived from debug snapshots.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DebugRenderArtifact {
     pub run_id: String,
-    pub scenario_id: ScenarioId,
+    #[serde(default)]
+    pub scenario_id: Option<ScenarioId>,
+    #[serde(default)]
+    pub fixture: Option<FixtureProvenance>,
     pub final_state_hash: String,
     pub frames: Vec<DebugRenderFrame>,
 }
@@ -150,6 +168,14 @@ pub struct DebugRenderFrame {
     pub ccd_miss_count: usize,
     #[serde(default)]
     pub ccd_clamp_count: usize,
+    #[serde(default)]
+    pub ccd_substep_count: usize,
+    #[serde(default)]
+    pub ccd_substep_fallback_count: usize,
+    #[serde(default)]
+    pub ccd_speculative_contact_count: usize,
+    #[serde(default)]
+    pub ccd_speculative_enforced_count: usize,
     pub world_bounds: Option<DebugAabb>,
     pub bodies: Vec<DebugBody>,
     pub colliders: Vec<DebugCollider>,
@@ -160,7 +186,11 @@ pub struct DebugRenderFrame {
     #[serde(default)]
     pub islands: Vec<picea::debug::DebugIsland>,
     #[serde(default)]
+    pub fluid_submersions: Vec<picea::debug::DebugFluidSubmersion>,
+    #[serde(default)]
     pub compound_provenance: Vec<CompoundProvenance>,
+    #[serde(default)]
+    pub vehicle_wheels: Vec<WheelState>,
     pub unmeasured: Vec<String>,
 }
 
@@ -239,20 +269,58 @@ impl ArtifactStore {
         let file = ArtifactFile::from_str(file_name)?;
         Ok(fs::read(self.artifact_path(run_id, file))?)
     }
+
+    pub fn read_manifest(&self, run_id: &str) -> LabResult<RunManifest> {
+        let bytes = fs::read(self.artifact_path(run_id, ArtifactFile::Manifest))?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    pub fn read_frames(&self, run_id: &str) -> LabResult<Vec<FrameRecord>> {
+        let text = fs::read_to_string(self.artifact_path(run_id, ArtifactFile::Frames))?;
+        text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect()
+    }
+
+    /// Ids of the runs under this store that have a manifest, in sorted order.
+    pub fn run_ids(&self) -> LabResult<Vec<String>> {
+        let mut run_ids = Vec::new();
+        for entry in fs::read_dir(&self.root)? {
+            let entry = entry?;
+            if !entry
+                .path()
+                .join(ArtifactFile::Manifest.file_name())
+                .is_file()
+            {
+                continue;
+            }
+            if let Some(run_id) = entry.file_name().to_str() {
+                run_ids.push(run_id.to_owned());
+            }
+        }
+        run_ids.sort();
+        Ok(run_ids)
+    }
 }
 
 pub fn run_scenario(store: &ArtifactStore, config: RunConfig) -> LabResult<RunResult> {
     let started = Instant::now();
     let frame_count = config.effective_frame_count();
+    let step_config = config.overrides.step_config()?;
     let run_id = config.run_id.clone().unwrap_or_else(make_run_id);
     let run_path = store.run_path(&run_id);
     fs::create_dir_all(&run_path)?;
 
-    let mut scenario = build_scenario(config.scenario_id, &config.overrides)?;
-    let mut pipeline = SimulationPipeline::new(StepConfig::default());
+    let scenario_id = config.source.scenario_id();
+    let fixture = config.source.fixture().map(FixtureSource::provenance);
+    let mut scenario = build_run_scenario(&config)?;
+    let dt = step_config.dt;
+    let mut pipeline = SimulationPipeline::new(step_config);
     let mut frames = Vec::with_capacity(frame_count);
 
     for frame_index in 0..frame_count {
+        scenario.before_step(frame_index, dt)?;
         let report = pipeline.step(&mut scenario.world);
         let snapshot = DebugSnapshot::from_world_with_step_report(
             &scenario.world,
@@ -269,6 +337,7 @@ pub fn run_scenario(store: &ArtifactStore, config: RunConfig) -> LabResult<RunRe
             report,
             snapshot,
             compound_provenance: scenario.compound_provenance.clone(),
+            vehicle_wheels: scenario.vehicle_wheels(),
         });
     }
 
@@ -279,10 +348,12 @@ pub fn run_scenario(store: &ArtifactStore, config: RunConfig) -> LabResult<RunRe
     let final_state_hash = state_hash(&final_snapshot)?;
     let manifest = RunManifest {
         run_id: run_id.clone(),
-        scenario_id: config.scenario_id,
+        scenario_id,
         frame_count,
         final_state_hash: final_state_hash.clone(),
         artifacts: artifact_entries(),
+        fixture: fixture.clone(),
+        overrides: config.overrides.clone(),
     };
 
     write_json(run_path.join(ArtifactFile::Manifest.file_name()), &manifest)?;
@@ -291,7 +362,8 @@ pub fn run_scenario(store: &ArtifactStore, config: RunConfig) -> LabResult<RunRe
         run_path.join(ArtifactFile::DebugRender.file_name()),
         &DebugRenderArtifact {
             run_id,
-            scenario_id: config.scenario_id,
+            scenario_id,
+            fixture,
             final_state_hash: final_state_hash.clone(),
             frames: frames
                 .iter()
@@ -332,6 +404,16 @@ pub fn run_scenario(store: &ArtifactStore, config: RunConfig) -> LabResult<RunRe
                     ccd_hit_count: frame.snapshot.stats.ccd_hit_count,
                     ccd_miss_count: frame.snapshot.stats.ccd_miss_count,
                     ccd_clamp_count: frame.snapshot.stats.ccd_clamp_count,
+                    ccd_substep_count: frame.snapshot.stats.ccd_substep_count,
+                    ccd_substep_fallback_count: frame.snapshot.stats.ccd_substep_fallback_count,
+                    ccd_speculative_contact_count: frame
+                        .snapshot
+                        .stats
+                        .ccd_speculative_contact_count,
+                    ccd_speculative_enforced_count: frame
+                        .snapshot
+                        .stats
+                        .ccd_speculative_enforced_count,
                     world_bounds: frame.snapshot.world_bounds(),
                     bodies: frame.snapshot.bodies.clone(),
                     colliders: frame.snapshot.colliders.clone(),
@@ -339,7 +421,9 @@ pub fn run_scenario(store: &ArtifactStore, config: RunConfig) -> LabResult<RunRe
                     manifolds: frame.snapshot.manifolds.clone(),
                     broadphase_tree: frame.snapshot.broadphase_tree.clone(),
                     islands: frame.snapshot.islands.clone(),
+                    fluid_submersions: frame.snapshot.fluid_submersions.clone(),
                     compound_provenance: frame.compound_provenance.clone(),
+                    vehicle_wheels: frame.vehicle_wheels.clone(),
                     // M5 exposes contact solver impulses; force/torque accumulation is still
                     // outside the lab artifact contract.
                     unmeasured: ["forces", "torques"]
@@ -433,7 +517,7 @@ fn make_run_id() -> String {
     format!("run-{nanos}")
 }
 
-fn state_hash(value: &impl Serialize) -> LabResult<String> {
+pub(crate) fn state_hash(value: &impl Serialize) -> LabResult<String> {
     let bytes = serde_json::to_vec(value)?;
     let mut hash = 0xcbf2_9ce4_8422_2325u64;
     for byte in bytes {
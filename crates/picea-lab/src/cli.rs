@@ -1,16 +1,22 @@
 //! Command-line entrypoints for local runs and the development server.
 
-use std::{net::SocketAddr, str::FromStr};
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use axum::serve;
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
+use picea::prelude::FloatNum;
 use tokio::net::TcpListener;
 
 use crate::{
     artifact::{run_scenario, ArtifactStore},
-    scenario::{list_scenarios, RunConfig, ScenarioId},
+    compare::{compare_runs, verify_golden_runs, CompareTolerances},
+    scenario::{list_scenarios, FixtureSource, RunConfig, RunSource, ScenarioId},
     server::{app, LabServerState},
-    LabResult,
+    LabError, LabResult,
 };
 
 #[derive(Debug, Parser)]
@@ -26,7 +32,11 @@ enum Command {
     List,
     /// Run one scenario and write artifacts under target/picea-lab/runs.
     Run {
-        scenario: String,
+        #[arg(required_unless_present = "fixture")]
+        scenario: Option<String>,
+        /// Run a scene fixture JSON file instead of a builtin scenario.
+        #[arg(long, conflicts_with = "scenario")]
+        fixture: Option<PathBuf>,
         #[arg(long, default_value_t = 120)]
         frames: usize,
     },
@@ -34,9 +44,95 @@ enum Command {
     Serve {
         #[arg(long, default_value = "127.0.0.1:8080")]
         bind: SocketAddr,
+        /// Directory `fixture_path` session requests may read from; unset
+        /// rejects them.
+        #[arg(long)]
+        fixture_root: Option<PathBuf>,
+    },
+    /// Compare two recorded runs frame by frame; exits non-zero on divergence.
+    Compare {
+        /// Reference run: a run directory or a run id in the default store.
+        run_a: String,
+        /// Candidate run: a run directory or a run id in the default store.
+        run_b: String,
+        #[command(flatten)]
+        tolerances: ToleranceArgs,
+    },
+    /// Replay every golden run and compare it; exits non-zero on divergence.
+    Verify {
+        /// Artifact store directory holding the recorded golden runs.
+        #[arg(long)]
+        golden: PathBuf,
+        /// Store for the replayed runs; defaults to target/picea-lab/runs.
+        #[arg(long)]
+        out: Option<PathBuf>,
+        #[command(flatten)]
+        tolerances: ToleranceArgs,
     },
 }
 
+/// Per-field overrides of [`CompareTolerances::default`].
+#[derive(Debug, Args)]
+struct ToleranceArgs {
+    #[arg(long)]
+    position_tolerance: Option<FloatNum>,
+    #[arg(long)]
+    rotation_tolerance: Option<FloatNum>,
+    #[arg(long)]
+    linear_velocity_tolerance: Option<FloatNum>,
+    #[arg(long)]
+    angular_velocity_tolerance: Option<FloatNum>,
+    #[arg(long)]
+    contact_point_tolerance: Option<FloatNum>,
+    #[arg(long)]
+    contact_normal_tolerance: Option<FloatNum>,
+    #[arg(long)]
+    contact_depth_tolerance: Option<FloatNum>,
+    #[arg(long)]
+    contact_impulse_tolerance: Option<FloatNum>,
+}
+
+impl ToleranceArgs {
+    fn tolerances(&self) -> CompareTolerances {
+        let defaults = CompareTolerances::default();
+        CompareTolerances {
+            position: self.position_tolerance.unwrap_or(defaults.position),
+            rotation: self.rotation_tolerance.unwrap_or(defaults.rotation),
+            linear_velocity: self
+                .linear_velocity_tolerance
+                .unwrap_or(defaults.linear_velocity),
+            angular_velocity: self
+                .angular_velocity_tolerance
+                .unwrap_or(defaults.angular_velocity),
+            contact_point: self
+                .contact_point_tolerance
+                .unwrap_or(defaults.contact_point),
+            contact_normal: self
+                .contact_normal_tolerance
+                .unwrap_or(defaults.contact_normal),
+            contact_depth: self
+                .contact_depth_tolerance
+                .unwrap_or(defaults.contact_depth),
+            contact_impulse: self
+                .contact_impulse_tolerance
+                .unwrap_or(defaults.contact_impulse),
+        }
+    }
+}
+
+/// Resolves a run argument: an existing directory is read in place, anything
+/// else is a run id in the default workspace store.
+fn resolve_run(run: &str) -> (ArtifactStore, String) {
+    let path = Path::new(run);
+    match (path.is_dir(), path.parent(), path.file_name()) {
+        (true, Some(parent), Some(run_id)) => (
+            ArtifactStore::new(parent),
+            run_id.to_string_lossy().into_owned(),
+        ),
+        _ => (ArtifactStore::default_in_workspace(), run.to_owned()),
+    }
+}
+
 pub async fn run() -> LabResult<()> {
     match Cli::parse().command {
         Command::List => {
@@ -45,25 +141,101 @@ pub async fn run() -> LabResult<()> {
             }
             Ok(())
         }
-        Command::Run { scenario, frames } => {
-            let scenario_id = ScenarioId::from_str(&scenario)?;
+        Command::Run {
+            scenario,
+            fixture,
+            frames,
+        } => {
+            let config = match (scenario, fixture) {
+                (_, Some(path)) => RunConfig::from_fixture(FixtureSource::from_path(path)?),
+                (Some(scenario), None) => RunConfig {
+                    source: RunSource::Builtin(ScenarioId::from_str(&scenario)?),
+                    ..RunConfig::default()
+                },
+                (None, None) => {
+                    return Err(LabError::InvalidScenarioSource(
+                        "pass a scenario or --fixture".to_owned(),
+                    ))
+                }
+            };
             let result = run_scenario(
                 &ArtifactStore::default_in_workspace(),
                 RunConfig {
-                    scenario_id,
                     frame_count: frames,
-                    ..RunConfig::default()
+                    ..config
                 },
             )?;
             println!("{}", result.path.display());
             Ok(())
         }
-        Command::Serve { bind } => {
+        Command::Serve { bind, fixture_root } => {
             let listener = TcpListener::bind(bind).await?;
-            let state = LabServerState::new(ArtifactStore::default_in_workspace());
+            let mut state = LabServerState::new(ArtifactStore::default_in_workspace());
+            if let Some(root) = fixture_root {
+                state = state.with_fixture_root(root);
+            }
             println!("listening on http://{bind}");
             serve(listener, app(state)).await?;
             Ok(())
         }
+        Command::Compare {
+            run_a,
+            run_b,
+            tolerances,
+        } => {
+            let (store_a, run_id_a) = resolve_run(&run_a);
+            let (store_b, run_id_b) = resolve_run(&run_b);
+            let comparison = compare_runs(
+                (&store_a, &run_id_a),
+                (&store_b, &run_id_b),
+                &tolerances.tolerances(),
+            )?;
+            println!("{comparison}");
+            match comparison.divergence {
+                Some(divergence) => Err(LabError::RunsDiverged(format!(
+                    "{run_a} and {run_b} at frame {}",
+                    divergence.frame_index
+                ))),
+                None => Ok(()),
+            }
+        }
+        Command::Verify {
+            golden,
+            out,
+            tolerances,
+        } => {
+            let candidates = out
+                .map(ArtifactStore::new)
+                .unwrap_or_else(ArtifactStore::default_in_workspace);
+            let verifications = verify_golden_runs(
+                &ArtifactStore::new(&golden),
+                &candidates,
+                &tolerances.tolerances(),
+            )?;
+            if verifications.is_empty() {
+                return Err(LabError::NoGoldenRuns(golden));
+            }
+            let mut diverged = 0;
+            for verification in &verifications {
+                println!(
+                    "{} -> {}",
+                    verification.golden_run_id,
+                    candidates
+                        .run_path(&verification.candidate_run_id)
+                        .display()
+                );
+                println!("{}", verification.comparison);
+                if verification.comparison.diverged() {
+                    diverged += 1;
+                }
+            }
+            if diverged > 0 {
+                return Err(LabError::RunsDiverged(format!(
+                    "{diverged} of {} golden runs",
+                    verifications.len()
+                )));
+            }
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,634 @@
+//! Golden-run regression comparison between recorded artifacts.
+//!
+//! Frames are compared in order. Equal `state_hash` values short-circuit;
+//! otherwise body and contact facts are diffed field by field against numeric
+//! tolerances, so float noise below the tolerance does not count as a
+//! divergence. A hash mismatch with identical bodies and contacts comes from a
+//! section that is not diffed (joints, colliders, manifolds, ...) and always
+//! diverges. The first diverging frame is reported.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+};
+
+use picea::{
+    debug::{DebugBody, DebugContact},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    artifact::{run_scenario, ArtifactStore, FrameRecord, RunManifest},
+    scenario::{FixtureSource, RunConfig, RunSource},
+    LabError, LabResult,
+};
+
+/// Largest absolute difference accepted for each compared fact.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompareTolerances {
+    pub position: FloatNum,
+    pub rotation: FloatNum,
+    pub linear_velocity: FloatNum,
+    pub angular_velocity: FloatNum,
+    pub contact_point: FloatNum,
+    pub contact_normal: FloatNum,
+    pub contact_depth: FloatNum,
+    pub contact_impulse: FloatNum,
+}
+
+impl Default for CompareTolerances {
+    fn default() -> Self {
+        Self {
+            position: 1.0e-5,
+            rotation: 1.0e-5,
+            linear_velocity: 1.0e-5,
+            angular_velocity: 1.0e-5,
+            contact_point: 1.0e-5,
+            contact_normal: 1.0e-5,
+            contact_depth: 1.0e-5,
+            contact_impulse: 1.0e-5,
+        }
+    }
+}
+
+/// Outcome of comparing a reference run against a candidate run.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunComparison {
+    pub frames_compared: usize,
+    /// Frames whose hashes differ only because bodies or contacts moved within
+    /// tolerance.
+    pub tolerated_frames: Vec<usize>,
+    pub divergence: Option<FrameDivergence>,
+}
+
+impl RunComparison {
+    pub fn diverged(&self) -> bool {
+        self.divergence.is_some()
+    }
+}
+
+/// First frame where the candidate run left the reference run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FrameDivergence {
+    pub frame_index: usize,
+    /// `None` when the reference run has no frame at this index.
+    pub expected_state_hash: Option<String>,
+    /// `None` when the candidate run has no frame at this index.
+    pub actual_state_hash: Option<String>,
+    pub bodies: Vec<BodyDivergence>,
+    pub contacts: Vec<ContactDivergence>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BodyDivergence {
+    pub handle: BodyHandle,
+    pub difference: FactDifference,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContactDivergence {
+    pub id: ContactId,
+    pub colliders: [ColliderHandle; 2],
+    pub difference: FactDifference,
+}
+
+/// How one body or contact differs between the reference and candidate frame.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FactDifference {
+    /// Present in the reference frame only.
+    Missing,
+    /// Present in the candidate frame only.
+    Unexpected,
+    Fields {
+        fields: Vec<FieldDifference>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FieldDifference {
+    pub field: String,
+    pub expected: FloatNum,
+    pub actual: FloatNum,
+    pub tolerance: FloatNum,
+}
+
+/// Compares two frame streams, treating `expected` as the reference.
+pub fn compare_frames(
+    expected: &[FrameRecord],
+    actual: &[FrameRecord],
+    tolerances: &CompareTolerances,
+) -> RunComparison {
+    let mut comparison = RunComparison::default();
+    for frame_index in 0..expected.len().max(actual.len()) {
+        comparison.frames_compared = frame_index + 1;
+        let (expected_frame, actual_frame) =
+            match (expected.get(frame_index), actual.get(frame_index)) {
+                (Some(expected_frame), Some(actual_frame)) => (expected_frame, actual_frame),
+                (expected_frame, actual_frame) => {
+                    comparison.divergence = Some(FrameDivergence {
+                        frame_index,
+                        expected_state_hash: expected_frame.map(|frame| frame.state_hash.clone()),
+                        actual_state_hash: actual_frame.map(|frame| frame.state_hash.clone()),
+                        bodies: Vec::new(),
+                        contacts: Vec::new(),
+                    });
+                    break;
+                }
+            };
+        if expected_frame.state_hash == actual_frame.state_hash {
+            continue;
+        }
+
+        let bodies = diff_facts(
+            &expected_frame.snapshot.bodies,
+            &actual_frame.snapshot.bodies,
+            |body| body.handle,
+            |expected, actual| body_fields(expected, actual, tolerances),
+        )
+        .into_iter()
+        .map(|(handle, difference)| BodyDivergence { handle, difference })
+        .collect::<Vec<_>>();
+        let contacts = diff_facts(
+            &expected_frame.snapshot.contacts,
+            &actual_frame.snapshot.contacts,
+            |contact| contact.id,
+            |expected, actual| contact_fields(expected, actual, tolerances),
+        )
+        .into_iter()
+        .map(|(id, difference)| ContactDivergence {
+            id,
+            colliders: expected_frame
+                .snapshot
+                .contacts
+                .iter()
+                .chain(&actual_frame.snapshot.contacts)
+                .find(|contact| contact.id == id)
+                .map(|contact| contact.colliders)
+                .unwrap_or_default(),
+            difference,
+        })
+        .collect::<Vec<_>>();
+
+        // Within-tolerance body or contact noise also moves the derived
+        // sections, so it explains the hash change; nothing else does.
+        let explained = expected_frame.snapshot.bodies != actual_frame.snapshot.bodies
+            || expected_frame.snapshot.contacts != actual_frame.snapshot.contacts;
+        if bodies.is_empty() && contacts.is_empty() && explained {
+            comparison.tolerated_frames.push(frame_index);
+            continue;
+        }
+        comparison.divergence = Some(FrameDivergence {
+            frame_index,
+            expected_state_hash: Some(expected_frame.state_hash.clone()),
+            actual_state_hash: Some(actual_frame.state_hash.clone()),
+            bodies,
+            contacts,
+        });
+        break;
+    }
+    comparison
+}
+
+/// Compares two recorded runs, treating `expected` as the reference.
+pub fn compare_runs(
+    expected: (&ArtifactStore, &str),
+    actual: (&ArtifactStore, &str),
+    tolerances: &CompareTolerances,
+) -> LabResult<RunComparison> {
+    let expected = expected.0.read_frames(expected.1)?;
+    let actual = actual.0.read_frames(actual.1)?;
+    Ok(compare_frames(&expected, &actual, tolerances))
+}
+
+/// Result of replaying one golden run with the current engine.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GoldenVerification {
+    pub golden_run_id: String,
+    pub candidate_run_id: String,
+    pub comparison: RunComparison,
+}
+
+/// Replays every run recorded under `golden` into `candidates` and compares
+/// the fresh frames against the recorded ones.
+pub fn verify_golden_runs(
+    golden: &ArtifactStore,
+    candidates: &ArtifactStore,
+    tolerances: &CompareTolerances,
+) -> LabResult<Vec<GoldenVerification>> {
+    golden
+        .run_ids()?
+        .into_iter()
+        .map(|golden_run_id| {
+            let manifest = golden.read_manifest(&golden_run_id)?;
+            let candidate = run_scenario(candidates, replay_config(&manifest)?)?;
+            let expected = golden.read_frames(&golden_run_id)?;
+            Ok(GoldenVerification {
+                golden_run_id,
+                candidate_run_id: candidate.manifest.run_id,
+                comparison: compare_frames(&expected, &candidate.frames, tolerances),
+            })
+        })
+        .collect()
+}
+
+/// Rebuilds the run config a manifest was recorded with. Fixture runs are
+/// re-read from their recorded path and must still hash to the recorded value.
+fn replay_config(manifest: &RunManifest) -> LabResult<RunConfig> {
+    let source = match (&manifest.fixture, manifest.scenario_id) {
+        (None, Some(id)) => RunSource::Builtin(id),
+        (None, None) => {
+            return Err(LabError::InvalidScenarioSource(format!(
+                "golden run {} records neither a scenario nor a fixture",
+                manifest.run_id
+            )))
+        }
+        (Some(provenance), _) => {
+            let Some(path) = &provenance.path else {
+                return Err(LabError::InvalidScenarioSource(format!(
+                    "golden run {} used an inline fixture and cannot be replayed",
+                    manifest.run_id
+                )));
+            };
+            let fixture = FixtureSource::from_path(path)?;
+            if fixture.hash != provenance.hash {
+                return Err(LabError::InvalidScenarioSource(format!(
+                    "fixture {path} changed since golden run {} (hash {}, recorded {})",
+                    manifest.run_id, fixture.hash, provenance.hash
+                )));
+            }
+            RunSource::Fixture(fixture)
+        }
+    };
+    Ok(RunConfig {
+        source,
+        frame_count: manifest.frame_count,
+        run_id: None,
+        overrides: manifest.overrides.clone(),
+    })
+}
+
+/// Pairs facts by key and keeps the ones that are missing, unexpected, or have
+/// out-of-tolerance fields. Results are ordered by key.
+fn diff_facts<T, K: Ord>(
+    expected: &[T],
+    actual: &[T],
+    key: impl Fn(&T) -> K,
+    fields: impl Fn(&T, &T) -> Vec<FieldDifference>,
+) -> Vec<(K, FactDifference)> {
+    let mut pairs = BTreeMap::<K, (Option<&T>, Option<&T>)>::new();
+    for fact in expected {
+        pairs.entry(key(fact)).or_default().0 = Some(fact);
+    }
+    for fact in actual {
+        pairs.entry(key(fact)).or_default().1 = Some(fact);
+    }
+    pairs
+        .into_iter()
+        .filter_map(|(key, pair)| {
+            let difference = match pair {
+                (Some(expected), Some(actual)) => {
+                    let fields = fields(expected, actual);
+                    if fields.is_empty() {
+                        return None;
+                    }
+                    FactDifference::Fields { fields }
+                }
+                (Some(_), None) => FactDifference::Missing,
+                (None, _) => FactDifference::Unexpected,
+            };
+            Some((key, difference))
+        })
+        .collect()
+}
+
+fn body_fields(
+    expected: &DebugBody,
+    actual: &DebugBody,
+    tolerances: &CompareTolerances,
+) -> Vec<FieldDifference> {
+    let mut fields = Vec::new();
+    let translation = (expected.transform.translation, actual.transform.translation);
+    let velocity = (expected.linear_velocity, actual.linear_velocity);
+    for (field, expected, actual, tolerance) in [
+        (
+            "transform.translation.x",
+            translation.0.x(),
+            translation.1.x(),
+            tolerances.position,
+        ),
+        (
+            "transform.translation.y",
+            translation.0.y(),
+            translation.1.y(),
+            tolerances.position,
+        ),
+        (
+            "transform.rotation",
+            expected.transform.rotation,
+            actual.transform.rotation,
+            tolerances.rotation,
+        ),
+        (
+            "linear_velocity.x",
+            velocity.0.x(),
+            velocity.1.x(),
+            tolerances.linear_velocity,
+        ),
+        (
+            "linear_velocity.y",
+            velocity.0.y(),
+            velocity.1.y(),
+            tolerances.linear_velocity,
+        ),
+        (
+            "angular_velocity",
+            expected.angular_velocity,
+            actual.angular_velocity,
+            tolerances.angular_velocity,
+        ),
+        (
+            "sleeping",
+            FloatNum::from(u8::from(expected.sleeping)),
+            FloatNum::from(u8::from(actual.sleeping)),
+            0.0,
+        ),
+    ] {
+        push_field(&mut fields, field, expected, actual, tolerance);
+    }
+    fields
+}
+
+fn contact_fields(
+    expected: &DebugContact,
+    actual: &DebugContact,
+    tolerances: &CompareTolerances,
+) -> Vec<FieldDifference> {
+    let mut fields = Vec::new();
+    for (field, expected, actual, tolerance) in [
+        (
+            "point.x",
+            expected.point.x(),
+            actual.point.x(),
+            tolerances.contact_point,
+        ),
+        (
+            "point.y",
+            expected.point.y(),
+            actual.point.y(),
+            tolerances.contact_point,
+        ),
+        (
+            "normal.x",
+            expected.normal.x(),
+            actual.normal.x(),
+            tolerances.contact_normal,
+        ),
+        (
+            "normal.y",
+            expected.normal.y(),
+            actual.normal.y(),
+            tolerances.contact_normal,
+        ),
+        (
+            "depth",
+            expected.depth,
+            actual.depth,
+            tolerances.contact_depth,
+        ),
+        (
+            "solver_normal_impulse",
+            expected.solver_normal_impulse,
+            actual.solver_normal_impulse,
+            tolerances.contact_impulse,
+        ),
+        (
+            "solver_tangent_impulse",
+            expected.solver_tangent_impulse,
+            actual.solver_tangent_impulse,
+            tolerances.contact_impulse,
+        ),
+    ] {
+        push_field(&mut fields, field, expected, actual, tolerance);
+    }
+    fields
+}
+
+fn push_field(
+    fields: &mut Vec<FieldDifference>,
+    field: &str,
+    expected: FloatNum,
+    actual: FloatNum,
+    tolerance: FloatNum,
+) {
+    // NaN never compares within tolerance, so a NaN on either side diverges
+    // unless both sides are NaN.
+    let within = (expected - actual).abs() <= tolerance || (expected.is_nan() && actual.is_nan());
+    if !within {
+        fields.push(FieldDifference {
+            field: field.to_owned(),
+            expected,
+            actual,
+            tolerance,
+        });
+    }
+}
+
+impl Display for RunComparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "frames compared: {}", self.frames_compared)?;
+        if !self.tolerated_frames.is_empty() {
+            write!(f, " ({} within tolerance)", self.tolerated_frames.len())?;
+        }
+        match &self.divergence {
+            None => write!(f, "\nno divergence"),
+            Some(divergence) => write!(f, "\n{divergence}"),
+        }
+    }
+}
+
+impl Display for FrameDivergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let hash = |hash: &Option<String>| hash.as_deref().unwrap_or("<no frame>").to_owned();
+        write!(
+            f,
+            "first divergence at frame {} (state hash {} != {})",
+            self.frame_index,
+            hash(&self.expected_state_hash),
+            hash(&self.actual_state_hash)
+        )?;
+        for body in &self.bodies {
+            write!(f, "\n  body {}: ", handle_label(&body.handle))?;
+            write_difference(f, &body.difference)?;
+        }
+        for contact in &self.contacts {
+            write!(
+                f,
+                "\n  contact {} (colliders {} / {}): ",
+                handle_label(&contact.id),
+                handle_label(&contact.colliders[0]),
+                handle_label(&contact.colliders[1])
+            )?;
+            write_difference(f, &contact.difference)?;
+        }
+        let both_recorded = self.expected_state_hash.is_some() && self.actual_state_hash.is_some();
+        if both_recorded && self.bodies.is_empty() && self.contacts.is_empty() {
+            write!(
+                f,
+                "\n  bodies and contacts match; the difference is in another snapshot section"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn write_difference(f: &mut Formatter<'_>, difference: &FactDifference) -> std::fmt::Result {
+    match difference {
+        FactDifference::Missing => write!(f, "missing from the candidate run"),
+        FactDifference::Unexpected => write!(f, "only in the candidate run"),
+        FactDifference::Fields { fields } => {
+            for (index, field) in fields.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(
+                    f,
+                    "{} expected {} got {} (tolerance {})",
+                    field.field, field.expected, field.actual, field.tolerance
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Handles serialize as their raw id, which is what artifacts show.
+fn handle_label(handle: &impl Serialize) -> String {
+    serde_json::to_string(handle).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::ScenarioId;
+
+    fn recorded_frames(scenario_id: ScenarioId, frame_count: usize) -> Vec<FrameRecord> {
+        let temp = tempfile::tempdir().expect("temp dir should be created");
+        run_scenario(
+            &ArtifactStore::new(temp.path()),
+            RunConfig {
+                source: RunSource::Builtin(scenario_id),
+                frame_count,
+                ..RunConfig::default()
+            },
+        )
+        .expect("scenario should run")
+        .frames
+    }
+
+    #[test]
+    fn identical_runs_do_not_diverge() {
+        let frames = recorded_frames(ScenarioId::FallingBoxContact, 6);
+
+        let comparison = compare_frames(&frames, &frames, &CompareTolerances::default());
+
+        assert_eq!(comparison.frames_compared, 6);
+        assert!(comparison.tolerated_frames.is_empty());
+        assert!(!comparison.diverged());
+    }
+
+    #[test]
+    fn body_drift_is_reported_at_the_first_frame_beyond_tolerance() {
+        let expected = recorded_frames(ScenarioId::FallingBoxContact, 6);
+        let mut actual = expected.clone();
+        let frame = &mut actual[3];
+        frame.state_hash = "drifted".to_owned();
+        let body = frame
+            .snapshot
+            .bodies
+            .last_mut()
+            .expect("scenario has bodies");
+        let translation = body.transform.translation;
+        body.transform.translation = Vector::new(translation.x(), translation.y() + 1.0e-3);
+        let handle = body.handle;
+
+        let comparison = compare_frames(&expected, &actual, &CompareTolerances::default());
+        let divergence = comparison.divergence.expect("drift should diverge");
+        assert_eq!(divergence.frame_index, 3);
+        assert_eq!(divergence.actual_state_hash.as_deref(), Some("drifted"));
+        assert!(divergence.contacts.is_empty());
+        assert_eq!(divergence.bodies.len(), 1);
+        assert_eq!(divergence.bodies[0].handle, handle);
+        let FactDifference::Fields { fields } = &divergence.bodies[0].difference else {
+            panic!("a shared body should report field differences");
+        };
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].field, "transform.translation.y");
+
+        let loose = CompareTolerances {
+            position: 1.0e-2,
+            ..CompareTolerances::default()
+        };
+        let comparison = compare_frames(&expected, &actual, &loose);
+        assert_eq!(comparison.tolerated_frames, vec![3]);
+        assert!(!comparison.diverged());
+    }
+
+    #[test]
+    fn contact_and_length_differences_diverge() {
+        let expected = recorded_frames(ScenarioId::Stack4, 30);
+        let frame_index = expected
+            .iter()
+            .position(|frame| !frame.snapshot.contacts.is_empty())
+            .expect("stack should make contact");
+        let mut actual = expected.clone();
+        actual[frame_index].state_hash = "contact-dropped".to_owned();
+        let dropped = actual[frame_index].snapshot.contacts.remove(0);
+
+        let divergence = compare_frames(&expected, &actual, &CompareTolerances::default())
+            .divergence
+            .expect("a dropped contact should diverge");
+        assert_eq!(divergence.frame_index, frame_index);
+        assert_eq!(divergence.contacts.len(), 1);
+        assert_eq!(divergence.contacts[0].id, dropped.id);
+        assert_eq!(divergence.contacts[0].colliders, dropped.colliders);
+        assert_eq!(divergence.contacts[0].difference, FactDifference::Missing);
+        assert!(divergence
+            .to_string()
+            .contains("missing from the candidate run"));
+
+        let divergence = compare_frames(&expected, &expected[..10], &CompareTolerances::default())
+            .divergence
+            .expect("a shorter run should diverge");
+        assert_eq!(divergence.frame_index, 10);
+        assert_eq!(divergence.actual_state_hash, None);
+    }
+
+    #[test]
+    fn hash_changes_outside_bodies_and_contacts_diverge() {
+        let expected = recorded_frames(ScenarioId::FallingBoxContact, 6);
+        let mut actual = expected.clone();
+        let frame = &mut actual[2];
+        frame.state_hash = "collider-changed".to_owned();
+        frame
+            .snapshot
+            .colliders
+            .first_mut()
+            .expect("scenario has colliders")
+            .material
+            .friction += 0.25;
+
+        let comparison = compare_frames(&expected, &actual, &CompareTolerances::default());
+        assert!(comparison.tolerated_frames.is_empty());
+        let divergence = comparison
+            .divergence
+            .expect("an unexplained hash change should diverge");
+        assert_eq!(divergence.frame_index, 2);
+        assert!(divergence.bodies.is_empty());
+        assert!(divergence.contacts.is_empty());
+        assert!(divergence
+            .to_string()
+            .contains("the difference is in another snapshot section"));
+    }
+}
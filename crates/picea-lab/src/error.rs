@@ -1,4 +1,7 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+};
 
 /// Result alias for lab operations that touch scenarios, artifacts, or sessions.
 pub type LabResult<T> = Result<T, LabError>;
@@ -14,7 +17,15 @@ pub enum LabError {
     InvalidArtifactFile(String),
     SessionNotFound(String),
     InvalidControlAction(String),
+    InvalidControlRequest(String),
+    InvalidOverrides(String),
+    InvalidFixture(String),
+    InvalidScenarioSource(String),
+    InvalidCommand(String),
+    SessionNotLive(String),
     World(String),
+    RunsDiverged(String),
+    NoGoldenRuns(PathBuf),
 }
 
 impl Display for LabError {
@@ -32,7 +43,15 @@ impl Display for LabError {
             Self::InvalidArtifactFile(file) => write!(f, "invalid artifact file: {file}"),
             Self::SessionNotFound(id) => write!(f, "session not found: {id}"),
             Self::InvalidControlAction(action) => write!(f, "invalid control action: {action}"),
+            Self::InvalidControlRequest(error) => write!(f, "invalid control request: {error}"),
+            Self::InvalidOverrides(error) => write!(f, "invalid overrides: {error}"),
+            Self::InvalidFixture(error) => write!(f, "invalid scene fixture: {error}"),
+            Self::InvalidScenarioSource(error) => write!(f, "invalid scenario source: {error}"),
+            Self::InvalidCommand(error) => write!(f, "invalid world command: {error}"),
+            Self::SessionNotLive(id) => write!(f, "session is not live: {id}"),
             Self::World(error) => write!(f, "world setup failed: {error}"),
+            Self::RunsDiverged(summary) => write!(f, "runs diverged: {summary}"),
+            Self::NoGoldenRuns(path) => write!(f, "no golden runs under {}", path.display()),
         }
     }
 }
@@ -0,0 +1,1255 @@
+//! Scene fixture schema v2 and the versioned fixture document.
+//!
+//! Schema v2 maps one-to-one onto the core descriptors: a body carries every
+//! `BodyDesc` field plus a list of colliders, and each collider carries every
+//! `ColliderDesc` field. Vectors stay `[x, y]` arrays and poses stay
+//! `[x, y, angle]` arrays like schema v1. Schema v1 documents keep loading
+//! through [`SceneRecipeFixture::migrate`], which runs the v1 validation first
+//! so v1 authoring errors keep their `scene.bodies[i].shape` paths.
+
+use picea::prelude::*;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+
+use crate::{
+    scenario::{
+        default_fixture_can_sleep, default_fixture_density, default_fixture_enable_sleep,
+        default_fixture_gravity, point_from_array, point_to_array, points_from_arrays,
+        pose_from_array, pose_to_array, validate_concave_vertices, validate_convex_vertices,
+        validate_local_pose, vector_to_array, CompoundProvenance, CompoundProvenancePiece,
+        SceneCompoundPieceShapeFixture, SceneJointFixture, SceneRecipeFixture,
+        SCENE_RECIPE_SCHEMA_VERSION,
+    },
+    LabError, LabResult,
+};
+
+/// Schema version of [`SceneRecipeFixtureV2`], the latest scene schema.
+pub const SCENE_RECIPE_SCHEMA_V2: u32 = 2;
+
+const MATERIAL_PRESETS: [MaterialPreset; 5] = [
+    MaterialPreset::Default,
+    MaterialPreset::Ice,
+    MaterialPreset::Rough,
+    MaterialPreset::Bouncy,
+    MaterialPreset::Sticky,
+];
+
+const LAYER_PRESETS: [CollisionLayerPreset; 5] = [
+    CollisionLayerPreset::Default,
+    CollisionLayerPreset::StaticGeometry,
+    CollisionLayerPreset::DynamicBody,
+    CollisionLayerPreset::Sensor,
+    CollisionLayerPreset::QueryOnly,
+];
+
+/// Any supported scene fixture document.
+///
+/// Deserialization dispatches on `schema_version`, which defaults to 1 so
+/// fixtures written before versioning still load. Serialization writes the
+/// document unchanged, so a v1 fixture keeps its v1 JSON and hash.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum SceneFixture {
+    V1(SceneRecipeFixture),
+    V2(SceneRecipeFixtureV2),
+}
+
+impl SceneFixture {
+    pub fn schema_version(&self) -> u32 {
+        match self {
+            Self::V1(fixture) => fixture.schema_version,
+            Self::V2(fixture) => fixture.schema_version,
+        }
+    }
+
+    /// Returns the document as schema v2, migrating v1 documents.
+    pub fn to_v2(&self) -> LabResult<SceneRecipeFixtureV2> {
+        match self {
+            Self::V1(fixture) => fixture.migrate(),
+            Self::V2(fixture) => Ok(fixture.clone()),
+        }
+    }
+
+    pub fn to_world_recipe(&self) -> LabResult<WorldRecipe> {
+        match self {
+            Self::V1(fixture) => fixture.to_world_recipe(),
+            Self::V2(fixture) => fixture.to_world_recipe(),
+        }
+    }
+
+    pub(crate) fn set_gravity(&mut self, gravity: [FloatNum; 2]) {
+        match self {
+            Self::V1(fixture) => fixture.world.gravity = gravity,
+            Self::V2(fixture) => fixture.world.gravity = gravity,
+        }
+    }
+}
+
+impl From<SceneRecipeFixture> for SceneFixture {
+    fn from(value: SceneRecipeFixture) -> Self {
+        Self::V1(value)
+    }
+}
+
+impl From<SceneRecipeFixtureV2> for SceneFixture {
+    fn from(value: SceneRecipeFixtureV2) -> Self {
+        Self::V2(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for SceneFixture {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let schema_version = match value.get("schema_version") {
+            Some(version) => u32::deserialize(version)
+                .map_err(|error| D::Error::custom(format!("schema_version: {error}")))?,
+            None => SCENE_RECIPE_SCHEMA_VERSION,
+        };
+        match schema_version {
+            SCENE_RECIPE_SCHEMA_VERSION => serde_json::from_value(value)
+                .map(Self::V1)
+                .map_err(D::Error::custom),
+            SCENE_RECIPE_SCHEMA_V2 => serde_json::from_value(value)
+                .map(Self::V2)
+                .map_err(D::Error::custom),
+            found => Err(D::Error::custom(LabError::UnsupportedSceneSchemaVersion {
+                found,
+                expected: SCENE_RECIPE_SCHEMA_V2,
+            })),
+        }
+    }
+}
+
+/// Scene fixture schema v2.
+///
+/// Joints reuse the v1 joint schema, which already names every joint
+/// descriptor field.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SceneRecipeFixtureV2 {
+    #[serde(default = "default_scene_recipe_schema_v2")]
+    pub schema_version: u32,
+    #[serde(default)]
+    pub world: SceneFixtureWorldV2,
+    #[serde(default)]
+    pub bodies: Vec<SceneBodyFixtureV2>,
+    #[serde(default)]
+    pub joints: Vec<SceneJointFixture>,
+}
+
+impl Default for SceneRecipeFixtureV2 {
+    fn default() -> Self {
+        Self {
+            schema_version: SCENE_RECIPE_SCHEMA_V2,
+            world: SceneFixtureWorldV2::default(),
+            bodies: Vec::new(),
+            joints: Vec::new(),
+        }
+    }
+}
+
+impl SceneRecipeFixtureV2 {
+    pub fn to_world_recipe(&self) -> LabResult<WorldRecipe> {
+        if self.schema_version != SCENE_RECIPE_SCHEMA_V2 {
+            return Err(LabError::UnsupportedSceneSchemaVersion {
+                found: self.schema_version,
+                expected: SCENE_RECIPE_SCHEMA_V2,
+            });
+        }
+        let mut recipe = WorldRecipe::new(self.world.to_world_desc());
+        for (body_index, body) in self.bodies.iter().enumerate() {
+            recipe = recipe.with_scene_body(body.to_body_bundle(body_index)?);
+        }
+        for joint in &self.joints {
+            recipe = recipe.with_joint(joint.to_joint_bundle());
+        }
+        Ok(recipe)
+    }
+
+    /// Captures a world's current state as an authorable document.
+    ///
+    /// Bodies listed in `compound_provenance` keep their compound marker, so
+    /// loading the exported document reports the same compound provenance.
+    pub fn from_world(world: &World, compound_provenance: &[CompoundProvenance]) -> Self {
+        let recipe = WorldRecipe::from_world(world);
+        let body_handles: Vec<_> = world.bodies().collect();
+        let mut bodies: Vec<_> = recipe
+            .bodies
+            .iter()
+            .map(SceneBodyFixtureV2::from_bundle)
+            .collect();
+        for compound in compound_provenance {
+            let body = compound
+                .body_handle
+                .and_then(|handle| body_handles.iter().position(|body| *body == handle))
+                .and_then(|index| bodies.get_mut(index));
+            if let Some(body) = body {
+                body.compound = Some(SceneCompoundFixture::from_provenance(compound));
+            }
+        }
+        Self {
+            world: SceneFixtureWorldV2::from_world_desc(&recipe.desc),
+            bodies,
+            joints: recipe
+                .joints
+                .iter()
+                .map(SceneJointFixture::from_joint_bundle)
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Compound provenance for bodies carrying a compound marker; pieces are
+    /// the body's colliders in authored order.
+    pub(crate) fn compound_provenance(
+        &self,
+        created: &WorldCommandReport,
+    ) -> Vec<CompoundProvenance> {
+        let mut collider_cursor = 0usize;
+        let mut provenance = Vec::new();
+
+        for (body_index, body) in self.bodies.iter().enumerate() {
+            if let Some(compound) = &body.compound {
+                provenance.push(CompoundProvenance {
+                    authored_body_index: body_index,
+                    body_handle: created.body_handles.get(body_index).copied(),
+                    validation_path: format!("scene.bodies[{body_index}].colliders"),
+                    inherited_material: compound.material,
+                    inherited_filter: compound.filter,
+                    inherited_density: compound.density,
+                    inherited_is_sensor: compound.is_sensor,
+                    pieces: body
+                        .colliders
+                        .iter()
+                        .enumerate()
+                        .map(|(piece_index, collider)| CompoundProvenancePiece {
+                            generated_piece_index: piece_index,
+                            collider_handle: created
+                                .collider_handles
+                                .get(collider_cursor + piece_index)
+                                .copied(),
+                            validation_path: format!(
+                                "scene.bodies[{body_index}].colliders[{piece_index}]"
+                            ),
+                            local_pose: collider.local_pose,
+                        })
+                        .collect(),
+                });
+            }
+            collider_cursor += body.colliders.len();
+        }
+
+        provenance
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SceneFixtureWorldV2 {
+    #[serde(default = "default_fixture_gravity")]
+    pub gravity: [FloatNum; 2],
+    #[serde(default)]
+    pub gravity_fields: Vec<SceneGravityFieldFixture>,
+    #[serde(default = "default_fixture_enable_sleep")]
+    pub enable_sleep: bool,
+    #[serde(default)]
+    pub deterministic: bool,
+}
+
+impl Default for SceneFixtureWorldV2 {
+    fn default() -> Self {
+        Self {
+            gravity: default_fixture_gravity(),
+            gravity_fields: Vec::new(),
+            enable_sleep: default_fixture_enable_sleep(),
+            deterministic: false,
+        }
+    }
+}
+
+impl SceneFixtureWorldV2 {
+    fn to_world_desc(&self) -> WorldDesc {
+        WorldDesc {
+            gravity: self.gravity.into(),
+            gravity_fields: self
+                .gravity_fields
+                .iter()
+                .copied()
+                .map(SceneGravityFieldFixture::to_gravity_field)
+                .collect(),
+            enable_sleep: self.enable_sleep,
+            deterministic: self.deterministic,
+        }
+    }
+
+    fn from_world_desc(desc: &WorldDesc) -> Self {
+        Self {
+            gravity: vector_to_array(desc.gravity),
+            gravity_fields: desc
+                .gravity_fields
+                .iter()
+                .map(SceneGravityFieldFixture::from_gravity_field)
+                .collect(),
+            enable_sleep: desc.enable_sleep,
+            deterministic: desc.deterministic,
+        }
+    }
+}
+
+/// Array-shaped mirror of `GravityField`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SceneGravityFieldFixture {
+    Uniform {
+        min: [FloatNum; 2],
+        max: [FloatNum; 2],
+        acceleration: [FloatNum; 2],
+    },
+    Radial {
+        center: [FloatNum; 2],
+        strength: FloatNum,
+        inner_radius: FloatNum,
+        outer_radius: FloatNum,
+        #[serde(default)]
+        falloff: GravityFalloff,
+    },
+}
+
+impl SceneGravityFieldFixture {
+    fn to_gravity_field(self) -> GravityField {
+        match self {
+            Self::Uniform {
+                min,
+                max,
+                acceleration,
+            } => GravityField::Uniform {
+                min: point_from_array(min),
+                max: point_from_array(max),
+                acceleration: acceleration.into(),
+            },
+            Self::Radial {
+                center,
+                strength,
+                inner_radius,
+                outer_radius,
+                falloff,
+            } => GravityField::Radial {
+                center: point_from_array(center),
+                strength,
+                inner_radius,
+                outer_radius,
+                falloff,
+            },
+        }
+    }
+
+    fn from_gravity_field(field: &GravityField) -> Self {
+        match *field {
+            GravityField::Uniform {
+                min,
+                max,
+                acceleration,
+            } => Self::Uniform {
+                min: point_to_array(min),
+                max: point_to_array(max),
+                acceleration: vector_to_array(acceleration),
+            },
+            GravityField::Radial {
+                center,
+                strength,
+                inner_radius,
+                outer_radius,
+                falloff,
+            } => Self::Radial {
+                center: point_to_array(center),
+                strength,
+                inner_radius,
+                outer_radius,
+                falloff,
+            },
+        }
+    }
+}
+
+/// One body with every `BodyDesc` field and its attached colliders.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SceneBodyFixtureV2 {
+    #[serde(default)]
+    pub body_type: BodyType,
+    #[serde(default)]
+    pub pose: [FloatNum; 3],
+    #[serde(default)]
+    pub linear_velocity: [FloatNum; 2],
+    #[serde(default)]
+    pub angular_velocity: FloatNum,
+    #[serde(default)]
+    pub linear_damping: FloatNum,
+    #[serde(default)]
+    pub angular_damping: FloatNum,
+    #[serde(default = "default_gravity_scale")]
+    pub gravity_scale: FloatNum,
+    #[serde(default)]
+    pub gravity_override: Option<[FloatNum; 2]>,
+    #[serde(default = "default_fixture_can_sleep")]
+    pub can_sleep: bool,
+    #[serde(default)]
+    pub sleeping: bool,
+    #[serde(default)]
+    pub sleep_thresholds: Option<SleepParams>,
+    #[serde(default = "default_ccd_enabled")]
+    pub ccd_enabled: bool,
+    #[serde(default)]
+    pub bullet: bool,
+    #[serde(default)]
+    pub ccd_motion_threshold: FloatNum,
+    #[serde(default)]
+    pub mass_override: Option<SceneMassFixture>,
+    #[serde(default)]
+    pub locked_axes: LockedAxes,
+    #[serde(default)]
+    pub user_data: u64,
+    #[serde(default)]
+    pub colliders: Vec<SceneColliderFixture>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compound: Option<SceneCompoundFixture>,
+}
+
+impl Default for SceneBodyFixtureV2 {
+    fn default() -> Self {
+        Self {
+            body_type: BodyType::default(),
+            pose: [0.0; 3],
+            linear_velocity: [0.0; 2],
+            angular_velocity: 0.0,
+            linear_damping: 0.0,
+            angular_damping: 0.0,
+            gravity_scale: default_gravity_scale(),
+            gravity_override: None,
+            can_sleep: default_fixture_can_sleep(),
+            sleeping: false,
+            sleep_thresholds: None,
+            ccd_enabled: default_ccd_enabled(),
+            bullet: false,
+            ccd_motion_threshold: 0.0,
+            mass_override: None,
+            locked_axes: LockedAxes::NONE,
+            user_data: 0,
+            colliders: Vec::new(),
+            compound: None,
+        }
+    }
+}
+
+impl SceneBodyFixtureV2 {
+    pub(crate) fn to_body_bundle(&self, body_index: usize) -> LabResult<BodyBundle> {
+        let mut colliders = Vec::with_capacity(self.colliders.len());
+        for (collider_index, collider) in self.colliders.iter().enumerate() {
+            collider.validate(body_index, collider_index)?;
+            colliders.push(collider.to_collider_bundle());
+        }
+        let [vx, vy] = self.linear_velocity;
+        let desc = BodyDesc {
+            body_type: self.body_type,
+            pose: pose_from_array(self.pose),
+            linear_velocity: Vector::new(vx, vy),
+            angular_velocity: self.angular_velocity,
+            linear_damping: self.linear_damping,
+            angular_damping: self.angular_damping,
+            gravity_scale: self.gravity_scale,
+            gravity_override: self.gravity_override.map(Vector::from),
+            can_sleep: self.can_sleep,
+            sleeping: self.sleeping,
+            sleep_thresholds: self.sleep_thresholds,
+            ccd_enabled: self.ccd_enabled,
+            bullet: self.bullet,
+            ccd_motion_threshold: self.ccd_motion_threshold,
+            mass_override: self.mass_override.map(SceneMassFixture::to_mass_properties),
+            locked_axes: self.locked_axes,
+            user_data: self.user_data,
+        };
+        Ok(BodyBundle::new(desc).with_colliders(colliders))
+    }
+
+    fn from_bundle(bundle: &BodyBundle) -> Self {
+        let desc = &bundle.desc;
+        Self {
+            body_type: desc.body_type,
+            pose: pose_to_array(desc.pose),
+            linear_velocity: vector_to_array(desc.linear_velocity),
+            angular_velocity: desc.angular_velocity,
+            linear_damping: desc.linear_damping,
+            angular_damping: desc.angular_damping,
+            gravity_scale: desc.gravity_scale,
+            gravity_override: desc.gravity_override.map(vector_to_array),
+            can_sleep: desc.can_sleep,
+            sleeping: desc.sleeping,
+            sleep_thresholds: desc.sleep_thresholds,
+            ccd_enabled: desc.ccd_enabled,
+            bullet: desc.bullet,
+            ccd_motion_threshold: desc.ccd_motion_threshold,
+            mass_override: desc
+                .mass_override
+                .map(SceneMassFixture::from_mass_properties),
+            locked_axes: desc.locked_axes,
+            user_data: desc.user_data,
+            colliders: bundle
+                .colliders
+                .iter()
+                .map(|collider| SceneColliderFixture::from_desc(&collider.desc))
+                .collect(),
+            compound: None,
+        }
+    }
+}
+
+/// Marks a body whose colliders are the pieces of one authored compound.
+///
+/// Schema v2 colliders carry their own material and filter; the marker only
+/// records the body-level values the pieces inherited when the compound was
+/// authored, so compound provenance survives v1 migration and world export.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SceneCompoundFixture {
+    #[serde(default)]
+    pub material: MaterialPreset,
+    #[serde(default)]
+    pub filter: CollisionLayerPreset,
+    #[serde(default = "default_fixture_density")]
+    pub density: FloatNum,
+    #[serde(default)]
+    pub is_sensor: bool,
+}
+
+impl SceneCompoundFixture {
+    fn from_provenance(provenance: &CompoundProvenance) -> Self {
+        Self {
+            material: provenance.inherited_material,
+            filter: provenance.inherited_filter,
+            density: provenance.inherited_density,
+            is_sensor: provenance.inherited_is_sensor,
+        }
+    }
+}
+
+/// Authored mass override; the inverse terms are derived by the core.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SceneMassFixture {
+    pub mass: FloatNum,
+    #[serde(default)]
+    pub local_center_of_mass: [FloatNum; 2],
+    pub inertia: FloatNum,
+}
+
+impl SceneMassFixture {
+    fn to_mass_properties(self) -> MassProperties {
+        MassProperties {
+            mass: self.mass,
+            local_center_of_mass: point_from_array(self.local_center_of_mass),
+            inertia: self.inertia,
+            ..MassProperties::default()
+        }
+    }
+
+    fn from_mass_properties(mass: MassProperties) -> Self {
+        Self {
+            mass: mass.mass,
+            local_center_of_mass: point_to_array(mass.local_center_of_mass),
+            inertia: mass.inertia,
+        }
+    }
+}
+
+/// One collider with every `ColliderDesc` field.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SceneColliderFixture {
+    pub shape: SceneColliderShapeFixture,
+    #[serde(default)]
+    pub local_pose: [FloatNum; 3],
+    #[serde(default = "default_fixture_density")]
+    pub density: FloatNum,
+    #[serde(default)]
+    pub mass: Option<FloatNum>,
+    #[serde(default)]
+    pub material: SceneMaterialFixture,
+    #[serde(default)]
+    pub filter: SceneFilterFixture,
+    #[serde(default)]
+    pub is_sensor: bool,
+    #[serde(default)]
+    pub fluid: Option<FluidVolume>,
+    #[serde(default)]
+    pub user_data: u64,
+}
+
+impl SceneColliderFixture {
+    /// Collider with the core descriptor defaults around `shape`.
+    pub fn new(shape: SceneColliderShapeFixture) -> Self {
+        Self {
+            shape,
+            local_pose: [0.0; 3],
+            density: default_fixture_density(),
+            mass: None,
+            material: SceneMaterialFixture::default(),
+            filter: SceneFilterFixture::default(),
+            is_sensor: false,
+            fluid: None,
+            user_data: 0,
+        }
+    }
+
+    fn validate(&self, body_index: usize, collider_index: usize) -> LabResult<()> {
+        let path = format!("scene.bodies[{body_index}].colliders[{collider_index}]");
+        self.shape.validate(&format!("{path}.shape"))?;
+        validate_local_pose(&format!("{path}.local_pose"), self.local_pose)
+    }
+
+    fn to_collider_bundle(&self) -> ColliderBundle {
+        ColliderBundle::from_desc(ColliderDesc {
+            shape: self.shape.to_shared_shape(),
+            local_pose: pose_from_array(self.local_pose),
+            density: self.density,
+            mass: self.mass,
+            material: self.material.into(),
+            filter: self.filter.into(),
+            is_sensor: self.is_sensor,
+            fluid: self.fluid,
+            user_data: self.user_data,
+        })
+    }
+
+    fn from_desc(desc: &ColliderDesc) -> Self {
+        Self {
+            shape: SceneColliderShapeFixture::from_shape(&desc.shape),
+            local_pose: pose_to_array(desc.local_pose),
+            density: desc.density,
+            mass: desc.mass,
+            material: desc.material.into(),
+            filter: desc.filter.into(),
+            is_sensor: desc.is_sensor,
+            fluid: desc.fluid,
+            user_data: desc.user_data,
+        }
+    }
+}
+
+/// Every `SharedShape`, authored with the lab's array conventions.
+///
+/// Scalar shape fields are validated by the core and reported with recipe
+/// paths; vertex loops are checked here so their errors name the authored
+/// vertices.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SceneColliderShapeFixture {
+    Circle {
+        radius: FloatNum,
+    },
+    Rect {
+        width: FloatNum,
+        height: FloatNum,
+    },
+    RegularPolygon {
+        sides: usize,
+        radius: FloatNum,
+    },
+    ConvexPolygon {
+        vertices: Vec<[FloatNum; 2]>,
+    },
+    ConcavePolygon {
+        vertices: Vec<[FloatNum; 2]>,
+    },
+    Segment {
+        start: [FloatNum; 2],
+        end: [FloatNum; 2],
+    },
+}
+
+impl SceneColliderShapeFixture {
+    fn validate(&self, path: &str) -> LabResult<()> {
+        match self {
+            Self::ConvexPolygon { vertices } => {
+                validate_convex_vertices(&format!("{path}.vertices"), vertices)
+            }
+            Self::ConcavePolygon { vertices } => {
+                validate_concave_vertices(&format!("{path}.vertices"), vertices)
+            }
+            Self::Circle { .. }
+            | Self::Rect { .. }
+            | Self::RegularPolygon { .. }
+            | Self::Segment { .. } => Ok(()),
+        }
+    }
+
+    fn to_shared_shape(&self) -> SharedShape {
+        match self {
+            Self::Circle { radius } => SharedShape::circle(*radius),
+            Self::Rect { width, height } => SharedShape::rect(*width, *height),
+            Self::RegularPolygon { sides, radius } => SharedShape::regular_polygon(*sides, *radius),
+            Self::ConvexPolygon { vertices } => {
+                SharedShape::convex_polygon(points_from_arrays(vertices))
+            }
+            Self::ConcavePolygon { vertices } => {
+                SharedShape::concave_polygon(points_from_arrays(vertices))
+            }
+            Self::Segment { start, end } => {
+                SharedShape::segment(point_from_array(*start), point_from_array(*end))
+            }
+        }
+    }
+
+    fn from_shape(shape: &SharedShape) -> Self {
+        match shape {
+            SharedShape::Circle { radius } => Self::Circle { radius: *radius },
+            // Doubling a half extent is exact, so widths survive a round trip.
+            SharedShape::Rect { half_extents } => Self::Rect {
+                width: half_extents.x() * 2.0,
+                height: half_extents.y() * 2.0,
+            },
+            SharedShape::RegularPolygon { sides, radius } => Self::RegularPolygon {
+                sides: *sides,
+                radius: *radius,
+            },
+            SharedShape::ConvexPolygon { vertices } => Self::ConvexPolygon {
+                vertices: vertices.iter().copied().map(point_to_array).collect(),
+            },
+            SharedShape::ConcavePolygon { vertices } => Self::ConcavePolygon {
+                vertices: vertices.iter().copied().map(point_to_array).collect(),
+            },
+            SharedShape::Segment { start, end } => Self::Segment {
+                start: point_to_array(*start),
+                end: point_to_array(*end),
+            },
+        }
+    }
+}
+
+impl From<&SceneCompoundPieceShapeFixture> for SceneColliderShapeFixture {
+    fn from(value: &SceneCompoundPieceShapeFixture) -> Self {
+        match value {
+            SceneCompoundPieceShapeFixture::Circle { radius } => Self::Circle { radius: *radius },
+            SceneCompoundPieceShapeFixture::Rect { width, height } => Self::Rect {
+                width: *width,
+                height: *height,
+            },
+            SceneCompoundPieceShapeFixture::ConvexPolygon { vertices } => Self::ConvexPolygon {
+                vertices: vertices.clone(),
+            },
+        }
+    }
+}
+
+/// A named material preset or explicit coefficients.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SceneMaterialFixture {
+    Preset(MaterialPreset),
+    Custom(Material),
+}
+
+impl Default for SceneMaterialFixture {
+    fn default() -> Self {
+        Self::Preset(MaterialPreset::default())
+    }
+}
+
+/// Exported materials keep their preset name when they match one exactly.
+impl From<Material> for SceneMaterialFixture {
+    fn from(value: Material) -> Self {
+        MATERIAL_PRESETS
+            .into_iter()
+            .find(|preset| Material::preset(*preset) == value)
+            .map_or(Self::Custom(value), Self::Preset)
+    }
+}
+
+impl From<SceneMaterialFixture> for Material {
+    fn from(value: SceneMaterialFixture) -> Self {
+        match value {
+            SceneMaterialFixture::Preset(preset) => preset.into(),
+            SceneMaterialFixture::Custom(material) => material,
+        }
+    }
+}
+
+/// A named collision-layer preset or explicit filter bits.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SceneFilterFixture {
+    Preset(CollisionLayerPreset),
+    Bits(CollisionFilter),
+}
+
+impl Default for SceneFilterFixture {
+    fn default() -> Self {
+        Self::Preset(CollisionLayerPreset::default())
+    }
+}
+
+/// Exported filters keep their preset name when they match one exactly.
+impl From<CollisionFilter> for SceneFilterFixture {
+    fn from(value: CollisionFilter) -> Self {
+        LAYER_PRESETS
+            .into_iter()
+            .find(|preset| CollisionFilter::preset(*preset) == value)
+            .map_or(Self::Bits(value), Self::Preset)
+    }
+}
+
+impl From<SceneFilterFixture> for CollisionFilter {
+    fn from(value: SceneFilterFixture) -> Self {
+        match value {
+            SceneFilterFixture::Preset(preset) => preset.into(),
+            SceneFilterFixture::Bits(filter) => filter,
+        }
+    }
+}
+
+fn default_scene_recipe_schema_v2() -> u32 {
+    SCENE_RECIPE_SCHEMA_V2
+}
+
+fn default_ccd_enabled() -> bool {
+    true
+}
+
+fn default_gravity_scale() -> FloatNum {
+    1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::{
+        build_run_scenario, instantiate_scene_fixture_with_provenance, RunConfig, RunSource,
+        ScenarioId,
+    };
+
+    fn recipe_error(json: &str) -> String {
+        let fixture: SceneFixture = serde_json::from_str(json).expect("fixture should decode");
+        match instantiate_scene_fixture_with_provenance(&fixture) {
+            Ok(_) => panic!("fixture should fail"),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn v2_fixture_maps_every_descriptor_field() {
+        let json = r#"
+        {
+          "schema_version": 2,
+          "world": {
+            "gravity": [0.0, 0.0],
+            "gravity_fields": [
+              { "kind": "uniform", "min": [-1.0, -1.0], "max": [1.0, 1.0], "acceleration": [0.0, 2.0] },
+              { "kind": "radial", "center": [0.0, 5.0], "strength": 3.0, "inner_radius": 1.0, "outer_radius": 4.0 }
+            ],
+            "enable_sleep": false,
+            "deterministic": true
+          },
+          "bodies": [
+            {
+              "body_type": "kinematic",
+              "pose": [1.0, 2.0, 0.5],
+              "linear_velocity": [3.0, -1.0],
+              "angular_velocity": 0.25,
+              "linear_damping": 0.1,
+              "angular_damping": 0.2,
+              "gravity_scale": 0.5,
+              "gravity_override": [0.0, -4.0],
+              "can_sleep": false,
+              "sleeping": true,
+              "sleep_thresholds": { "linear_threshold": 0.01 },
+              "ccd_enabled": false,
+              "bullet": true,
+              "ccd_motion_threshold": 0.75,
+              "mass_override": { "mass": 2.0, "local_center_of_mass": [0.1, 0.0], "inertia": 0.5 },
+              "locked_axes": { "translation_x": true, "translation_y": false, "rotation": true },
+              "user_data": 7,
+              "colliders": [
+                {
+                  "shape": { "type": "segment", "start": [-1.0, 0.0], "end": [1.0, 0.0] },
+                  "local_pose": [0.0, 0.5, 0.1],
+                  "density": 2.5,
+                  "mass": 1.5,
+                  "material": { "friction": 0.9, "restitution": 0.3 },
+                  "filter": { "memberships": 2, "collides_with": 5 },
+                  "is_sensor": true,
+                  "user_data": 11
+                },
+                {
+                  "shape": { "type": "regular_polygon", "sides": 6, "radius": 0.5 },
+                  "material": "bouncy",
+                  "filter": "sensor",
+                  "fluid": { "density": 2.0, "linear_drag": 0.1, "angular_drag": 0.2 }
+                },
+                {
+                  "shape": { "type": "concave_polygon", "vertices": [[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 1.0], [0.0, 1.0]] }
+                }
+              ]
+            },
+            {
+              "colliders": [{ "shape": { "type": "circle", "radius": 0.5 } }]
+            }
+          ],
+          "joints": [
+            { "type": "distance", "body_a": 0, "body_b": 1, "user_data": 3 },
+            { "type": "world_anchor", "body": 1, "user_data": 4 }
+          ]
+        }
+        "#;
+
+        let fixture: SceneFixture = serde_json::from_str(json).expect("v2 fixture should decode");
+        let SceneFixture::V2(v2) = &fixture else {
+            panic!("schema_version 2 should decode as v2");
+        };
+        let recipe = fixture
+            .to_world_recipe()
+            .expect("v2 fixture should convert");
+
+        assert_eq!(
+            recipe.desc,
+            WorldDesc {
+                gravity: Vector::new(0.0, 0.0),
+                gravity_fields: vec![
+                    GravityField::Uniform {
+                        min: Point::new(-1.0, -1.0),
+                        max: Point::new(1.0, 1.0),
+                        acceleration: Vector::new(0.0, 2.0),
+                    },
+                    GravityField::Radial {
+                        center: Point::new(0.0, 5.0),
+                        strength: 3.0,
+                        inner_radius: 1.0,
+                        outer_radius: 4.0,
+                        falloff: GravityFalloff::default(),
+                    },
+                ],
+                enable_sleep: false,
+                deterministic: true,
+            }
+        );
+        assert_eq!(
+            recipe.bodies[0].desc,
+            BodyDesc {
+                body_type: BodyType::Kinematic,
+                pose: Pose::from_xy_angle(1.0, 2.0, 0.5),
+                linear_velocity: Vector::new(3.0, -1.0),
+                angular_velocity: 0.25,
+                linear_damping: 0.1,
+                angular_damping: 0.2,
+                gravity_scale: 0.5,
+                gravity_override: Some(Vector::new(0.0, -4.0)),
+                can_sleep: false,
+                sleeping: true,
+                sleep_thresholds: Some(SleepParams {
+                    linear_threshold: 0.01,
+                    ..SleepParams::default()
+                }),
+                ccd_enabled: false,
+                bullet: true,
+                ccd_motion_threshold: 0.75,
+                mass_override: Some(MassProperties {
+                    mass: 2.0,
+                    local_center_of_mass: Point::new(0.1, 0.0),
+                    inertia: 0.5,
+                    ..MassProperties::default()
+                }),
+                locked_axes: LockedAxes {
+                    translation_x: true,
+                    translation_y: false,
+                    rotation: true,
+                },
+                user_data: 7,
+            }
+        );
+        assert_eq!(recipe.bodies[1].desc, BodyDesc::default());
+
+        let colliders: Vec<_> = recipe.bodies[0]
+            .colliders
+            .iter()
+            .map(|collider| &collider.desc)
+            .collect();
+        assert_eq!(
+            colliders[0],
+            &ColliderDesc {
+                shape: SharedShape::segment(Point::new(-1.0, 0.0), Point::new(1.0, 0.0)),
+                local_pose: Pose::from_xy_angle(0.0, 0.5, 0.1),
+                density: 2.5,
+                mass: Some(1.5),
+                material: Material {
+                    friction: 0.9,
+                    restitution: 0.3,
+                },
+                filter: CollisionFilter {
+                    memberships: 2,
+                    collides_with: 5,
+                },
+                is_sensor: true,
+                fluid: None,
+                user_data: 11,
+            }
+        );
+        assert_eq!(
+            colliders[1],
+            &ColliderDesc {
+                shape: SharedShape::regular_polygon(6, 0.5),
+                material: MaterialPreset::Bouncy.into(),
+                filter: CollisionLayerPreset::Sensor.into(),
+                fluid: Some(FluidVolume {
+                    density: 2.0,
+                    linear_drag: 0.1,
+                    angular_drag: 0.2,
+                }),
+                ..ColliderDesc::default()
+            }
+        );
+        assert!(matches!(
+            colliders[2].shape,
+            SharedShape::ConcavePolygon { .. }
+        ));
+        assert_eq!(
+            recipe.bodies[1].colliders[0].desc,
+            ColliderDesc::default(),
+            "omitted collider fields should borrow the core defaults"
+        );
+
+        let user_data: Vec<_> = recipe
+            .joints
+            .iter()
+            .map(|joint| match joint {
+                JointBundle::Distance { desc, .. } => desc.user_data,
+                JointBundle::WorldAnchor { desc, .. } => desc.user_data,
+            })
+            .collect();
+        assert_eq!(user_data, [3, 4]);
+
+        let encoded = serde_json::to_string(&fixture).expect("v2 fixture should serialize");
+        let decoded: SceneFixture =
+            serde_json::from_str(&encoded).expect("encoded v2 fixture should decode");
+        assert_eq!(&decoded, &fixture);
+        assert_eq!(decoded.to_v2().expect("v2 is already current"), *v2);
+        recipe
+            .instantiate()
+            .expect("every v2 shape should instantiate");
+    }
+
+    #[test]
+    fn v1_fixtures_migrate_to_equivalent_v2_documents() {
+        let json = r#"
+        {
+          "world": { "gravity": [0.0, 4.0], "enable_sleep": false },
+          "bodies": [
+            {
+              "body_type": "static",
+              "pose": [0.0, 2.0, 0.0],
+              "can_sleep": false,
+              "shape": { "type": "rect", "width": 8.0, "height": 0.5 },
+              "material": "rough",
+              "filter": "static_geometry"
+            },
+            {
+              "pose": [0.5, 0.0, 0.2],
+              "linear_velocity": [1.0, 0.0],
+              "shape": {
+                "type": "compound",
+                "pieces": [
+                  { "shape": { "type": "rect", "width": 1.0, "height": 0.4 } },
+                  { "shape": { "type": "circle", "radius": 0.25 }, "local_pose": [0.6, 0.0, 0.0] }
+                ]
+              },
+              "material": "sticky",
+              "density": 1.75,
+              "is_sensor": true
+            }
+          ],
+          "joints": [{ "type": "world_anchor", "body": 1, "stiffness": 2.0 }]
+        }
+        "#;
+
+        let fixture: SceneFixture = serde_json::from_str(json).expect("v1 fixture should decode");
+        let SceneFixture::V1(v1) = &fixture else {
+            panic!("fixtures without schema_version should decode as v1");
+        };
+        let migrated = fixture.to_v2().expect("v1 fixture should migrate");
+        assert_eq!(migrated.schema_version, SCENE_RECIPE_SCHEMA_V2);
+        assert_eq!(migrated.world.gravity, [0.0, 4.0]);
+        assert!(!migrated.world.enable_sleep);
+        assert_eq!(migrated.joints, v1.joints);
+
+        let compound = &migrated.bodies[1];
+        assert_eq!(compound.linear_velocity, [1.0, 0.0]);
+        assert_eq!(compound.colliders.len(), 2);
+        assert_eq!(compound.colliders[1].local_pose, [0.6, 0.0, 0.0]);
+        for collider in &compound.colliders {
+            assert_eq!(
+                collider.material,
+                SceneMaterialFixture::Preset(MaterialPreset::Sticky)
+            );
+            assert_eq!(
+                collider.filter,
+                SceneFilterFixture::Preset(CollisionLayerPreset::Default)
+            );
+            assert_eq!(collider.density, 1.75);
+            assert!(collider.is_sensor);
+        }
+
+        let encoded = serde_json::to_string(&SceneFixture::from(migrated))
+            .expect("migrated fixture should serialize");
+        let reloaded: SceneFixture =
+            serde_json::from_str(&encoded).expect("migrated fixture should decode");
+        assert!(matches!(reloaded, SceneFixture::V2(_)));
+        assert_eq!(
+            reloaded
+                .to_world_recipe()
+                .expect("migrated fixture should convert"),
+            fixture
+                .to_world_recipe()
+                .expect("v1 fixture should convert"),
+            "migration should not change the world a v1 fixture builds"
+        );
+    }
+
+    #[test]
+    fn v1_migration_keeps_v1_validation_paths() {
+        let json = r#"
+        {
+          "bodies": [
+            { "shape": { "type": "circle", "radius": 0.5 } },
+            {
+              "shape": {
+                "type": "compound",
+                "pieces": [
+                  { "shape": { "type": "convex_polygon", "vertices": [[0.0, 0.0], [1.0, 0.0]] } }
+                ]
+              }
+            }
+          ]
+        }
+        "#;
+
+        let fixture: SceneRecipeFixture =
+            serde_json::from_str(json).expect("v1 fixture should decode");
+        assert_eq!(
+            fixture
+                .migrate()
+                .expect_err("degenerate piece should fail")
+                .to_string(),
+            "world setup failed: scene.bodies[1].shape.pieces[0].shape.vertices: convex_polygon requires at least 3 non-degenerate vertices"
+        );
+    }
+
+    #[test]
+    fn v2_validation_errors_name_collider_paths() {
+        assert_eq!(
+            recipe_error(
+                r#"{
+                  "schema_version": 2,
+                  "bodies": [
+                    { "colliders": [{ "shape": { "type": "circle", "radius": 0.5 } }] },
+                    {
+                      "colliders": [
+                        { "shape": { "type": "circle", "radius": 0.5 } },
+                        { "shape": { "type": "convex_polygon", "vertices": [[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 1.0], [0.0, 1.0]] } }
+                      ]
+                    }
+                  ]
+                }"#
+            ),
+            "world setup failed: scene.bodies[1].colliders[1].shape.vertices: convex_polygon requires convex vertices"
+        );
+        assert_eq!(
+            recipe_error(
+                r#"{
+                  "schema_version": 2,
+                  "bodies": [
+                    { "colliders": [{ "shape": { "type": "concave_polygon", "vertices": [[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]] } }] }
+                  ]
+                }"#
+            ),
+            "world setup failed: scene.bodies[0].colliders[0].shape.vertices: concave_polygon requires at least 3 non-degenerate vertices"
+        );
+        assert_eq!(
+            recipe_error(
+                r#"{
+                  "schema_version": 2,
+                  "bodies": [
+                    { "colliders": [{ "shape": { "type": "regular_polygon", "sides": 2, "radius": 0.5 } }] }
+                  ]
+                }"#
+            ),
+            "world setup failed: recipe.bodies[0].colliders[0].desc.shape.sides: collider descriptor contains an invalid `shape.sides` value"
+        );
+    }
+
+    #[test]
+    fn exported_worlds_rebuild_through_v2_fixtures() {
+        let mut scenario = build_run_scenario(&RunConfig {
+            source: RunSource::Builtin(ScenarioId::CompoundProvenance),
+            ..RunConfig::default()
+        })
+        .expect("compound scenario should build");
+        let mut pipeline = SimulationPipeline::new(StepConfig::default());
+        for _ in 0..5 {
+            pipeline.step(&mut scenario.world);
+        }
+
+        let exported =
+            SceneRecipeFixtureV2::from_world(&scenario.world, &scenario.compound_provenance);
+        assert_eq!(
+            exported.bodies[0].colliders[0].material,
+            SceneMaterialFixture::Preset(MaterialPreset::Rough),
+            "exact preset values keep their preset name"
+        );
+        assert_eq!(
+            exported.bodies[1]
+                .compound
+                .map(|compound| compound.material),
+            Some(MaterialPreset::Sticky)
+        );
+        let encoded = serde_json::to_string(&SceneFixture::from(exported.clone()))
+            .expect("exported fixture should serialize");
+        let decoded: SceneFixture =
+            serde_json::from_str(&encoded).expect("exported fixture should decode");
+        assert_eq!(decoded, SceneFixture::V2(exported));
+
+        let rebuilt = instantiate_scene_fixture_with_provenance(&decoded)
+            .expect("exported fixture should instantiate");
+        assert_eq!(
+            WorldRecipe::from_world(&rebuilt.world),
+            WorldRecipe::from_world(&scenario.world),
+            "the exported fixture should rebuild the stepped world exactly"
+        );
+        let [source] = scenario.compound_provenance.as_slice() else {
+            panic!("the builtin has one compound body");
+        };
+        let [copy] = rebuilt.compound_provenance.as_slice() else {
+            panic!("the compound marker should survive export");
+        };
+        assert_eq!(copy.validation_path, "scene.bodies[1].colliders");
+        assert_eq!(copy.body_handle, source.body_handle);
+        assert_eq!(copy.inherited_density, source.inherited_density);
+        assert_eq!(copy.inherited_filter, source.inherited_filter);
+        let piece_facts = |provenance: &CompoundProvenance| {
+            provenance
+                .pieces
+                .iter()
+                .map(|piece| (piece.collider_handle, piece.local_pose))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(piece_facts(copy), piece_facts(source));
+    }
+
+    #[test]
+    fn unsupported_schema_versions_fail_while_decoding() {
+        let error = serde_json::from_str::<SceneFixture>(r#"{ "schema_version": 3 }"#)
+            .expect_err("schema v3 should be rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("unsupported scene schema version: 3 (expected v2)"),
+            "{error}"
+        );
+        assert!(matches!(
+            serde_json::from_str::<SceneFixture>("{}"),
+            Ok(SceneFixture::V1(_))
+        ));
+    }
+}
@@ -7,7 +7,10 @@
 
 pub mod artifact;
 pub mod cli;
+pub mod compare;
 mod error;
+pub mod fixture;
+pub mod live;
 pub mod scenario;
 pub mod server;
 
@@ -15,11 +18,23 @@ pub use artifact::{
     run_scenario, ArtifactEntry, ArtifactFile, ArtifactStore, DebugRenderArtifact,
     DebugRenderFrame, FrameRecord, PerfArtifact, RunManifest, RunResult,
 };
+pub use compare::{
+    compare_frames, compare_runs, verify_golden_runs, BodyDivergence, CompareTolerances,
+    ContactDivergence, FactDifference, FieldDifference, FrameDivergence, GoldenVerification,
+    RunComparison,
+};
 pub use error::{LabError, LabResult};
+pub use fixture::{
+    SceneBodyFixtureV2, SceneColliderFixture, SceneColliderShapeFixture, SceneCompoundFixture,
+    SceneFilterFixture, SceneFixture, SceneFixtureWorldV2, SceneGravityFieldFixture,
+    SceneMassFixture, SceneMaterialFixture, SceneRecipeFixtureV2, SCENE_RECIPE_SCHEMA_V2,
+};
+pub use live::{DebugSnapshotDelta, LiveBodyPatch, LiveCommand, LiveCommandReport, LiveSnapshot};
 pub use scenario::{
     instantiate_scene_fixture, list_scenarios, CompoundProvenance, CompoundProvenancePiece,
-    RunConfig, ScenarioDescriptor, ScenarioId, ScenarioOverrides, SceneBodyFixture,
-    SceneDistanceJointFixture, SceneFixtureWorld, SceneJointFixture, SceneRecipeFixture,
-    SceneShapeFixture, SceneWorldAnchorJointFixture, SCENE_RECIPE_SCHEMA_VERSION,
+    FixtureProvenance, FixtureSource, RunConfig, RunSource, ScenarioDescriptor, ScenarioId,
+    ScenarioOverrides, SceneBodyFixture, SceneDistanceJointFixture, SceneFixtureWorld,
+    SceneJointFixture, SceneRecipeFixture, SceneShapeFixture, SceneWorldAnchorJointFixture,
+    SCENE_RECIPE_SCHEMA_VERSION,
 };
 pub use server::SessionStatus;
@@ -0,0 +1,653 @@
+//! Live sessions that own a `World` and step it on demand.
+//!
+//! Recorded runs precompute every frame into artifacts. A live session instead
+//! keeps the world and pipeline in memory, accepts world commands between
+//! steps, and reports each new state as a [`DebugSnapshotDelta`] against the
+//! previous one so viewers only receive what changed.
+//!
+//! A playing session advances whenever the server catches it up with the
+//! wall clock, and reports the time a late catch-up could not cover.
+
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use picea::{
+    debug::{DebugBroadphaseTree, DebugMeta, DebugStats},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    artifact::state_hash,
+    fixture::SceneRecipeFixtureV2,
+    scenario::{build_run_scenario, BuiltScenario, RunConfig, SceneBodyFixture},
+    LabError, LabResult,
+};
+
+/// Live snapshots skip draw primitives: they are derived from colliders and
+/// would dominate every delta.
+const LIVE_SNAPSHOT_OPTIONS: DebugSnapshotOptions = DebugSnapshotOptions {
+    include_contacts: true,
+    include_manifolds: true,
+    include_primitives: false,
+    sanitize_non_finite: true,
+};
+
+fn default_drag_stiffness() -> FloatNum {
+    20.0
+}
+
+/// World edit accepted by a live session.
+///
+/// Commands address bodies by the handles streamed in snapshots and are
+/// translated into core `WorldCommand`s. A batch is applied atomically.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LiveCommand {
+    /// Creates a body from the scene fixture body schema.
+    Spawn { body: SceneBodyFixture },
+    /// Overwrites the given body state and wakes the body.
+    Patch {
+        body: BodyHandle,
+        #[serde(default)]
+        patch: LiveBodyPatch,
+    },
+    /// Destroys a body with its colliders and joints.
+    Destroy { body: BodyHandle },
+    /// Pulls a point on the body towards `target` with a world-anchor joint.
+    ///
+    /// Repeated drags on the same body move the existing joint; dragging a
+    /// different body replaces it.
+    Drag {
+        body: BodyHandle,
+        #[serde(default)]
+        local_anchor: [FloatNum; 2],
+        target: [FloatNum; 2],
+        #[serde(default = "default_drag_stiffness")]
+        stiffness: FloatNum,
+    },
+    /// Removes the drag joint, if any.
+    Release,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LiveBodyPatch {
+    #[serde(default)]
+    pub body_type: Option<BodyType>,
+    #[serde(default)]
+    pub pose: Option<[FloatNum; 3]>,
+    #[serde(default)]
+    pub linear_velocity: Option<[FloatNum; 2]>,
+    #[serde(default)]
+    pub angular_velocity: Option<FloatNum>,
+    #[serde(default)]
+    pub gravity_scale: Option<FloatNum>,
+}
+
+impl LiveBodyPatch {
+    fn to_body_patch(&self) -> BodyPatch {
+        BodyPatch {
+            body_type: self.body_type,
+            pose: self
+                .pose
+                .map(|[x, y, angle]| Pose::from_xy_angle(x, y, angle)),
+            linear_velocity: self.linear_velocity.map(|[x, y]| Vector::new(x, y)),
+            angular_velocity: self.angular_velocity,
+            gravity_scale: self.gravity_scale,
+            wake: true,
+            ..BodyPatch::default()
+        }
+    }
+}
+
+/// Result of one accepted command batch.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LiveCommandReport {
+    /// Bodies created by `spawn` commands, in command order.
+    pub created_bodies: Vec<BodyHandle>,
+    /// Joint currently held by a drag.
+    pub drag_joint: Option<JointHandle>,
+}
+
+/// Changes between two consecutive live snapshots.
+///
+/// Bodies, colliders, and joints are keyed by handle: the delta lists every
+/// live handle in snapshot order but only carries the entries that changed.
+/// Per-step facts such as contacts and islands are replaced wholesale.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DebugSnapshotDelta {
+    pub frame_index: usize,
+    /// Hash of the full snapshot after this delta is applied.
+    pub state_hash: String,
+    pub meta: DebugMeta,
+    pub body_handles: Vec<BodyHandle>,
+    pub bodies: Vec<DebugBody>,
+    pub collider_handles: Vec<ColliderHandle>,
+    pub colliders: Vec<DebugCollider>,
+    pub joint_handles: Vec<JointHandle>,
+    pub joints: Vec<DebugJoint>,
+    pub contacts: Vec<DebugContact>,
+    pub manifolds: Vec<DebugManifold>,
+    pub islands: Vec<DebugIsland>,
+    pub fluid_submersions: Vec<DebugFluidSubmersion>,
+    pub broadphase_tree: DebugBroadphaseTree,
+    pub stats: DebugStats,
+}
+
+impl DebugSnapshotDelta {
+    pub fn between(
+        previous: &DebugSnapshot,
+        next: &DebugSnapshot,
+        frame_index: usize,
+        state_hash: String,
+    ) -> Self {
+        let (body_handles, bodies) =
+            keyed_changes(&previous.bodies, &next.bodies, |body| body.handle);
+        let (collider_handles, colliders) =
+            keyed_changes(&previous.colliders, &next.colliders, |collider| {
+                collider.handle
+            });
+        let (joint_handles, joints) =
+            keyed_changes(&previous.joints, &next.joints, |joint| joint.handle);
+        Self {
+            frame_index,
+            state_hash,
+            meta: next.meta.clone(),
+            body_handles,
+            bodies,
+            collider_handles,
+            colliders,
+            joint_handles,
+            joints,
+            contacts: next.contacts.clone(),
+            manifolds: next.manifolds.clone(),
+            islands: next.islands.clone(),
+            fluid_submersions: next.fluid_submersions.clone(),
+            broadphase_tree: next.broadphase_tree.clone(),
+            stats: next.stats.clone(),
+        }
+    }
+
+    /// Rebuilds the next snapshot from the one this delta was taken against.
+    pub fn apply_to(&self, snapshot: &mut DebugSnapshot) {
+        snapshot.meta = self.meta.clone();
+        snapshot.bodies =
+            apply_keyed_changes(&snapshot.bodies, &self.body_handles, &self.bodies, |body| {
+                body.handle
+            });
+        snapshot.colliders = apply_keyed_changes(
+            &snapshot.colliders,
+            &self.collider_handles,
+            &self.colliders,
+            |collider| collider.handle,
+        );
+        snapshot.joints = apply_keyed_changes(
+            &snapshot.joints,
+            &self.joint_handles,
+            &self.joints,
+            |joint| joint.handle,
+        );
+        snapshot.contacts = self.contacts.clone();
+        snapshot.manifolds = self.manifolds.clone();
+        snapshot.islands = self.islands.clone();
+        snapshot.fluid_submersions = self.fluid_submersions.clone();
+        snapshot.broadphase_tree = self.broadphase_tree.clone();
+        snapshot.primitives.clear();
+        snapshot.stats = self.stats.clone();
+    }
+}
+
+fn keyed_changes<T, K>(previous: &[T], next: &[T], key: impl Fn(&T) -> K) -> (Vec<K>, Vec<T>)
+where
+    T: Clone + PartialEq,
+    K: Ord,
+{
+    let previous: BTreeMap<_, _> = previous.iter().map(|entry| (key(entry), entry)).collect();
+    let handles = next.iter().map(&key).collect();
+    let changed = next
+        .iter()
+        .filter(|entry| previous.get(&key(entry)) != Some(entry))
+        .cloned()
+        .collect();
+    (handles, changed)
+}
+
+fn apply_keyed_changes<T, K>(
+    current: &[T],
+    handles: &[K],
+    changed: &[T],
+    key: impl Fn(&T) -> K,
+) -> Vec<T>
+where
+    T: Clone,
+    K: Ord,
+{
+    // Changed entries come last so they replace the previous ones.
+    let entries: BTreeMap<_, _> = current
+        .iter()
+        .chain(changed)
+        .map(|entry| (key(entry), entry))
+        .collect();
+    handles
+        .iter()
+        .filter_map(|handle| entries.get(handle).map(|entry| (*entry).clone()))
+        .collect()
+}
+
+/// Full state sent when a viewer (re)joins a live session.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LiveSnapshot {
+    pub frame_index: usize,
+    pub state_hash: String,
+    pub snapshot: DebugSnapshot,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct DragJoint {
+    body: BodyHandle,
+    joint: JointHandle,
+}
+
+/// Steps taken by one [`LiveSession::catch_up`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct LiveCatchUp {
+    pub(crate) deltas: Vec<DebugSnapshotDelta>,
+    /// Wall-clock seconds skipped because more whole steps were due than
+    /// `max_substeps` allows.
+    pub(crate) dropped_time: f64,
+}
+
+/// Server-owned world for one live session.
+pub(crate) struct LiveSession {
+    pipeline: SimulationPipeline,
+    clock: Option<Instant>,
+    state: LiveState,
+}
+
+/// Everything a live step changes besides the pipeline, kept apart so the
+/// pipeline can lend itself to each step.
+struct LiveState {
+    scenario: BuiltScenario,
+    frame_count: usize,
+    drag: Option<DragJoint>,
+    snapshot: DebugSnapshot,
+    state_hash: String,
+}
+
+impl LiveSession {
+    pub(crate) fn new(config: &RunConfig) -> LabResult<Self> {
+        let pipeline = SimulationPipeline::new(config.overrides.step_config()?);
+        let scenario = build_run_scenario(config)?;
+        let snapshot = DebugSnapshot::from_world(&scenario.world, &LIVE_SNAPSHOT_OPTIONS);
+        let state_hash = state_hash(&snapshot)?;
+        Ok(Self {
+            pipeline,
+            clock: None,
+            state: LiveState {
+                scenario,
+                frame_count: 0,
+                drag: None,
+                snapshot,
+                state_hash,
+            },
+        })
+    }
+
+    /// Number of steps taken since the session was built.
+    pub(crate) fn frame_count(&self) -> usize {
+        self.state.frame_count
+    }
+
+    pub(crate) fn state_hash(&self) -> &str {
+        &self.state.state_hash
+    }
+
+    pub(crate) fn is_playing(&self) -> bool {
+        self.clock.is_some()
+    }
+
+    /// Wall-clock length of one fixed step.
+    pub(crate) fn step_period(&self) -> Duration {
+        // Widening, like the core's accumulator; a no-op under `f64`.
+        #[allow(clippy::useless_conversion)]
+        Duration::from_secs_f64(f64::from(self.pipeline.config().dt))
+    }
+
+    /// Exports the current world as a schema v2 fixture. The drag joint is an
+    /// interaction aid rather than scene content, so it is left out.
+    pub(crate) fn export_fixture(&self) -> SceneRecipeFixtureV2 {
+        let world = &self.state.scenario.world;
+        let mut fixture =
+            SceneRecipeFixtureV2::from_world(world, &self.state.scenario.compound_provenance);
+        if let Some(drag) = self.state.drag {
+            if let Some(index) = world.joints().position(|joint| joint == drag.joint) {
+                fixture.joints.remove(index);
+            }
+        }
+        fixture
+    }
+
+    pub(crate) fn full_snapshot(&self) -> LiveSnapshot {
+        LiveSnapshot {
+            frame_index: self.state.frame_index(),
+            state_hash: self.state.state_hash.clone(),
+            snapshot: self.state.snapshot.clone(),
+        }
+    }
+
+    /// Runs one fixed step, feeding scenario controllers first.
+    pub(crate) fn step(&mut self) -> LabResult<DebugSnapshotDelta> {
+        self.state.step(&mut self.pipeline).map(|(_, delta)| delta)
+    }
+
+    /// Starts advancing in real time from now.
+    pub(crate) fn play(&mut self) {
+        self.clock.get_or_insert_with(Instant::now);
+    }
+
+    /// Stops advancing in real time. The pipeline keeps its sub-step
+    /// remainder, so playing again resumes mid-step.
+    pub(crate) fn pause(&mut self) {
+        self.clock = None;
+    }
+
+    /// Catches a playing session up with the wall clock.
+    ///
+    /// The time since the last catch-up goes through
+    /// `SimulationPipeline::advance_with`, so the `max_substeps` cap and the
+    /// dropped backlog match `advance` while scenario controllers still run
+    /// before every step.
+    pub(crate) fn catch_up(&mut self) -> LabResult<LiveCatchUp> {
+        let Some(clock) = self.clock else {
+            return Ok(LiveCatchUp::default());
+        };
+        let now = Instant::now();
+        self.clock = Some(now);
+
+        let mut deltas = Vec::new();
+        let report =
+            self.pipeline
+                .advance_with(now.duration_since(clock).as_secs_f64(), |pipeline| {
+                    let (report, delta) = self.state.step(pipeline)?;
+                    deltas.push(delta);
+                    Ok::<_, LabError>(report)
+                })?;
+        Ok(LiveCatchUp {
+            deltas,
+            dropped_time: report.dropped_time,
+        })
+    }
+
+    /// Applies a command batch atomically between steps.
+    pub(crate) fn apply(
+        &mut self,
+        commands: &[LiveCommand],
+    ) -> LabResult<(LiveCommandReport, DebugSnapshotDelta)> {
+        let state = &mut self.state;
+        let mut world = state.scenario.world.clone();
+        let mut drag = state.drag;
+        let mut report = LiveCommandReport::default();
+        for (index, command) in commands.iter().enumerate() {
+            let created = apply_command(&mut world, &mut drag, index, command)?;
+            report.created_bodies.extend(created.body_handles);
+            // Destroying a dragged body also destroys its joint.
+            drag = drag.filter(|drag| world.joint(drag.joint).is_ok());
+        }
+        report.drag_joint = drag.map(|drag| drag.joint);
+
+        state.scenario.world = world;
+        state.drag = drag;
+        let delta = state.record(DebugSnapshot::from_world(
+            &state.scenario.world,
+            &LIVE_SNAPSHOT_OPTIONS,
+        ))?;
+        Ok((report, delta))
+    }
+}
+
+impl LiveState {
+    fn step(
+        &mut self,
+        pipeline: &mut SimulationPipeline,
+    ) -> LabResult<(StepReport, DebugSnapshotDelta)> {
+        self.scenario
+            .before_step(self.frame_count, pipeline.config().dt)?;
+        let report = pipeline.step(&mut self.scenario.world);
+        self.frame_count += 1;
+        let delta = self.record(DebugSnapshot::from_world_with_step_report(
+            &self.scenario.world,
+            &report,
+            &LIVE_SNAPSHOT_OPTIONS,
+        ))?;
+        Ok((report, delta))
+    }
+
+    fn frame_index(&self) -> usize {
+        self.frame_count.saturating_sub(1)
+    }
+
+    fn record(&mut self, snapshot: DebugSnapshot) -> LabResult<DebugSnapshotDelta> {
+        let state_hash = state_hash(&snapshot)?;
+        let delta = DebugSnapshotDelta::between(
+            &self.snapshot,
+            &snapshot,
+            self.frame_index(),
+            state_hash.clone(),
+        );
+        self.snapshot = snapshot;
+        self.state_hash = state_hash;
+        Ok(delta)
+    }
+}
+
+fn apply_command(
+    world: &mut World,
+    drag: &mut Option<DragJoint>,
+    index: usize,
+    command: &LiveCommand,
+) -> LabResult<WorldCommandReport> {
+    let command_error = |error: WorldCommandContextError| {
+        LabError::InvalidCommand(format!(
+            "commands[{index}]: {}: {}",
+            error.path, error.error.error
+        ))
+    };
+    let commands = match command {
+        LiveCommand::Spawn { body } => vec![WorldCommand::CreateBody(
+            body.to_body_bundle(index)
+                .map_err(|error| LabError::InvalidCommand(format!("commands[{index}]: {error}")))?,
+        )],
+        LiveCommand::Patch { body, patch } => vec![WorldCommand::PatchBody {
+            body: *body,
+            patch: patch.to_body_patch(),
+        }],
+        LiveCommand::Destroy { body } => vec![WorldCommand::DestroyBody { body: *body }],
+        LiveCommand::Drag {
+            body,
+            local_anchor: [ax, ay],
+            target: [tx, ty],
+            stiffness,
+        } => {
+            let wake = WorldCommand::PatchBody {
+                body: *body,
+                patch: BodyPatch {
+                    wake: true,
+                    ..BodyPatch::default()
+                },
+            };
+            match *drag {
+                Some(current) if current.body == *body => vec![
+                    wake,
+                    WorldCommand::PatchJoint {
+                        joint: current.joint,
+                        patch: JointPatch::WorldAnchor(WorldAnchorJointPatch {
+                            local_anchor: Some(Point::new(*ax, *ay)),
+                            world_anchor: Some(Point::new(*tx, *ty)),
+                            stiffness: Some(*stiffness),
+                            ..WorldAnchorJointPatch::default()
+                        }),
+                    },
+                ],
+                current => {
+                    let mut commands: Vec<_> = current
+                        .map(|current| WorldCommand::DestroyJoint {
+                            joint: current.joint,
+                        })
+                        .into_iter()
+                        .collect();
+                    commands.push(wake);
+                    commands.push(WorldCommand::CreateJoint {
+                        desc: JointDesc::WorldAnchor(WorldAnchorJointDesc {
+                            body: *body,
+                            local_anchor: Point::new(*ax, *ay),
+                            world_anchor: Point::new(*tx, *ty),
+                            stiffness: *stiffness,
+                            ..WorldAnchorJointDesc::default()
+                        }),
+                    });
+                    commands
+                }
+            }
+        }
+        LiveCommand::Release => drag
+            .take()
+            .map(|current| WorldCommand::DestroyJoint {
+                joint: current.joint,
+            })
+            .into_iter()
+            .collect(),
+    };
+
+    let report = world
+        .commands()
+        .apply_with_context(commands)
+        .map_err(command_error)?;
+    if let (LiveCommand::Drag { body, .. }, Some(&joint)) = (command, report.joint_handles.first())
+    {
+        *drag = Some(DragJoint { body: *body, joint });
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::{RunSource, ScenarioId, SceneShapeFixture};
+
+    #[test]
+    fn deltas_rebuild_the_live_snapshot_across_steps_and_commands() {
+        let mut live = LiveSession::new(&RunConfig {
+            source: RunSource::Builtin(ScenarioId::Stack4),
+            ..RunConfig::default()
+        })
+        .expect("stack scenario should build");
+        let mut viewer = live.full_snapshot().snapshot;
+        let static_bodies = viewer
+            .bodies
+            .iter()
+            .filter(|body| body.body_type == BodyType::Static)
+            .count();
+        assert!(static_bodies > 0);
+
+        for _ in 0..5 {
+            let delta = live.step().expect("step should succeed");
+            assert!(
+                delta.bodies.len() <= delta.body_handles.len() - static_bodies,
+                "resting static bodies are not resent"
+            );
+            delta.apply_to(&mut viewer);
+            assert_eq!(viewer, live.state.snapshot);
+            assert_eq!(
+                state_hash(&viewer).expect("snapshot should hash"),
+                delta.state_hash
+            );
+        }
+
+        let removed = viewer.bodies[0].handle;
+        let (report, delta) = live
+            .apply(&[
+                LiveCommand::Destroy { body: removed },
+                LiveCommand::Spawn {
+                    body: SceneBodyFixture {
+                        body_type: BodyType::Dynamic,
+                        pose: [0.0, -4.0, 0.0],
+                        linear_velocity: [0.0, 0.0],
+                        can_sleep: true,
+                        shape: SceneShapeFixture::Circle { radius: 0.25 },
+                        material: MaterialPreset::default(),
+                        filter: CollisionLayerPreset::default(),
+                        density: 1.0,
+                        is_sensor: false,
+                    },
+                },
+            ])
+            .expect("commands should apply");
+        assert_eq!(report.created_bodies.len(), 1);
+        assert!(!delta.body_handles.contains(&removed));
+        delta.apply_to(&mut viewer);
+        assert_eq!(viewer, live.state.snapshot);
+        assert_eq!(live.frame_count(), 5, "commands do not step the world");
+
+        let delta = live.step().expect("step should succeed");
+        delta.apply_to(&mut viewer);
+        assert_eq!(viewer, live.state.snapshot);
+    }
+
+    #[test]
+    // Widening `dt`; a no-op under `f64`.
+    #[allow(clippy::useless_conversion)]
+    fn late_catch_up_reports_the_time_beyond_max_substeps() {
+        let mut live = LiveSession::new(&RunConfig::default()).expect("scenario should build");
+        let dt = f64::from(live.pipeline.config().dt);
+        let max_steps = usize::from(live.pipeline.config().max_substeps);
+        let late = Duration::from_secs_f64(dt * (max_steps + 3) as f64 + dt * 0.5);
+        live.clock = Some(
+            Instant::now()
+                .checked_sub(late)
+                .expect("the clock should reach back one poll"),
+        );
+
+        let catch_up = live.catch_up().expect("catch-up should step");
+
+        assert_eq!(catch_up.deltas.len(), max_steps);
+        assert_eq!(live.frame_count(), max_steps);
+        assert!(
+            catch_up.dropped_time >= dt * 3.0 - 1.0e-9,
+            "{} s dropped",
+            catch_up.dropped_time
+        );
+        assert!(
+            live.pipeline.accumulated_time() < dt,
+            "only the remainder carries over"
+        );
+    }
+
+    #[test]
+    fn rejected_command_batches_leave_the_world_untouched() {
+        let mut live = LiveSession::new(&RunConfig::default()).expect("scenario should build");
+        let before = live.full_snapshot();
+        let body = before.snapshot.bodies[0].handle;
+
+        let error = live
+            .apply(&[
+                LiveCommand::Drag {
+                    body,
+                    local_anchor: [0.0, 0.0],
+                    target: [1.0, 0.0],
+                    stiffness: 20.0,
+                },
+                LiveCommand::Destroy {
+                    body: BodyHandle::INVALID,
+                },
+            ])
+            .expect_err("an invalid handle should reject the batch");
+        assert!(
+            matches!(&error, LabError::InvalidCommand(message) if message.starts_with("commands[1]")),
+            "{error}"
+        );
+        assert_eq!(live.full_snapshot(), before);
+        assert_eq!(live.state.drag, None);
+    }
+}
@@ -6,13 +6,23 @@
 
 use std::{
     fmt::{Display, Formatter},
+    fs,
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
 use picea::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{LabError, LabResult};
+use crate::{
+    artifact::state_hash,
+    fixture::{
+        SceneBodyFixtureV2, SceneColliderFixture, SceneColliderShapeFixture, SceneCompoundFixture,
+        SceneFilterFixture, SceneFixture, SceneFixtureWorldV2, SceneMaterialFixture,
+        SceneRecipeFixtureV2,
+    },
+    LabError, LabResult,
+};
 
 /// Stable identifiers for the builtin CS-simulator scenarios.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -28,10 +38,11 @@ pub enum ScenarioId {
     CcdFastCircleWall,
     CcdFastConvexWalls,
     CcdDynamicConvexPair,
+    VehicleRaycast,
 }
 
 impl ScenarioId {
-    pub const ALL: [Self; 9] = [
+    pub const ALL: [Self; 10] = [
         Self::FallingBoxContact,
         Self::Stack4,
         Self::JointAnchor,
@@ -41,6 +52,7 @@ impl ScenarioId {
         Self::CcdFastCircleWall,
         Self::CcdFastConvexWalls,
         Self::CcdDynamicConvexPair,
+        Self::VehicleRaycast,
     ];
 
     pub const fn as_str(self) -> &'static str {
@@ -54,6 +66,7 @@ impl ScenarioId {
             Self::CcdFastCircleWall => "ccd_fast_circle_wall",
             Self::CcdFastConvexWalls => "ccd_fast_convex_walls",
             Self::CcdDynamicConvexPair => "ccd_dynamic_convex_pair",
+            Self::VehicleRaycast => "vehicle_raycast",
         }
     }
 }
@@ -78,6 +91,7 @@ impl FromStr for ScenarioId {
             "ccd_fast_circle_wall" => Ok(Self::CcdFastCircleWall),
             "ccd_fast_convex_walls" => Ok(Self::CcdFastConvexWalls),
             "ccd_dynamic_convex_pair" => Ok(Self::CcdDynamicConvexPair),
+            "vehicle_raycast" => Ok(Self::VehicleRaycast),
             other => Err(LabError::UnknownScenario(other.to_owned())),
         }
     }
@@ -106,6 +120,7 @@ pub fn list_scenarios() -> Vec<ScenarioDescriptor> {
                 ScenarioId::CcdFastCircleWall => "CCD fast circle wall",
                 ScenarioId::CcdFastConvexWalls => "CCD fast convex walls",
                 ScenarioId::CcdDynamicConvexPair => "CCD dynamic convex pair",
+                ScenarioId::VehicleRaycast => "Ray-cast vehicle",
             },
             description: match id {
                 ScenarioId::FallingBoxContact => "A dynamic box falling into static floor contact.",
@@ -129,6 +144,9 @@ pub fn list_scenarios() -> Vec<ScenarioDescriptor> {
                 ScenarioId::CcdDynamicConvexPair => {
                     "Two fast dynamic rectangles swept against each other."
                 }
+                ScenarioId::VehicleRaycast => {
+                    "A two-wheel ray-cast vehicle accelerating over bumps, then braking."
+                }
             },
         })
         .collect()
@@ -139,13 +157,79 @@ pub fn list_scenarios() -> Vec<ScenarioDescriptor> {
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ScenarioOverrides {
     pub frame_count: Option<usize>,
-    pub gravity: Option<[f32; 2]>,
+    pub gravity: Option<[FloatNum; 2]>,
+    pub solver: Option<SolverParams>,
+    pub sleep: Option<SleepParams>,
+    pub ccd: Option<CcdParams>,
+}
+
+impl ScenarioOverrides {
+    /// Builds the validated step config a run uses, starting from the core
+    /// defaults and replacing whole tuning sections that are overridden.
+    pub fn step_config(&self) -> LabResult<StepConfig> {
+        let defaults = StepConfig::default();
+        let config = StepConfig {
+            solver: self.solver.unwrap_or(defaults.solver),
+            sleep: self.sleep.unwrap_or(defaults.sleep),
+            ccd: self.ccd.unwrap_or(defaults.ccd),
+            ..defaults
+        };
+        config
+            .validate()
+            .map_err(|error| LabError::InvalidOverrides(error.to_string()))?;
+        Ok(config)
+    }
+}
+
+/// What a run simulates.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RunSource {
+    Builtin(ScenarioId),
+    /// User-authored scene fixture.
+    Fixture(FixtureSource),
+}
+
+impl RunSource {
+    /// Builtin id recorded in artifacts; `None` for fixture runs.
+    pub fn scenario_id(&self) -> Option<ScenarioId> {
+        match self {
+            Self::Builtin(id) => Some(*id),
+            Self::Fixture(_) => None,
+        }
+    }
+
+    pub fn fixture(&self) -> Option<&FixtureSource> {
+        match self {
+            Self::Builtin(_) => None,
+            Self::Fixture(fixture) => Some(fixture),
+        }
+    }
+
+    /// Re-reads a file-backed fixture so resets pick up edits.
+    pub(crate) fn reload(&self) -> LabResult<Self> {
+        match self {
+            Self::Builtin(id) => Ok(Self::Builtin(*id)),
+            Self::Fixture(fixture) => fixture.reload().map(Self::Fixture),
+        }
+    }
+}
+
+impl Default for RunSource {
+    fn default() -> Self {
+        Self::Builtin(ScenarioId::FallingBoxContact)
+    }
+}
+
+impl From<ScenarioId> for RunSource {
+    fn from(id: ScenarioId) -> Self {
+        Self::Builtin(id)
+    }
 }
 
 /// Input for one deterministic scenario run.
 #[derive(Clone, Debug, PartialEq)]
 pub struct RunConfig {
-    pub scenario_id: ScenarioId,
+    pub source: RunSource,
     pub frame_count: usize,
     pub run_id: Option<String>,
     pub overrides: ScenarioOverrides,
@@ -154,7 +238,7 @@ pub struct RunConfig {
 impl Default for RunConfig {
     fn default() -> Self {
         Self {
-            scenario_id: ScenarioId::FallingBoxContact,
+            source: RunSource::default(),
             frame_count: 120,
             run_id: None,
             overrides: ScenarioOverrides::default(),
@@ -163,6 +247,14 @@ impl Default for RunConfig {
 }
 
 impl RunConfig {
+    /// Default run of a user-authored scene fixture.
+    pub fn from_fixture(fixture: FixtureSource) -> Self {
+        Self {
+            source: RunSource::Fixture(fixture),
+            ..Self::default()
+        }
+    }
+
     pub(crate) fn effective_frame_count(&self) -> usize {
         self.overrides
             .frame_count
@@ -171,6 +263,105 @@ impl RunConfig {
     }
 }
 
+/// A user-authored scene fixture plus the identity recorded in run artifacts.
+///
+/// The hash covers the fixture's canonical JSON rather than the source bytes,
+/// so reformatting a file keeps its hash while any authored value changes it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FixtureSource {
+    pub fixture: SceneFixture,
+    /// File the fixture was read from; `None` for inline fixtures.
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+    pub hash: String,
+    /// Directory `path` was confined to, re-checked on every reload.
+    #[serde(skip)]
+    root: Option<PathBuf>,
+}
+
+impl FixtureSource {
+    /// Wraps an inline fixture after checking that it converts into a recipe.
+    pub fn new(fixture: SceneFixture) -> LabResult<Self> {
+        Self::with_path(fixture, None)
+    }
+
+    /// Decodes and validates an inline fixture JSON value.
+    pub fn from_json(value: serde_json::Value) -> LabResult<Self> {
+        let fixture = serde_json::from_value(value).map_err(|error| fixture_error(None, error))?;
+        Self::with_path(fixture, None)
+    }
+
+    /// Reads and validates a fixture JSON file.
+    pub fn from_path(path: impl AsRef<Path>) -> LabResult<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|error| fixture_error(Some(path), error))?;
+        let fixture =
+            serde_json::from_slice(&bytes).map_err(|error| fixture_error(Some(path), error))?;
+        Self::with_path(fixture, Some(path.to_path_buf()))
+    }
+
+    /// Reads a fixture file that must resolve, symlinks included, to a file
+    /// under `root`. Relative paths are taken from `root`.
+    ///
+    /// Every failure reports the same error, so callers outside the root
+    /// cannot probe which files exist.
+    pub fn from_path_within(root: impl AsRef<Path>, path: impl AsRef<Path>) -> LabResult<Self> {
+        let path = path.as_ref();
+        let outside = || {
+            LabError::InvalidScenarioSource(format!(
+                "fixture path {} is not a file under the fixtures root",
+                path.display()
+            ))
+        };
+        let root = root.as_ref().canonicalize().map_err(|_| outside())?;
+        let resolved = root.join(path).canonicalize().map_err(|_| outside())?;
+        if !resolved.starts_with(&root) || !resolved.is_file() {
+            return Err(outside());
+        }
+        let mut source = Self::from_path(resolved)?;
+        source.root = Some(root);
+        Ok(source)
+    }
+
+    /// Re-reads a file-backed fixture so resets pick up edits; inline
+    /// fixtures are returned unchanged.
+    pub fn reload(&self) -> LabResult<Self> {
+        match (&self.path, &self.root) {
+            (Some(path), Some(root)) => Self::from_path_within(root, path),
+            (Some(path), None) => Self::from_path(path),
+            (None, _) => Ok(self.clone()),
+        }
+    }
+
+    fn with_path(fixture: SceneFixture, path: Option<PathBuf>) -> LabResult<Self> {
+        fixture
+            .to_world_recipe()
+            .map_err(|error| fixture_error(path.as_deref(), error))?;
+        let hash = state_hash(&fixture)?;
+        Ok(Self {
+            fixture,
+            path,
+            hash,
+            root: None,
+        })
+    }
+
+    pub fn provenance(&self) -> FixtureProvenance {
+        FixtureProvenance {
+            hash: self.hash.clone(),
+            path: self.path.as_ref().map(|path| path.display().to_string()),
+        }
+    }
+}
+
+/// Fixture identity written to manifests and debug render artifacts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureProvenance {
+    pub hash: String,
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
 /// Serializable scene setup fixture used by lab examples and smoke tests.
 ///
 /// Schema v1 covers the stable authoring layer for world flags, body placement,
@@ -178,6 +369,9 @@ impl RunConfig {
 /// distance/world-anchor joints. The fixture stays above low-level `World`
 /// commands: JSON is converted into a `WorldRecipe`, and the core command layer
 /// still owns handle resolution and validation paths.
+///
+/// Schema v2 ([`SceneRecipeFixtureV2`]) covers every descriptor field; v1
+/// documents are converted with [`SceneRecipeFixture::migrate`].
 pub const SCENE_RECIPE_SCHEMA_VERSION: u32 = 1;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -194,18 +388,31 @@ pub struct SceneRecipeFixture {
 
 impl SceneRecipeFixture {
     pub fn to_world_recipe(&self) -> LabResult<WorldRecipe> {
+        self.migrate()?.to_world_recipe()
+    }
+
+    /// Converts this v1 document into the equivalent schema v2 document.
+    ///
+    /// The v1 validation runs first, so v1 authoring errors keep their
+    /// `scene.bodies[i].shape` paths instead of the v2 collider paths.
+    pub fn migrate(&self) -> LabResult<SceneRecipeFixtureV2> {
         self.validate_schema_version()?;
-        let mut recipe = WorldRecipe::new(WorldDesc {
-            gravity: self.world.gravity.into(),
-            enable_sleep: self.world.enable_sleep,
-        });
-        for (body_index, body) in self.bodies.iter().enumerate() {
-            recipe = recipe.with_scene_body(body.to_body_bundle(body_index)?);
-        }
-        for joint in &self.joints {
-            recipe = recipe.with_joint(joint.to_joint_bundle());
-        }
-        Ok(recipe)
+        let bodies = self
+            .bodies
+            .iter()
+            .enumerate()
+            .map(|(body_index, body)| body.migrate(body_index))
+            .collect::<LabResult<_>>()?;
+        Ok(SceneRecipeFixtureV2 {
+            world: SceneFixtureWorldV2 {
+                gravity: self.world.gravity,
+                enable_sleep: self.world.enable_sleep,
+                ..SceneFixtureWorldV2::default()
+            },
+            bodies,
+            joints: self.joints.clone(),
+            ..SceneRecipeFixtureV2::default()
+        })
     }
 
     fn validate_schema_version(&self) -> LabResult<()> {
@@ -223,7 +430,7 @@ impl SceneRecipeFixture {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SceneFixtureWorld {
     #[serde(default = "default_fixture_gravity")]
-    pub gravity: [f32; 2],
+    pub gravity: [FloatNum; 2],
     #[serde(default = "default_fixture_enable_sleep")]
     pub enable_sleep: bool,
 }
@@ -242,9 +449,9 @@ pub struct SceneBodyFixture {
     #[serde(default)]
     pub body_type: BodyType,
     #[serde(default)]
-    pub pose: [f32; 3],
+    pub pose: [FloatNum; 3],
     #[serde(default)]
-    pub linear_velocity: [f32; 2],
+    pub linear_velocity: [FloatNum; 2],
     #[serde(default = "default_fixture_can_sleep")]
     pub can_sleep: bool,
     pub shape: SceneShapeFixture,
@@ -253,7 +460,7 @@ pub struct SceneBodyFixture {
     #[serde(default)]
     pub filter: CollisionLayerPreset,
     #[serde(default = "default_fixture_density")]
-    pub density: f32,
+    pub density: FloatNum,
     #[serde(default)]
     pub is_sensor: bool,
 }
@@ -276,7 +483,7 @@ pub struct CompoundProvenance {
     #[serde(default)]
     pub inherited_filter: CollisionLayerPreset,
     #[serde(default = "default_fixture_density")]
-    pub inherited_density: f32,
+    pub inherited_density: FloatNum,
     #[serde(default)]
     pub inherited_is_sensor: bool,
     #[serde(default)]
@@ -292,35 +499,46 @@ pub struct CompoundProvenancePiece {
     #[serde(default)]
     pub validation_path: String,
     #[serde(default)]
-    pub local_pose: [f32; 3],
+    pub local_pose: [FloatNum; 3],
 }
 
 impl SceneBodyFixture {
-    fn to_body_bundle(&self, body_index: usize) -> LabResult<BodyBundle> {
+    pub(crate) fn to_body_bundle(&self, body_index: usize) -> LabResult<BodyBundle> {
+        self.migrate(body_index)?.to_body_bundle(body_index)
+    }
+
+    /// Spreads the body-level material, filter, density and sensor flag onto
+    /// every collider the v1 shape expands to.
+    fn migrate(&self, body_index: usize) -> LabResult<SceneBodyFixtureV2> {
         let colliders = self
             .shape
-            .to_collider_bundles(body_index)?
+            .migrate(body_index)?
             .into_iter()
-            .map(|collider| {
-                collider
-                    .with_material(self.material)
-                    .with_filter(self.filter)
-                    .with_density(self.density)
-                    .with_sensor(self.is_sensor)
-            });
-        let base = match self.body_type {
-            BodyType::Static => BodyBundle::static_body(),
-            BodyType::Dynamic => BodyBundle::dynamic(),
-            BodyType::Kinematic => BodyBundle::kinematic(),
-        }
-        .with_colliders(colliders);
-        let asset = BodyAsset::from_bundle(base);
-        let [x, y, angle] = self.pose;
-        let mut bundle = asset.at(Pose::from_xy_angle(x, y, angle));
-        let [vx, vy] = self.linear_velocity;
-        bundle.desc.linear_velocity = Vector::new(vx, vy);
-        bundle.desc.can_sleep = self.can_sleep;
-        Ok(bundle)
+            .map(|(shape, local_pose)| SceneColliderFixture {
+                local_pose,
+                density: self.density,
+                material: SceneMaterialFixture::Preset(self.material),
+                filter: SceneFilterFixture::Preset(self.filter),
+                is_sensor: self.is_sensor,
+                ..SceneColliderFixture::new(shape)
+            })
+            .collect();
+        Ok(SceneBodyFixtureV2 {
+            body_type: self.body_type,
+            pose: self.pose,
+            linear_velocity: self.linear_velocity,
+            can_sleep: self.can_sleep,
+            colliders,
+            compound: matches!(self.shape, SceneShapeFixture::Compound { .. }).then_some(
+                SceneCompoundFixture {
+                    material: self.material,
+                    filter: self.filter,
+                    density: self.density,
+                    is_sensor: self.is_sensor,
+                },
+            ),
+            ..SceneBodyFixtureV2::default()
+        })
     }
 }
 
@@ -337,12 +555,43 @@ pub enum SceneJointFixture {
 }
 
 impl SceneJointFixture {
-    fn to_joint_bundle(&self) -> JointBundle {
+    pub(crate) fn to_joint_bundle(&self) -> JointBundle {
         match self {
             Self::Distance(joint) => joint.to_joint_bundle(),
             Self::WorldAnchor(joint) => joint.to_joint_bundle(),
         }
     }
+
+    /// Spells out every descriptor field so exported joints do not depend on
+    /// the core defaults of the reader.
+    pub(crate) fn from_joint_bundle(joint: &JointBundle) -> Self {
+        match joint {
+            JointBundle::Distance {
+                body_a,
+                body_b,
+                desc,
+            } => Self::Distance(SceneDistanceJointFixture {
+                body_a: *body_a,
+                body_b: *body_b,
+                rest_length: Some(desc.rest_length),
+                stiffness: Some(desc.stiffness),
+                damping: Some(desc.damping),
+                local_anchor_a: Some(point_to_array(desc.local_anchor_a)),
+                local_anchor_b: Some(point_to_array(desc.local_anchor_b)),
+                user_data: Some(desc.user_data),
+            }),
+            JointBundle::WorldAnchor { body, desc } => {
+                Self::WorldAnchor(SceneWorldAnchorJointFixture {
+                    body: *body,
+                    world_anchor: Some(point_to_array(desc.world_anchor)),
+                    local_anchor: Some(point_to_array(desc.local_anchor)),
+                    stiffness: Some(desc.stiffness),
+                    damping: Some(desc.damping),
+                    user_data: Some(desc.user_data),
+                })
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -350,15 +599,17 @@ pub struct SceneDistanceJointFixture {
     pub body_a: usize,
     pub body_b: usize,
     #[serde(default)]
-    pub rest_length: Option<f32>,
+    pub rest_length: Option<FloatNum>,
     #[serde(default)]
-    pub stiffness: Option<f32>,
+    pub stiffness: Option<FloatNum>,
     #[serde(default)]
-    pub damping: Option<f32>,
+    pub damping: Option<FloatNum>,
     #[serde(default)]
-    pub local_anchor_a: Option<[f32; 2]>,
+    pub local_anchor_a: Option<[FloatNum; 2]>,
     #[serde(default)]
-    pub local_anchor_b: Option<[f32; 2]>,
+    pub local_anchor_b: Option<[FloatNum; 2]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_data: Option<u64>,
 }
 
 impl SceneDistanceJointFixture {
@@ -379,6 +630,9 @@ impl SceneDistanceJointFixture {
         if let Some(local_anchor_b) = self.local_anchor_b {
             desc.local_anchor_b = point_from_array(local_anchor_b);
         }
+        if let Some(user_data) = self.user_data {
+            desc.user_data = user_data;
+        }
         JointBundle::Distance {
             body_a: self.body_a,
             body_b: self.body_b,
@@ -391,13 +645,15 @@ impl SceneDistanceJointFixture {
 pub struct SceneWorldAnchorJointFixture {
     pub body: usize,
     #[serde(default)]
-    pub world_anchor: Option<[f32; 2]>,
+    pub world_anchor: Option<[FloatNum; 2]>,
     #[serde(default)]
-    pub local_anchor: Option<[f32; 2]>,
+    pub local_anchor: Option<[FloatNum; 2]>,
     #[serde(default)]
-    pub stiffness: Option<f32>,
+    pub stiffness: Option<FloatNum>,
     #[serde(default)]
-    pub damping: Option<f32>,
+    pub damping: Option<FloatNum>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_data: Option<u64>,
 }
 
 impl SceneWorldAnchorJointFixture {
@@ -415,6 +671,9 @@ impl SceneWorldAnchorJointFixture {
         if let Some(damping) = self.damping {
             desc.damping = damping;
         }
+        if let Some(user_data) = self.user_data {
+            desc.user_data = user_data;
+        }
         JointBundle::WorldAnchor {
             body: self.body,
             desc,
@@ -426,20 +685,20 @@ impl SceneWorldAnchorJointFixture {
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SceneShapeFixture {
     Circle {
-        radius: f32,
+        radius: FloatNum,
     },
     Rect {
-        width: f32,
-        height: f32,
+        width: FloatNum,
+        height: FloatNum,
     },
     ConvexPolygon {
-        vertices: Vec<[f32; 2]>,
+        vertices: Vec<[FloatNum; 2]>,
     },
     Compound {
         pieces: Vec<SceneCompoundPieceFixture>,
     },
     ConcavePolygon {
-        vertices: Vec<[f32; 2]>,
+        vertices: Vec<[FloatNum; 2]>,
     },
 }
 
@@ -452,7 +711,7 @@ pub enum SceneShapeFixture {
 pub struct SceneCompoundPieceFixture {
     pub shape: SceneCompoundPieceShapeFixture,
     #[serde(default)]
-    pub local_pose: Option<[f32; 3]>,
+    pub local_pose: Option<[FloatNum; 3]>,
 }
 
 /// Authorable convex piece shapes for the M22 fixture boundary.
@@ -463,9 +722,9 @@ pub struct SceneCompoundPieceFixture {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SceneCompoundPieceShapeFixture {
-    Circle { radius: f32 },
-    Rect { width: f32, height: f32 },
-    ConvexPolygon { vertices: Vec<[f32; 2]> },
+    Circle { radius: FloatNum },
+    Rect { width: FloatNum, height: FloatNum },
+    ConvexPolygon { vertices: Vec<[FloatNum; 2]> },
 }
 
 impl SceneCompoundPieceFixture {
@@ -478,12 +737,8 @@ impl SceneCompoundPieceFixture {
         Ok(())
     }
 
-    fn to_collider_bundle(&self) -> ColliderBundle {
-        let mut collider = self.shape.to_collider_bundle();
-        if let Some(local_pose) = self.local_pose {
-            collider = collider.with_local_pose(pose_from_array(local_pose));
-        }
-        collider
+    fn migrate(&self) -> (SceneColliderShapeFixture, [FloatNum; 3]) {
+        ((&self.shape).into(), local_pose_or_origin(self.local_pose))
     }
 }
 
@@ -501,38 +756,42 @@ impl SceneCompoundPieceShapeFixture {
             }
         }
     }
-
-    fn to_collider_bundle(&self) -> ColliderBundle {
-        match self {
-            Self::Circle { radius } => ColliderBundle::circle(*radius),
-            Self::Rect { width, height } => ColliderBundle::rect(*width, *height),
-            Self::ConvexPolygon { vertices } => {
-                ColliderBundle::new(SharedShape::convex_polygon(points_from_arrays(vertices)))
-            }
-        }
-    }
 }
 
 impl SceneShapeFixture {
-    fn to_collider_bundles(&self, body_index: usize) -> LabResult<Vec<ColliderBundle>> {
+    /// Expands the v1 shape into v2 collider shapes with their local poses.
+    fn migrate(
+        &self,
+        body_index: usize,
+    ) -> LabResult<Vec<(SceneColliderShapeFixture, [FloatNum; 3])>> {
+        let origin = [0.0; 3];
         match self {
-            Self::Circle { radius } => Ok(vec![ColliderBundle::circle(*radius)]),
-            Self::Rect { width, height } => Ok(vec![ColliderBundle::rect(*width, *height)]),
+            Self::Circle { radius } => Ok(vec![(
+                SceneColliderShapeFixture::Circle { radius: *radius },
+                origin,
+            )]),
+            Self::Rect { width, height } => Ok(vec![(
+                SceneColliderShapeFixture::Rect {
+                    width: *width,
+                    height: *height,
+                },
+                origin,
+            )]),
             Self::ConvexPolygon { vertices } => {
                 validate_convex_vertices(
                     &format!("scene.bodies[{body_index}].shape.vertices"),
                     vertices,
                 )?;
-                Ok(vec![ColliderBundle::new(SharedShape::convex_polygon(
-                    points_from_arrays(vertices),
-                ))])
+                Ok(vec![(
+                    SceneColliderShapeFixture::ConvexPolygon {
+                        vertices: vertices.clone(),
+                    },
+                    origin,
+                )])
             }
             Self::Compound { pieces } => {
                 validate_compound_pieces(body_index, pieces)?;
-                Ok(pieces
-                    .iter()
-                    .map(SceneCompoundPieceFixture::to_collider_bundle)
-                    .collect())
+                Ok(pieces.iter().map(SceneCompoundPieceFixture::migrate).collect())
             }
             Self::ConcavePolygon { .. } => Err(LabError::World(format!(
                 "scene.bodies[{body_index}].shape: concave_polygon is not supported directly; use compound with convex pieces"
@@ -549,7 +808,7 @@ impl SceneShapeFixture {
 }
 
 pub fn instantiate_scene_fixture(fixture: &SceneRecipeFixture) -> LabResult<World> {
-    instantiate_scene_fixture_with_provenance(fixture).map(|result| result.world)
+    instantiate_scene_fixture_with_provenance(&fixture.clone().into()).map(|result| result.world)
 }
 
 pub(crate) struct InstantiatedSceneFixture {
@@ -558,7 +817,7 @@ pub(crate) struct InstantiatedSceneFixture {
 }
 
 pub(crate) fn instantiate_scene_fixture_with_provenance(
-    fixture: &SceneRecipeFixture,
+    fixture: &SceneFixture,
 ) -> LabResult<InstantiatedSceneFixture> {
     let result = fixture.to_world_recipe().and_then(|recipe| {
         recipe
@@ -566,37 +825,55 @@ pub(crate) fn instantiate_scene_fixture_with_provenance(
             .map_err(|error| LabError::World(format!("{}: {}", error.path, error.error.error)))
     })?;
 
-    let compound_provenance = build_compound_provenance(fixture, &result.created);
+    let compound_provenance = match fixture {
+        SceneFixture::V1(fixture) => build_compound_provenance(fixture, &result.created),
+        SceneFixture::V2(fixture) => fixture.compound_provenance(&result.created),
+    };
     Ok(InstantiatedSceneFixture {
         world: result.world,
         compound_provenance,
     })
 }
 
+fn fixture_error(path: Option<&Path>, error: impl Display) -> LabError {
+    LabError::InvalidFixture(match path {
+        Some(path) => format!("{}: {error}", path.display()),
+        None => error.to_string(),
+    })
+}
+
 fn default_scene_recipe_schema_version() -> u32 {
     SCENE_RECIPE_SCHEMA_VERSION
 }
 
-fn default_fixture_gravity() -> [f32; 2] {
+pub(crate) fn default_fixture_gravity() -> [FloatNum; 2] {
     [0.0, 9.8]
 }
 
-fn default_fixture_enable_sleep() -> bool {
+pub(crate) fn default_fixture_enable_sleep() -> bool {
     true
 }
 
-fn default_fixture_can_sleep() -> bool {
+pub(crate) fn default_fixture_can_sleep() -> bool {
     true
 }
 
-fn default_fixture_density() -> f32 {
+pub(crate) fn default_fixture_density() -> FloatNum {
     1.0
 }
 
-fn point_from_array([x, y]: [f32; 2]) -> Point {
+pub(crate) fn point_from_array([x, y]: [FloatNum; 2]) -> Point {
     Point::new(x, y)
 }
 
+pub(crate) fn point_to_array(point: Point) -> [FloatNum; 2] {
+    [point.x(), point.y()]
+}
+
+pub(crate) fn vector_to_array(vector: Vector) -> [FloatNum; 2] {
+    [vector.x(), vector.y()]
+}
+
 fn validate_compound_pieces(
     body_index: usize,
     pieces: &[SceneCompoundPieceFixture],
@@ -616,13 +893,8 @@ fn validate_compound_pieces(
 
 /// The scene fixture owns stable authoring errors for the validated fixture
 /// shape fields below, so these failures do not bounce back from recipe paths.
-fn validate_convex_vertices(path: &str, vertices: &[[f32; 2]]) -> LabResult<()> {
-    let has_enough_vertices = vertices.len() >= 3 && distinct_vertex_count(vertices) >= 3;
-    let vertices_are_finite = polygon_vertices_are_finite(vertices);
-    let has_no_zero_length_edges = polygon_has_no_zero_length_edges(vertices);
-    let has_area = polygon_twice_area(vertices).abs() > f32::EPSILON;
-
-    if !has_enough_vertices || !vertices_are_finite || !has_no_zero_length_edges || !has_area {
+pub(crate) fn validate_convex_vertices(path: &str, vertices: &[[FloatNum; 2]]) -> LabResult<()> {
+    if !polygon_is_non_degenerate(vertices) {
         Err(LabError::World(format!(
             "{path}: convex_polygon requires at least 3 non-degenerate vertices"
         )))
@@ -635,7 +907,17 @@ fn validate_convex_vertices(path: &str, vertices: &[[f32; 2]]) -> LabResult<()>
     }
 }
 
-fn validate_circle_radius(path: &str, radius: f32) -> LabResult<()> {
+pub(crate) fn validate_concave_vertices(path: &str, vertices: &[[FloatNum; 2]]) -> LabResult<()> {
+    if polygon_is_non_degenerate(vertices) {
+        Ok(())
+    } else {
+        Err(LabError::World(format!(
+            "{path}: concave_polygon requires at least 3 non-degenerate vertices"
+        )))
+    }
+}
+
+fn validate_circle_radius(path: &str, radius: FloatNum) -> LabResult<()> {
     if radius.is_finite() && radius > 0.0 {
         Ok(())
     } else {
@@ -645,18 +927,18 @@ fn validate_circle_radius(path: &str, radius: f32) -> LabResult<()> {
     }
 }
 
-fn validate_rect_size(path: &str, width: f32, height: f32) -> LabResult<()> {
+fn validate_rect_size(path: &str, width: FloatNum, height: FloatNum) -> LabResult<()> {
     validate_positive_finite_scalar(&format!("{path}.width"), width, "rect width")?;
     validate_positive_finite_scalar(&format!("{path}.height"), height, "rect height")
 }
 
-fn validate_local_pose(path: &str, [x, y, angle]: [f32; 3]) -> LabResult<()> {
+pub(crate) fn validate_local_pose(path: &str, [x, y, angle]: [FloatNum; 3]) -> LabResult<()> {
     validate_finite_scalar(&format!("{path}.x"), x, "local_pose.x")?;
     validate_finite_scalar(&format!("{path}.y"), y, "local_pose.y")?;
     validate_finite_scalar(&format!("{path}.angle"), angle, "local_pose.angle")
 }
 
-fn validate_positive_finite_scalar(path: &str, value: f32, label: &str) -> LabResult<()> {
+fn validate_positive_finite_scalar(path: &str, value: FloatNum, label: &str) -> LabResult<()> {
     if value.is_finite() && value > 0.0 {
         Ok(())
     } else {
@@ -666,7 +948,7 @@ fn validate_positive_finite_scalar(path: &str, value: f32, label: &str) -> LabRe
     }
 }
 
-fn validate_finite_scalar(path: &str, value: f32, label: &str) -> LabResult<()> {
+fn validate_finite_scalar(path: &str, value: FloatNum, label: &str) -> LabResult<()> {
     if value.is_finite() {
         Ok(())
     } else {
@@ -674,7 +956,15 @@ fn validate_finite_scalar(path: &str, value: f32, label: &str) -> LabResult<()>
     }
 }
 
-fn distinct_vertex_count(vertices: &[[f32; 2]]) -> usize {
+fn polygon_is_non_degenerate(vertices: &[[FloatNum; 2]]) -> bool {
+    vertices.len() >= 3
+        && distinct_vertex_count(vertices) >= 3
+        && polygon_vertices_are_finite(vertices)
+        && polygon_has_no_zero_length_edges(vertices)
+        && polygon_twice_area(vertices).abs() > FloatNum::EPSILON
+}
+
+fn distinct_vertex_count(vertices: &[[FloatNum; 2]]) -> usize {
     let mut distinct = Vec::with_capacity(vertices.len());
     for vertex in vertices {
         if !distinct.iter().any(|existing| existing == vertex) {
@@ -684,11 +974,11 @@ fn distinct_vertex_count(vertices: &[[f32; 2]]) -> usize {
     distinct.len()
 }
 
-fn polygon_vertices_are_finite(vertices: &[[f32; 2]]) -> bool {
+fn polygon_vertices_are_finite(vertices: &[[FloatNum; 2]]) -> bool {
     vertices.iter().all(|[x, y]| x.is_finite() && y.is_finite())
 }
 
-fn polygon_has_no_zero_length_edges(vertices: &[[f32; 2]]) -> bool {
+fn polygon_has_no_zero_length_edges(vertices: &[[FloatNum; 2]]) -> bool {
     if vertices.len() < 2 {
         return true;
     }
@@ -698,7 +988,7 @@ fn polygon_has_no_zero_length_edges(vertices: &[[f32; 2]]) -> bool {
         let next = vertices[(index + 1) % vertices.len()];
         let edge_x = next[0] - current[0];
         let edge_y = next[1] - current[1];
-        if edge_x.abs() <= f32::EPSILON && edge_y.abs() <= f32::EPSILON {
+        if edge_x.abs() <= FloatNum::EPSILON && edge_y.abs() <= FloatNum::EPSILON {
             return false;
         }
     }
@@ -706,7 +996,7 @@ fn polygon_has_no_zero_length_edges(vertices: &[[f32; 2]]) -> bool {
     true
 }
 
-fn polygon_twice_area(vertices: &[[f32; 2]]) -> f32 {
+fn polygon_twice_area(vertices: &[[FloatNum; 2]]) -> FloatNum {
     if vertices.len() < 3 {
         return 0.0;
     }
@@ -722,15 +1012,15 @@ fn polygon_twice_area(vertices: &[[f32; 2]]) -> f32 {
 
 /// "Convex" here means the authored loop turns consistently around the shape.
 /// Collinear edges are tolerated so authors can keep explicit seam vertices.
-fn polygon_is_convex(vertices: &[[f32; 2]]) -> bool {
-    let mut winding_sign = 0.0_f32;
+fn polygon_is_convex(vertices: &[[FloatNum; 2]]) -> bool {
+    let mut winding_sign: FloatNum = 0.0;
 
     for index in 0..vertices.len() {
         let previous = vertices[(index + vertices.len() - 1) % vertices.len()];
         let current = vertices[index];
         let next = vertices[(index + 1) % vertices.len()];
         let turn = polygon_turn_cross(previous, current, next);
-        if turn.abs() <= f32::EPSILON {
+        if turn.abs() <= FloatNum::EPSILON {
             continue;
         }
 
@@ -747,7 +1037,11 @@ fn polygon_is_convex(vertices: &[[f32; 2]]) -> bool {
     true
 }
 
-fn polygon_turn_cross(previous: [f32; 2], current: [f32; 2], next: [f32; 2]) -> f32 {
+fn polygon_turn_cross(
+    previous: [FloatNum; 2],
+    current: [FloatNum; 2],
+    next: [FloatNum; 2],
+) -> FloatNum {
     let incoming_x = current[0] - previous[0];
     let incoming_y = current[1] - previous[1];
     let outgoing_x = next[0] - current[0];
@@ -755,19 +1049,24 @@ fn polygon_turn_cross(previous: [f32; 2], current: [f32; 2], next: [f32; 2]) ->
     incoming_x * outgoing_y - incoming_y * outgoing_x
 }
 
-fn points_from_arrays(vertices: &[[f32; 2]]) -> Vec<Point> {
+pub(crate) fn points_from_arrays(vertices: &[[FloatNum; 2]]) -> Vec<Point> {
     vertices.iter().copied().map(point_from_array).collect()
 }
 
-fn pose_from_array([x, y, angle]: [f32; 3]) -> Pose {
+pub(crate) fn pose_from_array([x, y, angle]: [FloatNum; 3]) -> Pose {
     Pose::from_xy_angle(x, y, angle)
 }
 
-fn pose_to_array(pose: Option<[f32; 3]>) -> [f32; 3] {
+pub(crate) fn pose_to_array(pose: Pose) -> [FloatNum; 3] {
+    let translation = pose.translation();
+    [translation.x(), translation.y(), pose.angle()]
+}
+
+fn local_pose_or_origin(pose: Option<[FloatNum; 3]>) -> [FloatNum; 3] {
     pose.unwrap_or([0.0, 0.0, 0.0])
 }
 
-fn falling_box_contact_fixture(gravity: [f32; 2]) -> SceneRecipeFixture {
+fn falling_box_contact_fixture(gravity: [FloatNum; 2]) -> SceneRecipeFixture {
     SceneRecipeFixture {
         schema_version: SCENE_RECIPE_SCHEMA_VERSION,
         world: SceneFixtureWorld {
@@ -896,7 +1195,7 @@ fn build_compound_provenance(
                         validation_path: format!(
                             "scene.bodies[{body_index}].shape.pieces[{piece_index}]"
                         ),
-                        local_pose: pose_to_array(piece.local_pose),
+                        local_pose: local_pose_or_origin(piece.local_pose),
                     })
                     .collect(),
             });
@@ -910,6 +1209,53 @@ fn build_compound_provenance(
 pub(crate) struct BuiltScenario {
     pub(crate) world: World,
     pub(crate) compound_provenance: Vec<CompoundProvenance>,
+    pub(crate) vehicle: Option<VehicleController>,
+}
+
+/// Frames of full throttle before the vehicle scenario switches to braking.
+const VEHICLE_THROTTLE_FRAMES: usize = 150;
+const VEHICLE_ENGINE_TORQUE: FloatNum = 0.3;
+const VEHICLE_BRAKE_TORQUE: FloatNum = 1.0;
+
+impl BuiltScenario {
+    /// Feeds scripted controller input to the world before the next step.
+    pub(crate) fn before_step(&mut self, frame_index: usize, dt: FloatNum) -> LabResult<()> {
+        if let Some(vehicle) = &mut self.vehicle {
+            let braking = frame_index >= VEHICLE_THROTTLE_FRAMES;
+            vehicle.engine_torque = if braking { 0.0 } else { VEHICLE_ENGINE_TORQUE };
+            vehicle.brake_torque = if braking { VEHICLE_BRAKE_TORQUE } else { 0.0 };
+            vehicle
+                .update(&mut self.world, dt)
+                .map_err(|error| LabError::World(error.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Wheel states of the scenario vehicle, empty when there is none.
+    pub(crate) fn vehicle_wheels(&self) -> Vec<WheelState> {
+        self.vehicle
+            .as_ref()
+            .map(|vehicle| vehicle.wheel_states().to_vec())
+            .unwrap_or_default()
+    }
+}
+
+/// Builds the world for a run from its builtin scenario or fixture.
+pub(crate) fn build_run_scenario(config: &RunConfig) -> LabResult<BuiltScenario> {
+    let source = match &config.source {
+        RunSource::Builtin(id) => return build_scenario(*id, &config.overrides),
+        RunSource::Fixture(source) => source,
+    };
+    let mut fixture = source.fixture.clone();
+    if let Some(gravity) = config.overrides.gravity {
+        fixture.set_gravity(gravity);
+    }
+    let instantiated = instantiate_scene_fixture_with_provenance(&fixture)?;
+    Ok(BuiltScenario {
+        world: instantiated.world,
+        compound_provenance: instantiated.compound_provenance,
+        vehicle: None,
+    })
 }
 
 pub(crate) fn build_scenario(
@@ -923,6 +1269,7 @@ pub(crate) fn build_scenario(
     let mut world = World::new(WorldDesc {
         gravity,
         enable_sleep: true,
+        ..WorldDesc::default()
     });
 
     match id {
@@ -939,7 +1286,7 @@ pub(crate) fn build_scenario(
                     &mut world,
                     BodyType::Dynamic,
                     0.0,
-                    1.7 - index as f32,
+                    1.7 - index as FloatNum,
                     0.9,
                     0.9,
                 )?;
@@ -949,6 +1296,7 @@ pub(crate) fn build_scenario(
             world = World::new(WorldDesc {
                 gravity: Vector::default(),
                 enable_sleep: false,
+                ..WorldDesc::default()
             });
             let body = add_box(&mut world, BodyType::Dynamic, 2.0, 0.0, 0.8, 0.8)?;
             world
@@ -965,6 +1313,7 @@ pub(crate) fn build_scenario(
             world = World::new(WorldDesc {
                 gravity: Vector::default(),
                 enable_sleep: false,
+                ..WorldDesc::default()
             });
             add_box(&mut world, BodyType::Static, 0.0, 0.0, 1.0, 1.0)?;
             add_box(&mut world, BodyType::Static, 0.75, 0.0, 1.0, 1.0)?;
@@ -976,6 +1325,7 @@ pub(crate) fn build_scenario(
             world = World::new(WorldDesc {
                 gravity: Vector::default(),
                 enable_sleep: false,
+                ..WorldDesc::default()
             });
             add_box(&mut world, BodyType::Static, 0.0, 0.0, 2.0, 2.0)?;
             let body = world
@@ -1003,16 +1353,18 @@ pub(crate) fn build_scenario(
         }
         ScenarioId::CompoundProvenance => {
             let instantiated =
-                instantiate_scene_fixture_with_provenance(&compound_provenance_fixture())?;
+                instantiate_scene_fixture_with_provenance(&compound_provenance_fixture().into())?;
             return Ok(BuiltScenario {
                 world: instantiated.world,
                 compound_provenance: instantiated.compound_provenance,
+                vehicle: None,
             });
         }
         ScenarioId::CcdFastCircleWall => {
             world = World::new(WorldDesc {
                 gravity: Vector::default(),
                 enable_sleep: false,
+                ..WorldDesc::default()
             });
             add_box(&mut world, BodyType::Static, 0.0, 0.0, 0.1, 10.0)?;
             add_circle(
@@ -1028,6 +1380,7 @@ pub(crate) fn build_scenario(
             world = World::new(WorldDesc {
                 gravity: Vector::default(),
                 enable_sleep: false,
+                ..WorldDesc::default()
             });
             add_box(&mut world, BodyType::Static, 0.0, 0.0, 0.1, 10.0)?;
             add_box(&mut world, BodyType::Static, 0.8, 0.0, 0.1, 10.0)?;
@@ -1045,6 +1398,7 @@ pub(crate) fn build_scenario(
             world = World::new(WorldDesc {
                 gravity: Vector::default(),
                 enable_sleep: false,
+                ..WorldDesc::default()
             });
             add_box_with_velocity(
                 &mut world,
@@ -1065,21 +1419,50 @@ pub(crate) fn build_scenario(
                 Vector::new(-200.0, 0.0),
             )?;
         }
+        ScenarioId::VehicleRaycast => {
+            add_box(&mut world, BodyType::Static, 20.0, 2.5, 60.0, 0.5)?;
+            for (x, width, height) in [(6.0, 1.6, 0.2), (12.0, 3.0, 0.4)] {
+                add_static_polygon(
+                    &mut world,
+                    vec![
+                        Point::new(x - width / 2.0, 2.25),
+                        Point::new(x + width / 2.0, 2.25),
+                        Point::new(x, 2.25 - height),
+                    ],
+                )?;
+            }
+            let chassis = add_box(&mut world, BodyType::Dynamic, 0.0, 1.25, 2.0, 0.5)?;
+            let vehicle = [-0.8, 0.8].into_iter().fold(
+                VehicleController::new(chassis),
+                |vehicle, anchor_x| {
+                    vehicle.with_wheel(WheelDesc {
+                        local_anchor: Point::new(anchor_x, 0.25),
+                        ..WheelDesc::default()
+                    })
+                },
+            );
+            return Ok(BuiltScenario {
+                world,
+                compound_provenance: Vec::new(),
+                vehicle: Some(vehicle),
+            });
+        }
     }
 
     Ok(BuiltScenario {
         world,
         compound_provenance: Vec::new(),
+        vehicle: None,
     })
 }
 
 fn add_box(
     world: &mut World,
     body_type: BodyType,
-    x: f32,
-    y: f32,
-    width: f32,
-    height: f32,
+    x: FloatNum,
+    y: FloatNum,
+    width: FloatNum,
+    height: FloatNum,
 ) -> LabResult<BodyHandle> {
     let body = world
         .create_body(BodyDesc {
@@ -1101,13 +1484,33 @@ fn add_box(
     Ok(body)
 }
 
+fn add_static_polygon(world: &mut World, vertices: Vec<Point>) -> LabResult<BodyHandle> {
+    let body = world
+        .create_body(BodyDesc {
+            body_type: BodyType::Static,
+            can_sleep: false,
+            ..BodyDesc::default()
+        })
+        .map_err(|error| LabError::World(error.to_string()))?;
+    world
+        .create_collider(
+            body,
+            ColliderDesc {
+                shape: SharedShape::convex_polygon(vertices),
+                ..ColliderDesc::default()
+            },
+        )
+        .map_err(|error| LabError::World(error.to_string()))?;
+    Ok(body)
+}
+
 fn add_box_with_velocity(
     world: &mut World,
     body_type: BodyType,
-    x: f32,
-    y: f32,
-    width: f32,
-    height: f32,
+    x: FloatNum,
+    y: FloatNum,
+    width: FloatNum,
+    height: FloatNum,
     linear_velocity: Vector,
 ) -> LabResult<BodyHandle> {
     let body = world
@@ -1134,9 +1537,9 @@ fn add_box_with_velocity(
 fn add_circle(
     world: &mut World,
     body_type: BodyType,
-    x: f32,
-    y: f32,
-    radius: f32,
+    x: FloatNum,
+    y: FloatNum,
+    radius: FloatNum,
     linear_velocity: Vector,
 ) -> LabResult<BodyHandle> {
     let body = world
@@ -1684,7 +2087,7 @@ mod tests {
                 shape: SceneShapeFixture::Compound {
                     pieces: vec![SceneCompoundPieceFixture {
                         shape: SceneCompoundPieceShapeFixture::Circle {
-                            radius: f32::INFINITY,
+                            radius: FloatNum::INFINITY,
                         },
                         local_pose: None,
                     }],
@@ -1759,7 +2162,7 @@ mod tests {
                 shape: SceneShapeFixture::Compound {
                     pieces: vec![SceneCompoundPieceFixture {
                         shape: SceneCompoundPieceShapeFixture::Circle { radius: 0.5 },
-                        local_pose: Some([0.0, 0.0, f32::INFINITY]),
+                        local_pose: Some([0.0, 0.0, FloatNum::INFINITY]),
                     }],
                 },
                 material: MaterialPreset::Default,
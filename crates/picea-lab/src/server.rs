@@ -1,12 +1,24 @@
 //! Local HTTP and SSE protocol for the C/S simulator.
 //!
-//! The server owns sessions and artifact lookup, but each reset delegates to the
-//! headless runner. That keeps live protocol state separate from physics state.
+//! The server owns sessions and artifact lookup. Recorded sessions delegate each
+//! reset to the headless runner and replay its frames; live sessions keep a
+//! [`LiveSession`] world in memory and stream snapshot deltas as it steps.
+//!
+//! Each session sits behind its own lock and runs on a blocking thread, so a
+//! long run or step holds up neither the runtime nor other sessions. A playing
+//! live session is stepped in real time by its own ticker task, and every
+//! route that touches it first catches it up with the wall clock. Each
+//! catch-up runs at most `max_substeps` steps and reports the time it could
+//! not cover as `dropped_time`. The events route stays open while a live
+//! session plays and streams its events as they are queued.
 
 use std::{
     collections::BTreeMap,
+    convert::Infallible,
+    path::PathBuf,
     str::FromStr,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
 use axum::{
@@ -17,45 +29,162 @@ use axum::{
     routing::{get, patch, post},
     Json, Router,
 };
+use futures_util::stream;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use tokio::{sync::watch, time::MissedTickBehavior};
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
 
 use crate::{
     artifact::{run_scenario, ArtifactFile, ArtifactStore, FrameRecord},
-    scenario::{list_scenarios, RunConfig, ScenarioId, ScenarioOverrides},
-    LabError,
+    fixture::SceneFixture,
+    live::{DebugSnapshotDelta, LiveCommand, LiveSession, LiveSnapshot},
+    scenario::{
+        list_scenarios, FixtureProvenance, FixtureSource, RunConfig, RunSource, ScenarioId,
+        ScenarioOverrides,
+    },
+    LabError, LabResult,
 };
 
 #[derive(Clone)]
 pub struct LabServerState {
-    inner: Arc<Mutex<LabServerInner>>,
+    store: ArtifactStore,
+    sessions: Arc<Mutex<SessionTable>>,
+    fixture_root: Option<Arc<PathBuf>>,
 }
 
 impl LabServerState {
     pub fn new(store: ArtifactStore) -> Self {
         Self {
-            inner: Arc::new(Mutex::new(LabServerInner {
-                store,
+            store,
+            sessions: Arc::new(Mutex::new(SessionTable {
                 next_session: 1,
                 sessions: BTreeMap::new(),
             })),
+            fixture_root: None,
         }
     }
+
+    /// Lets `fixture_path` session requests read scene files under `root`;
+    /// without a root the server rejects them.
+    pub fn with_fixture_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.fixture_root = Some(Arc::new(root.into()));
+        self
+    }
+
+    fn session(&self, id: &str) -> LabResult<Arc<SessionEntry>> {
+        self.sessions
+            .lock()
+            .expect("lab state mutex should not poison")
+            .sessions
+            .get(id)
+            .cloned()
+            .ok_or_else(|| LabError::SessionNotFound(id.to_owned()))
+    }
+
+    /// Runs `work` under the session's own lock on a blocking thread, then
+    /// wakes the session's event streams if events are queued.
+    async fn with_session<T: Send + 'static>(
+        &self,
+        id: &str,
+        work: impl FnOnce(&ArtifactStore, &mut SessionSlot) -> LabResult<T> + Send + 'static,
+    ) -> LabResult<T> {
+        let entry = self.session(id)?;
+        let store = self.store.clone();
+        run_blocking(move || {
+            let mut slot = entry.slot.lock().expect("session mutex should not poison");
+            let result = work(&store, &mut slot);
+            if !slot.record.events.is_empty() {
+                entry.events_queued.send_replace(());
+            }
+            result
+        })
+        .await
+    }
 }
 
-struct LabServerInner {
-    store: ArtifactStore,
+/// Session ids and their entries. The table lock is only held to look an
+/// entry up, never while a session runs or steps.
+struct SessionTable {
     next_session: u64,
-    sessions: BTreeMap<String, SessionRecord>,
+    sessions: BTreeMap<String, Arc<SessionEntry>>,
+}
+
+struct SessionEntry {
+    slot: Mutex<SessionSlot>,
+    /// Signalled whenever work leaves events in the session's queue.
+    events_queued: watch::Sender<()>,
+}
+
+struct SessionSlot {
+    record: SessionRecord,
+    live: Option<LiveSession>,
+    /// Whether a ticker task currently drives the live session.
+    ticking: bool,
+}
+
+impl SessionSlot {
+    /// Rebuilds the session from its source: a fresh live world, or a new
+    /// recorded run.
+    fn reset(&mut self, store: &ArtifactStore) {
+        if self.record.live {
+            self.live = start_live_session(&mut self.record);
+        } else {
+            run_session(store, &mut self.record);
+        }
+    }
+
+    fn catch_up(&mut self) -> LabResult<()> {
+        match &mut self.live {
+            Some(live) => catch_up_live_session(&mut self.record, live),
+            None => Ok(()),
+        }
+    }
+
+    fn is_playing(&self) -> bool {
+        self.live.as_ref().is_some_and(LiveSession::is_playing)
+    }
+
+    /// One ticker beat. Returns `false`, releasing the ticker, once the
+    /// session no longer plays; a failed step fails the session.
+    fn tick(&mut self) -> bool {
+        if !self.is_playing() {
+            self.ticking = false;
+            return false;
+        }
+        if let Err(error) = self.catch_up() {
+            fail_session(&mut self.record, error);
+            self.live = None;
+            self.ticking = false;
+            return false;
+        }
+        true
+    }
+
+    fn live_mut(&mut self) -> LabResult<(&mut SessionRecord, &mut LiveSession)> {
+        match &mut self.live {
+            Some(live) => Ok((&mut self.record, live)),
+            None => Err(LabError::SessionNotLive(self.record.id.clone())),
+        }
+    }
 }
 
+/// Queued live events beyond which a session collapses its backlog into one
+/// full snapshot, so an unobserved session cannot grow without bound.
+const LIVE_EVENT_BACKLOG: usize = 240;
+
+/// Most frames one `step` control may advance, so a single request cannot
+/// occupy a session for long.
+const MAX_CONTROL_STEPS: usize = 1000;
+
 /// A session is the server-owned handle for one scenario run and its
 /// current override state.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SessionRecord {
     pub id: String,
-    pub scenario_id: ScenarioId,
+    /// Builtin scenario of the session; `None` for fixture sessions.
+    #[serde(default)]
+    pub scenario_id: Option<ScenarioId>,
     pub status: SessionStatus,
     pub run_id: Option<String>,
     pub frame_count: usize,
@@ -65,6 +194,19 @@ pub struct SessionRecord {
     pub manifest_artifact: Option<String>,
     pub final_snapshot_artifact: Option<String>,
     pub last_error: Option<String>,
+    /// Identity of the scene fixture a fixture session runs.
+    #[serde(default)]
+    pub fixture: Option<FixtureProvenance>,
+    /// Live sessions step an in-memory world instead of replaying a run; their
+    /// frame count grows with every step.
+    #[serde(default)]
+    pub live: bool,
+    /// Wall-clock seconds a playing live session skipped because a catch-up
+    /// came later than `max_substeps` steps could cover.
+    #[serde(default)]
+    pub dropped_time: f64,
+    #[serde(skip)]
+    source: RunSource,
     #[serde(skip)]
     events: Vec<SessionEvent>,
 }
@@ -89,20 +231,48 @@ enum SessionEvent {
     Failed {
         message: String,
     },
+    Snapshot(Box<LiveSnapshot>),
+    Delta(Box<DebugSnapshotDelta>),
+    Dropped {
+        seconds: f64,
+    },
 }
 
+/// Exactly one of `scenario_id`, `fixture`, or `fixture_path` picks what the
+/// session runs.
 #[derive(Clone, Debug, Deserialize)]
 struct CreateSessionRequest {
-    scenario_id: ScenarioId,
+    #[serde(default)]
+    scenario_id: Option<ScenarioId>,
+    /// Inline scene fixture of any supported schema version; decoded after the
+    /// request so fixture errors surface as lab errors.
+    #[serde(default)]
+    fixture: Option<serde_json::Value>,
+    /// Scene fixture file relative to the server's fixtures root, re-read on
+    /// every reset. Paths that resolve outside the root are rejected.
+    #[serde(default)]
+    fixture_path: Option<PathBuf>,
     #[serde(default = "default_session_frame_count")]
     frame_count: usize,
     #[serde(default)]
     overrides: ScenarioOverrides,
+    /// Keep the world in memory and step it on request instead of recording a
+    /// run up front.
+    #[serde(default)]
+    live: bool,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 struct ControlRequest {
     action: String,
+    /// Frames advanced by `step`, at most [`MAX_CONTROL_STEPS`].
+    #[serde(default = "default_step_count")]
+    steps: usize,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct CommandsRequest {
+    commands: Vec<LiveCommand>,
 }
 
 pub fn app(state: LabServerState) -> Router {
@@ -112,6 +282,8 @@ pub fn app(state: LabServerState) -> Router {
         .route("/api/sessions/:id", get(get_session))
         .route("/api/sessions/:id/control", post(control_session))
         .route("/api/sessions/:id/overrides", patch(patch_overrides))
+        .route("/api/sessions/:id/commands", post(apply_commands))
+        .route("/api/sessions/:id/fixture", get(export_fixture))
         .route("/api/sessions/:id/events", get(session_events))
         .route("/api/runs/:id/artifacts/:file", get(get_artifact))
         .layer(CorsLayer::permissive())
@@ -123,6 +295,10 @@ fn default_session_frame_count() -> usize {
     120
 }
 
+fn default_step_count() -> usize {
+    1
+}
+
 async fn get_scenarios() -> Json<serde_json::Value> {
     Json(json!({ "scenarios": list_scenarios() }))
 }
@@ -131,44 +307,76 @@ async fn create_session(
     State(state): State<LabServerState>,
     Json(request): Json<CreateSessionRequest>,
 ) -> Result<impl IntoResponse, LabHttpError> {
-    let mut session = {
-        let mut inner = state
-            .inner
+    let source = match (request.scenario_id, request.fixture, request.fixture_path) {
+        (Some(id), None, None) => RunSource::Builtin(id),
+        (None, Some(fixture), None) => RunSource::Fixture(FixtureSource::from_json(fixture)?),
+        (None, None, Some(path)) => {
+            let root = state.fixture_root.as_deref().ok_or_else(|| {
+                LabError::InvalidScenarioSource(
+                    "`fixture_path` is disabled: the server has no fixtures root".to_owned(),
+                )
+            })?;
+            RunSource::Fixture(FixtureSource::from_path_within(root, path)?)
+        }
+        _ => {
+            return Err(LabError::InvalidScenarioSource(
+                "set exactly one of `scenario_id`, `fixture`, or `fixture_path`".to_owned(),
+            )
+            .into())
+        }
+    };
+    let id = {
+        let mut table = state
+            .sessions
             .lock()
             .expect("lab state mutex should not poison");
-        let id = format!("session-{}", inner.next_session);
-        inner.next_session += 1;
-        SessionRecord {
-            id,
-            scenario_id: request.scenario_id,
-            status: SessionStatus::Created,
-            run_id: None,
-            frame_count: request.frame_count.max(1),
-            current_frame_index: 0,
-            overrides: request.overrides,
-            final_state_hash: None,
-            manifest_artifact: None,
-            final_snapshot_artifact: None,
-            last_error: None,
-            events: Vec::new(),
-        }
+        let id = format!("session-{}", table.next_session);
+        table.next_session += 1;
+        id
+    };
+    let record = SessionRecord {
+        id: id.clone(),
+        scenario_id: source.scenario_id(),
+        status: SessionStatus::Created,
+        run_id: None,
+        frame_count: request.frame_count.max(1),
+        current_frame_index: 0,
+        overrides: request.overrides,
+        final_state_hash: None,
+        manifest_artifact: None,
+        final_snapshot_artifact: None,
+        last_error: None,
+        fixture: source.fixture().map(FixtureSource::provenance),
+        live: request.live,
+        dropped_time: 0.0,
+        source,
+        events: Vec::new(),
     };
 
-    let store = state
-        .inner
-        .lock()
-        .expect("lab state mutex should not poison")
-        .store
-        .clone();
-    run_session(&store, &mut session);
-
-    let response_session = session.clone();
+    let store = state.store.clone();
+    let slot = run_blocking(move || {
+        let mut slot = SessionSlot {
+            record,
+            live: None,
+            ticking: false,
+        };
+        slot.reset(&store);
+        Ok(slot)
+    })
+    .await?;
+    let response_session = slot.record.clone();
     state
-        .inner
+        .sessions
         .lock()
         .expect("lab state mutex should not poison")
         .sessions
-        .insert(session.id.clone(), session);
+        .insert(
+            id,
+            Arc::new(SessionEntry {
+                slot: Mutex::new(slot),
+                events_queued: watch::channel(()).0,
+            }),
+        );
 
     Ok((
         StatusCode::CREATED,
@@ -181,13 +389,11 @@ async fn get_session(
     Path(id): Path<String>,
 ) -> Result<Json<serde_json::Value>, LabHttpError> {
     let session = state
-        .inner
-        .lock()
-        .expect("lab state mutex should not poison")
-        .sessions
-        .get(&id)
-        .cloned()
-        .ok_or_else(|| LabError::SessionNotFound(id))?;
+        .with_session(&id, |_, slot| {
+            slot.catch_up()?;
+            Ok(slot.record.clone())
+        })
+        .await?;
     Ok(Json(json!({ "session": session })))
 }
 
@@ -196,22 +402,30 @@ async fn patch_overrides(
     Path(id): Path<String>,
     Json(overrides): Json<ScenarioOverrides>,
 ) -> Result<Json<serde_json::Value>, LabHttpError> {
-    let mut inner = state
-        .inner
-        .lock()
-        .expect("lab state mutex should not poison");
-    let session = inner
-        .sessions
-        .get_mut(&id)
-        .ok_or_else(|| LabError::SessionNotFound(id.clone()))?;
-    if let Some(frame_count) = overrides.frame_count {
-        session.frame_count = frame_count.max(1);
-    }
-    if overrides.gravity.is_some() {
-        session.overrides.gravity = overrides.gravity;
-    }
-    session.overrides.frame_count = overrides.frame_count;
-    Ok(Json(json!({ "session": session.clone() })))
+    overrides.step_config()?;
+    let session = state
+        .with_session(&id, move |_, slot| {
+            let session = &mut slot.record;
+            if let Some(frame_count) = overrides.frame_count {
+                session.frame_count = frame_count.max(1);
+            }
+            if overrides.gravity.is_some() {
+                session.overrides.gravity = overrides.gravity;
+            }
+            if overrides.solver.is_some() {
+                session.overrides.solver = overrides.solver;
+            }
+            if overrides.sleep.is_some() {
+                session.overrides.sleep = overrides.sleep;
+            }
+            if overrides.ccd.is_some() {
+                session.overrides.ccd = overrides.ccd;
+            }
+            session.overrides.frame_count = overrides.frame_count;
+            Ok(session.clone())
+        })
+        .await?;
+    Ok(Json(json!({ "session": session })))
 }
 
 async fn control_session(
@@ -219,21 +433,84 @@ async fn control_session(
     Path(id): Path<String>,
     Json(request): Json<ControlRequest>,
 ) -> Result<Json<serde_json::Value>, LabHttpError> {
-    let mut inner = state
-        .inner
-        .lock()
-        .expect("lab state mutex should not poison");
-    let store = inner.store.clone();
-    let session = inner
-        .sessions
-        .get_mut(&id)
-        .ok_or_else(|| LabError::SessionNotFound(id.clone()))?;
+    if request.steps > MAX_CONTROL_STEPS {
+        return Err(LabError::InvalidControlRequest(format!(
+            "`steps` is {} but one request may advance at most {MAX_CONTROL_STEPS} frames",
+            request.steps
+        ))
+        .into());
+    }
+    let (session, ticker_period) = state
+        .with_session(&id, move |store, slot| {
+            let ticker_period = control_slot(store, slot, request)?;
+            Ok((slot.record.clone(), ticker_period))
+        })
+        .await?;
+    if let Some(period) = ticker_period {
+        tokio::spawn(run_live_ticker(state, id, period));
+    }
+    Ok(Json(json!({ "session": session })))
+}
+
+/// Applies one control action; returns the step period when a live session
+/// starts playing without a ticker.
+fn control_slot(
+    store: &ArtifactStore,
+    slot: &mut SessionSlot,
+    request: ControlRequest,
+) -> LabResult<Option<Duration>> {
+    if request.action == "reset" {
+        slot.reset(store);
+        return Ok(None);
+    }
+    let SessionSlot {
+        record: session,
+        live,
+        ticking,
+    } = slot;
+
+    if session.live {
+        let mut ticker_period = None;
+        match request.action.as_str() {
+            "play" | "run" => {
+                if let Some(live) = live {
+                    live.play();
+                    session.status = SessionStatus::Running;
+                    if !*ticking {
+                        *ticking = true;
+                        ticker_period = Some(live.step_period());
+                    }
+                }
+            }
+            "step" => {
+                if let Some(live) = live {
+                    live.pause();
+                    session.status = SessionStatus::Paused;
+                    for _ in 0..request.steps.max(1) {
+                        let delta = live.step()?;
+                        push_live_delta(session, live, delta);
+                    }
+                    sync_live_session(session, live);
+                }
+            }
+            "pause" => {
+                if let Some(live) = live {
+                    catch_up_live_session(session, live)?;
+                    live.pause();
+                }
+                session.status = SessionStatus::Paused;
+                session.events.push(SessionEvent::Paused);
+            }
+            _ => return Err(LabError::InvalidControlAction(request.action)),
+        }
+        return Ok(ticker_period);
+    }
 
     match request.action.as_str() {
         "play" | "run" => {
             session.status = SessionStatus::Running;
             if let Some(run_id) = session.run_id.as_deref() {
-                let frame_hash = read_frame_hash(&store, run_id, session.current_frame_index)
+                let frame_hash = read_frame_hash(store, run_id, session.current_frame_index)
                     .unwrap_or_else(|| "unknown".to_owned());
                 session.events.push(SessionEvent::Frame {
                     frame_index: session.current_frame_index,
@@ -241,13 +518,12 @@ async fn control_session(
                 });
             }
         }
-        "reset" => run_session(&store, session),
         "step" => {
             session.status = SessionStatus::Paused;
-            session.current_frame_index =
-                (session.current_frame_index + 1).min(session.frame_count.saturating_sub(1));
+            session.current_frame_index = (session.current_frame_index + request.steps.max(1))
+                .min(session.frame_count.saturating_sub(1));
             if let Some(run_id) = session.run_id.as_deref() {
-                let frame_hash = read_frame_hash(&store, run_id, session.current_frame_index)
+                let frame_hash = read_frame_hash(store, run_id, session.current_frame_index)
                     .unwrap_or_else(|| "unknown".to_owned());
                 session.events.push(SessionEvent::Frame {
                     frame_index: session.current_frame_index,
@@ -259,37 +535,134 @@ async fn control_session(
             session.status = SessionStatus::Paused;
             session.events.push(SessionEvent::Paused);
         }
-        _ => return Err(LabError::InvalidControlAction(request.action).into()),
+        _ => return Err(LabError::InvalidControlAction(request.action)),
+    }
+    Ok(None)
+}
+
+/// Steps a playing live session in real time until it stops playing.
+async fn run_live_ticker(state: LabServerState, id: String, period: Duration) {
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        let playing = state
+            .with_session(&id, |_, slot| Ok(slot.tick()))
+            .await
+            .unwrap_or(false);
+        if !playing {
+            break;
+        }
     }
-    Ok(Json(json!({ "session": session.clone() })))
 }
 
+/// Applies a batch of world commands to a live session.
+async fn apply_commands(
+    State(state): State<LabServerState>,
+    Path(id): Path<String>,
+    Json(request): Json<CommandsRequest>,
+) -> Result<Json<serde_json::Value>, LabHttpError> {
+    let (session, report) = state
+        .with_session(&id, move |_, slot| {
+            let (session, live) = slot.live_mut()?;
+            // Commands land at the present of a playing session, not at its
+            // last poll.
+            catch_up_live_session(session, live)?;
+            let (report, delta) = live.apply(&request.commands)?;
+            push_live_delta(session, live, delta);
+            sync_live_session(session, live);
+            Ok((session.clone(), report))
+        })
+        .await?;
+    Ok(Json(json!({ "session": session, "report": report })))
+}
+
+/// Saves a live session's current world as a schema v2 scene fixture.
+async fn export_fixture(
+    State(state): State<LabServerState>,
+    Path(id): Path<String>,
+) -> Result<Json<serde_json::Value>, LabHttpError> {
+    let (session, fixture) = state
+        .with_session(&id, |_, slot| {
+            let (session, live) = slot.live_mut()?;
+            catch_up_live_session(session, live)?;
+            Ok((session.clone(), SceneFixture::from(live.export_fixture())))
+        })
+        .await?;
+    Ok(Json(json!({ "session": session, "fixture": fixture })))
+}
+
+/// Streams the session's queued events over SSE.
+///
+/// The response starts with everything queued so far, or `idle` when nothing
+/// is. While a live session plays the stream stays open and carries events
+/// as the ticker and other routes queue them; otherwise, and once the session
+/// stops playing, it ends.
 async fn session_events(
     State(state): State<LabServerState>,
     Path(id): Path<String>,
 ) -> Result<Response, LabHttpError> {
-    let events = {
-        let mut inner = state
-            .inner
-            .lock()
-            .expect("lab state mutex should not poison");
-        let session = inner
-            .sessions
-            .get_mut(&id)
-            .ok_or_else(|| LabError::SessionNotFound(id))?;
-        std::mem::take(&mut session.events)
-    };
-
-    let body = format_session_events(events);
+    // Subscribe before draining so nothing queued in between is missed.
+    let events_queued = state.session(&id)?.events_queued.subscribe();
+    let (events, playing) = drain_session_events(&state, &id).await?;
+    let viewer = playing.then_some(EventViewer {
+        state,
+        id,
+        events_queued,
+    });
+
+    let chunks = stream::unfold(
+        (Some(format_session_events(events)), viewer),
+        |(first, viewer)| async move {
+            if let Some(first) = first {
+                return Some((Ok::<_, Infallible>(first), (None, viewer)));
+            }
+            let mut viewer = viewer?;
+            loop {
+                viewer.events_queued.changed().await.ok()?;
+                let (events, playing) =
+                    drain_session_events(&viewer.state, &viewer.id).await.ok()?;
+                if !events.is_empty() {
+                    let chunk = format_session_events(events);
+                    return Some((Ok(chunk), (None, playing.then_some(viewer))));
+                }
+                if !playing {
+                    return None;
+                }
+            }
+        },
+    );
 
-    let mut response = Response::new(Body::from(body));
+    let mut response = Response::new(Body::from_stream(chunks));
     response.headers_mut().insert(
         header::CONTENT_TYPE,
         HeaderValue::from_static("text/event-stream"),
     );
+    response
+        .headers_mut()
+        .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
     Ok(response)
 }
 
+/// Open event stream of a playing live session.
+struct EventViewer {
+    state: LabServerState,
+    id: String,
+    events_queued: watch::Receiver<()>,
+}
+
+/// Takes the session's queued events and reports whether it still plays.
+async fn drain_session_events(
+    state: &LabServerState,
+    id: &str,
+) -> LabResult<(Vec<SessionEvent>, bool)> {
+    state
+        .with_session(id, |_, slot| {
+            Ok((std::mem::take(&mut slot.record.events), slot.is_playing()))
+        })
+        .await
+}
+
 fn format_session_events(events: Vec<SessionEvent>) -> String {
     let mut body = String::new();
     for event in events {
@@ -312,6 +685,18 @@ fn format_session_events(events: Vec<SessionEvent>) -> String {
                 body.push_str("event: failed\n");
                 body.push_str(&format!("data: {}\n\n", json!({ "message": message })));
             }
+            SessionEvent::Snapshot(snapshot) => {
+                body.push_str("event: snapshot\n");
+                body.push_str(&format!("data: {}\n\n", json!(snapshot)));
+            }
+            SessionEvent::Delta(delta) => {
+                body.push_str("event: delta\n");
+                body.push_str(&format!("data: {}\n\n", json!(delta)));
+            }
+            SessionEvent::Dropped { seconds } => {
+                body.push_str("event: dropped\n");
+                body.push_str(&format!("data: {}\n\n", json!({ "seconds": seconds })));
+            }
         }
     }
     if body.is_empty() {
@@ -326,12 +711,7 @@ async fn get_artifact(
     Path((id, file)): Path<(String, String)>,
 ) -> Result<Response, LabHttpError> {
     let artifact_file = ArtifactFile::from_str(&file)?;
-    let bytes = state
-        .inner
-        .lock()
-        .expect("lab state mutex should not poison")
-        .store
-        .read_artifact(&id, &file)?;
+    let bytes = state.store.read_artifact(&id, &file)?;
     let mut response = Response::new(Body::from(bytes));
     response.headers_mut().insert(
         header::CONTENT_TYPE,
@@ -348,15 +728,20 @@ fn run_session(store: &ArtifactStore, session: &mut SessionRecord) {
     session.events.clear();
     let mut overrides = session.overrides.clone();
     overrides.frame_count = Some(session.frame_count);
-    match run_scenario(
-        store,
-        RunConfig {
-            scenario_id: session.scenario_id,
-            frame_count: session.frame_count,
-            run_id: None,
-            overrides,
-        },
-    ) {
+    let result = session.source.reload().and_then(|source| {
+        session.fixture = source.fixture().map(FixtureSource::provenance);
+        session.source = source.clone();
+        run_scenario(
+            store,
+            RunConfig {
+                source,
+                frame_count: session.frame_count,
+                run_id: None,
+                overrides,
+            },
+        )
+    });
+    match result {
         Ok(result) => {
             session.status = SessionStatus::Completed;
             session.run_id = Some(result.manifest.run_id);
@@ -376,20 +761,111 @@ fn run_session(store: &ArtifactStore, session: &mut SessionRecord) {
                 })
                 .collect();
         }
-        Err(error) => {
-            let message = error.to_string();
-            session.status = SessionStatus::Failed;
+        Err(error) => fail_session(session, error),
+    }
+}
+
+fn fail_session(session: &mut SessionRecord, error: LabError) {
+    let message = error.to_string();
+    session.status = SessionStatus::Failed;
+    session.run_id = None;
+    session.current_frame_index = 0;
+    session.final_state_hash = None;
+    session.manifest_artifact = None;
+    session.final_snapshot_artifact = None;
+    session.last_error = Some(message.clone());
+    session.events = vec![SessionEvent::Failed { message }];
+}
+
+/// Builds a fresh live world for the session and queues its full snapshot.
+fn start_live_session(session: &mut SessionRecord) -> Option<LiveSession> {
+    session.events.clear();
+    session.dropped_time = 0.0;
+    let result = session.source.reload().and_then(|source| {
+        session.fixture = source.fixture().map(FixtureSource::provenance);
+        session.source = source.clone();
+        LiveSession::new(&RunConfig {
+            source,
+            frame_count: session.frame_count,
+            run_id: None,
+            overrides: session.overrides.clone(),
+        })
+    });
+    match result {
+        Ok(live) => {
+            session.status = SessionStatus::Created;
             session.run_id = None;
-            session.current_frame_index = 0;
-            session.final_state_hash = None;
             session.manifest_artifact = None;
             session.final_snapshot_artifact = None;
-            session.last_error = Some(message.clone());
-            session.events = vec![SessionEvent::Failed { message }];
+            session.last_error = None;
+            session
+                .events
+                .push(SessionEvent::Snapshot(Box::new(live.full_snapshot())));
+            sync_live_session(session, &live);
+            Some(live)
         }
+        Err(error) => {
+            fail_session(session, error);
+            None
+        }
+    }
+}
+
+fn catch_up_live_session(session: &mut SessionRecord, live: &mut LiveSession) -> LabResult<()> {
+    let catch_up = live.catch_up()?;
+    for delta in catch_up.deltas {
+        push_live_delta(session, live, delta);
+    }
+    if catch_up.dropped_time > 0.0 {
+        session.dropped_time += catch_up.dropped_time;
+        session.events.push(SessionEvent::Dropped {
+            seconds: catch_up.dropped_time,
+        });
+    }
+    sync_live_session(session, live);
+    Ok(())
+}
+
+async fn run_blocking<T: Send + 'static>(
+    work: impl FnOnce() -> LabResult<T> + Send + 'static,
+) -> LabResult<T> {
+    tokio::task::spawn_blocking(work)
+        .await
+        .unwrap_or_else(|error| std::panic::resume_unwind(error.into_panic()))
+}
+
+fn push_live_delta(session: &mut SessionRecord, live: &LiveSession, delta: DebugSnapshotDelta) {
+    if session.events.len() >= LIVE_EVENT_BACKLOG {
+        collapse_live_backlog(&mut session.events, live.full_snapshot());
+    } else {
+        session.events.push(SessionEvent::Delta(Box::new(delta)));
     }
 }
 
+/// Replaces a live backlog with one full snapshot. Deltas only make sense in
+/// sequence, so a truncated backlog restarts from the current state; the
+/// dropped-time reports it held are merged into one that follows.
+fn collapse_live_backlog(events: &mut Vec<SessionEvent>, snapshot: LiveSnapshot) {
+    let dropped: f64 = events
+        .iter()
+        .filter_map(|event| match event {
+            SessionEvent::Dropped { seconds } => Some(*seconds),
+            _ => None,
+        })
+        .sum();
+    events.clear();
+    events.push(SessionEvent::Snapshot(Box::new(snapshot)));
+    if dropped > 0.0 {
+        events.push(SessionEvent::Dropped { seconds: dropped });
+    }
+}
+
+fn sync_live_session(session: &mut SessionRecord, live: &LiveSession) {
+    session.frame_count = live.frame_count();
+    session.current_frame_index = live.frame_count().saturating_sub(1);
+    session.final_state_hash = Some(live.state_hash().to_owned());
+}
+
 fn read_frame_hash(store: &ArtifactStore, run_id: &str, frame_index: usize) -> Option<String> {
     let bytes = store
         .read_artifact(run_id, ArtifactFile::Frames.file_name())
@@ -415,7 +891,13 @@ impl IntoResponse for LabHttpError {
             LabError::SessionNotFound(_) => StatusCode::NOT_FOUND,
             LabError::UnknownScenario(_)
             | LabError::InvalidArtifactFile(_)
-            | LabError::InvalidControlAction(_) => StatusCode::BAD_REQUEST,
+            | LabError::InvalidControlAction(_)
+            | LabError::InvalidControlRequest(_)
+            | LabError::InvalidOverrides(_)
+            | LabError::InvalidFixture(_)
+            | LabError::InvalidScenarioSource(_)
+            | LabError::InvalidCommand(_) => StatusCode::BAD_REQUEST,
+            LabError::SessionNotLive(_) => StatusCode::CONFLICT,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         };
         (status, Json(json!({ "error": self.0.to_string() }))).into_response()
@@ -424,7 +906,8 @@ impl IntoResponse for LabHttpError {
 
 #[cfg(test)]
 mod tests {
-    use super::{format_session_events, SessionEvent};
+    use super::{collapse_live_backlog, format_session_events, SessionEvent};
+    use crate::{live::LiveSession, scenario::RunConfig};
 
     #[test]
     fn empty_sse_stream_is_idle_not_failed() {
@@ -436,4 +919,24 @@ mod tests {
             "an empty event queue is not a failed simulation"
         );
     }
+
+    #[test]
+    fn collapsed_live_backlogs_keep_their_dropped_time() {
+        let live = LiveSession::new(&RunConfig::default()).expect("scenario should build");
+        let mut events = vec![
+            SessionEvent::Dropped { seconds: 0.25 },
+            SessionEvent::Paused,
+            SessionEvent::Dropped { seconds: 0.5 },
+        ];
+
+        collapse_live_backlog(&mut events, live.full_snapshot());
+
+        assert_eq!(
+            events,
+            vec![
+                SessionEvent::Snapshot(Box::new(live.full_snapshot())),
+                SessionEvent::Dropped { seconds: 0.75 },
+            ]
+        );
+    }
 }
@@ -1,9 +1,14 @@
 use std::{fs, path::Path};
 
-use picea::events::CcdTargetKind;
+use picea::{
+    events::CcdTargetKind,
+    prelude::{BodyType, SleepParams, SolverParams},
+};
 use picea_lab::{
-    instantiate_scene_fixture, run_scenario, ArtifactFile, ArtifactStore, DebugRenderArtifact,
-    DebugRenderFrame, FrameRecord, RunConfig, RunManifest, ScenarioId, SceneRecipeFixture,
+    instantiate_scene_fixture, run_scenario, verify_golden_runs, ArtifactFile, ArtifactStore,
+    CompareTolerances, DebugRenderArtifact, DebugRenderFrame, FactDifference, FixtureSource,
+    FrameRecord, LabError, RunConfig, RunManifest, RunSource, ScenarioId, ScenarioOverrides,
+    SceneFixture, SceneRecipeFixture,
 };
 
 #[test]
@@ -28,7 +33,7 @@ fn run_writes_expected_artifacts_and_keeps_state_hash_deterministic() {
     let first = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::FallingBoxContact,
+            source: RunSource::Builtin(ScenarioId::FallingBoxContact),
             frame_count: 8,
             run_id: Some("determinism-a".to_owned()),
             ..RunConfig::default()
@@ -38,7 +43,7 @@ fn run_writes_expected_artifacts_and_keeps_state_hash_deterministic() {
     let second = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::FallingBoxContact,
+            source: RunSource::Builtin(ScenarioId::FallingBoxContact),
             frame_count: 8,
             run_id: Some("determinism-b".to_owned()),
             ..RunConfig::default()
@@ -71,7 +76,7 @@ fn run_writes_expected_artifacts_and_keeps_state_hash_deterministic() {
             .expect("manifest should be readable"),
     )
     .expect("manifest should match schema");
-    assert_eq!(manifest.scenario_id, ScenarioId::FallingBoxContact);
+    assert_eq!(manifest.scenario_id, Some(ScenarioId::FallingBoxContact));
     assert_eq!(manifest.frame_count, 8);
     assert_eq!(manifest.artifacts.len(), 5);
 
@@ -123,6 +128,160 @@ fn run_writes_expected_artifacts_and_keeps_state_hash_deterministic() {
     );
 }
 
+#[test]
+fn solver_param_overrides_change_the_run_and_reject_invalid_values() {
+    let temp = tempfile::tempdir().expect("temp dir should be created");
+    let store = ArtifactStore::new(temp.path().join("runs"));
+    let run = |run_id: &str, overrides: ScenarioOverrides| {
+        run_scenario(
+            &store,
+            RunConfig {
+                source: RunSource::Builtin(ScenarioId::Stack4),
+                frame_count: 30,
+                run_id: Some(run_id.to_owned()),
+                overrides,
+            },
+        )
+    };
+
+    let baseline = run("params-default", ScenarioOverrides::default())
+        .expect("default run should write artifacts");
+    let loose_slop = run(
+        "params-loose-slop",
+        ScenarioOverrides {
+            solver: Some(SolverParams {
+                contact_slop: 0.05,
+                ..SolverParams::default()
+            }),
+            ..ScenarioOverrides::default()
+        },
+    )
+    .expect("overridden run should write artifacts");
+    assert_ne!(
+        baseline.manifest.final_state_hash, loose_slop.manifest.final_state_hash,
+        "a solver parameter override should reach the step config"
+    );
+
+    let invalid = run(
+        "params-invalid",
+        ScenarioOverrides {
+            sleep: Some(SleepParams {
+                stability_seconds: -1.0,
+                ..SleepParams::default()
+            }),
+            ..ScenarioOverrides::default()
+        },
+    );
+    assert!(matches!(invalid, Err(LabError::InvalidOverrides(_))));
+    assert!(!store.run_path("params-invalid").exists());
+}
+
+#[test]
+fn golden_runs_replay_with_their_overrides_and_fixtures() {
+    let temp = tempfile::tempdir().expect("temp dir should be created");
+    let golden = ArtifactStore::new(temp.path().join("golden"));
+    let candidates = ArtifactStore::new(temp.path().join("candidates"));
+    let path = temp.path().join("scene.json");
+    fs::write(
+        &path,
+        r#"{ "bodies": [
+          { "body_type": "static", "pose": [0.0, 2.0, 0.0],
+            "shape": { "type": "rect", "width": 8.0, "height": 0.5 } },
+          { "body_type": "dynamic", "shape": { "type": "circle", "radius": 0.5 } }
+        ] }"#,
+    )
+    .expect("fixture should be written");
+
+    let overridden = run_scenario(
+        &golden,
+        RunConfig {
+            source: RunSource::Builtin(ScenarioId::Stack4),
+            frame_count: 20,
+            run_id: Some("golden-stack".to_owned()),
+            overrides: ScenarioOverrides {
+                solver: Some(SolverParams {
+                    contact_slop: 0.05,
+                    ..SolverParams::default()
+                }),
+                ..ScenarioOverrides::default()
+            },
+        },
+    )
+    .expect("golden run should write artifacts");
+    assert_eq!(
+        golden
+            .read_manifest("golden-stack")
+            .expect("manifest should be readable")
+            .overrides,
+        overridden.manifest.overrides
+    );
+    run_scenario(
+        &golden,
+        RunConfig {
+            frame_count: 8,
+            run_id: Some("golden-fixture".to_owned()),
+            ..RunConfig::from_fixture(FixtureSource::from_path(&path).expect("fixture should load"))
+        },
+    )
+    .expect("golden fixture run should write artifacts");
+
+    let verifications = verify_golden_runs(&golden, &candidates, &CompareTolerances::default())
+        .expect("golden runs should replay");
+    assert_eq!(
+        verifications
+            .iter()
+            .map(|verification| verification.golden_run_id.as_str())
+            .collect::<Vec<_>>(),
+        ["golden-fixture", "golden-stack"]
+    );
+    for verification in &verifications {
+        assert!(
+            !verification.comparison.diverged(),
+            "{} should replay exactly: {}",
+            verification.golden_run_id,
+            verification.comparison
+        );
+        assert!(candidates.run_path(&verification.candidate_run_id).is_dir());
+    }
+
+    let mut frames = golden
+        .read_frames("golden-stack")
+        .expect("golden frames should be readable");
+    frames[5].state_hash = "edited".to_owned();
+    frames[5].snapshot.bodies[1].angular_velocity += 1.0;
+    let handle = frames[5].snapshot.bodies[1].handle;
+    let lines = frames
+        .iter()
+        .map(|frame| serde_json::to_string(frame).expect("frame should encode") + "\n")
+        .collect::<String>();
+    fs::write(
+        golden.artifact_path("golden-stack", ArtifactFile::Frames),
+        lines,
+    )
+    .expect("golden frames should be rewritten");
+
+    let verifications = verify_golden_runs(&golden, &candidates, &CompareTolerances::default())
+        .expect("golden runs should replay");
+    let divergence = verifications[1]
+        .comparison
+        .divergence
+        .as_ref()
+        .expect("an edited golden frame should diverge");
+    assert_eq!(divergence.frame_index, 5);
+    assert_eq!(divergence.bodies.len(), 1);
+    assert_eq!(divergence.bodies[0].handle, handle);
+    assert!(matches!(
+        &divergence.bodies[0].difference,
+        FactDifference::Fields { fields } if fields[0].field == "angular_velocity"
+    ));
+
+    fs::write(&path, r#"{ "bodies": [] }"#).expect("fixture should be edited");
+    assert!(matches!(
+        verify_golden_runs(&golden, &candidates, &CompareTolerances::default()),
+        Err(LabError::InvalidScenarioSource(message)) if message.contains("changed since golden run")
+    ));
+}
+
 #[test]
 fn broadphase_scenario_artifacts_capture_candidate_and_tree_facts() {
     let temp = tempfile::tempdir().expect("temp dir should be created");
@@ -131,7 +290,7 @@ fn broadphase_scenario_artifacts_capture_candidate_and_tree_facts() {
     let run = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::BroadphaseSparse,
+            source: RunSource::Builtin(ScenarioId::BroadphaseSparse),
             frame_count: 2,
             run_id: Some("broadphase-facts".to_owned()),
             ..RunConfig::default()
@@ -208,7 +367,7 @@ fn sat_polygon_artifacts_capture_manifold_points_and_normals() {
     let run = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::SatPolygon,
+            source: RunSource::Builtin(ScenarioId::SatPolygon),
             frame_count: 1,
             run_id: Some("sat-polygon-facts".to_owned()),
             ..RunConfig::default()
@@ -250,7 +409,7 @@ fn compound_provenance_scenario_writes_frame_and_debug_render_facts() {
     let run = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::CompoundProvenance,
+            source: RunSource::Builtin(ScenarioId::CompoundProvenance),
             frame_count: 2,
             run_id: Some("m24-compound-provenance".to_owned()),
             ..RunConfig::default()
@@ -298,10 +457,160 @@ fn compound_provenance_scenario_writes_frame_and_debug_render_facts() {
         .frames
         .first()
         .expect("debug render should include provenance facts");
-    assert_eq!(render_first.broadphase_tree.depth, first.snapshot.broadphase_tree.depth);
+    assert_eq!(
+        render_first.broadphase_tree.depth,
+        first.snapshot.broadphase_tree.depth
+    );
     assert_eq!(render_first.compound_provenance, first.compound_provenance);
 }
 
+#[test]
+fn fixture_runs_record_the_fixture_hash_in_artifacts() {
+    let temp = tempfile::tempdir().expect("temp dir should be created");
+    let store = ArtifactStore::new(temp.path().join("runs"));
+    let json = r#"{
+      "bodies": [
+        { "body_type": "static", "pose": [0.0, 2.0, 0.0],
+          "shape": { "type": "rect", "width": 8.0, "height": 0.5 } },
+        { "body_type": "dynamic", "shape": { "type": "circle", "radius": 0.5 } }
+      ]
+    }"#;
+    let path = temp.path().join("scene.json");
+    fs::write(&path, json).expect("fixture should be written");
+
+    let source = FixtureSource::from_path(&path).expect("fixture file should load");
+    let inline = FixtureSource::new(serde_json::from_str(json).expect("fixture should parse"))
+        .expect("inline fixture should validate");
+    assert_eq!(source.hash, inline.hash);
+    assert_eq!(inline.path, None);
+
+    let run = run_scenario(
+        &store,
+        RunConfig {
+            frame_count: 4,
+            run_id: Some("fixture-run".to_owned()),
+            ..RunConfig::from_fixture(source.clone())
+        },
+    )
+    .expect("fixture run should write artifacts");
+    assert_eq!(run.manifest.scenario_id, None);
+    assert_eq!(run.manifest.fixture, Some(source.provenance()));
+    assert_eq!(
+        run.manifest
+            .fixture
+            .as_ref()
+            .and_then(|fixture| fixture.path.clone()),
+        Some(path.display().to_string())
+    );
+    assert_eq!(run.frames[0].snapshot.bodies.len(), 2);
+
+    let render: DebugRenderArtifact = serde_json::from_slice(
+        &fs::read(run.path.join(ArtifactFile::DebugRender.file_name()))
+            .expect("debug render should be readable"),
+    )
+    .expect("debug render should match schema");
+    assert_eq!(render.scenario_id, None);
+    assert_eq!(render.fixture, run.manifest.fixture);
+
+    let SceneFixture::V1(mut edited) = inline.fixture.clone() else {
+        panic!("inline fixture should decode as schema v1");
+    };
+    edited.bodies[1].pose = [1.0, 0.0, 0.0];
+    assert_ne!(
+        FixtureSource::new(edited.into())
+            .expect("edited fixture should validate")
+            .hash,
+        inline.hash
+    );
+    assert!(matches!(
+        FixtureSource::from_path(temp.path().join("missing.json")),
+        Err(LabError::InvalidFixture(message)) if message.contains("missing.json")
+    ));
+
+    let root = temp.path().join("fixtures");
+    fs::create_dir_all(&root).expect("fixtures root should be created");
+    fs::write(root.join("scene.json"), json).expect("fixture should be written");
+    let confined =
+        FixtureSource::from_path_within(&root, "scene.json").expect("fixture under root loads");
+    assert_eq!(confined.hash, inline.hash);
+    assert_eq!(
+        confined.reload().expect("confined fixture reloads").hash,
+        inline.hash
+    );
+    for escape in [
+        Path::new("../scene.json").to_path_buf(),
+        path.clone(),
+        root.join("missing.json"),
+        root.clone(),
+    ] {
+        assert!(
+            matches!(
+                FixtureSource::from_path_within(&root, &escape),
+                Err(LabError::InvalidScenarioSource(_))
+            ),
+            "{} must stay under the fixtures root",
+            escape.display()
+        );
+    }
+}
+
+#[test]
+fn vehicle_raycast_scenario_records_wheel_states_while_driving() {
+    let temp = tempfile::tempdir().expect("temp dir should be created");
+    let store = ArtifactStore::new(temp.path().join("runs"));
+
+    let run = run_scenario(
+        &store,
+        RunConfig {
+            source: RunSource::Builtin(ScenarioId::VehicleRaycast),
+            frame_count: 120,
+            run_id: Some("vehicle-raycast".to_owned()),
+            ..RunConfig::default()
+        },
+    )
+    .expect("vehicle run should write artifacts");
+
+    let first = run.frames.first().expect("first frame should exist");
+    let last = run.frames.last().expect("last frame should exist");
+    assert_eq!(first.vehicle_wheels.len(), 2);
+    assert!(last
+        .vehicle_wheels
+        .iter()
+        .all(|wheel| wheel.contact.is_some() && wheel.spin > 0.0));
+    let chassis_x = |frame: &FrameRecord| {
+        frame
+            .snapshot
+            .bodies
+            .iter()
+            .find(|body| body.body_type == BodyType::Dynamic)
+            .expect("the chassis should be in the snapshot")
+            .transform
+            .translation
+            .x()
+    };
+    assert!(
+        chassis_x(last) > chassis_x(first) + 2.0,
+        "the scenario drives the chassis forward"
+    );
+
+    let render: DebugRenderArtifact = serde_json::from_slice(
+        &fs::read(run.path.join(ArtifactFile::DebugRender.file_name()))
+            .expect("debug render should be readable"),
+    )
+    .expect("debug render should match schema");
+    // JSON float round-trips are not bit-exact under the `f64` feature.
+    let rendered = &render.frames.last().expect("render frames").vehicle_wheels;
+    assert_eq!(rendered.len(), last.vehicle_wheels.len());
+    for (rendered, recorded) in rendered.iter().zip(&last.vehicle_wheels) {
+        assert_eq!(
+            rendered.contact.map(|contact| contact.collider),
+            recorded.contact.map(|contact| contact.collider)
+        );
+        assert!((rendered.hub - recorded.hub).length() < 1e-4);
+        assert!((rendered.suspension_length - recorded.suspension_length).abs() < 1e-4);
+    }
+}
+
 #[test]
 fn warm_start_artifacts_capture_per_step_manifold_cache_facts() {
     let temp = tempfile::tempdir().expect("temp dir should be created");
@@ -310,7 +619,7 @@ fn warm_start_artifacts_capture_per_step_manifold_cache_facts() {
     let run = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::SatPolygon,
+            source: RunSource::Builtin(ScenarioId::SatPolygon),
             frame_count: 2,
             run_id: Some("warm-start-facts".to_owned()),
             ..RunConfig::default()
@@ -385,7 +694,7 @@ fn stack_artifacts_capture_solver_impulse_facts() {
     let run = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::Stack4,
+            source: RunSource::Builtin(ScenarioId::Stack4),
             frame_count: 20,
             run_id: Some("m5-stack-impulses".to_owned()),
             ..RunConfig::default()
@@ -477,7 +786,7 @@ fn ccd_fast_circle_wall_artifacts_capture_toi_trace_facts() {
     let run = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::CcdFastCircleWall,
+            source: RunSource::Builtin(ScenarioId::CcdFastCircleWall),
             frame_count: 2,
             run_id: Some("m8-ccd-fast-circle-wall".to_owned()),
             ..RunConfig::default()
@@ -539,7 +848,7 @@ fn ccd_fast_convex_walls_artifacts_capture_ordered_budget_trace_facts() {
     let run = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::CcdFastConvexWalls,
+            source: RunSource::Builtin(ScenarioId::CcdFastConvexWalls),
             frame_count: 2,
             run_id: Some("m13-ccd-fast-convex-walls".to_owned()),
             ..RunConfig::default()
@@ -595,7 +904,7 @@ fn ccd_dynamic_convex_pair_artifacts_capture_dynamic_target_trace_facts() {
     let run = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::CcdDynamicConvexPair,
+            source: RunSource::Builtin(ScenarioId::CcdDynamicConvexPair),
             frame_count: 2,
             run_id: Some("m19-ccd-dynamic-convex-pair".to_owned()),
             ..RunConfig::default()
@@ -659,7 +968,7 @@ fn artifact_schema_keeps_final_observability_fact_set() {
     let run = run_scenario(
         &store,
         RunConfig {
-            scenario_id: ScenarioId::CcdFastCircleWall,
+            source: RunSource::Builtin(ScenarioId::CcdFastCircleWall),
             frame_count: 1,
             run_id: Some("m9-observability-schema".to_owned()),
             ..RunConfig::default()
@@ -851,6 +1160,10 @@ fn warm_start_debug_render_frame_fields_default_when_deserializing_older_json()
         ccd_hit_count: 2,
         ccd_miss_count: 1,
         ccd_clamp_count: 2,
+        ccd_substep_count: 1,
+        ccd_substep_fallback_count: 1,
+        ccd_speculative_contact_count: 1,
+        ccd_speculative_enforced_count: 1,
         world_bounds: None,
         bodies: Vec::new(),
         colliders: Vec::new(),
@@ -858,7 +1171,9 @@ fn warm_start_debug_render_frame_fields_default_when_deserializing_older_json()
         manifolds: Vec::new(),
         broadphase_tree: Default::default(),
         islands: Vec::new(),
+        fluid_submersions: Vec::new(),
         compound_provenance: Vec::new(),
+        vehicle_wheels: Vec::new(),
         unmeasured: Vec::new(),
     };
     let mut value = serde_json::to_value(frame).expect("debug render frame should serialize");
@@ -880,9 +1195,14 @@ fn warm_start_debug_render_frame_fields_default_when_deserializing_older_json()
     object.remove("ccd_hit_count");
     object.remove("ccd_miss_count");
     object.remove("ccd_clamp_count");
+    object.remove("ccd_substep_count");
+    object.remove("ccd_substep_fallback_count");
+    object.remove("ccd_speculative_contact_count");
+    object.remove("ccd_speculative_enforced_count");
     object.remove("broadphase_tree");
     object.remove("islands");
     object.remove("compound_provenance");
+    object.remove("vehicle_wheels");
 
     let decoded: DebugRenderFrame =
         serde_json::from_value(value).expect("older debug render frame should deserialize");
@@ -902,9 +1222,14 @@ fn warm_start_debug_render_frame_fields_default_when_deserializing_older_json()
     assert_eq!(decoded.ccd_hit_count, 0);
     assert_eq!(decoded.ccd_miss_count, 0);
     assert_eq!(decoded.ccd_clamp_count, 0);
+    assert_eq!(decoded.ccd_substep_count, 0);
+    assert_eq!(decoded.ccd_substep_fallback_count, 0);
+    assert_eq!(decoded.ccd_speculative_contact_count, 0);
+    assert_eq!(decoded.ccd_speculative_enforced_count, 0);
     assert!(decoded.broadphase_tree.nodes.is_empty());
     assert!(decoded.islands.is_empty());
     assert!(decoded.compound_provenance.is_empty());
+    assert!(decoded.vehicle_wheels.is_empty());
 }
 
 #[test]
@@ -918,16 +1243,19 @@ fn legacy_frame_record_defaults_compound_provenance_when_missing() {
         events: Vec::new(),
         snapshot: Default::default(),
         compound_provenance: Vec::new(),
+        vehicle_wheels: Vec::new(),
     };
     let mut value = serde_json::to_value(frame).expect("frame should serialize");
-    value
+    let object = value
         .as_object_mut()
-        .expect("frame should serialize as an object")
-        .remove("compound_provenance");
+        .expect("frame should serialize as an object");
+    object.remove("compound_provenance");
+    object.remove("vehicle_wheels");
 
     let decoded: FrameRecord =
         serde_json::from_value(value).expect("older frame record should deserialize");
     assert!(decoded.compound_provenance.is_empty());
+    assert!(decoded.vehicle_wheels.is_empty());
 }
 
 #[test]
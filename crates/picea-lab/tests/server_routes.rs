@@ -2,11 +2,13 @@ use axum::{
     body::{to_bytes, Body},
     http::{header, Method, Request, StatusCode},
 };
+use futures_util::StreamExt;
 use picea_lab::{
     server::{app, LabServerState},
     ArtifactStore, ScenarioId, SessionStatus,
 };
 use serde_json::{json, Value};
+use std::time::Duration;
 use tower::ServiceExt;
 
 #[tokio::test]
@@ -45,6 +47,7 @@ async fn server_exposes_scenarios_sessions_artifacts_and_sse_events() {
             "ccd_fast_circle_wall",
             "ccd_fast_convex_walls",
             "ccd_dynamic_convex_pair",
+            "vehicle_raycast",
         ]
     );
 
@@ -280,6 +283,525 @@ async fn server_exposes_scenarios_sessions_artifacts_and_sse_events() {
     );
 }
 
+#[tokio::test]
+async fn server_runs_inline_and_file_scene_fixtures() {
+    let temp = tempfile::tempdir().expect("temp dir should be created");
+    let fixture_root = temp.path().join("fixtures");
+    std::fs::create_dir(&fixture_root).expect("fixtures root should be created");
+    let rootless = app(LabServerState::new(ArtifactStore::new(
+        temp.path().join("runs"),
+    )));
+    let state = LabServerState::new(ArtifactStore::new(temp.path().join("runs")))
+        .with_fixture_root(&fixture_root);
+    let app = app(state);
+    let scene = json!({
+        "bodies": [
+            {
+                "body_type": "static",
+                "pose": [0.0, 2.0, 0.0],
+                "shape": { "type": "rect", "width": 8.0, "height": 0.5 }
+            },
+            {
+                "body_type": "dynamic",
+                "shape": { "type": "circle", "radius": 0.5 }
+            }
+        ]
+    });
+
+    let inline = post_json(
+        &app,
+        "/api/sessions",
+        json!({ "fixture": scene, "frame_count": 3 }),
+    )
+    .await;
+    assert_eq!(inline.status(), StatusCode::CREATED);
+    let inline_body = json_body(inline).await;
+    assert!(inline_body["session"]["scenario_id"].is_null());
+    assert_eq!(inline_body["session"]["status"], "completed");
+    assert!(inline_body["session"]["fixture"]["path"].is_null());
+    let inline_hash = inline_body["session"]["fixture"]["hash"].clone();
+    assert!(inline_hash.is_string());
+
+    let manifest = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::GET)
+                .uri(format!(
+                    "/api/runs/{}/artifacts/manifest.json",
+                    inline_body["session"]["run_id"].as_str().unwrap()
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let manifest_body = json_body(manifest).await;
+    assert!(manifest_body["scenario_id"].is_null());
+    assert_eq!(manifest_body["fixture"]["hash"], inline_hash);
+
+    let path = fixture_root.join("scene.json");
+    std::fs::write(&path, serde_json::to_string_pretty(&scene).unwrap())
+        .expect("fixture file should be written");
+    let from_file = post_json(
+        &app,
+        "/api/sessions",
+        json!({ "fixture_path": "scene.json", "frame_count": 3 }),
+    )
+    .await;
+    assert_eq!(from_file.status(), StatusCode::CREATED);
+    let file_body = json_body(from_file).await;
+    assert_eq!(
+        file_body["session"]["fixture"]["hash"], inline_hash,
+        "formatting does not change the fixture hash"
+    );
+    assert_eq!(
+        file_body["session"]["fixture"]["path"],
+        path.canonicalize().unwrap().display().to_string()
+    );
+
+    let mut edited = scene.clone();
+    edited["bodies"][1]["pose"] = json!([1.0, 0.0, 0.0]);
+    std::fs::write(&path, edited.to_string()).expect("fixture file should be rewritten");
+    let session_id = file_body["session"]["id"].as_str().unwrap();
+    let reset = post_json(
+        &app,
+        &format!("/api/sessions/{session_id}/control"),
+        json!({ "action": "reset" }),
+    )
+    .await;
+    assert_eq!(reset.status(), StatusCode::OK);
+    let reset_body = json_body(reset).await;
+    assert_ne!(
+        reset_body["session"]["fixture"]["hash"], inline_hash,
+        "reset re-reads the fixture file"
+    );
+    assert_ne!(
+        reset_body["session"]["run_id"],
+        file_body["session"]["run_id"]
+    );
+
+    let outside = temp.path().join("outside.json");
+    std::fs::write(&outside, scene.to_string()).expect("outside fixture should be written");
+    for request in [
+        json!({ "scenario_id": "stack_4", "fixture": scene }),
+        json!({}),
+        json!({ "fixture_path": "missing.json" }),
+        json!({ "fixture_path": "../outside.json" }),
+        json!({ "fixture_path": outside }),
+        json!({ "fixture": { "schema_version": 99 } }),
+    ] {
+        let response = post_json(&app, "/api/sessions", request.clone()).await;
+        assert_eq!(
+            response.status(),
+            StatusCode::BAD_REQUEST,
+            "{request} should be rejected"
+        );
+    }
+
+    let response = post_json(&rootless, "/api/sessions", json!({ "fixture_path": path })).await;
+    assert_eq!(
+        response.status(),
+        StatusCode::BAD_REQUEST,
+        "servers without a fixtures root reject fixture paths"
+    );
+}
+
+#[tokio::test]
+async fn live_sessions_step_apply_commands_and_stream_deltas() {
+    let temp = tempfile::tempdir().expect("temp dir should be created");
+    let state = LabServerState::new(ArtifactStore::new(temp.path().join("runs")));
+    let app = app(state);
+    let scene = json!({
+        "bodies": [
+            {
+                "body_type": "static",
+                "pose": [0.0, 2.0, 0.0],
+                "shape": { "type": "rect", "width": 8.0, "height": 0.5 }
+            },
+            {
+                "body_type": "dynamic",
+                "can_sleep": false,
+                "shape": { "type": "circle", "radius": 0.5 }
+            }
+        ]
+    });
+
+    let created = post_json(
+        &app,
+        "/api/sessions",
+        json!({ "fixture": scene, "live": true }),
+    )
+    .await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+    let created_body = json_body(created).await;
+    let session = &created_body["session"];
+    assert_eq!(session["live"], true);
+    assert_eq!(session["status"], "created");
+    assert_eq!(session["frame_count"], 0);
+    assert!(session["run_id"].is_null(), "live sessions record no run");
+    let id = session["id"].as_str().unwrap().to_owned();
+
+    let events = sse_events(&app, &id).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].0, "snapshot");
+    let mut bodies = events[0].1["snapshot"]["bodies"].clone();
+    let ball = bodies[1]["handle"].clone();
+    let start_y = bodies[1]["transform"]["translation"]["y"].as_f64().unwrap();
+
+    let stepped = post_json(
+        &app,
+        &format!("/api/sessions/{id}/control"),
+        json!({ "action": "step", "steps": 3 }),
+    )
+    .await;
+    assert_eq!(stepped.status(), StatusCode::OK);
+    let stepped_body = json_body(stepped).await;
+    assert_eq!(stepped_body["session"]["status"], "paused");
+    assert_eq!(stepped_body["session"]["frame_count"], 3);
+    assert_eq!(stepped_body["session"]["current_frame_index"], 2);
+    assert_eq!(stepped_body["session"]["dropped_time"], 0.0);
+
+    let too_many = post_json(
+        &app,
+        &format!("/api/sessions/{id}/control"),
+        json!({ "action": "step", "steps": 1_000_000 }),
+    )
+    .await;
+    assert_eq!(too_many.status(), StatusCode::BAD_REQUEST);
+    let too_many_body = json_body(too_many).await;
+    assert!(
+        too_many_body["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("invalid control request"),
+        "{too_many_body}"
+    );
+
+    let events = sse_events(&app, &id).await;
+    assert_eq!(
+        events
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["delta", "delta", "delta"]
+    );
+    for (_, delta) in &events {
+        assert_eq!(delta["body_handles"].as_array().unwrap().len(), 2);
+        let changed = delta["bodies"].as_array().unwrap();
+        assert!(
+            changed.iter().all(|body| body["handle"] == ball),
+            "only the falling ball changes"
+        );
+        bodies[1] = changed[0].clone();
+    }
+    assert_eq!(events[2].1["frame_index"], 2);
+    assert_eq!(
+        events[2].1["state_hash"],
+        stepped_body["session"]["final_state_hash"]
+    );
+    assert!(bodies[1]["transform"]["translation"]["y"].as_f64().unwrap() > start_y);
+
+    let spawned = post_json(
+        &app,
+        &format!("/api/sessions/{id}/commands"),
+        json!({ "commands": [{
+            "type": "spawn",
+            "body": {
+                "body_type": "dynamic",
+                "pose": [3.0, 0.0, 0.0],
+                "shape": { "type": "rect", "width": 0.5, "height": 0.5 }
+            }
+        }] }),
+    )
+    .await;
+    assert_eq!(spawned.status(), StatusCode::OK);
+    let spawned_body = json_body(spawned).await;
+    let created_box = spawned_body["report"]["created_bodies"][0].clone();
+    assert!(created_box.is_u64());
+    assert_eq!(spawned_body["session"]["frame_count"], 3);
+    let events = sse_events(&app, &id).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].1["body_handles"].as_array().unwrap().len(), 3);
+    assert_eq!(events[0].1["bodies"][0]["handle"], created_box);
+
+    let dragged = post_json(
+        &app,
+        &format!("/api/sessions/{id}/commands"),
+        json!({ "commands": [{ "type": "drag", "body": ball, "target": [2.0, -1.0] }] }),
+    )
+    .await;
+    assert_eq!(dragged.status(), StatusCode::OK);
+    let drag_joint = json_body(dragged).await["report"]["drag_joint"].clone();
+    assert!(drag_joint.is_u64());
+    post_json(
+        &app,
+        &format!("/api/sessions/{id}/control"),
+        json!({ "action": "step", "steps": 30 }),
+    )
+    .await;
+    let events = sse_events(&app, &id).await;
+    let last = &events.last().unwrap().1;
+    assert_eq!(last["joints"][0]["handle"], drag_joint);
+    let ball_x = last["bodies"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|body| body["handle"] == ball)
+        .expect("the dragged ball moves")["transform"]["translation"]["x"]
+        .as_f64()
+        .unwrap();
+    assert!(
+        ball_x > 1.0,
+        "drag pulls the ball towards the target: {ball_x}"
+    );
+
+    let released = post_json(
+        &app,
+        &format!("/api/sessions/{id}/commands"),
+        json!({ "commands": [
+            { "type": "release" },
+            { "type": "patch", "body": ball, "patch": { "linear_velocity": [0.0, -5.0] } },
+            { "type": "destroy", "body": created_box }
+        ] }),
+    )
+    .await;
+    assert_eq!(released.status(), StatusCode::OK);
+    assert!(json_body(released).await["report"]["drag_joint"].is_null());
+    let events = sse_events(&app, &id).await;
+    let delta = &events[0].1;
+    assert!(delta["joint_handles"].as_array().unwrap().is_empty());
+    assert_eq!(delta["body_handles"], json!([bodies[0]["handle"], ball]));
+    assert_eq!(
+        delta["bodies"][0]["linear_velocity"],
+        json!({ "x": 0.0, "y": -5.0 })
+    );
+
+    let rejected = post_json(
+        &app,
+        &format!("/api/sessions/{id}/commands"),
+        json!({ "commands": [
+            { "type": "destroy", "body": ball },
+            { "type": "destroy", "body": ball }
+        ] }),
+    )
+    .await;
+    assert_eq!(rejected.status(), StatusCode::BAD_REQUEST);
+    assert!(
+        sse_events(&app, &id).await[0].0 == "idle",
+        "a rejected batch changes nothing"
+    );
+
+    let played = post_json(
+        &app,
+        &format!("/api/sessions/{id}/control"),
+        json!({ "action": "play" }),
+    )
+    .await;
+    assert_eq!(json_body(played).await["session"]["status"], "running");
+    let stream = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::GET)
+                .uri(format!("/api/sessions/{id}/events"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let mut chunks = stream.into_body().into_data_stream();
+    let mut stream_text = String::new();
+    while !stream_text.contains("event: delta") {
+        let chunk = tokio::time::timeout(Duration::from_secs(5), chunks.next())
+            .await
+            .expect("the ticker should stream deltas without further requests")
+            .expect("the stream stays open while the session plays")
+            .expect("stream chunk should read");
+        stream_text.push_str(std::str::from_utf8(&chunk).unwrap());
+    }
+    let paused = post_json(
+        &app,
+        &format!("/api/sessions/{id}/control"),
+        json!({ "action": "pause" }),
+    )
+    .await;
+    let mut rest = String::new();
+    while let Some(chunk) = tokio::time::timeout(Duration::from_secs(5), chunks.next())
+        .await
+        .expect("pausing should end the stream")
+    {
+        rest.push_str(std::str::from_utf8(&chunk.expect("stream chunk should read")).unwrap());
+    }
+    assert!(rest.contains("event: paused"), "{rest}");
+    let paused_body = json_body(paused).await;
+    assert_eq!(paused_body["session"]["status"], "paused");
+    let frames = paused_body["session"]["frame_count"].as_u64().unwrap();
+    assert!(frames > 33, "playing advances in real time");
+
+    let reset = post_json(
+        &app,
+        &format!("/api/sessions/{id}/control"),
+        json!({ "action": "reset" }),
+    )
+    .await;
+    let reset_body = json_body(reset).await;
+    assert_eq!(reset_body["session"]["frame_count"], 0);
+    let events = sse_events(&app, &id).await;
+    assert_eq!(events[0].0, "snapshot");
+    assert_eq!(
+        events[0].1["snapshot"]["bodies"].as_array().unwrap().len(),
+        2
+    );
+
+    let recorded = post_json(
+        &app,
+        "/api/sessions",
+        json!({ "scenario_id": "stack_4", "frame_count": 2 }),
+    )
+    .await;
+    let recorded_id = json_body(recorded).await["session"]["id"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+    let not_live = post_json(
+        &app,
+        &format!("/api/sessions/{recorded_id}/commands"),
+        json!({ "commands": [{ "type": "release" }] }),
+    )
+    .await;
+    assert_eq!(not_live.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn live_sessions_export_the_current_world_as_a_fixture() {
+    let temp = tempfile::tempdir().expect("temp dir should be created");
+    let state = LabServerState::new(ArtifactStore::new(temp.path().join("runs")));
+    let app = app(state);
+
+    let created = post_json(
+        &app,
+        "/api/sessions",
+        json!({ "scenario_id": "compound_provenance", "live": true }),
+    )
+    .await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+    let id = json_body(created).await["session"]["id"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+    let snapshot = sse_events(&app, &id).await[0].1["snapshot"].clone();
+    let compound = snapshot["bodies"][1]["handle"].clone();
+    post_json(
+        &app,
+        &format!("/api/sessions/{id}/commands"),
+        json!({ "commands": [{ "type": "drag", "body": compound, "target": [2.0, 0.0] }] }),
+    )
+    .await;
+    post_json(
+        &app,
+        &format!("/api/sessions/{id}/control"),
+        json!({ "action": "step", "steps": 10 }),
+    )
+    .await;
+
+    let exported = get(&app, &format!("/api/sessions/{id}/fixture")).await;
+    assert_eq!(exported.status(), StatusCode::OK);
+    let exported_body = json_body(exported).await;
+    assert_eq!(exported_body["session"]["frame_count"], 10);
+    let fixture = exported_body["fixture"].clone();
+    assert_eq!(fixture["schema_version"], 2);
+    assert_eq!(fixture["bodies"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        fixture["bodies"][1]["colliders"].as_array().unwrap().len(),
+        3
+    );
+    assert_eq!(fixture["bodies"][1]["compound"]["material"], "sticky");
+    assert!(
+        fixture["joints"].as_array().unwrap().is_empty(),
+        "the drag joint is not scene content"
+    );
+    assert!(fixture["bodies"][1]["pose"][0].as_f64().unwrap() > 0.6);
+
+    let reloaded = post_json(&app, "/api/sessions", json!({ "fixture": fixture })).await;
+    assert_eq!(reloaded.status(), StatusCode::CREATED);
+    let reloaded_body = json_body(reloaded).await;
+    let run_id = reloaded_body["session"]["run_id"].as_str().unwrap();
+    let render = json_body(
+        get(
+            &app,
+            &format!("/api/runs/{run_id}/artifacts/debug_render.json"),
+        )
+        .await,
+    )
+    .await;
+    assert_eq!(
+        render["frames"][0]["compound_provenance"][0]["validation_path"],
+        "scene.bodies[1].colliders"
+    );
+    assert_eq!(
+        render["frames"][0]["compound_provenance"][0]["inherited_material"],
+        "sticky"
+    );
+
+    let recorded_id = reloaded_body["session"]["id"].as_str().unwrap();
+    let not_live = get(&app, &format!("/api/sessions/{recorded_id}/fixture")).await;
+    assert_eq!(not_live.status(), StatusCode::CONFLICT);
+}
+
+/// Drains the session's SSE queue into `(event, data)` pairs.
+async fn sse_events(app: &axum::Router, id: &str) -> Vec<(String, Value)> {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::GET)
+                .uri(format!("/api/sessions/{id}/events"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    body_text(response)
+        .await
+        .split("\n\n")
+        .filter_map(|block| {
+            let event = block
+                .lines()
+                .find_map(|line| line.strip_prefix("event: "))?;
+            let data = block.lines().find_map(|line| line.strip_prefix("data: "))?;
+            Some((event.to_owned(), serde_json::from_str(data).unwrap()))
+        })
+        .collect()
+}
+
+async fn get(app: &axum::Router, uri: &str) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::GET)
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+async fn post_json(app: &axum::Router, uri: &str, body: Value) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::POST)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
 async fn json_body(response: axum::response::Response) -> Value {
     serde_json::from_str(&body_text(response).await).expect("response body should be JSON")
 }
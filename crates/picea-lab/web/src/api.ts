@@ -1,4 +1,11 @@
-import type { DebugSnapshot, FrameRecord, ScenarioDescriptor, SessionRecord } from "./types";
+import type {
+  DebugSnapshot,
+  FrameRecord,
+  LiveCommand,
+  LiveCommandReport,
+  ScenarioDescriptor,
+  SessionRecord,
+} from "./types";
 
 const apiBase = import.meta.env.VITE_PICEA_LAB_API_BASE ?? "";
 
@@ -45,14 +52,31 @@ export async function createSession(
 export async function controlSession(
   sessionId: string,
   action: "play" | "run" | "reset" | "step" | "pause",
+  steps?: number,
 ): Promise<SessionRecord> {
   const data = await requestJson<{ session: SessionRecord }>(`/api/sessions/${sessionId}/control`, {
     method: "POST",
-    body: JSON.stringify({ action }),
+    body: JSON.stringify({ action, steps }),
   });
   return data.session;
 }
 
+export async function sendSessionCommands(
+  sessionId: string,
+  commands: LiveCommand[],
+): Promise<{ session: SessionRecord; report: LiveCommandReport }> {
+  return requestJson(`/api/sessions/${sessionId}/commands`, {
+    method: "POST",
+    body: JSON.stringify({ commands }),
+  });
+}
+
+export async function exportSessionFixture(
+  sessionId: string,
+): Promise<{ session: SessionRecord; fixture: Record<string, unknown> }> {
+  return requestJson(`/api/sessions/${sessionId}/fixture`);
+}
+
 export async function fetchFrames(runId: string): Promise<FrameRecord[]> {
   const response = await fetch(`${apiBase}/api/runs/${runId}/artifacts/frames.jsonl`);
   if (!response.ok) {
@@ -5,12 +5,14 @@ import type {
   DebugBroadphaseTree,
   DebugCollider,
   DebugContact,
+  DebugFluidSubmersion,
   DebugIsland,
   DebugPrimitive,
   DebugShape,
   FrameRecord,
   SelectedEntity,
   Vec2,
+  WheelState,
 } from "../../types";
 import type { LayerState } from "./types";
 
@@ -148,6 +150,14 @@ function drawWorld(
     drawIslands(ctx, frame.snapshot.islands ?? [], frame.snapshot.colliders, camera);
   }
 
+  if (layers.fluids) {
+    drawFluidSubmersions(ctx, frame.snapshot.fluid_submersions ?? [], camera);
+  }
+
+  if (layers.vehicles) {
+    drawVehicleWheels(ctx, frame.vehicle_wheels ?? [], camera);
+  }
+
   if (layers.provenance) {
     drawProvenance(ctx, frame, camera);
   }
@@ -315,6 +325,80 @@ function drawIslands(
   }
 }
 
+function drawFluidSubmersions(
+  ctx: CanvasRenderingContext2D,
+  submersions: DebugFluidSubmersion[],
+  camera: Camera,
+) {
+  for (const submersion of submersions) {
+    if (submersion.polygon.length < 3) {
+      continue;
+    }
+
+    ctx.save();
+    ctx.beginPath();
+    submersion.polygon.forEach((point, index) => {
+      const screen = worldToScreen(point, camera);
+      if (index === 0) {
+        ctx.moveTo(screen.x, screen.y);
+      } else {
+        ctx.lineTo(screen.x, screen.y);
+      }
+    });
+    ctx.closePath();
+    ctx.fillStyle = "rgba(64, 160, 255, 0.28)";
+    ctx.strokeStyle = "rgba(64, 160, 255, 0.9)";
+    ctx.lineWidth = 1.5;
+    ctx.fill();
+    ctx.stroke();
+
+    const centroid = worldToScreen(submersion.centroid, camera);
+    ctx.fillStyle = "#40a0ff";
+    ctx.beginPath();
+    ctx.arc(centroid.x, centroid.y, 3, 0, Math.PI * 2);
+    ctx.fill();
+    ctx.font = "10px ui-monospace, SFMono-Regular, monospace";
+    ctx.fillText(submersion.submerged_area.toFixed(2), centroid.x + 5, centroid.y - 5);
+    ctx.restore();
+  }
+}
+
+function drawVehicleWheels(ctx: CanvasRenderingContext2D, wheels: WheelState[], camera: Camera) {
+  for (const wheel of wheels) {
+    // Wheels are ray casts, so their radius is the gap between hub and ground.
+    const hub = worldToScreen(wheel.hub, camera);
+    const worldRadius = wheel.contact
+      ? Math.hypot(wheel.contact.point.x - wheel.hub.x, wheel.contact.point.y - wheel.hub.y)
+      : 6 / camera.scale;
+    const radius = worldRadius * camera.scale;
+    const spoke = worldToScreen(
+      {
+        x: wheel.hub.x + Math.cos(wheel.rotation) * worldRadius,
+        y: wheel.hub.y + Math.sin(wheel.rotation) * worldRadius,
+      },
+      camera,
+    );
+    ctx.save();
+    ctx.strokeStyle = wheel.slipping ? "#e4572e" : "#f2c14e";
+    ctx.lineWidth = 2;
+    ctx.beginPath();
+    ctx.arc(hub.x, hub.y, Math.max(radius, 2), 0, Math.PI * 2);
+    ctx.stroke();
+    ctx.beginPath();
+    ctx.moveTo(hub.x, hub.y);
+    ctx.lineTo(spoke.x, spoke.y);
+    ctx.stroke();
+    if (wheel.contact) {
+      const contact = worldToScreen(wheel.contact.point, camera);
+      ctx.fillStyle = ctx.strokeStyle;
+      ctx.beginPath();
+      ctx.arc(contact.x, contact.y, 3, 0, Math.PI * 2);
+      ctx.fill();
+    }
+    ctx.restore();
+  }
+}
+
 function drawProvenance(ctx: CanvasRenderingContext2D, frame: FrameRecord, camera: Camera) {
   for (const entry of frame.compound_provenance ?? []) {
     for (const piece of entry.pieces) {
@@ -8,6 +8,8 @@ export type LayerState = {
   trace: boolean
   broadphaseTree: boolean
   islands: boolean
+  fluids: boolean
+  vehicles: boolean
   provenance: boolean
 }
 
@@ -19,6 +21,8 @@ export const defaultLayers: LayerState = {
   trace: true,
   broadphaseTree: false,
   islands: false,
+  fluids: true,
+  vehicles: true,
   provenance: false,
 }
 
@@ -526,6 +526,7 @@ function baseSnapshot(frameIndex: number, simulatedTime: number, bodies: DebugSn
     contacts: [],
     manifolds: [],
     islands: [],
+    fluid_submersions: [],
     broadphase_tree: { root: null, depth: 0, nodes: [] },
     primitives: [],
     stats: {
@@ -543,6 +544,10 @@ function baseSnapshot(frameIndex: number, simulatedTime: number, bodies: DebugSn
       ccd_hit_count: 0,
       ccd_miss_count: 0,
       ccd_clamp_count: 0,
+      ccd_substep_count: 0,
+      ccd_substep_fallback_count: 0,
+      ccd_speculative_contact_count: 0,
+      ccd_speculative_enforced_count: 0,
     },
   };
 }
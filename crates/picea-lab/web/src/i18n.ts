@@ -15,6 +15,8 @@ export type LayerKey =
   | "trace"
   | "broadphaseTree"
   | "islands"
+  | "fluids"
+  | "vehicles"
   | "provenance";
 
 const storageKey = "picea-lab.locale";
@@ -362,6 +364,10 @@ const scenarioMessages: Record<string, Record<Locale, Pick<ScenarioDescriptor, "
     "zh-CN": { name: "CCD 动态凸体对撞", description: "两个高速动态矩形彼此扫掠命中，用于观察动态目标 CCD 的 TOI、目标扫掠和目标钳制事实。" },
     "en-US": { name: "CCD dynamic convex pair", description: "Two fast dynamic rectangles swept against each other." },
   },
+  vehicle_raycast: {
+    "zh-CN": { name: "射线车辆", description: "双轮射线车辆先加速越过凸起再刹车，用于观察悬挂、轮胎摩擦和每个车轮的接触状态。" },
+    "en-US": { name: "Ray-cast vehicle", description: "A two-wheel ray-cast vehicle accelerating over bumps, then braking." },
+  },
   compound_provenance: {
     "zh-CN": { name: "复合体来源", description: "作者提供的复合体 fixture，展示 piece 顺序、继承语义、宽阶段树和岛事实。" },
     "en-US": { name: "Compound provenance fixture", description: "An authored compound fixture exposing piece order, inherited collider semantics, broadphase tree, and island facts." },
@@ -379,8 +385,8 @@ const entityKindLabels: Record<Locale, Record<EntityKind, string>> = {
 };
 
 const layerLabels: Record<Locale, Record<LayerKey, string>> = {
-  "zh-CN": { shapes: "形状", aabbs: "AABB", contacts: "接触点", velocities: "速度", trace: "轨迹", broadphaseTree: "宽阶段树", islands: "岛", provenance: "来源" },
-  "en-US": { shapes: "Shapes", aabbs: "AABBs", contacts: "Contacts", velocities: "Velocities", trace: "Trace", broadphaseTree: "Broadphase tree", islands: "Islands", provenance: "Provenance" },
+  "zh-CN": { shapes: "形状", aabbs: "AABB", contacts: "接触点", velocities: "速度", trace: "轨迹", broadphaseTree: "宽阶段树", islands: "岛", fluids: "流体", vehicles: "车辆", provenance: "来源" },
+  "en-US": { shapes: "Shapes", aabbs: "AABBs", contacts: "Contacts", velocities: "Velocities", trace: "Trace", broadphaseTree: "Broadphase tree", islands: "Islands", fluids: "Fluids", vehicles: "Vehicles", provenance: "Provenance" },
 };
 
 const sourceLabels: Record<Locale, Record<SourceKind, string>> = {
@@ -441,6 +447,8 @@ const dynamicValueLabels: Record<Locale, Record<string, string>> = {
     velocity_edit: "速度编辑",
     user_patch: "用户补丁",
     sleep_disabled: "禁用休眠",
+    manual_wake: "手动唤醒",
+    manual_sleep: "手动休眠",
     hit: "命中",
     miss_no_previous: "无历史",
     miss_feature_id: "特征不匹配",
@@ -485,6 +493,8 @@ const dynamicValueLabels: Record<Locale, Record<string, string>> = {
     velocity_edit: "velocity edit",
     user_patch: "user patch",
     sleep_disabled: "sleep disabled",
+    manual_wake: "manual wake",
+    manual_sleep: "manual sleep",
   },
 };
 
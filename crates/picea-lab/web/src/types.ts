@@ -132,7 +132,7 @@ export type CcdTrace = {
   static_body: number;
   moving_collider: number;
   static_collider: number;
-  target_kind?: "static" | "dynamic";
+  target_kind?: "static" | "dynamic" | "kinematic";
   swept_start: Vec2;
   swept_end: Vec2;
   target_swept_start?: Vec2;
@@ -143,6 +143,10 @@ export type CcdTrace = {
   target_clamp?: number;
   slop: number;
   toi_point: Vec2;
+  method?: "shape_cast" | "conservative_advancement";
+  angular_sweep?: number;
+  target_angular_sweep?: number;
+  resolution?: "pose_clamp" | "sub_step";
 };
 
 export type DebugContact = {
@@ -205,7 +209,9 @@ export type SleepTransitionReason =
   | "transform_edit"
   | "velocity_edit"
   | "user_patch"
-  | "sleep_disabled";
+  | "sleep_disabled"
+  | "manual_wake"
+  | "manual_sleep";
 
 export type DebugIsland = {
   id: number;
@@ -214,6 +220,33 @@ export type DebugIsland = {
   reason?: SleepTransitionReason;
 };
 
+export type DebugFluidSubmersion = {
+  fluid_collider: number;
+  collider: number;
+  body: number;
+  submerged_area: number;
+  centroid: Vec2;
+  polygon: Vec2[];
+};
+
+export type WheelContact = {
+  body: number;
+  collider: number;
+  point: Vec2;
+  normal: Vec2;
+};
+
+export type WheelState = {
+  hub: Vec2;
+  suspension_length: number;
+  contact: WheelContact | null;
+  suspension_impulse: number;
+  tire_impulse: number;
+  slipping: boolean;
+  spin: number;
+  rotation: number;
+};
+
 export type CompoundProvenancePiece = {
   generated_piece_index: number;
   collider_handle?: number | null;
@@ -255,6 +288,7 @@ export type DebugSnapshot = {
   contacts: DebugContact[];
   manifolds: DebugManifold[];
   islands?: DebugIsland[];
+  fluid_submersions?: DebugFluidSubmersion[];
   broadphase_tree?: DebugBroadphaseTree;
   primitives: DebugPrimitive[];
   stats: {
@@ -283,15 +317,70 @@ export type DebugSnapshot = {
     ccd_hit_count?: number;
     ccd_miss_count?: number;
     ccd_clamp_count?: number;
+    ccd_substep_count?: number;
+    ccd_substep_fallback_count?: number;
+    ccd_speculative_contact_count?: number;
+    ccd_speculative_enforced_count?: number;
   };
 };
 
+export type LiveSnapshot = {
+  frame_index: number;
+  state_hash: string;
+  snapshot: DebugSnapshot;
+};
+
+export type DebugSnapshotDelta = {
+  frame_index: number;
+  state_hash: string;
+  meta: DebugSnapshot["meta"];
+  body_handles: number[];
+  bodies: DebugBody[];
+  collider_handles: number[];
+  colliders: DebugCollider[];
+  joint_handles: number[];
+  joints: DebugJoint[];
+  contacts: DebugContact[];
+  manifolds: DebugManifold[];
+  islands: DebugIsland[];
+  fluid_submersions: DebugFluidSubmersion[];
+  broadphase_tree: DebugBroadphaseTree;
+  stats: DebugSnapshot["stats"];
+};
+
+export type LiveBodyPatch = {
+  body_type?: "static" | "dynamic" | "kinematic";
+  pose?: [number, number, number];
+  linear_velocity?: [number, number];
+  angular_velocity?: number;
+  gravity_scale?: number;
+};
+
+export type LiveCommand =
+  | { type: "spawn"; body: Record<string, unknown> }
+  | { type: "patch"; body: number; patch: LiveBodyPatch }
+  | { type: "destroy"; body: number }
+  | {
+      type: "drag";
+      body: number;
+      local_anchor?: [number, number];
+      target: [number, number];
+      stiffness?: number;
+    }
+  | { type: "release" };
+
+export type LiveCommandReport = {
+  created_bodies: number[];
+  drag_joint: number | null;
+};
+
 export type FrameRecord = {
   frame_index: number;
   simulated_time: number;
   state_hash: string;
   snapshot: DebugSnapshot;
   compound_provenance?: CompoundProvenance[];
+  vehicle_wheels?: WheelState[];
 };
 
 export type ScenarioDescriptor = {
@@ -300,9 +389,41 @@ export type ScenarioDescriptor = {
   description: string;
 };
 
+export type SolverParams = {
+  contact_slop: number;
+  position_correction_percent: number;
+  velocity_bias: number;
+  warm_start_normal_dot_threshold: number;
+  warm_start_point_drift_threshold: number;
+  soft_contact_hertz: number;
+  soft_contact_damping_ratio: number;
+  soft_contact_max_push_velocity: number;
+};
+
+export type SleepParams = {
+  stability_seconds: number;
+  linear_threshold: number;
+  angular_threshold: number;
+};
+
+export type CcdParams = {
+  toi_epsilon: number;
+  clamp_slop: number;
+  support_epsilon: number;
+  mode: "sweep" | "speculative" | "sweep_and_speculative";
+  resolution: "pose_clamp" | "sub_step";
+  max_toi_substeps: number;
+  max_body_toi_substeps: number;
+};
+
+export type FixtureProvenance = {
+  hash: string;
+  path: string | null;
+};
+
 export type SessionRecord = {
   id: string;
-  scenario_id: string;
+  scenario_id: string | null;
   status: "created" | "running" | "paused" | "completed" | "failed";
   run_id: string | null;
   frame_count: number;
@@ -310,7 +431,13 @@ export type SessionRecord = {
   overrides: {
     frame_count?: number | null;
     gravity?: [number, number] | null;
+    solver?: Partial<SolverParams> | null;
+    sleep?: Partial<SleepParams> | null;
+    ccd?: Partial<CcdParams> | null;
   };
+  fixture?: FixtureProvenance | null;
+  live?: boolean;
+  dropped_time?: number;
   final_state_hash: string | null;
   manifest_artifact?: string | null;
   final_snapshot_artifact?: string | null;
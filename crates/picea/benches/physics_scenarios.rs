@@ -2,10 +2,10 @@ use std::hint::black_box;
 
 use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
 use picea::prelude::{
-    BodyBundle, BodyDesc, BodyType, ColliderBundle, ColliderDesc, CollisionLayerPreset,
-    DistanceJointDesc, JointDesc, MaterialPreset, Point, Pose, QueryFilter, QueryPipeline,
-    QueryStats, SharedShape, SimulationPipeline, StepConfig, StepReport, World, WorldCommands,
-    WorldDesc,
+    BodyBundle, BodyDesc, BodyType, CcdMode, CcdParams, CcdResolution, ColliderBundle,
+    ColliderDesc, CollisionLayerPreset, DistanceJointDesc, FloatNum, JointDesc, MaterialPreset,
+    Point, Pose, QueryFilter, QueryPipeline, QueryStats, SharedShape, SimulationPipeline,
+    SolverMode, StepConfig, StepReport, World, WorldCommands, WorldDesc,
 };
 
 const BROADPHASE_GRID: usize = 12;
@@ -13,6 +13,7 @@ const DENSE_GRID: usize = 9;
 const STACK_HEIGHT: usize = 10;
 const API_BATCH_SIZE: usize = 128;
 const ISLAND_COUNT: usize = 32;
+const ISLAND_STACK_HEIGHT: usize = 3;
 
 fn step_config() -> StepConfig {
     StepConfig {
@@ -21,8 +22,26 @@ fn step_config() -> StepConfig {
     }
 }
 
-fn run_steps(mut world: World, steps: usize) -> StepReport {
-    let mut pipeline = SimulationPipeline::new(step_config());
+fn soft_step_config() -> StepConfig {
+    StepConfig {
+        solver_mode: SolverMode::SoftStep { substeps: 4 },
+        ..step_config()
+    }
+}
+
+fn ccd_config(mode: CcdMode, resolution: CcdResolution) -> StepConfig {
+    StepConfig {
+        ccd: CcdParams {
+            mode,
+            resolution,
+            ..CcdParams::default()
+        },
+        ..step_config()
+    }
+}
+
+fn run_steps(mut world: World, steps: usize, config: StepConfig) -> StepReport {
+    let mut pipeline = SimulationPipeline::new(config);
     let mut report = StepReport::default();
     for _ in 0..steps {
         report = pipeline.step(&mut world);
@@ -36,7 +55,17 @@ fn bench_step_scenario(
     make_world: fn() -> World,
     steps: usize,
 ) {
-    let baseline = run_steps(make_world(), steps);
+    bench_step_scenario_with_config(c, group_name, make_world, steps, step_config());
+}
+
+fn bench_step_scenario_with_config(
+    c: &mut Criterion,
+    group_name: &str,
+    make_world: fn() -> World,
+    steps: usize,
+    config: StepConfig,
+) {
+    let baseline = run_steps(make_world(), steps, config);
     let stats = baseline.stats;
     // Criterion records timing; the benchmark id also records deterministic
     // engine counters so a local baseline explains what the timed step did
@@ -49,7 +78,8 @@ fn bench_step_scenario(
                 "broadphase_traversals={}/broadphase_pruned={}/",
                 "contacts={}/contact_rows={}/joint_rows={}/",
                 "islands={}/active_islands={}/sleep_skips={}/solver_slots={}/",
-                "ccd_hits={}"
+                "ccd_hits={}/ccd_clamps={}/ccd_substeps={}/",
+                "speculative_rows={}/speculative_enforced={}"
             ),
             steps,
             stats.body_count,
@@ -64,15 +94,19 @@ fn bench_step_scenario(
             stats.active_island_count,
             stats.sleeping_island_skip_count,
             stats.solver_body_slot_count,
-            stats.ccd_hit_count
+            stats.ccd_hit_count,
+            stats.ccd_clamp_count,
+            stats.ccd_substep_count,
+            stats.ccd_speculative_contact_count,
+            stats.ccd_speculative_enforced_count
         ),
     );
 
     let mut group = c.benchmark_group(group_name);
     group.bench_function(id, |bench| {
         bench.iter_batched(
-            || make_world(),
-            |world| black_box(run_steps(world, steps)),
+            make_world,
+            |world| black_box(run_steps(world, steps, config)),
             BatchSize::SmallInput,
         );
     });
@@ -80,19 +114,35 @@ fn bench_step_scenario(
 }
 
 fn many_small_islands_world() -> World {
+    // Each island is a short stack on its own static platform. Static bodies do
+    // not join islands, so every stack is an independent contact solve batch;
+    // compare `--features parallel` against the default build to see the
+    // island-parallel speed-up.
     let mut world = World::new(WorldDesc {
-        gravity: (0.0, 0.0).into(),
-        enable_sleep: true,
+        enable_sleep: false,
+        ..WorldDesc::default()
     });
     let bodies = (0..ISLAND_COUNT)
-        .map(|index| {
-            BodyBundle::dynamic()
-                .with_pose(Pose::from_xy_angle(index as f32 * 4.0, 0.0, 0.0))
-                .with_collider(
-                    ColliderBundle::new(SharedShape::circle(0.35))
-                        .with_filter(CollisionLayerPreset::DynamicBody)
-                        .with_material(MaterialPreset::Default),
-                )
+        .flat_map(|index| {
+            let x = index as FloatNum * 4.0;
+            std::iter::once(
+                BodyBundle::static_body()
+                    .with_pose(Pose::from_xy_angle(x, 2.0, 0.0))
+                    .with_collider(
+                        ColliderBundle::new(SharedShape::rect(2.0, 1.0))
+                            .with_filter(CollisionLayerPreset::StaticGeometry)
+                            .with_material(MaterialPreset::Rough),
+                    ),
+            )
+            .chain((0..ISLAND_STACK_HEIGHT).map(move |level| {
+                BodyBundle::dynamic()
+                    .with_pose(Pose::from_xy_angle(x, 1.0 - level as FloatNum * 1.02, 0.0))
+                    .with_collider(
+                        ColliderBundle::new(SharedShape::rect(1.0, 1.0))
+                            .with_filter(CollisionLayerPreset::DynamicBody)
+                            .with_material(MaterialPreset::Rough),
+                    )
+            }))
         })
         .collect::<Vec<_>>();
     world
@@ -106,13 +156,14 @@ fn one_large_island_world() -> World {
     let mut world = World::new(WorldDesc {
         gravity: (0.0, 0.0).into(),
         enable_sleep: true,
+        ..WorldDesc::default()
     });
     let mut handles = Vec::new();
     for index in 0..ISLAND_COUNT {
         let body = world
             .create_body(BodyDesc {
                 body_type: BodyType::Dynamic,
-                pose: Pose::from_xy_angle(index as f32 * 0.8, 0.0, 0.0),
+                pose: Pose::from_xy_angle(index as FloatNum * 0.8, 0.0, 0.0),
                 gravity_scale: 0.0,
                 ..BodyDesc::default()
             })
@@ -149,7 +200,11 @@ fn query_heavy_world() -> World {
         .flat_map(|row| {
             (0..BROADPHASE_GRID).map(move |col| {
                 BodyBundle::static_body()
-                    .with_pose(Pose::from_xy_angle(col as f32 * 1.5, row as f32 * 1.5, 0.0))
+                    .with_pose(Pose::from_xy_angle(
+                        col as FloatNum * 1.5,
+                        row as FloatNum * 1.5,
+                        0.0,
+                    ))
                     .with_collider(
                         ColliderBundle::new(SharedShape::rect(0.75, 0.75))
                             .with_filter(CollisionLayerPreset::StaticGeometry)
@@ -168,7 +223,8 @@ fn query_heavy_world() -> World {
 fn query_points() -> Vec<Point> {
     (0..BROADPHASE_GRID)
         .flat_map(|row| {
-            (0..BROADPHASE_GRID).map(move |col| Point::new(col as f32 * 1.5, row as f32 * 1.5))
+            (0..BROADPHASE_GRID)
+                .map(move |col| Point::new(col as FloatNum * 1.5, row as FloatNum * 1.5))
         })
         .collect()
 }
@@ -225,8 +281,8 @@ fn sparse_broadphase_world() -> World {
     let bodies = (0..BROADPHASE_GRID)
         .flat_map(|row| {
             (0..BROADPHASE_GRID).map(move |col| {
-                let x = col as f32 * 5.0;
-                let y = row as f32 * 5.0;
+                let x = col as FloatNum * 5.0;
+                let y = row as FloatNum * 5.0;
                 BodyBundle::static_body()
                     .with_pose(Pose::from_xy_angle(x, y, 0.0))
                     .with_collider(
@@ -249,8 +305,8 @@ fn dense_broadphase_world() -> World {
     let bodies = (0..DENSE_GRID)
         .flat_map(|row| {
             (0..DENSE_GRID).map(move |col| {
-                let x = col as f32 * 0.45;
-                let y = row as f32 * 0.45;
+                let x = col as FloatNum * 0.45;
+                let y = row as FloatNum * 0.45;
                 BodyBundle::dynamic()
                     .with_pose(Pose::from_xy_angle(x, y, 0.0))
                     .with_collider(
@@ -282,7 +338,11 @@ fn stack_stability_world() -> World {
     );
     bodies.extend((0..STACK_HEIGHT).map(|index| {
         BodyBundle::dynamic()
-            .with_pose(Pose::from_xy_angle(0.0, 5.0 - index as f32 * 1.05, 0.0))
+            .with_pose(Pose::from_xy_angle(
+                0.0,
+                5.0 - index as FloatNum * 1.05,
+                0.0,
+            ))
             .with_collider(
                 ColliderBundle::new(SharedShape::rect(1.0, 1.0))
                     .with_filter(CollisionLayerPreset::DynamicBody)
@@ -300,6 +360,7 @@ fn ccd_bullet_world() -> World {
     let mut world = World::new(WorldDesc {
         gravity: (0.0, 0.0).into(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let wall = BodyBundle::static_body()
         .with_pose(Pose::from_xy_angle(2.0, 0.0, 0.0))
@@ -332,6 +393,7 @@ fn ccd_dynamic_pair_world() -> World {
     let mut world = World::new(WorldDesc {
         gravity: (0.0, 0.0).into(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let left = BodyBundle::new(BodyDesc {
         body_type: BodyType::Dynamic,
@@ -369,8 +431,8 @@ fn ccd_dynamic_pair_world() -> World {
 fn api_batch_bodies(count: usize) -> Vec<BodyBundle> {
     (0..count)
         .map(|index| {
-            let x = (index % 16) as f32 * 1.25;
-            let y = (index / 16) as f32 * 1.25;
+            let x = (index % 16) as FloatNum * 1.25;
+            let y = (index / 16) as FloatNum * 1.25;
             BodyBundle::dynamic()
                 .with_pose(Pose::from_xy_angle(x, y, 0.0))
                 .with_collider(
@@ -427,7 +489,40 @@ fn physics_scenarios(c: &mut Criterion) {
     bench_step_scenario(c, "many_small_islands", many_small_islands_world, 10);
     bench_step_scenario(c, "one_large_island", one_large_island_world, 10);
     bench_step_scenario(c, "stack_stability", stack_stability_world, 60);
+    bench_step_scenario_with_config(
+        c,
+        "stack_stability_soft_step",
+        stack_stability_world,
+        60,
+        soft_step_config(),
+    );
     bench_step_scenario(c, "ccd_bullet", ccd_bullet_world, 1);
+    // The bullet reaches the wall on the second step, so three steps cover the
+    // approach for every strategy, including speculative-only.
+    for (name, mode, resolution) in [
+        (
+            "ccd_bullet_world_pose_clamp",
+            CcdMode::Sweep,
+            CcdResolution::PoseClamp,
+        ),
+        (
+            "ccd_bullet_world_substep",
+            CcdMode::Sweep,
+            CcdResolution::SubStep,
+        ),
+        (
+            "ccd_bullet_world_speculative",
+            CcdMode::Speculative,
+            CcdResolution::PoseClamp,
+        ),
+        (
+            "ccd_bullet_world_sweep_and_speculative",
+            CcdMode::SweepAndSpeculative,
+            CcdResolution::PoseClamp,
+        ),
+    ] {
+        bench_step_scenario_with_config(c, name, ccd_bullet_world, 3, ccd_config(mode, resolution));
+    }
     bench_step_scenario(c, "ccd_dynamic_pair", ccd_dynamic_pair_world, 1);
     bench_api_batch_creation(c);
 }
@@ -5,15 +5,18 @@ use serde::{Deserialize, Serialize};
 use crate::{
     handles::{BodyHandle, ColliderHandle, JointHandle},
     math::{point::Point, vector::Vector, FloatNum},
+    pipeline::SleepParams,
     world::ValidationError,
 };
 
-/// Density-derived mass facts for a body.
+/// Mass facts for a body.
 ///
 /// `mass` and `inertia` describe how much linear and angular resistance the
-/// attached collider shapes contribute. The inverse fields are what solvers
-/// consume: static and kinematic bodies keep zero inverses so contacts cannot
-/// move them even though their density-derived facts remain inspectable.
+/// attached collider shapes contribute, unless `BodyDesc::mass_override`
+/// replaces them. The inverse fields are what solvers consume: static and
+/// kinematic bodies keep zero inverses so contacts cannot move them even though
+/// their mass facts remain inspectable. Overrides only need `mass`,
+/// `local_center_of_mass` and `inertia`; the inverses are always derived.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct MassProperties {
     /// Total collider mass in local body units.
@@ -97,6 +100,17 @@ impl Pose {
         }
     }
 
+    /// Linearly blends from this pose toward `other`; `alpha` 0 returns `self`.
+    ///
+    /// Angles are blended without wrapping because body angles accumulate
+    /// continuously across steps.
+    pub fn lerp(&self, other: Pose, alpha: FloatNum) -> Pose {
+        Self {
+            translation: self.translation + (other.translation - self.translation) * alpha,
+            angle: self.angle + (other.angle - self.angle) * alpha,
+        }
+    }
+
     /// Transforms a local-space point into world space.
     pub fn transform_point(&self, point: Point) -> Point {
         let rotated = Vector::from(point).rotated(self.angle);
@@ -140,6 +154,57 @@ impl BodyType {
     }
 }
 
+/// Degrees of freedom a dynamic body may not move along.
+///
+/// Locked axes keep zero velocity and read as infinite mass to contacts, CCD
+/// and joints, so the body still pushes others but is never pushed along them.
+/// Translation axes are world-space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LockedAxes {
+    /// Freezes motion along world x.
+    pub translation_x: bool,
+    /// Freezes motion along world y.
+    pub translation_y: bool,
+    /// Freezes rotation.
+    pub rotation: bool,
+}
+
+impl LockedAxes {
+    /// Every degree of freedom is free.
+    pub const NONE: Self = Self {
+        translation_x: false,
+        translation_y: false,
+        rotation: false,
+    };
+    /// Free translation without rotation, e.g. for characters.
+    pub const ROTATION: Self = Self {
+        translation_x: false,
+        translation_y: false,
+        rotation: true,
+    };
+    /// Free rotation around a fixed position.
+    pub const TRANSLATION: Self = Self {
+        translation_x: true,
+        translation_y: true,
+        rotation: false,
+    };
+
+    pub(crate) fn mask_linear(self, vector: Vector) -> Vector {
+        Vector::new(
+            if self.translation_x { 0.0 } else { vector.x() },
+            if self.translation_y { 0.0 } else { vector.y() },
+        )
+    }
+
+    pub(crate) fn mask_angular(self, value: FloatNum) -> FloatNum {
+        if self.rotation {
+            0.0
+        } else {
+            value
+        }
+    }
+}
+
 /// Descriptor used to create a body in a [`World`](crate::world::World).
 #[derive(Clone, Debug, PartialEq)]
 pub struct BodyDesc {
@@ -157,10 +222,28 @@ pub struct BodyDesc {
     pub angular_damping: FloatNum,
     /// Gravity scale multiplier applied by the simulation pipeline.
     pub gravity_scale: FloatNum,
+    /// Gravity vector used instead of world gravity and gravity fields; still
+    /// multiplied by `gravity_scale`.
+    pub gravity_override: Option<Vector>,
     /// Whether the body may enter a sleeping state.
     pub can_sleep: bool,
     /// Whether the body starts asleep.
     pub sleeping: bool,
+    /// Per-body sleep thresholds; `None` uses `StepConfig::sleep`.
+    pub sleep_thresholds: Option<SleepParams>,
+    /// Whether the pose-clamp CCD pass sweeps this body. Disabled bodies are
+    /// also skipped as moving CCD targets unless the other side is a bullet.
+    pub ccd_enabled: bool,
+    /// Marks a fast projectile: always swept, regardless of `ccd_enabled`
+    /// and `ccd_motion_threshold`, and tested against every moving target.
+    pub bullet: bool,
+    /// Minimum per-step displacement before a non-bullet body is swept.
+    pub ccd_motion_threshold: FloatNum,
+    /// Replaces the collider-derived mass, center of mass and inertia.
+    /// Dynamic bodies need a positive override mass.
+    pub mass_override: Option<MassProperties>,
+    /// Degrees of freedom the body may not move along.
+    pub locked_axes: LockedAxes,
     /// User-owned opaque payload preserved by the core API.
     pub user_data: u64,
 }
@@ -175,8 +258,15 @@ impl Default for BodyDesc {
             linear_damping: 0.0,
             angular_damping: 0.0,
             gravity_scale: 1.0,
+            gravity_override: None,
             can_sleep: true,
             sleeping: false,
+            sleep_thresholds: None,
+            ccd_enabled: true,
+            bullet: false,
+            ccd_motion_threshold: 0.0,
+            mass_override: None,
+            locked_axes: LockedAxes::NONE,
             user_data: 0,
         }
     }
@@ -229,6 +319,42 @@ impl BodyDesc {
                 field: "gravity_scale",
             });
         }
+        if self
+            .gravity_override
+            .is_some_and(|gravity| !gravity.x().is_finite() || !gravity.y().is_finite())
+        {
+            return Err(ValidationError::BodyDesc {
+                field: "gravity_override",
+            });
+        }
+        if let Some(field) = self
+            .sleep_thresholds
+            .and_then(invalid_sleep_threshold_field)
+        {
+            return Err(ValidationError::BodyDesc { field });
+        }
+        if !self.ccd_motion_threshold.is_finite() || self.ccd_motion_threshold < 0.0 {
+            return Err(ValidationError::BodyDesc {
+                field: "ccd_motion_threshold",
+            });
+        }
+        if self
+            .mass_override
+            .is_some_and(|mass_override| !mass_override.is_finite_non_negative())
+        {
+            return Err(ValidationError::BodyDesc {
+                field: "mass_override",
+            });
+        }
+        if self.body_type.is_dynamic()
+            && self
+                .mass_override
+                .is_some_and(|mass_override| mass_override.mass <= 0.0)
+        {
+            return Err(ValidationError::BodyDesc {
+                field: "mass_override.mass",
+            });
+        }
         Ok(())
     }
 }
@@ -250,10 +376,27 @@ pub struct BodyPatch {
     pub angular_damping: Option<FloatNum>,
     /// Replaces the gravity scale when present.
     pub gravity_scale: Option<FloatNum>,
+    /// Replaces the gravity override when present; `Some(None)` returns the
+    /// body to world gravity and gravity fields.
+    pub gravity_override: Option<Option<Vector>>,
     /// Replaces the sleep eligibility when present.
     pub can_sleep: Option<bool>,
     /// Explicitly sets the sleeping state when present.
     pub sleeping: Option<bool>,
+    /// Replaces the per-body sleep thresholds when present; `Some(None)`
+    /// reverts to `StepConfig::sleep`.
+    pub sleep_thresholds: Option<Option<SleepParams>>,
+    /// Enables or disables CCD sweeps when present.
+    pub ccd_enabled: Option<bool>,
+    /// Sets the bullet flag when present.
+    pub bullet: Option<bool>,
+    /// Replaces the CCD motion threshold when present.
+    pub ccd_motion_threshold: Option<FloatNum>,
+    /// Replaces the mass override when present; `Some(None)` reverts to the
+    /// collider-derived mass properties.
+    pub mass_override: Option<Option<MassProperties>>,
+    /// Replaces the locked axes when present.
+    pub locked_axes: Option<LockedAxes>,
     /// Replaces the user payload when present.
     pub user_data: Option<u64>,
     /// Clears sleeping even if the patch does not touch `sleeping`.
@@ -323,10 +466,73 @@ impl BodyPatch {
                 field: "gravity_scale",
             });
         }
+        if self
+            .gravity_override
+            .flatten()
+            .is_some_and(|gravity| !gravity.x().is_finite() || !gravity.y().is_finite())
+        {
+            return Err(ValidationError::BodyPatch {
+                field: "gravity_override",
+            });
+        }
+        if let Some(field) = self
+            .sleep_thresholds
+            .flatten()
+            .and_then(invalid_sleep_threshold_field)
+        {
+            return Err(ValidationError::BodyPatch { field });
+        }
+        if self
+            .ccd_motion_threshold
+            .is_some_and(|threshold| !threshold.is_finite() || threshold < 0.0)
+        {
+            return Err(ValidationError::BodyPatch {
+                field: "ccd_motion_threshold",
+            });
+        }
+        if self
+            .mass_override
+            .flatten()
+            .is_some_and(|mass_override| !mass_override.is_finite_non_negative())
+        {
+            return Err(ValidationError::BodyPatch {
+                field: "mass_override",
+            });
+        }
         Ok(())
     }
 }
 
+/// Runs [`SleepParams::validate`] and renames the failing `sleep.*` field to
+/// the body-level `sleep_thresholds.*` path.
+fn invalid_sleep_threshold_field(thresholds: SleepParams) -> Option<&'static str> {
+    const FIELDS: [(&str, &str); 3] = [
+        (
+            "sleep.stability_seconds",
+            "sleep_thresholds.stability_seconds",
+        ),
+        (
+            "sleep.linear_threshold",
+            "sleep_thresholds.linear_threshold",
+        ),
+        (
+            "sleep.angular_threshold",
+            "sleep_thresholds.angular_threshold",
+        ),
+    ];
+    let error = thresholds.validate().err()?;
+    let field = match error {
+        ValidationError::StepConfig { field } => field,
+        _ => return Some("sleep_thresholds"),
+    };
+    Some(
+        FIELDS
+            .iter()
+            .find(|(step_field, _)| *step_field == field)
+            .map_or("sleep_thresholds", |(_, body_field)| body_field),
+    )
+}
+
 /// Read-only dynamic body status exposed by the stable API.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct BodyStatusView {
@@ -364,8 +570,15 @@ pub struct BodyView {
     linear_damping: FloatNum,
     angular_damping: FloatNum,
     gravity_scale: FloatNum,
+    gravity_override: Option<Vector>,
     can_sleep: bool,
     sleeping: bool,
+    sleep_thresholds: Option<SleepParams>,
+    ccd_enabled: bool,
+    bullet: bool,
+    ccd_motion_threshold: FloatNum,
+    mass_override: Option<MassProperties>,
+    locked_axes: LockedAxes,
     user_data: u64,
 }
 
@@ -385,11 +598,21 @@ impl BodyView {
         self.pose
     }
 
-    /// Returns density-derived body mass and inertia facts.
+    /// Returns the body mass and inertia facts the solver uses.
     pub fn mass_properties(&self) -> MassProperties {
         self.mass_properties
     }
 
+    /// Returns the mass override, if one replaces the collider-derived facts.
+    pub fn mass_override(&self) -> Option<MassProperties> {
+        self.mass_override
+    }
+
+    /// Returns the degrees of freedom the body may not move along.
+    pub fn locked_axes(&self) -> LockedAxes {
+        self.locked_axes
+    }
+
     /// Returns the current linear velocity.
     pub fn linear_velocity(&self) -> Vector {
         self.linear_velocity
@@ -415,6 +638,11 @@ impl BodyView {
         self.gravity_scale
     }
 
+    /// Returns the gravity vector replacing world gravity, if any.
+    pub fn gravity_override(&self) -> Option<Vector> {
+        self.gravity_override
+    }
+
     /// Returns whether the body may sleep.
     pub fn can_sleep(&self) -> bool {
         self.can_sleep
@@ -425,6 +653,26 @@ impl BodyView {
         self.sleeping
     }
 
+    /// Returns the per-body sleep thresholds, if any override the step config.
+    pub fn sleep_thresholds(&self) -> Option<SleepParams> {
+        self.sleep_thresholds
+    }
+
+    /// Returns whether CCD sweeps this body.
+    pub fn ccd_enabled(&self) -> bool {
+        self.ccd_enabled
+    }
+
+    /// Returns whether the body is flagged as a bullet.
+    pub fn bullet(&self) -> bool {
+        self.bullet
+    }
+
+    /// Returns the per-step displacement below which CCD skips this body.
+    pub fn ccd_motion_threshold(&self) -> FloatNum {
+        self.ccd_motion_threshold
+    }
+
     /// Returns the opaque user payload.
     pub fn user_data(&self) -> u64 {
         self.user_data
@@ -438,21 +686,55 @@ impl BodyView {
             sleeping: self.sleeping,
         }
     }
+
+    /// Returns a descriptor that recreates this body in its current state.
+    pub fn desc(&self) -> BodyDesc {
+        BodyDesc {
+            body_type: self.body_type,
+            pose: self.pose,
+            linear_velocity: self.linear_velocity,
+            angular_velocity: self.angular_velocity,
+            linear_damping: self.linear_damping,
+            angular_damping: self.angular_damping,
+            gravity_scale: self.gravity_scale,
+            gravity_override: self.gravity_override,
+            can_sleep: self.can_sleep,
+            sleeping: self.sleeping,
+            sleep_thresholds: self.sleep_thresholds,
+            ccd_enabled: self.ccd_enabled,
+            bullet: self.bullet,
+            ccd_motion_threshold: self.ccd_motion_threshold,
+            mass_override: self.mass_override,
+            locked_axes: self.locked_axes,
+            user_data: self.user_data,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub(crate) struct BodyRecord {
     pub(crate) body_type: BodyType,
     pub(crate) pose: Pose,
+    /// Pose at the start of the most recent step, used for render interpolation.
+    pub(crate) previous_pose: Pose,
     pub(crate) mass_properties: MassProperties,
     pub(crate) linear_velocity: Vector,
     pub(crate) angular_velocity: FloatNum,
     pub(crate) linear_damping: FloatNum,
     pub(crate) angular_damping: FloatNum,
     pub(crate) gravity_scale: FloatNum,
+    pub(crate) gravity_override: Option<Vector>,
     pub(crate) can_sleep: bool,
     pub(crate) sleeping: bool,
     pub(crate) sleep_idle_time: FloatNum,
+    pub(crate) sleep_thresholds: Option<SleepParams>,
+    pub(crate) ccd_enabled: bool,
+    pub(crate) bullet: bool,
+    pub(crate) ccd_motion_threshold: FloatNum,
+    pub(crate) mass_override: Option<MassProperties>,
+    pub(crate) locked_axes: LockedAxes,
+    /// Pose a kinematic body is driven to over the next step.
+    pub(crate) kinematic_target: Option<Pose>,
     pub(crate) island_id: Option<u32>,
     pub(crate) user_data: u64,
     pub(crate) colliders: Vec<ColliderHandle>,
@@ -464,15 +746,27 @@ impl BodyRecord {
         let mut record = Self {
             body_type: desc.body_type,
             pose: desc.pose,
-            mass_properties: MassProperties::default().with_body_type(desc.body_type),
+            previous_pose: desc.pose,
+            mass_properties: desc
+                .mass_override
+                .unwrap_or_default()
+                .with_body_type(desc.body_type),
             linear_velocity: desc.linear_velocity,
             angular_velocity: desc.angular_velocity,
             linear_damping: desc.linear_damping,
             angular_damping: desc.angular_damping,
             gravity_scale: desc.gravity_scale,
+            gravity_override: desc.gravity_override,
             can_sleep: desc.can_sleep,
             sleeping: desc.sleeping,
             sleep_idle_time: 0.0,
+            sleep_thresholds: desc.sleep_thresholds,
+            ccd_enabled: desc.ccd_enabled,
+            bullet: desc.bullet,
+            ccd_motion_threshold: desc.ccd_motion_threshold,
+            mass_override: desc.mass_override,
+            locked_axes: desc.locked_axes,
+            kinematic_target: None,
             island_id: None,
             user_data: desc.user_data,
             colliders: Vec::new(),
@@ -487,7 +781,9 @@ impl BodyRecord {
             self.body_type = body_type;
         }
         if let Some(pose) = patch.pose {
+            // Teleports should not smear across the interpolation window.
             self.pose = pose;
+            self.previous_pose = pose;
         }
         if let Some(linear_velocity) = patch.linear_velocity {
             self.linear_velocity = linear_velocity;
@@ -504,15 +800,44 @@ impl BodyRecord {
         if let Some(gravity_scale) = patch.gravity_scale {
             self.gravity_scale = gravity_scale;
         }
+        if let Some(gravity_override) = patch.gravity_override {
+            self.gravity_override = gravity_override;
+        }
         if let Some(can_sleep) = patch.can_sleep {
             self.can_sleep = can_sleep;
         }
         if let Some(sleeping) = patch.sleeping {
             self.sleeping = sleeping;
         }
+        if let Some(sleep_thresholds) = patch.sleep_thresholds {
+            self.sleep_thresholds = sleep_thresholds;
+        }
+        if let Some(ccd_enabled) = patch.ccd_enabled {
+            self.ccd_enabled = ccd_enabled;
+        }
+        if let Some(bullet) = patch.bullet {
+            self.bullet = bullet;
+        }
+        if let Some(ccd_motion_threshold) = patch.ccd_motion_threshold {
+            self.ccd_motion_threshold = ccd_motion_threshold;
+        }
+        if let Some(mass_override) = patch.mass_override {
+            self.mass_override = mass_override;
+        }
+        if let Some(locked_axes) = patch.locked_axes {
+            self.locked_axes = locked_axes;
+        }
         if let Some(user_data) = patch.user_data {
             self.user_data = user_data;
         }
+        if patch.body_type.is_some()
+            || patch.pose.is_some()
+            || patch.linear_velocity.is_some()
+            || patch.angular_velocity.is_some()
+        {
+            // Direct pose or velocity edits take over from target driving.
+            self.kinematic_target = None;
+        }
         if patch.body_type.is_some()
             || patch.pose.is_some()
             || patch.linear_velocity.is_some()
@@ -541,8 +866,15 @@ impl BodyRecord {
             linear_damping: self.linear_damping,
             angular_damping: self.angular_damping,
             gravity_scale: self.gravity_scale,
+            gravity_override: self.gravity_override,
             can_sleep: self.can_sleep,
             sleeping: self.sleeping,
+            sleep_thresholds: self.sleep_thresholds,
+            ccd_enabled: self.ccd_enabled,
+            bullet: self.bullet,
+            ccd_motion_threshold: self.ccd_motion_threshold,
+            mass_override: self.mass_override,
+            locked_axes: self.locked_axes,
             user_data: self.user_data,
         }
     }
@@ -559,6 +891,18 @@ impl BodyRecord {
         self.mass_properties = mass_properties.with_body_type(self.body_type);
     }
 
+    /// Per-axis inverse mass along world x and y; locked axes read as infinite mass.
+    pub(crate) fn solver_inverse_mass(&self) -> Vector {
+        let inverse_mass = self.mass_properties.inverse_mass;
+        self.locked_axes
+            .mask_linear(Vector::new(inverse_mass, inverse_mass))
+    }
+
+    pub(crate) fn solver_inverse_inertia(&self) -> FloatNum {
+        self.locked_axes
+            .mask_angular(self.mass_properties.inverse_inertia)
+    }
+
     pub(crate) fn attach_joint(&mut self, handle: JointHandle) {
         if !self.joints.contains(&handle) {
             self.joints.push(handle);
@@ -577,6 +921,9 @@ impl BodyRecord {
             self.angular_velocity = 0.0;
             self.sleeping = false;
             self.sleep_idle_time = 0.0;
+        } else if self.body_type.is_dynamic() {
+            self.linear_velocity = self.locked_axes.mask_linear(self.linear_velocity);
+            self.angular_velocity = self.locked_axes.mask_angular(self.angular_velocity);
         }
     }
 }
@@ -0,0 +1,595 @@
+//! Kinematic character controller built on broadphase queries and CCD sweeps.
+//!
+//! The controller moves a kinematic body by sweeping its collider through the
+//! world, sliding along what it hits. It never integrates velocity itself:
+//! callers feed it a desired translation each step (input plus gravity) and it
+//! drives the body with [`World::set_kinematic_target`], so contacts and CCD
+//! see a moving body instead of a teleport.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    body::{BodyPatch, BodyType, Pose},
+    collider::{ColliderRecord, SharedShape},
+    debug::DebugAabb,
+    handles::{BodyHandle, ColliderHandle},
+    math::{num, pi, point::Point, tau, vector::Vector, FloatNum},
+    pipeline::{
+        ccd::{swept_circle_convex_toi, swept_convex_convex_toi},
+        CcdParams,
+    },
+    query::{QueryFilter, WorldQueries},
+    solver::body_state::{angular_point_velocity, inverse_mass_along, linear_response},
+    world::{HandleError, ValidationError, World, WorldError},
+};
+
+/// Segment count for circles treated as obstacles by the convex sweeps.
+const CIRCLE_OBSTACLE_SEGMENTS: usize = 16;
+/// Half thickness given to segment obstacles so the convex sweeps can hit them.
+const SEGMENT_OBSTACLE_HALF_THICKNESS: FloatNum = 1.0e-3;
+/// Extra reach of the ground probe beyond the controller offset.
+const GROUND_PROBE_MARGIN: FloatNum = 1.0e-2;
+const MOVE_EPSILON: FloatNum = 1.0e-6;
+
+/// Tuning for [`CharacterController::move_body`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterController {
+    /// Unit direction the character treats as up. World gravity points along
+    /// +y by default, so up defaults to -y.
+    pub up: Vector,
+    /// Gap kept between the character and the surfaces it touches.
+    pub offset: FloatNum,
+    /// Steepest slope, in radians from level, the character can walk up and
+    /// stand on. Steeper surfaces act as walls.
+    pub max_slope_angle: FloatNum,
+    /// Tallest ledge the character climbs while grounded; zero disables stepping.
+    pub max_step_height: FloatNum,
+    /// Distance the character is pulled down to stay on descending ground
+    /// while grounded and not moving up; zero disables snapping.
+    pub snap_to_ground: FloatNum,
+    /// Membership bits of one-way platforms. Colliders in these layers only
+    /// block a character moving down onto them from above.
+    pub one_way_platforms: u64,
+    /// Mass used to push dynamic bodies out of the way; zero makes dynamic
+    /// bodies plain obstacles.
+    pub character_mass: FloatNum,
+    /// Maximum number of slide iterations per move.
+    pub max_slide_iterations: u16,
+}
+
+impl Default for CharacterController {
+    fn default() -> Self {
+        Self {
+            up: Vector::new(0.0, -1.0),
+            offset: 0.01,
+            max_slope_angle: pi() / 4.0,
+            max_step_height: 0.25,
+            snap_to_ground: 0.2,
+            one_way_platforms: 0,
+            character_mass: 1.0,
+            max_slide_iterations: 4,
+        }
+    }
+}
+
+/// Surface the character stands on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CharacterGround {
+    /// Body owning the ground collider.
+    pub body: BodyHandle,
+    /// Ground collider.
+    pub collider: ColliderHandle,
+    /// Ground normal, pointing toward the character.
+    pub normal: Vector,
+    /// Velocity of the ground under the character, inherited on the next move.
+    pub velocity: Vector,
+}
+
+/// Obstacle met while sliding.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CharacterCollision {
+    /// Body owning the obstacle.
+    pub body: BodyHandle,
+    /// Obstacle collider.
+    pub collider: ColliderHandle,
+    /// Approximate world-space contact point.
+    pub point: Point,
+    /// Obstacle normal, pointing toward the character.
+    pub normal: Vector,
+    /// Impulse applied to a dynamic obstacle; zero for everything else.
+    pub impulse: Vector,
+}
+
+/// Result of one [`CharacterController::move_body`] call.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CharacterMovement {
+    /// Translation actually applied, including inherited platform motion.
+    pub translation: Vector,
+    /// Whether the character ends the move on walkable ground.
+    pub grounded: bool,
+    /// Walkable ground under the character after the move.
+    pub ground: Option<CharacterGround>,
+    /// Whether the move climbed a step.
+    pub stepped: bool,
+    /// Whether the move snapped down onto ground.
+    pub snapped: bool,
+    /// Obstacles hit while sliding, in hit order.
+    pub collisions: Vec<CharacterCollision>,
+}
+
+impl CharacterController {
+    /// Checks that every tuning value is finite and in range.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let invalid = |field| Err(ValidationError::CharacterController { field });
+        if !self.up.x().is_finite()
+            || !self.up.y().is_finite()
+            || (self.up.length() - 1.0).abs() > 1.0e-3
+        {
+            return invalid("up");
+        }
+        if !self.offset.is_finite() || self.offset < 0.0 {
+            return invalid("offset");
+        }
+        if !self.max_slope_angle.is_finite() || !(0.0..=pi() / 2.0).contains(&self.max_slope_angle)
+        {
+            return invalid("max_slope_angle");
+        }
+        if !self.max_step_height.is_finite() || self.max_step_height < 0.0 {
+            return invalid("max_step_height");
+        }
+        if !self.snap_to_ground.is_finite() || self.snap_to_ground < 0.0 {
+            return invalid("snap_to_ground");
+        }
+        if !self.character_mass.is_finite() || self.character_mass < 0.0 {
+            return invalid("character_mass");
+        }
+        if self.max_slide_iterations == 0 {
+            return invalid("max_slide_iterations");
+        }
+        Ok(())
+    }
+
+    /// Moves a kinematic character body by `desired_translation` over `dt`.
+    ///
+    /// The body's first non-sensor collider, a circle or convex polygon, is
+    /// swept through every collider its filter allows. Hits slide the
+    /// remaining motion along walkable ground, while steeper surfaces act as
+    /// walls that stop upward motion. A grounded character climbs ledges up to
+    /// `max_step_height`, follows descending ground within `snap_to_ground`,
+    /// and inherits the ground's velocity. Dynamic obstacles are pushed with
+    /// `character_mass`. The resulting pose becomes the body's kinematic
+    /// target, so call this once before each step. Sweeps use the same `ccd`
+    /// tolerances as the step, normally `pipeline.config().ccd`.
+    ///
+    /// Concave polygon colliders are not obstacles, just as the contact
+    /// pipeline does not collide them; split them into convex colliders for
+    /// characters to stand on.
+    pub fn move_body(
+        &self,
+        world: &mut World,
+        body: BodyHandle,
+        desired_translation: Vector,
+        dt: FloatNum,
+        ccd: &CcdParams,
+    ) -> Result<CharacterMovement, WorldError> {
+        self.validate().map_err(WorldError::Validation)?;
+        ccd.validate().map_err(WorldError::Validation)?;
+        let invalid = |field| {
+            Err(WorldError::Validation(
+                ValidationError::CharacterController { field },
+            ))
+        };
+        if !desired_translation.x().is_finite() || !desired_translation.y().is_finite() {
+            return invalid("desired_translation");
+        }
+        if !dt.is_finite() || dt <= 0.0 {
+            return invalid("dt");
+        }
+        let record = world.body_record(body)?;
+        if !record.body_type.is_kinematic() {
+            return Err(WorldError::Handle(HandleError::WrongBodyType {
+                handle: body,
+                expected: BodyType::Kinematic,
+                actual: record.body_type,
+            }));
+        }
+        let pose = record.kinematic_target.unwrap_or(record.pose);
+        let Some((collision_filter, shape)) = world
+            .try_colliders_for_body(body)?
+            .filter_map(|handle| world.collider_record(handle).ok())
+            .filter(|collider| !collider.is_sensor)
+            .find_map(|collider| Some((collider.filter, CharacterShape::new(collider, pose)?)))
+        else {
+            return invalid("collider");
+        };
+
+        world.sync_query_broadphase();
+        let sweeper = Sweeper {
+            world,
+            controller: self,
+            queries: WorldQueries::new(world),
+            filter: QueryFilter::default()
+                .excluding_body(body)
+                .colliding_with(collision_filter),
+            shape,
+            params: *ccd,
+        };
+
+        let mut movement = CharacterMovement::default();
+        let ground_before = sweeper.ground(Vector::default(), self.ground_probe());
+        let translation = desired_translation
+            + ground_before.map_or(Vector::default(), |ground| ground.velocity * dt);
+        let mut displacement = Vector::default();
+        let mut remaining = translation;
+        for _ in 0..self.max_slide_iterations {
+            if remaining.length() <= MOVE_EPSILON {
+                break;
+            }
+            let Some(hit) = sweeper.cast(displacement, remaining) else {
+                displacement += remaining;
+                break;
+            };
+            let travel = self.advance(Some(hit), remaining);
+            displacement += travel;
+            remaining -= travel;
+            movement.collisions.push(CharacterCollision {
+                body: hit.body,
+                collider: hit.collider,
+                point: hit.point,
+                normal: hit.normal,
+                impulse: self.push_impulse(sweeper.world, &hit, remaining, dt),
+            });
+
+            if !self.is_walkable(hit.normal) && ground_before.is_some() {
+                let horizontal = remaining - self.up * remaining.dot(self.up);
+                if let Some(stepped) = self.try_step(&sweeper, displacement, horizontal) {
+                    displacement = stepped;
+                    remaining = self.up * remaining.dot(self.up).min(0.0);
+                    movement.stepped = true;
+                    continue;
+                }
+            }
+            remaining = remaining - hit.normal * remaining.dot(hit.normal);
+            if !self.is_walkable(hit.normal) && remaining.dot(self.up) > 0.0 {
+                // Steep surfaces never lift the character.
+                remaining = remaining - self.up * remaining.dot(self.up);
+            }
+        }
+
+        let mut ground = sweeper.ground(displacement, self.ground_probe());
+        if ground.is_none()
+            && ground_before.is_some()
+            && desired_translation.dot(self.up) <= MOVE_EPSILON
+            && self.snap_to_ground > 0.0
+        {
+            let drop = -self.up * (self.snap_to_ground + self.offset);
+            if let Some(hit) = sweeper
+                .cast(displacement, drop)
+                .filter(|hit| self.is_walkable(hit.normal))
+            {
+                displacement += self.advance(Some(hit), drop);
+                movement.snapped = true;
+                ground = sweeper.ground(displacement, self.ground_probe());
+            }
+        }
+
+        movement.translation = displacement;
+        movement.grounded = ground.is_some();
+        movement.ground = ground;
+        world.set_kinematic_target(
+            body,
+            Pose::from_xy_angle(
+                pose.translation().x() + displacement.x(),
+                pose.translation().y() + displacement.y(),
+                pose.angle(),
+            ),
+        )?;
+        for collision in &movement.collisions {
+            if collision.impulse.length() > 0.0 {
+                apply_push(world, collision)?;
+            }
+        }
+        Ok(movement)
+    }
+
+    fn ground_probe(&self) -> FloatNum {
+        self.offset + GROUND_PROBE_MARGIN.max(self.offset)
+    }
+
+    fn is_walkable(&self, normal: Vector) -> bool {
+        normal.dot(self.up) >= num::sin_cos(self.max_slope_angle).1 - 1.0e-4
+    }
+
+    /// Motion up to the hit, stopping `offset` short of the surface.
+    fn advance(&self, hit: Option<SweepHit>, motion: Vector) -> Vector {
+        let Some(hit) = hit else {
+            return motion;
+        };
+        let length = motion.length();
+        if length <= MOVE_EPSILON {
+            return Vector::default();
+        }
+        let direction = motion / length;
+        let approach = (-direction.dot(hit.normal)).max(MOVE_EPSILON);
+        direction * (length * hit.toi - self.offset / approach).max(0.0)
+    }
+
+    /// Lift, move forward, then drop back onto walkable ground.
+    fn try_step(
+        &self,
+        sweeper: &Sweeper<'_>,
+        displacement: Vector,
+        horizontal: Vector,
+    ) -> Option<Vector> {
+        if self.max_step_height <= 0.0 || horizontal.length() <= MOVE_EPSILON {
+            return None;
+        }
+        let lift = self.up * self.max_step_height;
+        let lifted = displacement + self.advance(sweeper.cast(displacement, lift), lift);
+        let forward = self.advance(sweeper.cast(lifted, horizontal), horizontal);
+        if forward.length() <= self.offset.max(MOVE_EPSILON) {
+            return None;
+        }
+        let drop = -self.up * ((lifted - displacement).dot(self.up) + self.offset);
+        let landing = sweeper
+            .cast(lifted + forward, drop)
+            .filter(|hit| self.is_walkable(hit.normal))?;
+        Some(lifted + forward + self.advance(Some(landing), drop))
+    }
+
+    /// Impulse pushing a dynamic obstacle with the motion it blocked.
+    fn push_impulse(&self, world: &World, hit: &SweepHit, blocked: Vector, dt: FloatNum) -> Vector {
+        if self.character_mass <= 0.0 {
+            return Vector::default();
+        }
+        let Ok(record) = world.body_record(hit.body) else {
+            return Vector::default();
+        };
+        if !record.body_type.is_dynamic() {
+            return Vector::default();
+        }
+        let push_direction = -hit.normal;
+        let anchor = hit.point
+            - record
+                .pose
+                .transform_point(record.mass_properties.local_center_of_mass);
+        let body_speed = (record.linear_velocity
+            + angular_point_velocity(record.angular_velocity, anchor))
+        .dot(push_direction);
+        let closing_speed = blocked.dot(push_direction) / dt - body_speed;
+        if closing_speed <= 0.0 {
+            return Vector::default();
+        }
+        let inverse_mass = inverse_mass_along(record.solver_inverse_mass(), push_direction);
+        let mass = if inverse_mass > 0.0 {
+            let body_mass = 1.0 / inverse_mass;
+            self.character_mass * body_mass / (self.character_mass + body_mass)
+        } else {
+            self.character_mass
+        };
+        push_direction * (mass * closing_speed)
+    }
+}
+
+fn apply_push(world: &mut World, collision: &CharacterCollision) -> Result<(), WorldError> {
+    let record = world.body_record(collision.body)?;
+    let anchor = collision.point
+        - record
+            .pose
+            .transform_point(record.mass_properties.local_center_of_mass);
+    let linear_velocity =
+        record.linear_velocity + linear_response(record.solver_inverse_mass(), collision.impulse);
+    let angular_velocity =
+        record.angular_velocity - anchor.cross(collision.impulse) * record.solver_inverse_inertia();
+    world.apply_body_patch(
+        collision.body,
+        BodyPatch {
+            linear_velocity: Some(linear_velocity),
+            angular_velocity: Some(angular_velocity),
+            ..BodyPatch::default()
+        },
+    )
+}
+
+#[derive(Clone, Debug)]
+enum CharacterShape {
+    Circle { center: Point, radius: FloatNum },
+    Polygon(Vec<Point>),
+}
+
+impl CharacterShape {
+    fn new(collider: &ColliderRecord, body_pose: Pose) -> Option<Self> {
+        match collider.shape {
+            SharedShape::Circle { radius } => Some(Self::Circle {
+                center: collider.world_pose(body_pose).point(),
+                radius,
+            }),
+            SharedShape::Rect { .. }
+            | SharedShape::RegularPolygon { .. }
+            | SharedShape::ConvexPolygon { .. } => {
+                collider.convex_world_vertices(body_pose).map(Self::Polygon)
+            }
+            SharedShape::ConcavePolygon { .. } | SharedShape::Segment { .. } => None,
+        }
+    }
+
+    fn bounds(&self, displacement: Vector, margin: FloatNum) -> Option<DebugAabb> {
+        match self {
+            Self::Circle { center, radius } => Some(DebugAabb::from_circle(
+                *center + displacement,
+                radius + margin,
+            )),
+            Self::Polygon(vertices) => {
+                let moved = vertices
+                    .iter()
+                    .map(|point| *point + displacement)
+                    .collect::<Vec<_>>();
+                DebugAabb::from_points(&moved).map(|aabb| {
+                    DebugAabb::new(
+                        Point::new(aabb.min.x() - margin, aabb.min.y() - margin),
+                        Point::new(aabb.max.x() + margin, aabb.max.y() + margin),
+                    )
+                })
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SweepHit {
+    body: BodyHandle,
+    collider: ColliderHandle,
+    toi: FloatNum,
+    point: Point,
+    normal: Vector,
+}
+
+struct Sweeper<'a> {
+    world: &'a World,
+    controller: &'a CharacterController,
+    queries: WorldQueries<'a>,
+    filter: QueryFilter,
+    shape: CharacterShape,
+    params: CcdParams,
+}
+
+impl Sweeper<'_> {
+    /// Earliest hit when moving the character from `displacement` by `motion`.
+    fn cast(&self, displacement: Vector, motion: Vector) -> Option<SweepHit> {
+        if motion.length() <= MOVE_EPSILON {
+            return None;
+        }
+        let margin = self.controller.offset;
+        let start = self.shape.bounds(displacement, margin)?;
+        let end = self.shape.bounds(displacement + motion, margin)?;
+        let swept = DebugAabb::new(
+            Point::new(
+                start.min.x().min(end.min.x()),
+                start.min.y().min(end.min.y()),
+            ),
+            Point::new(
+                start.max.x().max(end.max.x()),
+                start.max.y().max(end.max.y()),
+            ),
+        );
+
+        let mut best: Option<SweepHit> = None;
+        for candidate in self.queries.intersect_aabb(swept, self.filter) {
+            let Ok(collider) = self.world.collider_record(candidate.collider) else {
+                continue;
+            };
+            let Ok(body) = self.world.body_record(candidate.body) else {
+                continue;
+            };
+            let Some(obstacle) = obstacle_polygon(collider, body.pose) else {
+                continue;
+            };
+            let Some((toi, point, normal)) = self.sweep(displacement, motion, &obstacle) else {
+                continue;
+            };
+            let one_way = collider.filter.memberships & self.controller.one_way_platforms != 0;
+            if one_way
+                && (normal.dot(self.controller.up) <= 0.0 || motion.dot(self.controller.up) >= 0.0)
+            {
+                continue;
+            }
+            let closer = best.as_ref().is_none_or(|current| {
+                toi < current.toi || (toi == current.toi && candidate.collider < current.collider)
+            });
+            if closer {
+                best = Some(SweepHit {
+                    body: candidate.body,
+                    collider: candidate.collider,
+                    toi,
+                    point,
+                    normal,
+                });
+            }
+        }
+        best
+    }
+
+    fn sweep(
+        &self,
+        displacement: Vector,
+        motion: Vector,
+        obstacle: &[Point],
+    ) -> Option<(FloatNum, Point, Vector)> {
+        match &self.shape {
+            CharacterShape::Circle { center, radius } => {
+                let start = *center + displacement;
+                let hit = swept_circle_convex_toi(
+                    start,
+                    start + motion,
+                    *radius,
+                    obstacle,
+                    &self.params,
+                )?;
+                let point = start + motion * hit.toi - hit.normal * *radius;
+                Some((hit.toi, point, hit.normal))
+            }
+            CharacterShape::Polygon(vertices) => {
+                let moved = vertices
+                    .iter()
+                    .map(|point| *point + displacement)
+                    .collect::<Vec<_>>();
+                let hit = swept_convex_convex_toi(&moved, motion, obstacle, &self.params)?;
+                Some((hit.toi, hit.toi_point, hit.normal))
+            }
+        }
+    }
+
+    /// Walkable ground within `distance` below the character.
+    fn ground(&self, displacement: Vector, distance: FloatNum) -> Option<CharacterGround> {
+        let hit = self
+            .cast(displacement, -self.controller.up * distance)
+            .filter(|hit| self.controller.is_walkable(hit.normal))?;
+        let record = self.world.body_record(hit.body).ok()?;
+        let anchor = hit.point
+            - record
+                .pose
+                .transform_point(record.mass_properties.local_center_of_mass);
+        let velocity = match record.body_type {
+            BodyType::Static => Vector::default(),
+            BodyType::Kinematic | BodyType::Dynamic => {
+                record.linear_velocity + angular_point_velocity(record.angular_velocity, anchor)
+            }
+        };
+        Some(CharacterGround {
+            body: hit.body,
+            collider: hit.collider,
+            normal: hit.normal,
+            velocity,
+        })
+    }
+}
+
+/// World-space convex polygon for an obstacle collider.
+fn obstacle_polygon(collider: &ColliderRecord, body_pose: Pose) -> Option<Vec<Point>> {
+    match collider.shape {
+        SharedShape::Circle { radius } => {
+            // Circumscribe the circle so the character never dips into it.
+            let center = collider.world_pose(body_pose).point();
+            let segments = CIRCLE_OBSTACLE_SEGMENTS as FloatNum;
+            let reach = radius / num::sin_cos(pi() / segments).1;
+            Some(
+                (0..CIRCLE_OBSTACLE_SEGMENTS)
+                    .map(|index| {
+                        center
+                            + Vector::new(reach, 0.0).rotated(index as FloatNum * tau() / segments)
+                    })
+                    .collect(),
+            )
+        }
+        SharedShape::Segment { start, end } => {
+            let pose = collider.world_pose(body_pose);
+            let start = pose.transform_point(start);
+            let end = pose.transform_point(end);
+            let side = (end - start).perp().normalized_or_zero() * SEGMENT_OBSTACLE_HALF_THICKNESS;
+            Some(vec![start - side, end - side, end + side, start + side])
+        }
+        SharedShape::ConcavePolygon { .. } => None,
+        _ => collider.convex_world_vertices(body_pose),
+    }
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     body::{MassProperties, Pose},
     handles::{BodyHandle, ColliderHandle},
-    math::{point::Point, vector::Vector, FloatNum},
+    math::{pi, point::Point, vector::Vector, FloatNum},
     world::ValidationError,
 };
 
@@ -150,7 +150,7 @@ impl SharedShape {
 
         let local = match self {
             Self::Circle { radius } => {
-                let mass = density * std::f32::consts::PI * radius * radius;
+                let mass = density * pi() * radius * radius;
                 // A solid disk has half its mass times radius squared as centroid inertia.
                 MassProperties {
                     mass,
@@ -185,15 +185,47 @@ impl SharedShape {
         })
     }
 
+    /// Mass facts for an explicit collider mass: the unit-density shape is
+    /// scaled so the centroid and inertia distribution still follow the shape.
+    pub(crate) fn mass_properties_with_mass(
+        &self,
+        mass: FloatNum,
+        local_pose: Pose,
+    ) -> Option<MassProperties> {
+        let unit = self.mass_properties(1.0, local_pose)?;
+        if unit.mass > 0.0 {
+            return Some(MassProperties {
+                mass,
+                inertia: unit.inertia * mass / unit.mass,
+                ..unit
+            });
+        }
+
+        // Zero-area shapes such as segments carry the mass as a point at their centroid.
+        let vertices = self.local_vertices();
+        let centroid = vertices
+            .iter()
+            .fold(Vector::default(), |sum, vertex| sum + Vector::from(*vertex))
+            / vertices.len().max(1) as FloatNum;
+        Some(MassProperties {
+            mass,
+            local_center_of_mass: local_pose.transform_point(Point::from(centroid)),
+            ..MassProperties::default()
+        })
+    }
+
     pub(crate) fn validate_mass_properties(
         &self,
         density: FloatNum,
+        mass: Option<FloatNum>,
         local_pose: Pose,
         field_scope: &'static str,
     ) -> Result<MassProperties, ValidationError> {
-        let mass_properties = self
-            .mass_properties(density, local_pose)
-            .ok_or_else(|| collider_mass_properties_error(field_scope))?;
+        let mass_properties = match mass {
+            Some(mass) => self.mass_properties_with_mass(mass, local_pose),
+            None => self.mass_properties(density, local_pose),
+        }
+        .ok_or_else(|| collider_mass_properties_error(field_scope))?;
         if !mass_properties.is_finite_non_negative() {
             return Err(collider_mass_properties_error(field_scope));
         }
@@ -254,6 +286,11 @@ impl SharedShape {
         }
     }
 
+    /// Whether the shape can bound a fluid volume: circles and convex shapes.
+    pub(crate) fn supports_fluid(&self) -> bool {
+        !matches!(self, Self::ConcavePolygon { .. } | Self::Segment { .. })
+    }
+
     #[allow(dead_code)]
     fn local_vertices(&self) -> Vec<Point> {
         match self {
@@ -490,6 +527,39 @@ fn polygon_mass_properties(vertices: &[Point], density: FloatNum) -> Option<Mass
     })
 }
 
+/// Fluid properties that turn a collider into a buoyancy and drag volume.
+///
+/// Fluid colliders behave as sensors: bodies pass through them and receive
+/// buoyancy at the centroid of their submerged area plus drag each step.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FluidVolume {
+    /// Fluid mass per unit area; buoyancy lifts `density * submerged_area`
+    /// against gravity.
+    pub density: FloatNum,
+    /// Drag per unit submerged area against the submerged centroid's velocity.
+    pub linear_drag: FloatNum,
+    /// Drag per unit submerged area against the body's angular velocity.
+    pub angular_drag: FloatNum,
+}
+
+impl Default for FluidVolume {
+    fn default() -> Self {
+        Self {
+            density: 1.0,
+            linear_drag: 0.5,
+            angular_drag: 0.5,
+        }
+    }
+}
+
+impl FluidVolume {
+    fn is_valid(&self) -> bool {
+        [self.density, self.linear_drag, self.angular_drag]
+            .into_iter()
+            .all(|value| value.is_finite() && value >= 0.0)
+    }
+}
+
 /// Descriptor used to create a collider attached to a body.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ColliderDesc {
@@ -499,12 +569,18 @@ pub struct ColliderDesc {
     pub local_pose: Pose,
     /// Mass per unit area used when deriving body mass properties.
     pub density: FloatNum,
+    /// Explicit collider mass; replaces the density-derived mass while the
+    /// center of mass and inertia keep following the shape.
+    pub mass: Option<FloatNum>,
     /// Surface material parameters.
     pub material: Material,
     /// Collision filtering bits.
     pub filter: CollisionFilter,
     /// Whether this collider should skip impulse generation.
     pub is_sensor: bool,
+    /// Turns the collider into a fluid volume; fluid colliders are always
+    /// sensors and must use a convex shape or a circle.
+    pub fluid: Option<FluidVolume>,
     /// User-owned opaque payload preserved by the core API.
     pub user_data: u64,
 }
@@ -515,9 +591,11 @@ impl Default for ColliderDesc {
             shape: SharedShape::circle(0.5),
             local_pose: Pose::default(),
             density: 1.0,
+            mass: None,
             material: Material::default(),
             filter: CollisionFilter::default(),
             is_sensor: false,
+            fluid: None,
             user_data: 0,
         }
     }
@@ -544,6 +622,12 @@ impl ColliderDesc {
         if !self.density.is_finite() || self.density < 0.0 {
             return Err(ValidationError::ColliderDesc { field: "density" });
         }
+        if self
+            .mass
+            .is_some_and(|mass| !mass.is_finite() || mass < 0.0)
+        {
+            return Err(ValidationError::ColliderDesc { field: "mass" });
+        }
         if !self.material.friction.is_finite() || self.material.friction < 0.0 {
             return Err(ValidationError::ColliderDesc {
                 field: "material.friction",
@@ -554,6 +638,12 @@ impl ColliderDesc {
                 field: "material.restitution",
             });
         }
+        if self
+            .fluid
+            .is_some_and(|fluid| !fluid.is_valid() || !self.shape.supports_fluid())
+        {
+            return Err(ValidationError::ColliderDesc { field: "fluid" });
+        }
         Ok(())
     }
 }
@@ -567,12 +657,18 @@ pub struct ColliderPatch {
     pub local_pose: Option<Pose>,
     /// Replaces the density when present.
     pub density: Option<FloatNum>,
+    /// Replaces the explicit mass when present; `Some(None)` reverts to the
+    /// density-derived mass.
+    pub mass: Option<Option<FloatNum>>,
     /// Replaces the material when present.
     pub material: Option<Material>,
     /// Replaces the collision filter when present.
     pub filter: Option<CollisionFilter>,
     /// Replaces the sensor flag when present.
     pub is_sensor: Option<bool>,
+    /// Replaces the fluid volume when present; `Some(None)` turns the fluid
+    /// off but leaves the collider a sensor.
+    pub fluid: Option<Option<FluidVolume>>,
     /// Replaces the user payload when present.
     pub user_data: Option<u64>,
 }
@@ -605,6 +701,13 @@ impl ColliderPatch {
         {
             return Err(ValidationError::ColliderPatch { field: "density" });
         }
+        if self
+            .mass
+            .flatten()
+            .is_some_and(|mass| !mass.is_finite() || mass < 0.0)
+        {
+            return Err(ValidationError::ColliderPatch { field: "mass" });
+        }
         if self.material.is_some_and(|material| {
             !material.friction.is_finite()
                 || material.friction < 0.0
@@ -613,6 +716,9 @@ impl ColliderPatch {
         }) {
             return Err(ValidationError::ColliderPatch { field: "material" });
         }
+        if self.fluid.flatten().is_some_and(|fluid| !fluid.is_valid()) {
+            return Err(ValidationError::ColliderPatch { field: "fluid" });
+        }
         Ok(())
     }
 }
@@ -626,9 +732,11 @@ pub struct ColliderView {
     local_pose: Pose,
     world_pose: Pose,
     density: FloatNum,
+    mass: Option<FloatNum>,
     material: Material,
     filter: CollisionFilter,
     is_sensor: bool,
+    fluid: Option<FluidVolume>,
     user_data: u64,
 }
 
@@ -663,6 +771,11 @@ impl ColliderView {
         self.density
     }
 
+    /// Returns the explicit collider mass, if one replaces the density.
+    pub fn mass(&self) -> Option<FloatNum> {
+        self.mass
+    }
+
     /// Returns the material parameters.
     pub fn material(&self) -> Material {
         self.material
@@ -678,10 +791,30 @@ impl ColliderView {
         self.is_sensor
     }
 
+    /// Returns the fluid volume, if the collider is one.
+    pub fn fluid(&self) -> Option<FluidVolume> {
+        self.fluid
+    }
+
     /// Returns the opaque user payload.
     pub fn user_data(&self) -> u64 {
         self.user_data
     }
+
+    /// Returns a descriptor that recreates this collider on its body.
+    pub fn desc(&self) -> ColliderDesc {
+        ColliderDesc {
+            shape: self.shape.clone(),
+            local_pose: self.local_pose,
+            density: self.density,
+            mass: self.mass,
+            material: self.material,
+            filter: self.filter,
+            is_sensor: self.is_sensor,
+            fluid: self.fluid,
+            user_data: self.user_data,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -730,9 +863,11 @@ pub(crate) struct ColliderRecord {
     pub(crate) shape: SharedShape,
     pub(crate) local_pose: Pose,
     pub(crate) density: FloatNum,
+    pub(crate) mass: Option<FloatNum>,
     pub(crate) material: Material,
     pub(crate) filter: CollisionFilter,
     pub(crate) is_sensor: bool,
+    pub(crate) fluid: Option<FluidVolume>,
     pub(crate) user_data: u64,
     geometry_revision: u32,
     geometry_cache: RwLock<DerivedGeometryCache>,
@@ -745,9 +880,11 @@ impl ColliderRecord {
             shape: desc.shape,
             local_pose: desc.local_pose,
             density: desc.density,
+            mass: desc.mass,
             material: desc.material,
             filter: desc.filter,
-            is_sensor: desc.is_sensor,
+            is_sensor: desc.is_sensor || desc.fluid.is_some(),
+            fluid: desc.fluid,
             user_data: desc.user_data,
             geometry_revision: 0,
             geometry_cache: RwLock::new(DerivedGeometryCache::default()),
@@ -765,6 +902,9 @@ impl ColliderRecord {
         if let Some(density) = patch.density {
             self.density = density;
         }
+        if let Some(mass) = patch.mass {
+            self.mass = mass;
+        }
         if let Some(material) = patch.material {
             self.material = material;
         }
@@ -774,6 +914,11 @@ impl ColliderRecord {
         if let Some(is_sensor) = patch.is_sensor {
             self.is_sensor = is_sensor;
         }
+        if let Some(fluid) = patch.fluid {
+            self.fluid = fluid;
+        }
+        // Fluid volumes never generate contact impulses.
+        self.is_sensor |= self.fluid.is_some();
         if let Some(user_data) = patch.user_data {
             self.user_data = user_data;
         }
@@ -795,9 +940,11 @@ impl ColliderRecord {
             local_pose: self.local_pose,
             world_pose: self.world_pose(body_pose),
             density: self.density,
+            mass: self.mass,
             material: self.material,
             filter: self.filter,
             is_sensor: self.is_sensor,
+            fluid: self.fluid,
             user_data: self.user_data,
         }
     }
@@ -856,9 +1003,11 @@ impl Clone for ColliderRecord {
             shape: self.shape.clone(),
             local_pose: self.local_pose,
             density: self.density,
+            mass: self.mass,
             material: self.material,
             filter: self.filter,
             is_sensor: self.is_sensor,
+            fluid: self.fluid,
             user_data: self.user_data,
             geometry_revision: self.geometry_revision,
             geometry_cache: RwLock::new(DerivedGeometryCache::default()),
@@ -943,11 +1092,11 @@ fn point_on_segment(point: Point, start: Point, end: Point) -> bool {
 #[cfg(test)]
 mod tests {
     use super::SharedShape;
-    use crate::{body::Pose, math::point::Point};
+    use crate::{body::Pose, math::point::Point, math::FloatNum};
 
-    const EPSILON: f32 = 1e-4;
+    const EPSILON: FloatNum = 1e-4;
 
-    fn assert_near(actual: f32, expected: f32) {
+    fn assert_near(actual: FloatNum, expected: FloatNum) {
         assert!(
             (actual - expected).abs() <= EPSILON,
             "expected {actual} to be within {EPSILON} of {expected}"
@@ -959,9 +1108,9 @@ mod tests {
         let circle = SharedShape::circle(2.0)
             .mass_properties(0.5, Pose::default())
             .expect("circle mass should compute");
-        assert_near(circle.mass, 2.0 * std::f32::consts::PI);
+        assert_near(circle.mass, 2.0 * crate::math::pi());
         assert_eq!(circle.local_center_of_mass, Point::new(0.0, 0.0));
-        assert_near(circle.inertia, 4.0 * std::f32::consts::PI);
+        assert_near(circle.inertia, 4.0 * crate::math::pi());
 
         let rect = SharedShape::rect(4.0, 2.0)
             .mass_properties(3.0, Pose::default())
@@ -980,7 +1129,7 @@ mod tests {
 
     #[test]
     fn collider_mass_properties_follow_regular_convex_and_concave_polygon_formulas() {
-        let regular_square = SharedShape::regular_polygon(4, 2.0_f32.sqrt())
+        let regular_square = SharedShape::regular_polygon(4, (2.0 as FloatNum).sqrt())
             .mass_properties(1.0, Pose::default())
             .expect("regular polygon mass should compute");
         assert_near(regular_square.mass, 4.0);
@@ -21,9 +21,9 @@ use crate::{
         WorldRevision,
     },
     joint::JointDesc,
-    math::{point::Point, vector::Vector, FloatNum},
+    math::{point::Point, tau, vector::Vector, FloatNum},
     pipeline::StepReport,
-    world::World,
+    world::{GravityField, World},
 };
 
 /// World-space bounds that consumers can use for camera framing or coarse culling.
@@ -312,6 +312,18 @@ pub struct DebugStats {
     /// Number of dynamic bodies clamped by CCD before contact generation.
     #[serde(default)]
     pub ccd_clamp_count: usize,
+    /// Number of time-of-impact sub-steps resolved by CCD.
+    #[serde(default)]
+    pub ccd_substep_count: usize,
+    /// Number of CCD hits handed to pose clamping because a sub-step budget ran out.
+    #[serde(default)]
+    pub ccd_substep_fallback_count: usize,
+    /// Number of speculative contact rows built for separated pairs.
+    #[serde(default)]
+    pub ccd_speculative_contact_count: usize,
+    /// Number of speculative contact rows that applied an impulse.
+    #[serde(default)]
+    pub ccd_speculative_enforced_count: usize,
 }
 
 /// Translation/rotation facts exported without exposing engine internals.
@@ -386,6 +398,36 @@ pub struct DebugIsland {
     pub reason: SleepTransitionReason,
 }
 
+/// Part of a collider submerged in a fluid volume during the last step.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DebugFluidSubmersion {
+    /// Collider carrying the fluid volume.
+    pub fluid_collider: ColliderHandle,
+    /// Submerged collider.
+    pub collider: ColliderHandle,
+    /// Body that received buoyancy and drag.
+    pub body: BodyHandle,
+    /// World-space area of the submerged polygon.
+    pub submerged_area: FloatNum,
+    /// Point where buoyancy was applied.
+    pub centroid: Point,
+    /// World-space submerged polygon.
+    pub polygon: Vec<Point>,
+}
+
+impl DebugFluidSubmersion {
+    pub(crate) fn sanitized(&self) -> Self {
+        Self {
+            fluid_collider: self.fluid_collider,
+            collider: self.collider,
+            body: self.body,
+            submerged_area: sanitize_scalar(self.submerged_area).max(0.0),
+            centroid: sanitize_point(self.centroid),
+            polygon: self.polygon.iter().copied().map(sanitize_point).collect(),
+        }
+    }
+}
+
 /// Read-only collider facts for external consumers.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DebugCollider {
@@ -568,6 +610,10 @@ fn sanitize_ccd_trace(trace: CcdTrace) -> CcdTrace {
         target_clamp: sanitize_scalar(trace.target_clamp).max(0.0),
         slop: sanitize_scalar(trace.slop).max(0.0),
         toi_point: sanitize_point(trace.toi_point),
+        method: trace.method,
+        angular_sweep: sanitize_scalar(trace.angular_sweep),
+        target_angular_sweep: sanitize_scalar(trace.target_angular_sweep),
+        resolution: trace.resolution,
     }
 }
 
@@ -813,7 +859,7 @@ impl Default for DebugSnapshotOptions {
 }
 
 /// Full read-only snapshot exported by the core.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct DebugSnapshot {
     /// World-level metadata.
     pub meta: DebugMeta,
@@ -830,6 +876,9 @@ pub struct DebugSnapshot {
     /// Island facts used to explain sleep/wake behavior.
     #[serde(default)]
     pub islands: Vec<DebugIsland>,
+    /// Fluid submersions that produced buoyancy and drag in the last step.
+    #[serde(default)]
+    pub fluid_submersions: Vec<DebugFluidSubmersion>,
     /// Read-only broadphase tree facts for process visualization.
     ///
     /// The tree is a snapshot-local debug carrier. It intentionally exposes
@@ -942,6 +991,18 @@ impl DebugSnapshot {
             .unwrap_or_else(|| world.last_step_events());
         let (contacts, manifolds) = debug_contacts_and_manifolds(step_events);
         let islands = debug_islands(&bodies, step_events);
+        let fluid_submersions = world
+            .fluid_submersions()
+            .iter()
+            .map(|submersion| DebugFluidSubmersion {
+                fluid_collider: submersion.fluid_collider,
+                collider: submersion.collider,
+                body: submersion.body,
+                submerged_area: submersion.area,
+                centroid: submersion.centroid,
+                polygon: submersion.polygon.clone(),
+            })
+            .collect::<Vec<_>>();
         let broadphase_tree = if options.include_broadphase_tree() {
             world.debug_broadphase_tree()
         } else {
@@ -959,7 +1020,13 @@ impl DebugSnapshot {
             Vec::new()
         };
         let primitives = if options.include_primitives {
-            build_debug_primitives(&colliders, &joints, &contacts)
+            build_debug_primitives(
+                &colliders,
+                &joints,
+                &contacts,
+                &fluid_submersions,
+                world.gravity_fields(),
+            )
         } else {
             Vec::new()
         };
@@ -1001,7 +1068,10 @@ impl DebugSnapshot {
                 ccd_hit_count: stats.ccd_hit_count,
                 ccd_miss_count: stats.ccd_miss_count,
                 ccd_clamp_count: stats.ccd_clamp_count,
-                ..DebugStats::default()
+                ccd_substep_count: stats.ccd_substep_count,
+                ccd_substep_fallback_count: stats.ccd_substep_fallback_count,
+                ccd_speculative_contact_count: stats.ccd_speculative_contact_count,
+                ccd_speculative_enforced_count: stats.ccd_speculative_enforced_count,
             },
             bodies,
             colliders,
@@ -1009,6 +1079,7 @@ impl DebugSnapshot {
             contacts,
             manifolds,
             islands,
+            fluid_submersions,
             broadphase_tree,
             primitives,
         };
@@ -1039,6 +1110,11 @@ impl DebugSnapshot {
             contacts: self.contacts.iter().map(DebugContact::sanitized).collect(),
             manifolds: self.manifolds.iter().map(sanitize_manifold).collect(),
             islands: self.islands.clone(),
+            fluid_submersions: self
+                .fluid_submersions
+                .iter()
+                .map(DebugFluidSubmersion::sanitized)
+                .collect(),
             broadphase_tree: self.broadphase_tree.sanitized(),
             primitives: self
                 .primitives
@@ -1065,23 +1141,6 @@ impl DebugSnapshot {
     }
 }
 
-impl Default for DebugSnapshot {
-    fn default() -> Self {
-        Self {
-            meta: DebugMeta::default(),
-            bodies: Vec::new(),
-            colliders: Vec::new(),
-            joints: Vec::new(),
-            contacts: Vec::new(),
-            manifolds: Vec::new(),
-            islands: Vec::new(),
-            broadphase_tree: DebugBroadphaseTree::default(),
-            primitives: Vec::new(),
-            stats: DebugStats::default(),
-        }
-    }
-}
-
 fn debug_islands(bodies: &[DebugBody], events: &[WorldEvent]) -> Vec<DebugIsland> {
     let mut islands: BTreeMap<u32, DebugIsland> = BTreeMap::new();
     for body in bodies {
@@ -1246,13 +1305,27 @@ fn build_debug_primitives(
     colliders: &[DebugCollider],
     joints: &[DebugJoint],
     contacts: &[DebugContact],
+    fluid_submersions: &[DebugFluidSubmersion],
+    gravity_fields: &[GravityField],
 ) -> Vec<DebugPrimitive> {
     let mut primitives = Vec::new();
 
+    for field in gravity_fields {
+        primitives.extend(gravity_field_primitives(field));
+    }
+
     for collider in colliders {
         primitives.push(debug_primitive_from_shape(&collider.shape));
     }
 
+    for submersion in fluid_submersions {
+        primitives.push(DebugPrimitive::Polygon {
+            points: submersion.polygon.clone(),
+            stroke: DebugColor::rgba(64, 160, 255, 255),
+            fill: Some(DebugColor::rgba(64, 160, 255, 64)),
+        });
+    }
+
     for joint in joints {
         if joint.anchors.len() >= 2 {
             primitives.push(DebugPrimitive::Line {
@@ -1279,6 +1352,67 @@ fn build_debug_primitives(
     primitives
 }
 
+fn gravity_field_primitives(field: &GravityField) -> Vec<DebugPrimitive> {
+    let color = DebugColor::rgba(160, 120, 255, 255);
+    match *field {
+        GravityField::Uniform {
+            min,
+            max,
+            acceleration,
+        } => {
+            let center = Point::new((min.x() + max.x()) * 0.5, (min.y() + max.y()) * 0.5);
+            vec![
+                DebugPrimitive::Polygon {
+                    points: vec![
+                        min,
+                        Point::new(max.x(), min.y()),
+                        max,
+                        Point::new(min.x(), max.y()),
+                    ],
+                    stroke: color,
+                    fill: Some(DebugColor::rgba(160, 120, 255, 32)),
+                },
+                DebugPrimitive::Arrow {
+                    origin: center,
+                    direction: acceleration.normalized_or_zero(),
+                    color,
+                },
+            ]
+        }
+        GravityField::Radial {
+            center,
+            strength,
+            inner_radius,
+            outer_radius,
+            ..
+        } => {
+            // Arrows on the outer ring show whether the field pulls or pushes.
+            let mut primitives = vec![
+                DebugPrimitive::Circle {
+                    center,
+                    radius: inner_radius,
+                    color,
+                },
+                DebugPrimitive::Circle {
+                    center,
+                    radius: outer_radius,
+                    color: DebugColor::rgba(160, 120, 255, 96),
+                },
+            ];
+            let arrow_length = (outer_radius - inner_radius).min(1.0) * strength.signum();
+            for index in 0..4 {
+                let direction = Vector::new(1.0, 0.0).rotated(index as FloatNum * tau() / 4.0);
+                primitives.push(DebugPrimitive::Arrow {
+                    origin: center + direction * outer_radius,
+                    direction: -direction * arrow_length,
+                    color,
+                });
+            }
+            primitives
+        }
+    }
+}
+
 fn debug_primitive_from_shape(shape: &DebugShape) -> DebugPrimitive {
     match shape {
         DebugShape::Circle { center, radius } => DebugPrimitive::Circle {
@@ -1299,7 +1433,7 @@ fn debug_primitive_from_shape(shape: &DebugShape) -> DebugPrimitive {
     }
 }
 
-fn debug_shape_from_shared_shape(shape: &SharedShape, world_pose: Pose) -> DebugShape {
+pub(crate) fn debug_shape_from_shared_shape(shape: &SharedShape, world_pose: Pose) -> DebugShape {
     match shape {
         SharedShape::Circle { radius } => DebugShape::Circle {
             center: world_pose.point(),
@@ -1420,14 +1554,18 @@ mod tests {
         sanitize_ccd_trace, sanitize_scalar, DebugAabb, DebugPrimitive, DebugShape, DebugSnapshot,
     };
     use crate::{
-        events::{CcdTargetKind, CcdTrace},
+        events::{CcdMethod, CcdTargetKind, CcdTrace},
         handles::{BodyHandle, ColliderHandle},
-        math::{point::Point, vector::Vector},
+        math::{point::Point, vector::Vector, FloatNum},
+        pipeline::CcdResolution,
     };
 
     #[test]
     fn debug_aabb_normalizes_non_finite_ranges() {
-        let aabb = DebugAabb::new(Point::new(f32::NAN, 2.0), Point::new(1.0, f32::INFINITY));
+        let aabb = DebugAabb::new(
+            Point::new(FloatNum::NAN, 2.0),
+            Point::new(1.0, FloatNum::INFINITY),
+        );
 
         assert_eq!(aabb.min, Point::new(0.0, 0.0));
         assert_eq!(aabb.max, Point::new(1.0, 2.0));
@@ -1469,8 +1607,8 @@ mod tests {
 
     #[test]
     fn sanitize_scalar_collapses_non_finite_numbers() {
-        assert_eq!(sanitize_scalar(f32::NAN), 0.0);
-        assert_eq!(sanitize_scalar(f32::INFINITY), 0.0);
+        assert_eq!(sanitize_scalar(FloatNum::NAN), 0.0);
+        assert_eq!(sanitize_scalar(FloatNum::INFINITY), 0.0);
         assert_eq!(sanitize_scalar(-1.5), -1.5);
     }
 
@@ -1482,16 +1620,20 @@ mod tests {
             moving_collider: ColliderHandle::from_raw_parts(3, 0),
             static_collider: ColliderHandle::from_raw_parts(4, 0),
             target_kind: CcdTargetKind::Dynamic,
-            swept_start: Point::new(f32::NAN, -1.0),
-            swept_end: Point::new(2.0, f32::INFINITY),
-            target_swept_start: Point::new(f32::NEG_INFINITY, 1.0),
-            target_swept_end: Point::new(3.0, f32::NAN),
-            toi: f32::INFINITY,
-            advancement: f32::NAN,
+            swept_start: Point::new(FloatNum::NAN, -1.0),
+            swept_end: Point::new(2.0, FloatNum::INFINITY),
+            target_swept_start: Point::new(FloatNum::NEG_INFINITY, 1.0),
+            target_swept_end: Point::new(3.0, FloatNum::NAN),
+            toi: FloatNum::INFINITY,
+            advancement: FloatNum::NAN,
             clamp: -1.0,
-            target_clamp: f32::NAN,
+            target_clamp: FloatNum::NAN,
             slop: -0.5,
-            toi_point: Point::new(f32::NAN, f32::INFINITY),
+            toi_point: Point::new(FloatNum::NAN, FloatNum::INFINITY),
+            method: CcdMethod::ConservativeAdvancement,
+            angular_sweep: FloatNum::NAN,
+            target_angular_sweep: 0.5,
+            resolution: CcdResolution::SubStep,
         });
 
         assert_eq!(trace.target_kind, CcdTargetKind::Dynamic);
@@ -1505,5 +1647,9 @@ mod tests {
         assert_eq!(trace.target_clamp, 0.0);
         assert_eq!(trace.slop, 0.0);
         assert_eq!(trace.toi_point, Point::default());
+        assert_eq!(trace.method, CcdMethod::ConservativeAdvancement);
+        assert_eq!(trace.angular_sweep, 0.0);
+        assert_eq!(trace.target_angular_sweep, 0.5);
+        assert_eq!(trace.resolution, CcdResolution::SubStep);
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     handles::{BodyHandle, ColliderHandle, ContactFeatureId, ContactId, JointHandle, ManifoldId},
     math::{point::Point, vector::Vector, FloatNum},
+    pipeline::CcdResolution,
 };
 
 /// Kind of CCD target hit by a swept moving collider.
@@ -21,6 +22,21 @@ pub enum CcdTargetKind {
     Static,
     /// The target was another translational dynamic collider.
     Dynamic,
+    /// The target was a translating kinematic collider; only the dynamic side
+    /// is clamped.
+    Kinematic,
+}
+
+/// Time-of-impact query that produced a CCD trace.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CcdMethod {
+    /// Translational shape cast of the swept reference points.
+    #[default]
+    ShapeCast,
+    /// Conservative advancement over linear and angular motion, used when
+    /// either side rotates during the sweep.
+    ConservativeAdvancement,
 }
 
 /// Compact trace facts for a continuous collision detection clamp.
@@ -82,6 +98,20 @@ pub struct CcdTrace {
     /// World-space point where the swept collider first touched the target.
     #[serde(default)]
     pub toi_point: Point,
+    /// Query that found the time of impact.
+    #[serde(default)]
+    pub method: CcdMethod,
+    /// Rotation of the moving body across the sweep, in radians.
+    #[serde(default)]
+    pub angular_sweep: FloatNum,
+    /// Rotation of the target body across the sweep, in radians.
+    #[serde(default)]
+    pub target_angular_sweep: FloatNum,
+    /// How the hit was resolved. For sub-step resolutions `advancement` is the
+    /// fraction of the remaining step at which the pair was resolved and
+    /// `clamp` is how far the re-integrated end pose moved.
+    #[serde(default)]
+    pub resolution: CcdResolution,
 }
 
 /// Stable explanation for how a contact manifold was reduced to exported points.
@@ -260,6 +290,10 @@ pub enum SleepTransitionReason {
     UserPatch,
     /// Sleep was disabled at the world or step level.
     SleepDisabled,
+    /// User code called `World::wake_island` on a body in the island.
+    ManualWake,
+    /// User code called `World::sleep_island` on a body in the island.
+    ManualSleep,
 }
 
 /// Contact lifecycle information exposed by the stable event stream.
@@ -373,13 +407,15 @@ pub enum WorldEvent {
 mod tests {
     use crate::{
         events::{
-            CcdTargetKind, CcdTrace, ContactEvent, ContactReductionReason, NumericsWarningEvent,
-            SleepEvent, SleepTransitionReason, WarmStartCacheReason, WorldEvent,
+            CcdMethod, CcdTargetKind, CcdTrace, ContactEvent, ContactReductionReason,
+            NumericsWarningEvent, SleepEvent, SleepTransitionReason, WarmStartCacheReason,
+            WorldEvent,
         },
         handles::{
             BodyHandle, ColliderHandle, ContactFeatureId, ContactId, JointHandle, ManifoldId,
         },
         math::{point::Point, vector::Vector},
+        pipeline::CcdResolution,
     };
 
     #[test]
@@ -476,6 +512,10 @@ mod tests {
             target_clamp: 0.75,
             slop: 0.001,
             toi_point: Point::new(0.0, 0.0),
+            method: CcdMethod::ConservativeAdvancement,
+            angular_sweep: 0.5,
+            target_angular_sweep: -0.25,
+            resolution: CcdResolution::SubStep,
         };
         let mut value = serde_json::to_value(trace).expect("ccd trace should serialize");
         let object = value
@@ -485,6 +525,10 @@ mod tests {
         object.remove("target_swept_start");
         object.remove("target_swept_end");
         object.remove("target_clamp");
+        object.remove("method");
+        object.remove("angular_sweep");
+        object.remove("target_angular_sweep");
+        object.remove("resolution");
 
         let decoded: CcdTrace =
             serde_json::from_value(value).expect("legacy CCD trace should deserialize");
@@ -493,5 +537,9 @@ mod tests {
         assert_eq!(decoded.target_swept_start, Point::default());
         assert_eq!(decoded.target_swept_end, Point::default());
         assert_eq!(decoded.target_clamp, 0.0);
+        assert_eq!(decoded.method, CcdMethod::ShapeCast);
+        assert_eq!(decoded.angular_sweep, 0.0);
+        assert_eq!(decoded.target_angular_sweep, 0.0);
+        assert_eq!(decoded.resolution, CcdResolution::PoseClamp);
     }
 }
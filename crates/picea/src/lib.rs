@@ -1,5 +1,6 @@
 pub mod algo;
 pub mod body;
+pub mod character;
 pub mod collider;
 pub mod debug;
 pub mod events;
@@ -10,16 +11,23 @@ pub mod pipeline;
 pub mod query;
 pub mod recipe;
 mod solver;
+pub mod vehicle;
 pub mod world;
 
 pub mod prelude {
-    pub use super::body::{BodyDesc, BodyPatch, BodyType, BodyView, MassProperties, Pose};
+    pub use super::body::{
+        BodyDesc, BodyPatch, BodyType, BodyView, LockedAxes, MassProperties, Pose,
+    };
+    pub use super::character::{
+        CharacterCollision, CharacterController, CharacterGround, CharacterMovement,
+    };
     pub use super::collider::{
-        ColliderDesc, ColliderPatch, ColliderView, CollisionFilter, Material, SharedShape,
+        ColliderDesc, ColliderPatch, ColliderView, CollisionFilter, FluidVolume, Material,
+        SharedShape,
     };
     pub use super::debug::{
-        DebugBody, DebugCollider, DebugContact, DebugIsland, DebugJoint, DebugManifold,
-        DebugManifoldPoint, DebugPrimitive, DebugSnapshot, DebugSnapshotOptions,
+        DebugBody, DebugCollider, DebugContact, DebugFluidSubmersion, DebugIsland, DebugJoint,
+        DebugManifold, DebugManifoldPoint, DebugPrimitive, DebugSnapshot, DebugSnapshotOptions,
     };
     pub use super::events::{
         CcdTrace, ContactEvent, ContactReductionReason, EpaTerminationReason,
@@ -35,7 +43,10 @@ pub mod prelude {
         WorldAnchorJointDesc, WorldAnchorJointPatch,
     };
     pub use super::math::{edge::Edge, point::Point, segment::Segment, vector::Vector, FloatNum};
-    pub use super::pipeline::{SimulationPipeline, StepConfig, StepReport, StepStats};
+    pub use super::pipeline::{
+        AdvanceReport, CcdMode, CcdParams, CcdResolution, SimulationPipeline, SleepParams,
+        SolverMode, SolverParams, StepConfig, StepReport, StepStats,
+    };
     pub use super::query::{
         AabbHit, PointHit, QueryFilter, QueryPipeline, QueryShape, QueryShapeError, QueryStats,
         RayHit, ShapeHit,
@@ -46,7 +57,9 @@ pub mod prelude {
         WorldCommandEvent, WorldCommandKind, WorldCommandReport, WorldCommands, WorldRecipe,
         WorldRecipeResult,
     };
+    pub use super::vehicle::{VehicleController, WheelContact, WheelDesc, WheelState};
     pub use super::world::{
-        HandleError, TopologyError, ValidationError, World, WorldDesc, WorldError,
+        GravityFalloff, GravityField, HandleError, ImpulseFalloff, RadialImpulseFilter,
+        RadialImpulseHit, TopologyError, ValidationError, World, WorldDesc, WorldError,
     };
 }
@@ -1,4 +1,4 @@
-use super::point::Point;
+use super::{point::Point, FloatNum};
 
 #[derive(Debug, Clone, Copy)]
 pub enum Edge<'a> {
@@ -9,7 +9,7 @@ pub enum Edge<'a> {
     },
     Circle {
         center_point: Point,
-        radius: f32,
+        radius: FloatNum,
     },
     Line {
         start_point: &'a Point,
@@ -6,13 +6,23 @@ pub mod segment;
 pub mod vector;
 
 pub(crate) mod num;
+#[cfg(not(feature = "f64"))]
+use std::f32::consts;
+#[cfg(feature = "f64")]
+use std::f64::consts;
+
 // TODO rename
+/// Scalar type used by every engine API; `f64` when the `f64` feature is on.
+#[cfg(not(feature = "f64"))]
 pub type FloatNum = f32;
+/// Scalar type used by every engine API; `f64` when the `f64` feature is on.
+#[cfg(feature = "f64")]
+pub type FloatNum = f64;
 
 pub const fn pi() -> FloatNum {
-    std::f32::consts::PI
+    consts::PI
 }
 
 pub const fn tau() -> FloatNum {
-    std::f32::consts::TAU
+    consts::TAU
 }
@@ -18,3 +18,110 @@ pub(crate) fn limit_at_range(value: FloatNum, range: RangeInclusive<FloatNum>) -
         value
     }
 }
+
+// fdlibm-derived split of pi/2 and kernel coefficients. Only IEEE-754 basic
+// operations are used below, so results are bit-identical on every platform
+// instead of depending on the host libm.
+const PIO2_HI: f64 = 1.570_796_326_734_125_6;
+const PIO2_LO: f64 = 6.077_100_506_506_192e-11;
+const FRAC_2_PI: f64 = std::f64::consts::FRAC_2_PI;
+const SIN_COEFFICIENTS: [f64; 6] = [
+    -1.666_666_666_666_663_2e-1,
+    8.333_333_333_322_49e-3,
+    -1.984_126_982_985_795e-4,
+    2.755_731_370_707_006_8e-6,
+    -2.505_076_025_340_686_3e-8,
+    1.589_690_995_211_55e-10,
+];
+const COS_COEFFICIENTS: [f64; 6] = [
+    4.166_666_666_666_66e-2,
+    -1.388_888_888_887_411e-3,
+    2.480_158_728_947_673e-5,
+    -2.755_731_435_139_066_3e-7,
+    2.087_572_321_298_175e-9,
+    -1.135_964_755_778_819_5e-11,
+];
+
+/// Widens an engine scalar to `f64` in both the `f32` and `f64` builds.
+#[allow(clippy::useless_conversion)]
+pub(crate) fn widen(value: FloatNum) -> f64 {
+    f64::from(value)
+}
+
+/// Platform-independent `(sin, cos)` used by every rotation in the core.
+pub(crate) fn sin_cos(radians: FloatNum) -> (FloatNum, FloatNum) {
+    let x = widen(radians);
+    if !x.is_finite() {
+        return (FloatNum::NAN, FloatNum::NAN);
+    }
+
+    let quadrant = (x * FRAC_2_PI).round();
+    let r = (x - quadrant * PIO2_HI) - quadrant * PIO2_LO;
+    let z = r * r;
+    let sin = r + r * z * polynomial(z, &SIN_COEFFICIENTS);
+    let cos = 1.0 - 0.5 * z + z * z * polynomial(z, &COS_COEFFICIENTS);
+
+    let (sin, cos) = match (quadrant as i64).rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    };
+    (sin as FloatNum, cos as FloatNum)
+}
+
+/// Platform-independent `sqrt(x * x + y * y)` that avoids intermediate overflow.
+pub(crate) fn hypot(x: FloatNum, y: FloatNum) -> FloatNum {
+    let (x, y) = (x.abs(), y.abs());
+    let scale = x.max(y);
+    if scale == 0.0 || !scale.is_finite() {
+        return scale + x.min(y);
+    }
+
+    let (x, y) = (x / scale, y / scale);
+    scale * (x * x + y * y).sqrt()
+}
+
+fn polynomial(z: f64, coefficients: &[f64; 6]) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |accumulator, coefficient| {
+            accumulator * z + coefficient
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sin_cos_matches_std_within_one_ulp() {
+        for step in -4000..=4000 {
+            let angle = step as FloatNum * 0.0125;
+            let (sin, cos) = sin_cos(angle);
+            let expected_sin = widen(angle).sin() as FloatNum;
+            let expected_cos = widen(angle).cos() as FloatNum;
+            assert!(
+                (sin - expected_sin).abs() <= FloatNum::EPSILON * expected_sin.abs().max(1e-3),
+                "sin({angle}) = {sin}, expected {expected_sin}"
+            );
+            assert!(
+                (cos - expected_cos).abs() <= FloatNum::EPSILON * expected_cos.abs().max(1e-3),
+                "cos({angle}) = {cos}, expected {expected_cos}"
+            );
+        }
+        assert_eq!(sin_cos(0.0), (0.0, 1.0));
+        assert!(sin_cos(FloatNum::NAN).0.is_nan());
+    }
+
+    #[test]
+    fn hypot_is_overflow_safe_and_exact_for_axis_vectors() {
+        assert_eq!(hypot(3.0, 4.0), 5.0);
+        assert_eq!(hypot(-2.0, 0.0), 2.0);
+        assert_eq!(hypot(0.0, 0.0), 0.0);
+        assert!(hypot(FloatNum::MAX, FloatNum::MAX).is_infinite());
+        assert!((hypot(1.0e30, 1.0e30) - 1.414_213_5e30).abs() < 1.0e24);
+        assert!(hypot(FloatNum::INFINITY, FloatNum::NAN).is_infinite());
+    }
+}
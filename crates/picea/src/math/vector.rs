@@ -1,4 +1,4 @@
-use super::{point::Point, segment::Segment, FloatNum};
+use super::{num, point::Point, segment::Segment, FloatNum};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::Display,
@@ -42,7 +42,7 @@ impl Vector {
 
     #[inline]
     pub fn length(&self) -> FloatNum {
-        self.x.hypot(self.y)
+        num::hypot(self.x, self.y)
     }
 
     #[inline]
@@ -93,8 +93,7 @@ impl Vector {
 
     #[inline]
     pub fn rotated(&self, radians: FloatNum) -> Self {
-        let cos = radians.cos();
-        let sin = radians.sin();
+        let (sin, cos) = num::sin_cos(radians);
         let new_x = self.y * sin + self.x * cos;
         let new_y = self.y * cos - self.x * sin;
         Self::new(new_x, new_y)
@@ -239,13 +238,13 @@ impl Neg for Vector {
 
 #[cfg(test)]
 mod tests {
-    use super::Vector;
+    use super::{FloatNum, Vector};
 
     #[test]
     fn normalized_or_zero_returns_zero_for_degenerate_vectors() {
         let vectors = [
             Vector::new(0.0, 0.0),
-            Vector::new(f32::MIN_POSITIVE * f32::EPSILON, 0.0),
+            Vector::new(FloatNum::MIN_POSITIVE * FloatNum::EPSILON, 0.0),
         ];
 
         for vector in vectors {
@@ -260,7 +259,7 @@ mod tests {
     fn normalized_preserves_regular_vectors() {
         let normalized = Vector::new(3.0, 4.0).normalized();
 
-        assert!((normalized.length() - 1.0).abs() <= f32::EPSILON);
+        assert!((normalized.length() - 1.0).abs() <= FloatNum::EPSILON);
         assert_eq!(normalized, Vector::new(0.6, 0.8));
     }
 
@@ -268,9 +267,19 @@ mod tests {
     fn projection_onto_degenerate_vector_returns_zero() {
         let vector = Vector::new(3.0, 4.0);
         let zero = Vector::new(0.0, 0.0);
-        let tiny = Vector::new(f32::MIN_POSITIVE * f32::EPSILON, 0.0);
+        let tiny = Vector::new(FloatNum::MIN_POSITIVE * FloatNum::EPSILON, 0.0);
 
         assert_eq!(vector.project_onto(zero), 0.0);
         assert_eq!(vector.project_onto(tiny), 0.0);
     }
+
+    #[test]
+    fn serde_round_trip_preserves_full_float_num_precision() {
+        let vector = Vector::new(1.0 / 3.0, -1.0e5 / 7.0);
+        let json = serde_json::to_string(&vector).expect("vector should serialize");
+        let decoded: Vector = serde_json::from_str(&json).expect("vector should deserialize");
+
+        assert_eq!(decoded.x().to_bits(), vector.x().to_bits());
+        assert_eq!(decoded.y().to_bits(), vector.y().to_bits());
+    }
 }
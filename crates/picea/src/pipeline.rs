@@ -7,27 +7,59 @@
 pub(crate) mod broadphase;
 pub(crate) mod ccd;
 pub(crate) mod contacts;
+pub(crate) mod fluid;
 pub(crate) mod gjk;
 pub(crate) mod integrate;
 pub(crate) mod island;
 pub(crate) mod joints;
 pub(crate) mod narrowphase;
+mod params;
 pub(crate) mod sleep;
 pub(crate) mod step;
 
+use std::convert::Infallible;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{events::WorldEvent, handles::WorldRevision, math::FloatNum};
+use crate::{events::WorldEvent, handles::WorldRevision, math::FloatNum, world::ValidationError};
+
+pub use params::{CcdMode, CcdParams, CcdResolution, SleepParams, SolverParams};
 
 const DEFAULT_STEP_DT: FloatNum = 1.0 / 60.0;
 const DEFAULT_VELOCITY_ITERATIONS: u16 = 10;
 const DEFAULT_POSITION_ITERATIONS: u16 = 20;
 const DEFAULT_RESTITUTION_VELOCITY_THRESHOLD: FloatNum = 1.0;
+const DEFAULT_MAX_SUBSTEPS: u16 = 8;
 
 const fn default_restitution_velocity_threshold() -> FloatNum {
     DEFAULT_RESTITUTION_VELOCITY_THRESHOLD
 }
 
+const fn default_max_substeps() -> u16 {
+    DEFAULT_MAX_SUBSTEPS
+}
+
+/// Contact solver strategy used for each simulation step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolverMode {
+    /// Sequential impulses over `velocity_iterations`, followed by
+    /// `position_iterations` of residual position correction.
+    #[default]
+    SequentialImpulse,
+    /// Splits the contact solve into `substeps` soft sub-steps (TGS-soft style).
+    ///
+    /// Each sub-step re-applies its share of gravity, warm starts, runs one
+    /// biased soft-contact pass, integrates positions, and runs one unbiased
+    /// relaxation pass. Restitution is applied once after the last sub-step.
+    /// `velocity_iterations` and `position_iterations` are not used, and
+    /// reported contact impulses are per sub-step.
+    SoftStep {
+        /// Number of sub-steps per step; must be positive.
+        substeps: u16,
+    },
+}
+
 /// Stable step configuration owned by the simulation pipeline.
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StepConfig {
@@ -42,6 +74,26 @@ pub struct StepConfig {
     pub restitution_velocity_threshold: FloatNum,
     /// Enables or disables world sleep evaluation for this step.
     pub enable_sleep: bool,
+    /// Forces deterministic state canonicalization even when the world did not
+    /// opt in through `WorldDesc::deterministic`.
+    #[serde(default)]
+    pub deterministic: bool,
+    /// Maximum number of fixed steps one `SimulationPipeline::advance` call may
+    /// run before the remaining backlog is dropped.
+    #[serde(default = "default_max_substeps")]
+    pub max_substeps: u16,
+    /// Contact solver strategy.
+    #[serde(default)]
+    pub solver_mode: SolverMode,
+    /// Contact solver tuning.
+    #[serde(default)]
+    pub solver: SolverParams,
+    /// Island sleep thresholds.
+    #[serde(default)]
+    pub sleep: SleepParams,
+    /// Continuous collision detection tolerances.
+    #[serde(default)]
+    pub ccd: CcdParams,
 }
 
 impl Default for StepConfig {
@@ -52,10 +104,46 @@ impl Default for StepConfig {
             position_iterations: DEFAULT_POSITION_ITERATIONS,
             restitution_velocity_threshold: DEFAULT_RESTITUTION_VELOCITY_THRESHOLD,
             enable_sleep: true,
+            deterministic: false,
+            max_substeps: DEFAULT_MAX_SUBSTEPS,
+            solver_mode: SolverMode::SequentialImpulse,
+            solver: SolverParams::default(),
+            sleep: SleepParams::default(),
+            ccd: CcdParams::default(),
         }
     }
 }
 
+impl StepConfig {
+    /// Checks the step timing, solver mode, and every tuning section.
+    ///
+    /// `SimulationPipeline::new` panics on the same errors; callers that build
+    /// configs from user input can validate first.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let checks = [
+            (self.dt.is_finite() && self.dt > 0.0, "dt"),
+            (
+                self.restitution_velocity_threshold.is_finite()
+                    && self.restitution_velocity_threshold >= 0.0,
+                "restitution_velocity_threshold",
+            ),
+            (self.max_substeps > 0, "max_substeps"),
+            (
+                !matches!(self.solver_mode, SolverMode::SoftStep { substeps: 0 }),
+                "solver_mode.substeps",
+            ),
+        ];
+        for (valid, field) in checks {
+            if !valid {
+                return Err(ValidationError::StepConfig { field });
+            }
+        }
+        self.solver.validate()?;
+        self.sleep.validate()?;
+        self.ccd.validate()
+    }
+}
+
 /// Stable high-level counters returned after each simulation step.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct StepStats {
@@ -136,6 +224,19 @@ pub struct StepStats {
     /// Number of dynamic bodies clamped by CCD before contact generation.
     #[serde(default)]
     pub ccd_clamp_count: usize,
+    /// Number of time-of-impact sub-steps resolved by `CcdResolution::SubStep`.
+    #[serde(default)]
+    pub ccd_substep_count: usize,
+    /// Number of CCD hits handed to pose clamping because a sub-step budget ran out.
+    #[serde(default)]
+    pub ccd_substep_fallback_count: usize,
+    /// Number of speculative contact rows built for separated pairs.
+    #[serde(default)]
+    pub ccd_speculative_contact_count: usize,
+    /// Number of speculative contact rows that applied an impulse because the
+    /// pair would otherwise have closed its gap.
+    #[serde(default)]
+    pub ccd_speculative_enforced_count: usize,
     /// Number of velocity iterations used for the step.
     pub velocity_iterations: u16,
     /// Number of position iterations used for the step.
@@ -163,6 +264,22 @@ pub struct StepReport {
     pub events: Vec<WorldEvent>,
 }
 
+/// Result of one `SimulationPipeline::advance` call.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdvanceReport {
+    /// Reports for every fixed step run during this call, in order.
+    pub steps: Vec<StepReport>,
+    /// Fraction of a fixed step left in the accumulator, in `[0, 1)`.
+    ///
+    /// Pass this to `World::interpolated_pose` to render between the previous
+    /// and current step.
+    pub alpha: FloatNum,
+    /// Real time still waiting in the accumulator after this call.
+    pub accumulated_time: f64,
+    /// Real time discarded because the step backlog exceeded `max_substeps`.
+    pub dropped_time: f64,
+}
+
 /// Adapter output produced by a concrete world implementation.
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct StepOutcome {
@@ -186,6 +303,8 @@ pub struct SimulationPipeline {
     config: StepConfig,
     next_step_index: u64,
     simulated_time: f64,
+    #[serde(default)]
+    accumulated_time: f64,
 }
 
 impl SimulationPipeline {
@@ -196,6 +315,7 @@ impl SimulationPipeline {
             config,
             next_step_index: 0,
             simulated_time: 0.0,
+            accumulated_time: 0.0,
         }
     }
 
@@ -210,7 +330,7 @@ impl SimulationPipeline {
         W: SimulationWorld,
     {
         let step_index = self.next_step_index + 1;
-        self.simulated_time += f64::from(self.config.dt);
+        self.simulated_time += crate::math::num::widen(self.config.dt);
 
         let StepOutcome {
             revision,
@@ -233,18 +353,84 @@ impl SimulationPipeline {
             events,
         }
     }
+
+    /// Accumulates `elapsed` real seconds and runs as many fixed steps as fit.
+    ///
+    /// At most `StepConfig::max_substeps` steps run per call. Any whole steps
+    /// beyond that cap are dropped instead of carried over, so a slow frame
+    /// cannot force ever-longer catch-up frames. The sub-step remainder stays
+    /// in the accumulator and is reported as `AdvanceReport::alpha`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `elapsed` is negative or not finite.
+    pub fn advance<W>(&mut self, world: &mut W, elapsed: f64) -> AdvanceReport
+    where
+        W: SimulationWorld,
+    {
+        let report = self.advance_with(elapsed, |pipeline| {
+            Ok::<_, Infallible>(pipeline.step(world))
+        });
+        match report {
+            Ok(report) => report,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Runs the `advance` accumulator, calling `step` once for every fixed
+    /// step that is due.
+    ///
+    /// `step` must run exactly one [`SimulationPipeline::step`] and return its
+    /// report; use this over `advance` when each step needs work around it,
+    /// such as feeding controllers or recording snapshots. An error from
+    /// `step` stops the call and is returned as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `elapsed` is negative or not finite.
+    pub fn advance_with<E>(
+        &mut self,
+        elapsed: f64,
+        mut step: impl FnMut(&mut Self) -> Result<StepReport, E>,
+    ) -> Result<AdvanceReport, E> {
+        assert!(
+            elapsed.is_finite() && elapsed >= 0.0,
+            "advance elapsed time must be finite and non-negative"
+        );
+        let dt = crate::math::num::widen(self.config.dt);
+        self.accumulated_time += elapsed;
+
+        let mut steps = Vec::new();
+        while self.accumulated_time >= dt && steps.len() < usize::from(self.config.max_substeps) {
+            steps.push(step(self)?);
+            self.accumulated_time -= dt;
+        }
+
+        let mut dropped_time = 0.0;
+        if self.accumulated_time >= dt {
+            let remainder = self.accumulated_time % dt;
+            dropped_time = self.accumulated_time - remainder;
+            self.accumulated_time = remainder;
+        }
+
+        Ok(AdvanceReport {
+            steps,
+            alpha: (self.accumulated_time / dt) as FloatNum,
+            accumulated_time: self.accumulated_time,
+            dropped_time,
+        })
+    }
+
+    /// Returns real time accumulated by `advance` but not yet simulated.
+    pub fn accumulated_time(&self) -> f64 {
+        self.accumulated_time
+    }
 }
 
 fn validate_step_config(config: &StepConfig) {
-    assert!(
-        config.dt.is_finite() && config.dt > 0.0,
-        "step dt must be finite and positive"
-    );
-    assert!(
-        config.restitution_velocity_threshold.is_finite()
-            && config.restitution_velocity_threshold >= 0.0,
-        "restitution velocity threshold must be finite and non-negative"
-    );
+    if let Err(error) = config.validate() {
+        panic!("{error}");
+    }
 }
 
 #[cfg(test)]
@@ -256,8 +442,12 @@ mod tests {
         handles::{
             BodyHandle, ColliderHandle, ContactFeatureId, ContactId, ManifoldId, WorldRevision,
         },
-        math::{point::Point, vector::Vector},
-        pipeline::{SimulationPipeline, SimulationWorld, StepConfig, StepOutcome, StepStats},
+        math::{num::widen, point::Point, vector::Vector, FloatNum},
+        pipeline::{
+            CcdParams, CcdResolution, SimulationPipeline, SimulationWorld, SleepParams, SolverMode,
+            SolverParams, StepConfig, StepOutcome, StepStats,
+        },
+        world::ValidationError,
     };
 
     #[derive(Default)]
@@ -291,11 +481,17 @@ mod tests {
         assert_eq!(config.position_iterations, 20);
         assert_eq!(config.restitution_velocity_threshold, 1.0);
         assert!(config.enable_sleep);
+        assert!(!config.deterministic);
+        assert_eq!(config.max_substeps, 8);
+        assert_eq!(config.solver_mode, SolverMode::SequentialImpulse);
+        assert_eq!(config.solver.contact_slop, 0.005);
+        assert_eq!(config.sleep.stability_seconds, 0.5);
+        assert_eq!(config.ccd.clamp_slop, 1.0e-3);
     }
 
     #[test]
     fn new_rejects_non_positive_or_non_finite_dt() {
-        for dt in [0.0, -0.1, f32::NAN, f32::INFINITY] {
+        for dt in [0.0, -0.1, FloatNum::NAN, FloatNum::INFINITY] {
             let config = StepConfig {
                 dt,
                 ..StepConfig::default()
@@ -306,9 +502,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn validate_names_the_invalid_step_config_field() {
+        assert_eq!(StepConfig::default().validate(), Ok(()));
+        let cases = [
+            (
+                StepConfig {
+                    dt: FloatNum::NAN,
+                    ..StepConfig::default()
+                },
+                "dt",
+            ),
+            (
+                StepConfig {
+                    max_substeps: 0,
+                    ..StepConfig::default()
+                },
+                "max_substeps",
+            ),
+            (
+                StepConfig {
+                    solver_mode: SolverMode::SoftStep { substeps: 0 },
+                    ..StepConfig::default()
+                },
+                "solver_mode.substeps",
+            ),
+            (
+                StepConfig {
+                    ccd: CcdParams {
+                        clamp_slop: -1.0,
+                        ..CcdParams::default()
+                    },
+                    ..StepConfig::default()
+                },
+                "ccd.clamp_slop",
+            ),
+        ];
+        for (config, field) in cases {
+            assert_eq!(
+                config.validate(),
+                Err(ValidationError::StepConfig { field })
+            );
+        }
+    }
+
     #[test]
     fn new_rejects_negative_or_non_finite_restitution_threshold() {
-        for threshold in [-0.1, f32::NAN, f32::INFINITY] {
+        for threshold in [-0.1, FloatNum::NAN, FloatNum::INFINITY] {
             let config = StepConfig {
                 restitution_velocity_threshold: threshold,
                 ..StepConfig::default()
@@ -361,6 +601,12 @@ mod tests {
             position_iterations: 4,
             restitution_velocity_threshold: 2.0,
             enable_sleep: false,
+            deterministic: false,
+            max_substeps: 4,
+            solver_mode: SolverMode::SequentialImpulse,
+            solver: SolverParams::default(),
+            sleep: SleepParams::default(),
+            ccd: CcdParams::default(),
         });
         let mut world = FakeWorld::with_outcomes([StepOutcome {
             revision: WorldRevision::from_raw(9),
@@ -384,7 +630,7 @@ mod tests {
         assert_eq!(report.step_index, 1);
         assert_eq!(report.stats.step_index, 1);
         assert_eq!(report.dt, 1.0 / 120.0);
-        assert_eq!(report.simulated_time, f64::from(1.0_f32 / 120.0_f32));
+        assert_eq!(report.simulated_time, widen(1.0 as FloatNum / 120.0));
         assert_eq!(report.revision, WorldRevision::from_raw(9));
         assert_eq!(report.stats.velocity_iterations, 6);
         assert_eq!(report.stats.position_iterations, 4);
@@ -412,10 +658,227 @@ mod tests {
 
         assert_eq!(first.step_index, 1);
         assert_eq!(second.step_index, 2);
-        assert_eq!(first.simulated_time, f64::from(StepConfig::default().dt));
+        assert_eq!(first.simulated_time, widen(StepConfig::default().dt));
+        assert_eq!(second.simulated_time, widen(StepConfig::default().dt) * 2.0);
+    }
+
+    #[test]
+    fn advance_runs_whole_steps_and_carries_the_remainder() {
+        let mut pipeline = SimulationPipeline::new(StepConfig::default());
+        let mut world = FakeWorld::default();
+        let dt = widen(pipeline.config().dt);
+
+        let first = pipeline.advance(&mut world, dt * 0.5);
+        assert!(first.steps.is_empty());
+        assert!((first.alpha - 0.5).abs() < 1.0e-6);
+
+        let second = pipeline.advance(&mut world, dt * 2.0);
+        assert_eq!(second.steps.len(), 2);
+        assert_eq!(second.steps[1].step_index, 2);
+        assert!((second.alpha - 0.5).abs() < 1.0e-6);
+        assert_eq!(second.dropped_time, 0.0);
+        assert_eq!(pipeline.accumulated_time(), second.accumulated_time);
+        assert_eq!(world.seen_configs.len(), 2);
+    }
+
+    #[test]
+    fn advance_caps_substeps_and_drops_the_backlog() {
+        let mut pipeline = SimulationPipeline::new(StepConfig {
+            max_substeps: 3,
+            ..StepConfig::default()
+        });
+        let mut world = FakeWorld::default();
+        let dt = widen(pipeline.config().dt);
+
+        let report = pipeline.advance(&mut world, dt * 10.25);
+
+        assert_eq!(report.steps.len(), 3);
+        assert!((report.dropped_time - dt * 7.0).abs() < 1.0e-9);
+        assert!((report.alpha - 0.25).abs() < 1.0e-6);
+        assert!(report.accumulated_time < dt);
+
+        let next = pipeline.advance(&mut world, 0.0);
+        assert!(next.steps.is_empty());
+        assert_eq!(next.dropped_time, 0.0);
+    }
+
+    #[test]
+    fn advance_rejects_negative_or_non_finite_elapsed_time() {
+        for elapsed in [-0.1, f64::NAN, f64::INFINITY] {
+            let result = catch_unwind(|| {
+                SimulationPipeline::new(StepConfig::default())
+                    .advance(&mut FakeWorld::default(), elapsed)
+            });
+            assert!(
+                result.is_err(),
+                "expected elapsed {elapsed:?} to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn advance_with_calls_back_per_due_step_and_stops_on_error() {
+        let mut pipeline = SimulationPipeline::new(StepConfig::default());
+        let mut world = FakeWorld::default();
+        let dt = widen(pipeline.config().dt);
+
+        let mut calls = 0;
+        let report = pipeline
+            .advance_with(dt * 2.5, |pipeline| {
+                calls += 1;
+                Ok::<_, &str>(pipeline.step(&mut world))
+            })
+            .expect("steps should succeed");
+        assert_eq!(calls, 2);
+        assert_eq!(report.steps.len(), 2);
+        assert!((report.alpha - 0.5).abs() < 1.0e-6);
+
+        let result = pipeline.advance_with(dt, |_| Err("controller failed"));
+        assert_eq!(result, Err("controller failed"));
+        assert!(
+            pipeline.accumulated_time() >= dt,
+            "a failed step leaves its time due"
+        );
+    }
+
+    #[test]
+    fn new_rejects_out_of_range_tuning_params() {
+        let configs = [
+            StepConfig {
+                solver: SolverParams {
+                    position_correction_percent: 1.5,
+                    ..SolverParams::default()
+                },
+                ..StepConfig::default()
+            },
+            StepConfig {
+                sleep: SleepParams {
+                    linear_threshold: -1.0,
+                    ..SleepParams::default()
+                },
+                ..StepConfig::default()
+            },
+            StepConfig {
+                ccd: CcdParams {
+                    toi_epsilon: 0.0,
+                    ..CcdParams::default()
+                },
+                ..StepConfig::default()
+            },
+        ];
+
+        for config in configs {
+            assert!(catch_unwind(|| SimulationPipeline::new(config)).is_err());
+        }
+        assert_eq!(
+            SolverParams {
+                soft_contact_hertz: FloatNum::NAN,
+                ..SolverParams::default()
+            }
+            .validate(),
+            Err(ValidationError::StepConfig {
+                field: "solver.soft_contact_hertz"
+            })
+        );
+    }
+
+    #[test]
+    fn step_config_deserializes_partial_tuning_sections() {
+        let mut value = serde_json::to_value(StepConfig::default()).expect("config serializes");
+        let object = value.as_object_mut().expect("config is an object");
+        object.remove("sleep");
+        object.remove("ccd");
+        object.insert(
+            "solver".to_owned(),
+            serde_json::json!({ "contact_slop": 0.01 }),
+        );
+
+        let config: StepConfig = serde_json::from_value(value).expect("config deserializes");
         assert_eq!(
-            second.simulated_time,
-            f64::from(StepConfig::default().dt) * 2.0
+            config.solver,
+            SolverParams {
+                contact_slop: 0.01,
+                ..SolverParams::default()
+            }
+        );
+        assert_eq!(config.sleep, SleepParams::default());
+        assert_eq!(config.ccd, CcdParams::default());
+    }
+
+    #[test]
+    fn new_rejects_zero_soft_step_substeps() {
+        let config = StepConfig {
+            solver_mode: SolverMode::SoftStep { substeps: 0 },
+            ..StepConfig::default()
+        };
+
+        assert!(catch_unwind(|| SimulationPipeline::new(config)).is_err());
+    }
+
+    #[test]
+    fn step_config_without_solver_mode_deserializes_to_sequential_impulse() {
+        let mut value = serde_json::to_value(StepConfig::default()).expect("config serializes");
+        value
+            .as_object_mut()
+            .expect("config is an object")
+            .remove("solver_mode");
+
+        let config: StepConfig = serde_json::from_value(value).expect("config deserializes");
+        assert_eq!(config.solver_mode, SolverMode::SequentialImpulse);
+
+        let soft = StepConfig {
+            solver_mode: SolverMode::SoftStep { substeps: 4 },
+            ..StepConfig::default()
+        };
+        let round_trip: StepConfig =
+            serde_json::from_str(&serde_json::to_string(&soft).expect("config serializes"))
+                .expect("config deserializes");
+        assert_eq!(round_trip, soft);
+    }
+
+    #[test]
+    fn new_rejects_zero_max_substeps() {
+        let config = StepConfig {
+            max_substeps: 0,
+            ..StepConfig::default()
+        };
+
+        assert!(catch_unwind(|| SimulationPipeline::new(config)).is_err());
+    }
+
+    #[test]
+    fn ccd_params_reject_zero_toi_substep_budgets() {
+        assert_eq!(CcdParams::default().resolution, CcdResolution::PoseClamp);
+        assert_eq!(
+            CcdParams {
+                max_toi_substeps: 0,
+                ..CcdParams::default()
+            }
+            .validate(),
+            Err(ValidationError::StepConfig {
+                field: "ccd.max_toi_substeps"
+            })
+        );
+        assert_eq!(
+            CcdParams {
+                max_body_toi_substeps: 0,
+                ..CcdParams::default()
+            }
+            .validate(),
+            Err(ValidationError::StepConfig {
+                field: "ccd.max_body_toi_substeps"
+            })
+        );
+
+        let params: CcdParams =
+            serde_json::from_value(serde_json::json!({ "resolution": "sub_step" }))
+                .expect("ccd params deserialize");
+        assert_eq!(
+            params,
+            CcdParams {
+                resolution: CcdResolution::SubStep,
+                ..CcdParams::default()
+            }
         );
     }
 }
@@ -10,6 +10,12 @@ use crate::{
 const FAT_AABB_MIN_MARGIN: FloatNum = 0.1;
 const FAT_AABB_EXTENT_RATIO: FloatNum = 0.1;
 const MIN_REBUILD_LEAF_COUNT: usize = 4;
+/// Trees smaller than this are traversed on the calling thread.
+#[cfg(feature = "parallel")]
+const PARALLEL_TRAVERSAL_MIN_LEAVES: usize = 64;
+/// How many tree levels are split into parallel traversal tasks.
+#[cfg(feature = "parallel")]
+const PARALLEL_TRAVERSAL_SPLIT_DEPTH: usize = 4;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) struct ColliderProxy {
@@ -44,6 +50,15 @@ pub(crate) struct TreeQueryStats {
     pub(crate) candidate_count: usize,
 }
 
+#[cfg(feature = "parallel")]
+impl TreeQueryStats {
+    fn accumulate(&mut self, other: Self) {
+        self.traversal_count += other.traversal_count;
+        self.pruned_count += other.pruned_count;
+        self.candidate_count += other.candidate_count;
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub(crate) struct TreeQueryOutput {
     pub(crate) proxy_indices: Vec<usize>,
@@ -55,8 +70,37 @@ pub(crate) struct Broadphase {
     tree: DynamicAabbTree,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct BroadphaseRefresh {
+    pub(crate) update_count: usize,
+    pub(crate) stale_proxy_drop_count: usize,
+    pub(crate) rebuild_count: usize,
+}
+
 impl Broadphase {
     pub(crate) fn update(&mut self, proxies: &[ColliderProxy]) -> BroadphaseOutput {
+        let refresh = self.refresh(proxies);
+        let candidate_output = self.tree.candidate_pairs_with_stats();
+        BroadphaseOutput {
+            stats: BroadphaseStats {
+                candidate_count: candidate_output.pairs.len(),
+                update_count: refresh.update_count,
+                stale_proxy_drop_count: refresh.stale_proxy_drop_count,
+                same_body_drop_count: 0,
+                filter_drop_count: 0,
+                narrowphase_drop_count: 0,
+                traversal_count: candidate_output.stats.traversal_count,
+                pruned_count: candidate_output.stats.pruned_count,
+                rebuild_count: refresh.rebuild_count,
+                tree_depth: self.tree.depth(),
+            },
+            candidate_pairs: candidate_output.pairs,
+        }
+    }
+
+    /// Moves the tree to `proxies` without collecting pairs, so queries made
+    /// between steps see colliders created or moved since the last step.
+    pub(crate) fn refresh(&mut self, proxies: &[ColliderProxy]) -> BroadphaseRefresh {
         let live_handles = proxies
             .iter()
             .map(|proxy| proxy.handle)
@@ -82,25 +126,31 @@ impl Broadphase {
             self.tree.rebuild_balanced_from_proxies(proxies);
             rebuild_count = 1;
         }
-
-        let candidate_output = self.tree.candidate_pairs_with_stats();
-        BroadphaseOutput {
-            stats: BroadphaseStats {
-                candidate_count: candidate_output.pairs.len(),
-                update_count,
-                stale_proxy_drop_count,
-                same_body_drop_count: 0,
-                filter_drop_count: 0,
-                narrowphase_drop_count: 0,
-                traversal_count: candidate_output.stats.traversal_count,
-                pruned_count: candidate_output.stats.pruned_count,
-                rebuild_count,
-                tree_depth: self.tree.depth(),
-            },
-            candidate_pairs: candidate_output.pairs,
+        BroadphaseRefresh {
+            update_count,
+            stale_proxy_drop_count,
+            rebuild_count,
         }
     }
 
+    /// Colliders whose fat bounds overlap `aabb`, in handle order.
+    pub(crate) fn query_aabb(&self, aabb: ShapeAabb) -> Vec<ColliderHandle> {
+        self.tree
+            .query_handles(|node_aabb| aabb_overlaps_inclusive(aabb, node_aabb))
+    }
+
+    /// Colliders whose fat bounds the ray crosses within `max_toi`, in handle
+    /// order.
+    pub(crate) fn query_ray(
+        &self,
+        origin: crate::math::point::Point,
+        direction: crate::math::vector::Vector,
+        max_toi: FloatNum,
+    ) -> Vec<ColliderHandle> {
+        self.tree
+            .query_handles(|node_aabb| ray_intersects_aabb(origin, direction, max_toi, node_aabb))
+    }
+
     pub(crate) fn debug_tree(&self) -> DebugBroadphaseTree {
         self.tree.debug_tree()
     }
@@ -204,7 +254,7 @@ impl DynamicAabbTree {
         let mut stats = TreeQueryStats::default();
 
         if let Some(root) = self.root {
-            self.collect_subtree_candidate_pairs(root, &mut pairs, &mut stats);
+            self.collect_root_candidate_pairs(root, &mut pairs, &mut stats);
         }
 
         // Preserve the old contact pass ordering: live collider snapshot order,
@@ -215,6 +265,76 @@ impl DynamicAabbTree {
         CandidatePairOutput { pairs, stats }
     }
 
+    #[cfg(not(feature = "parallel"))]
+    fn collect_root_candidate_pairs(
+        &self,
+        root: usize,
+        pairs: &mut Vec<(usize, usize)>,
+        stats: &mut TreeQueryStats,
+    ) {
+        self.collect_subtree_candidate_pairs(root, pairs, stats);
+    }
+
+    #[cfg(feature = "parallel")]
+    fn collect_root_candidate_pairs(
+        &self,
+        root: usize,
+        pairs: &mut Vec<(usize, usize)>,
+        stats: &mut TreeQueryStats,
+    ) {
+        if self.leaves.len() < PARALLEL_TRAVERSAL_MIN_LEAVES || rayon::current_num_threads() < 2 {
+            self.collect_subtree_candidate_pairs(root, pairs, stats);
+            return;
+        }
+        let output = self.parallel_subtree_candidate_pairs(root, PARALLEL_TRAVERSAL_SPLIT_DEPTH);
+        pairs.extend(output.pairs);
+        stats.accumulate(output.stats);
+    }
+
+    /// Splits the top of the traversal into independent subtree and
+    /// between-subtree tasks. Counters are plain sums and the caller sorts the
+    /// pairs, so the result does not depend on which task finishes first.
+    #[cfg(feature = "parallel")]
+    fn parallel_subtree_candidate_pairs(
+        &self,
+        node_index: usize,
+        split_depth: usize,
+    ) -> CandidatePairOutput {
+        let mut output = CandidatePairOutput::default();
+        let node = &self.nodes[node_index];
+        let (Some(left), Some(right)) = (node.left, node.right) else {
+            return output;
+        };
+        if split_depth == 0 {
+            self.collect_subtree_candidate_pairs(node_index, &mut output.pairs, &mut output.stats);
+            return output;
+        }
+
+        let ((left_output, right_output), between_output) = rayon::join(
+            || {
+                rayon::join(
+                    || self.parallel_subtree_candidate_pairs(left, split_depth - 1),
+                    || self.parallel_subtree_candidate_pairs(right, split_depth - 1),
+                )
+            },
+            || {
+                let mut between = CandidatePairOutput::default();
+                self.collect_candidate_pairs_between(
+                    left,
+                    right,
+                    &mut between.pairs,
+                    &mut between.stats,
+                );
+                between
+            },
+        );
+        for part in [left_output, right_output, between_output] {
+            output.pairs.extend(part.pairs);
+            output.stats.accumulate(part.stats);
+        }
+        output
+    }
+
     fn collect_subtree_candidate_pairs(
         &self,
         node_index: usize,
@@ -591,8 +711,26 @@ impl DynamicAabbTree {
 
     fn query_indices(
         &self,
-        mut overlaps: impl FnMut(ShapeAabb) -> bool,
+        overlaps: impl FnMut(ShapeAabb) -> bool,
         out: &mut Vec<usize>,
+    ) -> TreeQueryStats {
+        let stats = self.visit_leaves(overlaps, |proxy_index, _| out.push(proxy_index));
+        // Query callers keep the stable public ordering contract: snapshot order.
+        out.sort_unstable();
+        stats
+    }
+
+    fn query_handles(&self, overlaps: impl FnMut(ShapeAabb) -> bool) -> Vec<ColliderHandle> {
+        let mut handles = Vec::new();
+        self.visit_leaves(overlaps, |_, handle| handles.push(handle));
+        handles.sort_unstable();
+        handles
+    }
+
+    fn visit_leaves(
+        &self,
+        mut overlaps: impl FnMut(ShapeAabb) -> bool,
+        mut leaf: impl FnMut(usize, ColliderHandle),
     ) -> TreeQueryStats {
         let Some(root) = self.root else {
             return TreeQueryStats::default();
@@ -609,7 +747,7 @@ impl DynamicAabbTree {
             }
             if let Some(proxy_index) = node.proxy_index {
                 stats.candidate_count += 1;
-                out.push(proxy_index);
+                leaf(proxy_index, node.handle);
                 continue;
             }
             if let Some(right) = node.right {
@@ -619,9 +757,6 @@ impl DynamicAabbTree {
                 stack.push(left);
             }
         }
-
-        // Query callers keep the stable public ordering contract: snapshot order.
-        out.sort_unstable();
         stats
     }
 
@@ -760,7 +895,7 @@ fn aabb_overlaps(a: ShapeAabb, b: ShapeAabb) -> bool {
     overlap_x > 0.0 && overlap_y > 0.0
 }
 
-fn aabb_overlaps_inclusive(a: ShapeAabb, b: ShapeAabb) -> bool {
+pub(crate) fn aabb_overlaps_inclusive(a: ShapeAabb, b: ShapeAabb) -> bool {
     !(a.max.x() < b.min.x()
         || b.max.x() < a.min.x()
         || a.max.y() < b.min.y()
@@ -812,7 +947,7 @@ fn ray_intersects_aabb(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::math::point::Point;
+    use crate::math::{point::Point, vector::Vector};
 
     fn assert_debug_tree_contract(
         tree: &DebugBroadphaseTree,
@@ -903,7 +1038,7 @@ mod tests {
         ColliderHandle::from_raw_parts(index, generation)
     }
 
-    fn aabb(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> ShapeAabb {
+    fn aabb(min_x: FloatNum, min_y: FloatNum, max_x: FloatNum, max_y: FloatNum) -> ShapeAabb {
         ShapeAabb {
             min: Point::new(min_x, min_y),
             max: Point::new(max_x, max_y),
@@ -950,6 +1085,30 @@ mod tests {
         assert_eq!(outside_fat_aabb.stats.update_count, 1);
     }
 
+    #[test]
+    fn refreshed_broadphase_answers_handle_queries_between_updates() {
+        let mut broadphase = Broadphase::default();
+        broadphase.update(&[proxy(1, aabb(0.0, 0.0, 1.0, 1.0))]);
+        let refresh = broadphase.refresh(&[
+            proxy(1, aabb(0.0, 0.0, 1.0, 1.0)),
+            proxy(0, aabb(0.5, 0.0, 1.5, 1.0)),
+            proxy(2, aabb(8.0, 0.0, 9.0, 1.0)),
+        ]);
+        assert_eq!(refresh.update_count, 2);
+
+        assert_eq!(
+            broadphase.query_aabb(aabb(0.0, 0.0, 2.0, 1.0)),
+            vec![handle(0), handle(1)]
+        );
+        assert_eq!(
+            broadphase.query_ray(Point::new(-1.0, 0.5), Vector::new(1.0, 0.0), 20.0),
+            vec![handle(0), handle(1), handle(2)]
+        );
+        assert!(broadphase
+            .query_ray(Point::new(-1.0, 0.5), Vector::new(-1.0, 0.0), 20.0)
+            .is_empty());
+    }
+
     #[test]
     fn persistent_broadphase_removes_stale_and_recycled_proxy_handles() {
         let mut broadphase = Broadphase::default();
@@ -982,7 +1141,7 @@ mod tests {
         let mut broadphase = Broadphase::default();
         let proxies = (0..32)
             .map(|index| {
-                let x = index as f32 * 2.0;
+                let x = index as FloatNum * 2.0;
                 proxy(index, aabb(x, 0.0, x + 1.0, 1.0))
             })
             .collect::<Vec<_>>();
@@ -1005,7 +1164,7 @@ mod tests {
         let mut saw_compaction = false;
 
         for step in 0..24 {
-            let x = step as f32;
+            let x = step as FloatNum;
             let output = broadphase.update(&[proxy(0, aabb(x, 0.0, x + 1.0, 1.0)), stable]);
             saw_compaction |= output.stats.rebuild_count > 0;
         }
@@ -1124,7 +1283,7 @@ mod tests {
     fn query_tree_builds_balanced_from_ordered_snapshot_proxies() {
         let proxies = (0..64)
             .map(|index| {
-                let x = index as f32 * 2.0;
+                let x = index as FloatNum * 2.0;
                 proxy(index, aabb(x, 0.0, x + 1.0, 1.0))
             })
             .collect::<Vec<_>>();
@@ -1188,7 +1347,7 @@ mod tests {
 
         let many_proxies = (100..132)
             .map(|index| {
-                let x = index as f32 * 2.0;
+                let x = index as FloatNum * 2.0;
                 proxy(index, aabb(x, 2.0, x + 1.0, 3.0))
             })
             .collect::<Vec<_>>();
@@ -1240,7 +1399,7 @@ mod tests {
 
         let many_proxies = (100..132)
             .map(|index| {
-                let x = index as f32 * 2.0;
+                let x = index as FloatNum * 2.0;
                 proxy(index, aabb(x, 2.0, x + 1.0, 3.0))
             })
             .collect::<Vec<_>>();
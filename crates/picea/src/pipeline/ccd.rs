@@ -4,18 +4,19 @@ use std::{
 };
 
 use crate::{
-    body::{BodyType, Pose},
+    body::{BodyRecord, BodyType, Pose},
     collider::{CollisionFilter, ShapeAabb, SharedShape},
-    events::{CcdTargetKind, CcdTrace},
+    events::{CcdMethod, CcdTargetKind, CcdTrace},
     handles::{BodyHandle, ColliderHandle},
     math::{point::Point, vector::Vector, FloatNum},
+    pipeline::{
+        contacts::combine_materials, gjk::gjk_separation, integrate::translated_pose, CcdParams,
+        CcdResolution, StepConfig,
+    },
+    solver::body_state::{angular_point_velocity, inverse_mass_along, linear_response},
     world::World,
 };
 
-const CCD_TOI_EPSILON: FloatNum = 1.0e-5;
-const CCD_CLAMP_SLOP: FloatNum = 1.0e-3;
-const CLIP_SUPPORT_EPSILON: FloatNum = 1.0e-4;
-
 #[derive(Clone, Debug, Default, PartialEq)]
 pub(crate) struct CcdPoseClampOutcome {
     pub(crate) stats: CcdPoseClampStats,
@@ -28,6 +29,8 @@ pub(crate) struct CcdPoseClampStats {
     pub(crate) hit_count: usize,
     pub(crate) miss_count: usize,
     pub(crate) clamp_count: usize,
+    pub(crate) substep_count: usize,
+    pub(crate) substep_fallback_count: usize,
 }
 
 #[derive(Clone, Debug)]
@@ -36,6 +39,9 @@ struct CcdColliderSnapshot {
     body: BodyHandle,
     body_type: BodyType,
     shape: SharedShape,
+    local_pose: Pose,
+    start_body_pose: Pose,
+    end_body_pose: Pose,
     start_pose: Pose,
     end_pose: Pose,
     aabb: ShapeAabb,
@@ -43,6 +49,29 @@ struct CcdColliderSnapshot {
     end_convex_vertices: Option<Vec<Point>>,
     filter: CollisionFilter,
     is_sensor: bool,
+    ccd: BodyCcd,
+}
+
+/// Per-body CCD opt-in copied from the body record.
+#[derive(Clone, Copy, Debug)]
+struct BodyCcd {
+    enabled: bool,
+    bullet: bool,
+    motion_threshold: FloatNum,
+}
+
+impl BodyCcd {
+    /// Whether the body takes part in CCD at all.
+    fn active(self) -> bool {
+        self.enabled || self.bullet
+    }
+
+    /// Whether a sweep of `length` is long enough to be cast.
+    fn sweeps(self, length: FloatNum, params: &CcdParams) -> bool {
+        self.active()
+            && length > params.toi_epsilon
+            && (self.bullet || length > self.motion_threshold)
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -55,6 +84,7 @@ struct MovingCircle {
     swept_aabb: ShapeAabb,
     filter: CollisionFilter,
     is_sensor: bool,
+    ccd: BodyCcd,
 }
 
 #[derive(Clone, Debug)]
@@ -67,10 +97,12 @@ struct MovingConvex {
     swept_aabb: ShapeAabb,
     filter: CollisionFilter,
     is_sensor: bool,
+    ccd: BodyCcd,
 }
 
+/// Translational convex sweep of a dynamic or kinematic collider.
 #[derive(Clone, Debug)]
-struct DynamicConvexTarget {
+struct SweptConvex {
     body: BodyHandle,
     collider: ColliderHandle,
     start: Point,
@@ -79,6 +111,7 @@ struct DynamicConvexTarget {
     swept_aabb: ShapeAabb,
     filter: CollisionFilter,
     is_sensor: bool,
+    ccd: BodyCcd,
 }
 
 #[derive(Clone, Debug)]
@@ -92,6 +125,73 @@ struct StaticConvex {
     is_sensor: bool,
 }
 
+/// Collider swept with its body's full linear and angular motion, used by
+/// conservative advancement for pairs the translational casts cannot handle.
+#[derive(Clone, Debug)]
+struct RigidSweep {
+    body: BodyHandle,
+    collider: ColliderHandle,
+    body_type: BodyType,
+    shape: SharedShape,
+    local_pose: Pose,
+    start_body_pose: Pose,
+    end_body_pose: Pose,
+    start: Point,
+    end: Point,
+    /// Farthest distance of any collider point from the body origin.
+    reach: FloatNum,
+    /// Narrowest width of the collider; zero for segments.
+    thickness: FloatNum,
+    swept_aabb: ShapeAabb,
+    filter: CollisionFilter,
+    is_sensor: bool,
+    ccd: BodyCcd,
+}
+
+impl RigidSweep {
+    fn collider_pose_at(&self, t: FloatNum) -> Pose {
+        self.start_body_pose
+            .lerp(self.end_body_pose, t)
+            .compose(self.local_pose)
+    }
+
+    fn angular_sweep(&self) -> FloatNum {
+        self.end_body_pose.angle() - self.start_body_pose.angle()
+    }
+
+    fn linear_sweep(&self) -> Vector {
+        self.end_body_pose.translation() - self.start_body_pose.translation()
+    }
+
+    /// Circles keep using the translational cast of their reference point.
+    fn rotates(&self, params: &CcdParams) -> bool {
+        !matches!(self.shape, SharedShape::Circle { .. })
+            && self.angular_sweep().abs() > params.toi_epsilon
+    }
+
+    /// Upper bound on how far any collider point travels over the sweep.
+    fn motion_bound(&self) -> FloatNum {
+        self.linear_sweep().length() + self.angular_sweep().abs() * self.reach
+    }
+}
+
+/// Start and end body poses of both sides of a conservative-advancement hit.
+#[derive(Clone, Copy, Debug)]
+struct RigidHitPoses {
+    moving_start: Pose,
+    moving_end: Pose,
+    target_start: Pose,
+    target_end: Pose,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct AdvancementHit {
+    toi: FloatNum,
+    point: Point,
+    /// Unit separation direction from the moving collider toward the target.
+    normal: Vector,
+}
+
 #[derive(Clone, Copy, Debug)]
 struct CcdHit {
     moving_body: BodyHandle,
@@ -107,32 +207,182 @@ struct CcdHit {
     exit: FloatNum,
     slop_sweep_length: FloatNum,
     toi_point: Point,
+    /// Unit contact normal at the time of impact, pointing from the target
+    /// toward the moving collider.
+    normal: Vector,
+    rigid: Option<RigidHitPoses>,
 }
 
-pub(crate) fn run_pose_clamp_phase(
+const CONSERVATIVE_ADVANCEMENT_MAX_ITERATIONS: usize = 32;
+
+pub(crate) fn run_ccd_phase(
     world: &mut World,
     previous_body_poses: &BTreeMap<BodyHandle, Pose>,
+    config: &StepConfig,
 ) -> CcdPoseClampOutcome {
-    let snapshots = collect_snapshots(world, previous_body_poses);
+    if !config.ccd.mode.sweeps() {
+        return CcdPoseClampOutcome::default();
+    }
+    match config.ccd.resolution {
+        CcdResolution::PoseClamp => run_pose_clamp_phase(world, previous_body_poses, &config.ccd),
+        CcdResolution::SubStep => run_toi_substep_phase(world, previous_body_poses, config),
+    }
+}
+
+fn run_pose_clamp_phase(
+    world: &mut World,
+    previous_body_poses: &BTreeMap<BodyHandle, Pose>,
+    params: &CcdParams,
+) -> CcdPoseClampOutcome {
+    let mut stats = CcdPoseClampStats::default();
+    let hits = collect_hits(world, previous_body_poses, params, &mut stats);
+    let traces = clamp_hits(world, hits, params, &mut stats);
+    CcdPoseClampOutcome { stats, traces }
+}
+
+/// Resolves CCD hits in time order instead of clamping every hit body.
+///
+/// Each pass re-sweeps the rest of the step, advances every body to the
+/// earliest time of impact, resolves every hit at that instant with a short
+/// normal-impulse solve, and integrates the hit bodies over the remaining
+/// time. A collider pair is resolved at most once per step. Hits left once the
+/// step budget is spent, or that involve a body which used up its own budget,
+/// are pose-clamped instead.
+fn run_toi_substep_phase(
+    world: &mut World,
+    previous_body_poses: &BTreeMap<BodyHandle, Pose>,
+    config: &StepConfig,
+) -> CcdPoseClampOutcome {
+    let params = &config.ccd;
+    let mut sweep_starts = previous_body_poses.clone();
+    let mut elapsed: FloatNum = 0.0;
+    let mut body_substeps = BTreeMap::<BodyHandle, u32>::new();
+    let mut resolved_pairs = BTreeSet::new();
+    let mut stats = CcdPoseClampStats::default();
+    let mut traces = Vec::new();
+    let mut hit_pairs = BTreeSet::new();
+    let mut first_pass = true;
+    loop {
+        // Candidate and miss counts come from the first, full-step sweep.
+        // Re-sweeps only count pairs they newly find hitting, which turns one
+        // of those misses into a hit, so each pair is counted once per step.
+        let mut pass_stats = CcdPoseClampStats::default();
+        let mut hits = collect_hits(world, &sweep_starts, params, &mut pass_stats);
+        for hit in &hits {
+            if hit_pairs.insert((hit.moving_collider, hit.static_collider)) && !first_pass {
+                stats.hit_count += 1;
+                stats.miss_count = stats.miss_count.saturating_sub(1);
+            }
+        }
+        if first_pass {
+            stats.candidate_count = pass_stats.candidate_count;
+            stats.hit_count = pass_stats.hit_count;
+            stats.miss_count = pass_stats.miss_count;
+            first_pass = false;
+        }
+        hits.retain(|hit| !resolved_pairs.contains(&(hit.moving_collider, hit.static_collider)));
+        hits.sort_by(compare_hits);
+        let Some(&earliest) = hits.first() else {
+            break;
+        };
+        if stats.substep_count >= params.max_toi_substeps as usize {
+            stats.substep_fallback_count += hits.len();
+            traces.extend(clamp_hits(world, hits, params, &mut stats));
+            break;
+        }
+        // Hits at the same instant are resolved together; once one of them is
+        // resolved the others already touch and a later sweep cannot see them.
+        let has_budget =
+            |body| body_substeps.get(&body).copied().unwrap_or(0) < params.max_body_toi_substeps;
+        let (batch, over_budget): (Vec<_>, Vec<_>) = hits
+            .into_iter()
+            .take_while(|hit| hit.toi <= earliest.toi + params.toi_epsilon)
+            .partition(|hit| {
+                has_budget(hit.moving_body)
+                    && (hit.target_kind != CcdTargetKind::Dynamic || has_budget(hit.static_body))
+            });
+        resolved_pairs.extend(
+            batch
+                .iter()
+                .chain(&over_budget)
+                .map(|hit| (hit.moving_collider, hit.static_collider)),
+        );
+        if !over_budget.is_empty() {
+            stats.substep_fallback_count += over_budget.len();
+            traces.extend(clamp_hits(world, over_budget, params, &mut stats));
+        }
+        let advancement = hit_advancement(&earliest, params);
+        let resolved = resolve_toi_substep(
+            world,
+            &batch,
+            advancement,
+            &mut sweep_starts,
+            &mut elapsed,
+            config,
+        );
+        if resolved.is_empty() {
+            continue;
+        }
+        stats.substep_count += 1;
+        let bodies = resolved
+            .iter()
+            .flat_map(|trace| {
+                let target =
+                    (trace.target_kind == CcdTargetKind::Dynamic).then_some(trace.static_body);
+                std::iter::once(trace.moving_body).chain(target)
+            })
+            .collect::<BTreeSet<_>>();
+        for body in bodies {
+            *body_substeps.entry(body).or_default() += 1;
+        }
+        traces.extend(resolved);
+    }
+    CcdPoseClampOutcome { stats, traces }
+}
+
+/// Sweeps every collider from `sweep_starts` to its current pose and returns
+/// the unsorted hits, adding candidate, hit, and miss counts to `stats`.
+fn collect_hits(
+    world: &World,
+    sweep_starts: &BTreeMap<BodyHandle, Pose>,
+    params: &CcdParams,
+    stats: &mut CcdPoseClampStats,
+) -> Vec<CcdHit> {
+    let snapshots = collect_snapshots(world, sweep_starts);
     let mut moving_circles = Vec::with_capacity(snapshots.len());
     let mut moving_convexes = Vec::with_capacity(snapshots.len());
+    let mut dynamic_circles = Vec::with_capacity(snapshots.len());
     let mut dynamic_convex_targets = Vec::with_capacity(snapshots.len());
+    let mut kinematic_convexes = Vec::new();
     let mut static_convexes = Vec::with_capacity(snapshots.len());
+    let mut rigid_sweeps = Vec::with_capacity(snapshots.len());
     for snapshot in &snapshots {
-        if let Some(moving) = moving_circle(snapshot) {
-            moving_circles.push(moving);
+        if let Some(sweep) = rigid_sweep(snapshot, params) {
+            rigid_sweeps.push(sweep);
         }
-        if let Some(target) = dynamic_convex_target(snapshot) {
+        if let Some(circle) = dynamic_circle(snapshot) {
+            if circle
+                .ccd
+                .sweeps((circle.end - circle.start).length(), params)
+            {
+                moving_circles.push(circle);
+            }
+            dynamic_circles.push(circle);
+        }
+        if let Some(target) = kinematic_convex(snapshot, params) {
+            kinematic_convexes.push(target);
+        }
+        if let Some(target) = dynamic_convex_target(snapshot, params) {
             dynamic_convex_targets.push(target);
         }
-        if let Some(moving) = moving_convex(snapshot) {
+        if let Some(moving) = moving_convex(snapshot, params) {
             moving_convexes.push(moving);
         }
         if let Some(target) = static_convex(snapshot) {
             static_convexes.push(target);
         }
     }
-    let mut stats = CcdPoseClampStats::default();
+    let candidate_count = stats.candidate_count;
     let mut hits = Vec::with_capacity(
         moving_circles
             .len()
@@ -162,9 +412,13 @@ pub(crate) fn run_pose_clamp_phase(
             }
             stats.candidate_count += 1;
             let sweep = moving.end - moving.start;
-            if let Some(hit) =
-                swept_circle_convex_toi(moving.start, moving.end, moving.radius, &target.vertices)
-            {
+            if let Some(hit) = swept_circle_convex_toi(
+                moving.start,
+                moving.end,
+                moving.radius,
+                &target.vertices,
+                params,
+            ) {
                 hits.push(CcdHit {
                     moving_body: moving.body,
                     static_body: target.body,
@@ -180,6 +434,8 @@ pub(crate) fn run_pose_clamp_phase(
                     slop_sweep_length: sweep.length(),
                     toi_point: moving.start + (moving.end - moving.start) * hit.toi
                         - hit.normal * moving.radius,
+                    normal: hit.normal,
+                    rigid: None,
                 });
             }
         }
@@ -201,7 +457,7 @@ pub(crate) fn run_pose_clamp_phase(
             stats.candidate_count += 1;
             let sweep = moving.end - moving.start;
             if let Some(hit) =
-                swept_convex_convex_toi(&moving.start_vertices, sweep, &target.vertices)
+                swept_convex_convex_toi(&moving.start_vertices, sweep, &target.vertices, params)
             {
                 hits.push(CcdHit {
                     moving_body: moving.body,
@@ -217,6 +473,8 @@ pub(crate) fn run_pose_clamp_phase(
                     exit: hit.exit,
                     slop_sweep_length: sweep.length(),
                     toi_point: hit.toi_point,
+                    normal: hit.normal,
+                    rigid: None,
                 });
             }
         }
@@ -226,7 +484,13 @@ pub(crate) fn run_pose_clamp_phase(
             if moving.body == target.body || moving.is_sensor || target.is_sensor {
                 continue;
             }
-            if dynamic_convex_is_moving(target)
+            if !target.ccd.active() && !moving.ccd.bullet {
+                continue;
+            }
+            // A target that sweeps itself tests this pair from its own side.
+            if target
+                .ccd
+                .sweeps((target.end - target.start).length(), params)
                 && (moving.body, moving.collider) > (target.body, target.collider)
             {
                 continue;
@@ -245,6 +509,7 @@ pub(crate) fn run_pose_clamp_phase(
                 &moving.start_vertices,
                 relative_sweep,
                 &target.start_vertices,
+                params,
             ) {
                 hits.push(CcdHit {
                     moving_body: moving.body,
@@ -260,15 +525,191 @@ pub(crate) fn run_pose_clamp_phase(
                     exit: hit.exit,
                     slop_sweep_length: relative_sweep.length(),
                     toi_point: hit.toi_point + target_sweep * hit.toi,
+                    normal: hit.normal,
+                    rigid: None,
+                });
+            }
+        }
+    }
+    // Kinematic sweeps are tested against every dynamic collider, including
+    // resting ones, because the kinematic body can run into them. Only the
+    // dynamic side is clamped; it is placed where the contact starts in the
+    // target's frame while the kinematic body keeps its driven pose.
+    for target in &kinematic_convexes {
+        if target.is_sensor {
+            continue;
+        }
+        let target_sweep = target.end - target.start;
+        for moving in &dynamic_circles {
+            if moving.body == target.body || moving.is_sensor {
+                continue;
+            }
+            if !moving.ccd.active() && !target.ccd.bullet {
+                continue;
+            }
+            if !moving.filter.allows(&target.filter) {
+                continue;
+            }
+            if !aabb_overlaps(moving.swept_aabb, target.swept_aabb) {
+                continue;
+            }
+            stats.candidate_count += 1;
+            let relative_sweep = (moving.end - moving.start) - target_sweep;
+            if let Some(hit) = swept_circle_convex_toi(
+                moving.start,
+                moving.start + relative_sweep,
+                moving.radius,
+                &target.start_vertices,
+                params,
+            ) {
+                hits.push(CcdHit {
+                    moving_body: moving.body,
+                    static_body: target.body,
+                    moving_collider: moving.collider,
+                    static_collider: target.collider,
+                    target_kind: CcdTargetKind::Kinematic,
+                    swept_start: moving.start,
+                    swept_end: moving.end,
+                    target_swept_start: target.start,
+                    target_swept_end: target.end,
+                    toi: hit.toi,
+                    exit: hit.exit,
+                    slop_sweep_length: relative_sweep.length(),
+                    toi_point: moving.start + (moving.end - moving.start) * hit.toi
+                        - hit.normal * moving.radius,
+                    normal: hit.normal,
+                    rigid: None,
+                });
+            }
+        }
+        for moving in &dynamic_convex_targets {
+            if moving.body == target.body || moving.is_sensor {
+                continue;
+            }
+            if !moving.ccd.active() && !target.ccd.bullet {
+                continue;
+            }
+            if !moving.filter.allows(&target.filter) {
+                continue;
+            }
+            if !aabb_overlaps(moving.swept_aabb, target.swept_aabb) {
+                continue;
+            }
+            stats.candidate_count += 1;
+            let relative_sweep = (moving.end - moving.start) - target_sweep;
+            if let Some(hit) = swept_convex_convex_toi(
+                &moving.start_vertices,
+                relative_sweep,
+                &target.start_vertices,
+                params,
+            ) {
+                hits.push(CcdHit {
+                    moving_body: moving.body,
+                    static_body: target.body,
+                    moving_collider: moving.collider,
+                    static_collider: target.collider,
+                    target_kind: CcdTargetKind::Kinematic,
+                    swept_start: moving.start,
+                    swept_end: moving.end,
+                    target_swept_start: target.start,
+                    target_swept_end: target.end,
+                    toi: hit.toi,
+                    exit: hit.exit,
+                    slop_sweep_length: relative_sweep.length(),
+                    toi_point: hit.toi_point + target_sweep * hit.toi,
+                    normal: hit.normal,
+                    rigid: None,
+                });
+            }
+        }
+    }
+    // Rotating colliders are left out of the translational casts above. Their
+    // pairs advance conservatively over the full rigid motion instead, which
+    // also covers translating or resting dynamic bodies they run into.
+    for moving in &rigid_sweeps {
+        if moving.is_sensor || !moving.body_type.is_dynamic() {
+            continue;
+        }
+        if !moving.ccd.sweeps(moving.motion_bound(), params) {
+            continue;
+        }
+        for target in &rigid_sweeps {
+            if moving.body == target.body || target.is_sensor {
+                continue;
+            }
+            let target_kind = match target.body_type {
+                BodyType::Static if moving.rotates(params) => CcdTargetKind::Static,
+                BodyType::Dynamic if moving.rotates(params) || target.rotates(params) => {
+                    if !target.ccd.active() && !moving.ccd.bullet {
+                        continue;
+                    }
+                    // Let the lower handle own pairs both sides would sweep.
+                    let target_sweeps = target.ccd.sweeps(target.motion_bound(), params)
+                        && (moving.ccd.active() || target.ccd.bullet);
+                    if target_sweeps
+                        && (moving.body, moving.collider) > (target.body, target.collider)
+                    {
+                        continue;
+                    }
+                    CcdTargetKind::Dynamic
+                }
+                _ => continue,
+            };
+            if !moving.filter.allows(&target.filter) {
+                continue;
+            }
+            if !aabb_overlaps(moving.swept_aabb, target.swept_aabb) {
+                continue;
+            }
+            let motion_bound = (target.linear_sweep() - moving.linear_sweep()).length()
+                + moving.angular_sweep().abs() * moving.reach
+                + target.angular_sweep().abs() * target.reach;
+            // Tunneling needs relative motion on the order of the thinner
+            // collider's width; slower pairs are left to the narrowphase.
+            if motion_bound <= moving.thickness.min(target.thickness) * 0.5 {
+                continue;
+            }
+            stats.candidate_count += 1;
+            if let Some(hit) = conservative_advancement_toi(moving, target, params) {
+                hits.push(CcdHit {
+                    moving_body: moving.body,
+                    static_body: target.body,
+                    moving_collider: moving.collider,
+                    static_collider: target.collider,
+                    target_kind,
+                    swept_start: moving.start,
+                    swept_end: moving.end,
+                    target_swept_start: target.start,
+                    target_swept_end: target.end,
+                    toi: hit.toi,
+                    exit: 1.0,
+                    slop_sweep_length: motion_bound,
+                    toi_point: hit.point,
+                    normal: -hit.normal,
+                    rigid: Some(RigidHitPoses {
+                        moving_start: moving.start_body_pose,
+                        moving_end: moving.end_body_pose,
+                        target_start: target.start_body_pose,
+                        target_end: target.end_body_pose,
+                    }),
                 });
             }
         }
     }
 
-    stats.hit_count = hits.len();
-    stats.miss_count = stats.candidate_count.saturating_sub(stats.hit_count);
-    hits.sort_by(compare_hits);
+    stats.hit_count += hits.len();
+    stats.miss_count += (stats.candidate_count - candidate_count).saturating_sub(hits.len());
+    hits
+}
 
+/// Clamps hits in time order, at most once per body, and returns their traces.
+fn clamp_hits(
+    world: &mut World,
+    mut hits: Vec<CcdHit>,
+    params: &CcdParams,
+    stats: &mut CcdPoseClampStats,
+) -> Vec<CcdTrace> {
+    hits.sort_by(compare_hits);
     let mut clamped_bodies = BTreeSet::new();
     let mut traces = Vec::new();
     for hit in hits {
@@ -278,7 +719,7 @@ pub(crate) fn run_pose_clamp_phase(
         {
             continue;
         }
-        if let Some((trace, clamp_count)) = clamp_hit_to_toi(world, hit) {
+        if let Some((trace, clamp_count)) = clamp_hit_to_toi(world, hit, params) {
             clamped_bodies.insert(trace.moving_body);
             if trace.target_kind == CcdTargetKind::Dynamic {
                 clamped_bodies.insert(trace.static_body);
@@ -287,8 +728,245 @@ pub(crate) fn run_pose_clamp_phase(
             traces.push(trace);
         }
     }
+    traces
+}
 
-    CcdPoseClampOutcome { stats, traces }
+/// Normal-impulse row for one hit inside a time-of-impact sub-step.
+#[derive(Clone, Copy, Debug)]
+struct SubstepContact {
+    moving_body: BodyHandle,
+    target_body: BodyHandle,
+    target_is_dynamic: bool,
+    normal: Vector,
+    moving_anchor: Vector,
+    target_anchor: Vector,
+    inverse_effective_mass: FloatNum,
+    /// Separating speed restitution asks for after the impact.
+    bounce_speed: FloatNum,
+    impulse: FloatNum,
+}
+
+/// Advances the world to `advancement` and resolves the hits found there.
+///
+/// Every body moves to its pose at that fraction of the remaining sweep,
+/// which becomes the start of its next sweep. The hits then get
+/// accumulated normal impulses over `velocity_iterations` passes, so stacked
+/// simultaneous hits share the response, and each hit body is integrated from
+/// its impact pose over the rest of the step.
+fn resolve_toi_substep(
+    world: &mut World,
+    hits: &[CcdHit],
+    advancement: FloatNum,
+    sweep_starts: &mut BTreeMap<BodyHandle, Pose>,
+    elapsed: &mut FloatNum,
+    config: &StepConfig,
+) -> Vec<CcdTrace> {
+    let hits = hits
+        .iter()
+        .copied()
+        .filter(|hit| {
+            let is_dynamic = |body| {
+                world
+                    .body_record(body)
+                    .is_ok_and(|record| record.body_type.is_dynamic())
+            };
+            is_dynamic(hit.moving_body)
+                && (hit.target_kind != CcdTargetKind::Dynamic || is_dynamic(hit.static_body))
+        })
+        .collect::<Vec<_>>();
+    if hits.is_empty() {
+        return Vec::new();
+    }
+    let involved = hits
+        .iter()
+        .flat_map(|hit| {
+            let target = (hit.target_kind == CcdTargetKind::Dynamic).then_some(hit.static_body);
+            std::iter::once(hit.moving_body).chain(target)
+        })
+        .collect::<BTreeSet<_>>();
+    let end_poses = involved
+        .iter()
+        .filter_map(|body| Some((*body, world.body_record(*body).ok()?.pose)))
+        .collect::<BTreeMap<_, _>>();
+
+    for (handle, start) in sweep_starts.iter_mut() {
+        if let Ok(record) = world.body_record(*handle) {
+            *start = start.lerp(record.pose, advancement);
+        }
+    }
+    *elapsed += (1.0 - *elapsed) * advancement;
+    let remaining_dt = config.dt * (1.0 - *elapsed);
+    for body in &involved {
+        if let (Some(pose), Ok(record)) = (sweep_starts.get(body), world.body_record_mut(*body)) {
+            record.pose = *pose;
+        }
+    }
+
+    let mut contacts = hits
+        .iter()
+        .filter_map(|hit| substep_contact(world, hit, config))
+        .collect::<Vec<_>>();
+    for _ in 0..config.velocity_iterations.max(1) {
+        for contact in &mut contacts {
+            solve_substep_contact(world, contact);
+        }
+    }
+
+    for body in &involved {
+        if let Ok(record) = world.body_record_mut(*body) {
+            record.pose = translated_pose(
+                record.pose,
+                record.linear_velocity * remaining_dt,
+                record.angular_velocity * remaining_dt,
+            );
+            record.sleeping = false;
+            record.sleep_idle_time = 0.0;
+        }
+    }
+
+    let shift = |world: &World, body: BodyHandle| {
+        let end = end_poses.get(&body)?;
+        let pose = world.body_record(body).ok()?.pose;
+        Some((end.translation() - pose.translation()).length())
+    };
+    hits.iter()
+        .map(|hit| CcdTrace {
+            moving_body: hit.moving_body,
+            static_body: hit.static_body,
+            moving_collider: hit.moving_collider,
+            static_collider: hit.static_collider,
+            target_kind: hit.target_kind,
+            swept_start: hit.swept_start,
+            swept_end: hit.swept_end,
+            target_swept_start: hit.target_swept_start,
+            target_swept_end: hit.target_swept_end,
+            toi: hit.toi,
+            advancement,
+            clamp: shift(world, hit.moving_body).unwrap_or(0.0),
+            target_clamp: if hit.target_kind == CcdTargetKind::Dynamic {
+                shift(world, hit.static_body).unwrap_or(0.0)
+            } else {
+                0.0
+            },
+            slop: config.ccd.clamp_slop,
+            toi_point: hit.toi_point,
+            method: hit_method(hit),
+            angular_sweep: hit.rigid.map_or(0.0, |poses| {
+                poses.moving_end.angle() - poses.moving_start.angle()
+            }),
+            target_angular_sweep: hit.rigid.map_or(0.0, |poses| {
+                poses.target_end.angle() - poses.target_start.angle()
+            }),
+            resolution: CcdResolution::SubStep,
+        })
+        .collect()
+}
+
+fn substep_contact(world: &World, hit: &CcdHit, config: &StepConfig) -> Option<SubstepContact> {
+    let moving = world.body_record(hit.moving_body).ok()?;
+    let target = world.body_record(hit.static_body).ok()?;
+    let target_is_dynamic = hit.target_kind == CcdTargetKind::Dynamic;
+    let material = combine_materials(
+        world.collider_record(hit.moving_collider).ok()?.material,
+        world.collider_record(hit.static_collider).ok()?.material,
+    );
+    let moving_anchor = hit.toi_point
+        - moving
+            .pose
+            .transform_point(moving.mass_properties.local_center_of_mass);
+    let target_anchor = hit.toi_point
+        - target
+            .pose
+            .transform_point(target.mass_properties.local_center_of_mass);
+    let moving_arm = moving_anchor.cross(hit.normal);
+    let target_arm = target_anchor.cross(hit.normal);
+    let mut inverse_effective_mass = inverse_mass_along(moving.solver_inverse_mass(), hit.normal)
+        + moving.solver_inverse_inertia() * moving_arm * moving_arm;
+    if target_is_dynamic {
+        inverse_effective_mass += inverse_mass_along(target.solver_inverse_mass(), hit.normal)
+            + target.solver_inverse_inertia() * target_arm * target_arm;
+    }
+    if inverse_effective_mass <= config.ccd.toi_epsilon {
+        return None;
+    }
+    let normal_speed = (point_velocity(moving, moving_anchor)
+        - point_velocity(target, target_anchor))
+    .dot(hit.normal);
+    let bounce_speed = if -normal_speed > config.restitution_velocity_threshold {
+        -normal_speed * material.restitution
+    } else {
+        0.0
+    };
+    Some(SubstepContact {
+        moving_body: hit.moving_body,
+        target_body: hit.static_body,
+        target_is_dynamic,
+        normal: hit.normal,
+        moving_anchor,
+        target_anchor,
+        inverse_effective_mass,
+        bounce_speed,
+        impulse: 0.0,
+    })
+}
+
+fn solve_substep_contact(world: &mut World, contact: &mut SubstepContact) {
+    let (Ok(moving), Ok(target)) = (
+        world.body_record(contact.moving_body),
+        world.body_record(contact.target_body),
+    ) else {
+        return;
+    };
+    let normal_speed = (point_velocity(moving, contact.moving_anchor)
+        - point_velocity(target, contact.target_anchor))
+    .dot(contact.normal);
+    let accumulated = (contact.impulse
+        + (contact.bounce_speed - normal_speed) / contact.inverse_effective_mass)
+        .max(0.0);
+    let impulse = contact.normal * (accumulated - contact.impulse);
+    contact.impulse = accumulated;
+
+    if let Ok(record) = world.body_record_mut(contact.moving_body) {
+        record.linear_velocity += linear_response(record.solver_inverse_mass(), impulse);
+        record.angular_velocity -=
+            contact.moving_anchor.cross(impulse) * record.solver_inverse_inertia();
+    }
+    if contact.target_is_dynamic {
+        if let Ok(record) = world.body_record_mut(contact.target_body) {
+            record.linear_velocity -= linear_response(record.solver_inverse_mass(), impulse);
+            record.angular_velocity +=
+                contact.target_anchor.cross(impulse) * record.solver_inverse_inertia();
+        }
+    }
+}
+
+/// Velocity of the body point at `anchor` from its center of mass.
+fn point_velocity(record: &BodyRecord, anchor: Vector) -> Vector {
+    record.linear_velocity + angular_point_velocity(record.angular_velocity, anchor)
+}
+
+/// Fraction of the sweep a hit body advances to: the time of impact plus
+/// `clamp_slop`, kept well inside the overlap interval.
+fn hit_advancement(hit: &CcdHit, params: &CcdParams) -> FloatNum {
+    let slop_fraction = if hit.slop_sweep_length > params.toi_epsilon {
+        params.clamp_slop / hit.slop_sweep_length
+    } else {
+        0.0
+    };
+    let safe_exit = if hit.exit > hit.toi {
+        hit.toi + (hit.exit - hit.toi) * 0.5
+    } else {
+        hit.toi
+    };
+    (hit.toi + slop_fraction).min(safe_exit).clamp(hit.toi, 1.0)
+}
+
+fn hit_method(hit: &CcdHit) -> CcdMethod {
+    if hit.rigid.is_some() {
+        CcdMethod::ConservativeAdvancement
+    } else {
+        CcdMethod::ShapeCast
+    }
 }
 
 fn collect_snapshots(
@@ -316,6 +994,9 @@ fn collect_snapshots(
                 body: collider.body,
                 body_type: body.body_type,
                 shape: collider.shape.clone(),
+                local_pose: collider.local_pose,
+                start_body_pose,
+                end_body_pose: body.pose,
                 start_pose,
                 end_pose,
                 aabb: end_geometry.aabb,
@@ -323,12 +1004,89 @@ fn collect_snapshots(
                 end_convex_vertices: end_geometry.convex_vertices,
                 filter: collider.filter,
                 is_sensor: collider.is_sensor,
+                ccd: BodyCcd {
+                    enabled: body.ccd_enabled,
+                    bullet: body.bullet,
+                    motion_threshold: body.ccd_motion_threshold,
+                },
             })
         })
         .collect()
 }
 
-fn moving_circle(snapshot: &CcdColliderSnapshot) -> Option<MovingCircle> {
+fn rigid_sweep(snapshot: &CcdColliderSnapshot, params: &CcdParams) -> Option<RigidSweep> {
+    let reach = match snapshot.shape {
+        SharedShape::Circle { radius } => snapshot.local_pose.translation().length() + radius,
+        SharedShape::Rect { .. }
+        | SharedShape::RegularPolygon { .. }
+        | SharedShape::ConvexPolygon { .. }
+        | SharedShape::Segment { .. } => snapshot
+            .shape
+            .world_vertices(snapshot.local_pose)
+            .into_iter()
+            .map(|point| Vector::from(point).length())
+            .fold(0.0, FloatNum::max),
+        SharedShape::ConcavePolygon { .. } => return None,
+    };
+    let start_origin = snapshot.start_body_pose.point();
+    let end_origin = snapshot.end_body_pose.point();
+    if !reach.is_finite()
+        || !point_is_finite(start_origin)
+        || !point_is_finite(end_origin)
+        || !snapshot.start_body_pose.angle().is_finite()
+        || !snapshot.end_body_pose.angle().is_finite()
+    {
+        return None;
+    }
+    let thickness = match snapshot.shape {
+        SharedShape::Circle { radius } => radius * 2.0,
+        _ => {
+            let vertices = snapshot.shape.world_vertices(Pose::default());
+            polygon_axes(&vertices, params)
+                .into_iter()
+                .filter_map(|axis| project_points(&vertices, axis))
+                .map(|projection| projection.max - projection.min)
+                .fold(FloatNum::INFINITY, FloatNum::min)
+        }
+    };
+    let mut sweep = RigidSweep {
+        body: snapshot.body,
+        collider: snapshot.handle,
+        body_type: snapshot.body_type,
+        shape: snapshot.shape.clone(),
+        local_pose: snapshot.local_pose,
+        start_body_pose: snapshot.start_body_pose,
+        end_body_pose: snapshot.end_body_pose,
+        start: snapshot.start_pose.point(),
+        end: snapshot.end_pose.point(),
+        reach,
+        thickness: if thickness.is_finite() {
+            thickness
+        } else {
+            0.0
+        },
+        swept_aabb: snapshot.aabb,
+        filter: snapshot.filter,
+        is_sensor: snapshot.is_sensor,
+        ccd: snapshot.ccd,
+    };
+    if sweep.angular_sweep().abs() > params.toi_epsilon {
+        // Every collider point stays within `reach` of the body origin, whose
+        // path is the straight segment between the two poses.
+        sweep.swept_aabb = swept_circle_aabb(start_origin, end_origin, reach);
+    } else if start_origin != end_origin {
+        let start_aabb = snapshot.shape.aabb(snapshot.start_pose);
+        sweep.swept_aabb = swept_points_aabb([
+            start_aabb.min,
+            start_aabb.max,
+            snapshot.aabb.min,
+            snapshot.aabb.max,
+        ]);
+    }
+    Some(sweep)
+}
+
+fn dynamic_circle(snapshot: &CcdColliderSnapshot) -> Option<MovingCircle> {
     let SharedShape::Circle { radius } = snapshot.shape else {
         return None;
     };
@@ -340,10 +1098,6 @@ fn moving_circle(snapshot: &CcdColliderSnapshot) -> Option<MovingCircle> {
     if !point_is_finite(start) || !point_is_finite(end) {
         return None;
     }
-    let sweep = end - start;
-    if sweep.length() <= CCD_TOI_EPSILON {
-        return None;
-    }
     Some(MovingCircle {
         body: snapshot.body,
         collider: snapshot.handle,
@@ -353,13 +1107,16 @@ fn moving_circle(snapshot: &CcdColliderSnapshot) -> Option<MovingCircle> {
         swept_aabb: swept_circle_aabb(start, end, radius),
         filter: snapshot.filter,
         is_sensor: snapshot.is_sensor,
+        ccd: snapshot.ccd,
     })
 }
 
-fn moving_convex(snapshot: &CcdColliderSnapshot) -> Option<MovingConvex> {
-    let target = dynamic_convex_target(snapshot)?;
-    let sweep = target.end - target.start;
-    if sweep.length() <= CCD_TOI_EPSILON {
+fn moving_convex(snapshot: &CcdColliderSnapshot, params: &CcdParams) -> Option<MovingConvex> {
+    let target = dynamic_convex_target(snapshot, params)?;
+    if !target
+        .ccd
+        .sweeps((target.end - target.start).length(), params)
+    {
         return None;
     }
     Some(MovingConvex {
@@ -371,19 +1128,38 @@ fn moving_convex(snapshot: &CcdColliderSnapshot) -> Option<MovingConvex> {
         swept_aabb: target.swept_aabb,
         filter: target.filter,
         is_sensor: target.is_sensor,
+        ccd: target.ccd,
     })
 }
 
-fn dynamic_convex_target(snapshot: &CcdColliderSnapshot) -> Option<DynamicConvexTarget> {
+fn dynamic_convex_target(
+    snapshot: &CcdColliderSnapshot,
+    params: &CcdParams,
+) -> Option<SweptConvex> {
     if !snapshot.body_type.is_dynamic() {
         return None;
     }
+    swept_convex(snapshot, params)
+}
+
+fn kinematic_convex(snapshot: &CcdColliderSnapshot, params: &CcdParams) -> Option<SweptConvex> {
+    if !snapshot.body_type.is_kinematic() {
+        return None;
+    }
+    swept_convex(snapshot, params).filter(|target| {
+        target
+            .ccd
+            .sweeps((target.end - target.start).length(), params)
+    })
+}
+
+fn swept_convex(snapshot: &CcdColliderSnapshot, params: &CcdParams) -> Option<SweptConvex> {
     if matches!(snapshot.shape, SharedShape::Circle { .. }) {
         return None;
     }
     // This first M13 slice is a translational convex shape cast. Rotational CCD
     // needs a wider angular sweep bound so it stays outside this minimal path.
-    if (snapshot.start_pose.angle() - snapshot.end_pose.angle()).abs() > CCD_TOI_EPSILON {
+    if (snapshot.start_pose.angle() - snapshot.end_pose.angle()).abs() > params.toi_epsilon {
         return None;
     }
     let start_vertices = convex_shape_vertices(
@@ -410,7 +1186,7 @@ fn dynamic_convex_target(snapshot: &CcdColliderSnapshot) -> Option<DynamicConvex
         return None;
     }
     let swept_aabb = swept_points_aabb(start_vertices.iter().chain(end_vertices.iter()).copied());
-    Some(DynamicConvexTarget {
+    Some(SweptConvex {
         body: snapshot.body,
         collider: snapshot.handle,
         start,
@@ -419,13 +1195,10 @@ fn dynamic_convex_target(snapshot: &CcdColliderSnapshot) -> Option<DynamicConvex
         swept_aabb,
         filter: snapshot.filter,
         is_sensor: snapshot.is_sensor,
+        ccd: snapshot.ccd,
     })
 }
 
-fn dynamic_convex_is_moving(target: &DynamicConvexTarget) -> bool {
-    (target.end - target.start).length() > CCD_TOI_EPSILON
-}
-
 fn static_convex(snapshot: &CcdColliderSnapshot) -> Option<StaticConvex> {
     if snapshot.body_type != BodyType::Static {
         return None;
@@ -496,31 +1269,32 @@ fn aabb_overlaps(a: ShapeAabb, b: ShapeAabb) -> bool {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-struct SweptCircleConvexToi {
-    toi: FloatNum,
+pub(crate) struct SweptCircleConvexToi {
+    pub(crate) toi: FloatNum,
     exit: FloatNum,
-    normal: Vector,
+    pub(crate) normal: Vector,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-struct SweptConvexConvexToi {
-    toi: FloatNum,
+pub(crate) struct SweptConvexConvexToi {
+    pub(crate) toi: FloatNum,
     exit: FloatNum,
-    normal: Vector,
-    toi_point: Point,
+    pub(crate) normal: Vector,
+    pub(crate) toi_point: Point,
 }
 
-fn swept_circle_convex_toi(
+pub(crate) fn swept_circle_convex_toi(
     start: Point,
     end: Point,
     radius: FloatNum,
     vertices: &[Point],
+    params: &CcdParams,
 ) -> Option<SweptCircleConvexToi> {
     if vertices.len() < 3 || radius <= 0.0 || !radius.is_finite() {
         return None;
     }
     let sweep = end - start;
-    if sweep.length() <= CCD_TOI_EPSILON {
+    if sweep.length() <= params.toi_epsilon {
         return None;
     }
 
@@ -530,7 +1304,7 @@ fn swept_circle_convex_toi(
 
     for edge_index in 0..vertices.len() {
         let normal = edge_outward_normal(vertices, edge_index);
-        if normal.length() <= CCD_TOI_EPSILON {
+        if normal.length() <= params.toi_epsilon {
             return None;
         }
         // Treat the circle as a moving point against the static convex expanded
@@ -542,7 +1316,7 @@ fn swept_circle_convex_toi(
             return None;
         }
         let denominator = start_distance - end_distance;
-        if denominator.abs() <= CCD_TOI_EPSILON {
+        if denominator.abs() <= params.toi_epsilon {
             continue;
         }
         let t = start_distance / denominator;
@@ -559,10 +1333,10 @@ fn swept_circle_convex_toi(
         }
     }
 
-    if !(CCD_TOI_EPSILON..=1.0).contains(&enter) || exit < 0.0 {
+    if !(params.toi_epsilon..=1.0).contains(&enter) || exit < 0.0 {
         return None;
     }
-    if enter_normal.length() <= CCD_TOI_EPSILON {
+    if enter_normal.length() <= params.toi_epsilon {
         return None;
     }
 
@@ -571,7 +1345,7 @@ fn swept_circle_convex_toi(
     // arcs. Bracket the first real circle-vs-convex overlap inside that
     // interval, then bisect the actual distance predicate.
     let (toi, normal) =
-        refine_actual_circle_convex_toi(start, sweep, radius, vertices, enter, exit)?;
+        refine_actual_circle_convex_toi(start, sweep, radius, vertices, enter, exit, params)?;
 
     Some(SweptCircleConvexToi {
         toi,
@@ -580,14 +1354,15 @@ fn swept_circle_convex_toi(
     })
 }
 
-fn swept_convex_convex_toi(
+pub(crate) fn swept_convex_convex_toi(
     moving_start_vertices: &[Point],
     sweep: Vector,
     static_vertices: &[Point],
+    params: &CcdParams,
 ) -> Option<SweptConvexConvexToi> {
     if moving_start_vertices.len() < 3
         || static_vertices.len() < 3
-        || sweep.length() <= CCD_TOI_EPSILON
+        || sweep.length() <= params.toi_epsilon
     {
         return None;
     }
@@ -595,15 +1370,15 @@ fn swept_convex_convex_toi(
     let mut enter = 0.0;
     let mut exit = 1.0;
     let mut enter_normal = Vector::default();
-    let axes = polygon_axes(moving_start_vertices)
+    let axes = polygon_axes(moving_start_vertices, params)
         .into_iter()
-        .chain(polygon_axes(static_vertices));
+        .chain(polygon_axes(static_vertices, params));
 
     for axis in axes {
         let moving = project_points(moving_start_vertices, axis)?;
         let static_projection = project_points(static_vertices, axis)?;
         let velocity = sweep.dot(axis);
-        if velocity.abs() <= CCD_TOI_EPSILON {
+        if velocity.abs() <= params.toi_epsilon {
             if moving.max < static_projection.min || static_projection.max < moving.min {
                 return None;
             }
@@ -635,10 +1410,10 @@ fn swept_convex_convex_toi(
         }
     }
 
-    if !(CCD_TOI_EPSILON..=1.0).contains(&enter) || exit < 0.0 {
+    if !(params.toi_epsilon..=1.0).contains(&enter) || exit < 0.0 {
         return None;
     }
-    if enter_normal.length() <= CCD_TOI_EPSILON {
+    if enter_normal.length() <= params.toi_epsilon {
         return None;
     }
 
@@ -646,7 +1421,8 @@ fn swept_convex_convex_toi(
         .iter()
         .map(|point| *point + sweep * enter)
         .collect::<Vec<_>>();
-    let toi_point = support_feature_contact_point(&toi_vertices, static_vertices, enter_normal)?;
+    let toi_point =
+        support_feature_contact_point(&toi_vertices, static_vertices, enter_normal, params)?;
 
     Some(SweptConvexConvexToi {
         toi: enter.clamp(0.0, 1.0),
@@ -676,11 +1452,11 @@ fn project_points(points: &[Point], axis: Vector) -> Option<Projection> {
     (min.is_finite() && max.is_finite()).then_some(Projection { min, max })
 }
 
-fn polygon_axes(vertices: &[Point]) -> Vec<Vector> {
+fn polygon_axes(vertices: &[Point], params: &CcdParams) -> Vec<Vector> {
     let mut axes = Vec::new();
     for edge_index in 0..vertices.len() {
         let normal = edge_outward_normal(vertices, edge_index);
-        if normal.length() > CCD_TOI_EPSILON {
+        if normal.length() > params.toi_epsilon {
             axes.push(normal.normalized_or_zero());
         }
     }
@@ -691,17 +1467,18 @@ fn support_feature_contact_point(
     moving_vertices: &[Point],
     static_vertices: &[Point],
     normal: Vector,
+    params: &CcdParams,
 ) -> Option<Point> {
     let normal = normal.normalized_or_zero();
-    if normal.length() <= CCD_TOI_EPSILON {
+    if normal.length() <= params.toi_epsilon {
         return None;
     }
     let tangent = normal.perp().normalized_or_zero();
-    let moving = support_feature(moving_vertices, -normal, normal, tangent)?;
-    let target = support_feature(static_vertices, normal, normal, tangent)?;
+    let moving = support_feature(moving_vertices, -normal, normal, tangent, params)?;
+    let target = support_feature(static_vertices, normal, normal, tangent, params)?;
     let overlap_min = moving.tangent_min.max(target.tangent_min);
     let overlap_max = moving.tangent_max.min(target.tangent_max);
-    let tangent_coord = if overlap_min <= overlap_max + CCD_TOI_EPSILON {
+    let tangent_coord = if overlap_min <= overlap_max + params.toi_epsilon {
         (overlap_min + overlap_max) * 0.5
     } else {
         (moving.tangent_center() + target.tangent_center()) * 0.5
@@ -728,8 +1505,9 @@ fn support_feature(
     direction: Vector,
     normal: Vector,
     tangent: Vector,
+    params: &CcdParams,
 ) -> Option<SupportFeature> {
-    if direction.length() <= CCD_TOI_EPSILON {
+    if direction.length() <= params.toi_epsilon {
         return None;
     }
     let mut support_projection = FloatNum::NEG_INFINITY;
@@ -753,7 +1531,8 @@ fn support_feature(
         .copied()
         .filter(|point| point_is_finite(*point))
     {
-        if (Vector::from(point).dot(direction) - support_projection).abs() <= CLIP_SUPPORT_EPSILON {
+        if (Vector::from(point).dot(direction) - support_projection).abs() <= params.support_epsilon
+        {
             let tangent_coord = Vector::from(point).dot(tangent);
             tangent_min = tangent_min.min(tangent_coord);
             tangent_max = tangent_max.max(tangent_coord);
@@ -775,10 +1554,11 @@ fn refine_actual_circle_convex_toi(
     vertices: &[Point],
     enter: FloatNum,
     exit: FloatNum,
+    params: &CcdParams,
 ) -> Option<(FloatNum, Vector)> {
     let enter = enter.clamp(0.0, 1.0);
     let exit = exit.clamp(enter, 1.0);
-    if let Some(contact) = circle_convex_contact(start + sweep * enter, radius, vertices) {
+    if let Some(contact) = circle_convex_contact(start + sweep * enter, radius, vertices, params) {
         return Some((enter, contact.normal));
     }
 
@@ -787,7 +1567,7 @@ fn refine_actual_circle_convex_toi(
     let sample_count = 32;
     for sample in 1..=sample_count {
         let t = enter + (exit - enter) * (sample as FloatNum / sample_count as FloatNum);
-        if circle_convex_contact(start + sweep * t, radius, vertices).is_some() {
+        if circle_convex_contact(start + sweep * t, radius, vertices, params).is_some() {
             high = Some(t);
             break;
         }
@@ -797,13 +1577,13 @@ fn refine_actual_circle_convex_toi(
 
     for _ in 0..24 {
         let mid = (low + high) * 0.5;
-        if circle_convex_contact(start + sweep * mid, radius, vertices).is_some() {
+        if circle_convex_contact(start + sweep * mid, radius, vertices, params).is_some() {
             high = mid;
         } else {
             low = mid;
         }
     }
-    let contact = circle_convex_contact(start + sweep * high, radius, vertices)?;
+    let contact = circle_convex_contact(start + sweep * high, radius, vertices, params)?;
     Some((high, contact.normal))
 }
 
@@ -816,6 +1596,7 @@ fn circle_convex_contact(
     center: Point,
     radius: FloatNum,
     vertices: &[Point],
+    params: &CcdParams,
 ) -> Option<CircleConvexContact> {
     let mut inside = true;
     let mut deepest_inside = (FloatNum::NEG_INFINITY, Vector::default());
@@ -835,14 +1616,14 @@ fn circle_convex_contact(
         });
     }
 
-    let closest = closest_point_on_polygon(center, vertices)?;
+    let closest = closest_point_on_polygon(center, vertices, params)?;
     let offset = center - closest;
     let distance_squared = offset.length_squared();
     if distance_squared > radius * radius {
         return None;
     }
     let distance = distance_squared.sqrt();
-    let normal = if distance <= CCD_TOI_EPSILON {
+    let normal = if distance <= params.toi_epsilon {
         deepest_inside.1
     } else {
         offset / distance
@@ -852,12 +1633,12 @@ fn circle_convex_contact(
     })
 }
 
-fn closest_point_on_polygon(point: Point, vertices: &[Point]) -> Option<Point> {
+fn closest_point_on_polygon(point: Point, vertices: &[Point], params: &CcdParams) -> Option<Point> {
     let mut closest = None::<(Point, FloatNum)>;
     for edge_index in 0..vertices.len() {
         let start = vertices[edge_index];
         let end = vertices[(edge_index + 1) % vertices.len()];
-        let point_on_edge = closest_point_on_segment(point, start, end);
+        let point_on_edge = closest_point_on_segment(point, start, end, params);
         let distance_squared = (point - point_on_edge).length_squared();
         if closest
             .as_ref()
@@ -869,10 +1650,10 @@ fn closest_point_on_polygon(point: Point, vertices: &[Point]) -> Option<Point> {
     closest.map(|(point, _)| point)
 }
 
-fn closest_point_on_segment(point: Point, start: Point, end: Point) -> Point {
+fn closest_point_on_segment(point: Point, start: Point, end: Point, params: &CcdParams) -> Point {
     let segment = end - start;
     let length_squared = segment.length_squared();
-    let t = if length_squared <= CCD_TOI_EPSILON {
+    let t = if length_squared <= params.toi_epsilon {
         0.0
     } else {
         (point - start).dot(segment) / length_squared
@@ -910,13 +1691,83 @@ fn polygon_area(vertices: &[Point]) -> FloatNum {
     area * 0.5
 }
 
-fn clamp_hit_to_toi(world: &mut World, hit: CcdHit) -> Option<(CcdTrace, usize)> {
+/// Finds the first time two rigid sweeps come within the contact tolerance.
+///
+/// Each iteration measures the current gap with GJK and advances by the gap
+/// divided by an upper bound on the closing speed, so the sweep can never
+/// step past the first touch. Pairs that already touch or overlap at the
+/// start are resting contacts and stay with the narrowphase.
+fn conservative_advancement_toi(
+    moving: &RigidSweep,
+    target: &RigidSweep,
+    params: &CcdParams,
+) -> Option<AdvancementHit> {
+    let relative_sweep = target.linear_sweep() - moving.linear_sweep();
+    let angular_bound =
+        moving.angular_sweep().abs() * moving.reach + target.angular_sweep().abs() * target.reach;
+    let tolerance = (params.clamp_slop * 0.5).max(params.toi_epsilon);
+    let mut t: FloatNum = 0.0;
+    let mut last_separation = None;
+    for _ in 0..CONSERVATIVE_ADVANCEMENT_MAX_ITERATIONS {
+        let Some(separation) = gjk_separation(
+            &moving.shape,
+            moving.collider_pose_at(t),
+            &target.shape,
+            target.collider_pose_at(t),
+        ) else {
+            // Touching after a conservative step still counts as the impact.
+            return last_separation.map(|(point, normal)| AdvancementHit {
+                toi: t,
+                point,
+                normal,
+            });
+        };
+        if separation.distance <= tolerance {
+            return last_separation.map(|_| AdvancementHit {
+                toi: t,
+                point: separation.point_a,
+                normal: separation.normal,
+            });
+        }
+        let closing_speed = -relative_sweep.dot(separation.normal) + angular_bound;
+        if closing_speed <= params.toi_epsilon {
+            return None;
+        }
+        t += separation.distance / closing_speed;
+        if t > 1.0 {
+            return None;
+        }
+        last_separation = Some((separation.point_a, separation.normal));
+    }
+    last_separation.map(|(point, normal)| AdvancementHit {
+        toi: t,
+        point,
+        normal,
+    })
+}
+
+fn clamp_hit_to_toi(
+    world: &mut World,
+    hit: CcdHit,
+    params: &CcdParams,
+) -> Option<(CcdTrace, usize)> {
     let sweep = hit.swept_end - hit.swept_start;
     let target_sweep = hit.target_swept_end - hit.target_swept_start;
-    if sweep.length() <= CCD_TOI_EPSILON || hit.slop_sweep_length <= CCD_TOI_EPSILON {
+    let target_is_dynamic = hit.target_kind == CcdTargetKind::Dynamic;
+    // Against a kinematic target the moving body is rolled back along the
+    // relative sweep, which keeps it riding in front of the target.
+    let rollback_sweep = if hit.target_kind == CcdTargetKind::Kinematic {
+        sweep - target_sweep
+    } else {
+        sweep
+    };
+    // Rotating hits may have no linear sweep at all, so only the total motion
+    // bound decides whether they are worth clamping.
+    if (hit.rigid.is_none() && rollback_sweep.length() <= params.toi_epsilon)
+        || hit.slop_sweep_length <= params.toi_epsilon
+    {
         return None;
     }
-    let target_is_dynamic = hit.target_kind == CcdTargetKind::Dynamic;
     if !world
         .body_record(hit.moving_body)
         .ok()?
@@ -935,31 +1786,49 @@ fn clamp_hit_to_toi(world: &mut World, hit: CcdHit) -> Option<(CcdTrace, usize)>
         return None;
     }
 
-    let slop_fraction = CCD_CLAMP_SLOP / hit.slop_sweep_length;
-    let safe_exit = if hit.exit > hit.toi {
-        hit.toi + (hit.exit - hit.toi) * 0.5
-    } else {
-        hit.toi
+    let advancement = hit_advancement(&hit, params);
+    // Conservative-advancement hits restore the interpolated rigid pose,
+    // angle included; shape-cast hits only roll the translation back.
+    let clamped_poses = hit.rigid.map(|poses| {
+        (
+            poses.moving_start.lerp(poses.moving_end, advancement),
+            poses.target_start.lerp(poses.target_end, advancement),
+        )
+    });
+    let (rollback, target_rollback) = match (hit.rigid, clamped_poses) {
+        (Some(poses), Some((moving_pose, target_pose))) => (
+            poses.moving_end.translation() - moving_pose.translation(),
+            poses.target_end.translation() - target_pose.translation(),
+        ),
+        _ => (
+            rollback_sweep * (1.0 - advancement),
+            hit.target_swept_end - (hit.target_swept_start + target_sweep * advancement),
+        ),
     };
-    let advancement = (hit.toi + slop_fraction).min(safe_exit).clamp(hit.toi, 1.0);
-    let clamped_center = hit.swept_start + sweep * advancement;
-    let rollback = hit.swept_end - clamped_center;
     let target_rollback = if target_is_dynamic {
-        hit.target_swept_end - (hit.target_swept_start + target_sweep * advancement)
+        target_rollback
     } else {
         Vector::default()
     };
 
     {
         let record = world.body_record_mut(hit.moving_body).ok()?;
-        crate::solver::body_state::translate_pose(&mut record.pose, -rollback, 0.0);
+        match clamped_poses {
+            Some((moving_pose, _)) => record.pose = moving_pose,
+            None => crate::solver::body_state::translate_pose(&mut record.pose, -rollback, 0.0),
+        }
         record.sleeping = false;
         record.sleep_idle_time = 0.0;
     }
     let mut clamp_count = 1;
     if target_is_dynamic {
         let record = world.body_record_mut(hit.static_body).ok()?;
-        crate::solver::body_state::translate_pose(&mut record.pose, -target_rollback, 0.0);
+        match clamped_poses {
+            Some((_, target_pose)) => record.pose = target_pose,
+            None => {
+                crate::solver::body_state::translate_pose(&mut record.pose, -target_rollback, 0.0)
+            }
+        }
         record.sleeping = false;
         record.sleep_idle_time = 0.0;
         clamp_count += 1;
@@ -980,8 +1849,16 @@ fn clamp_hit_to_toi(world: &mut World, hit: CcdHit) -> Option<(CcdTrace, usize)>
             advancement,
             clamp: rollback.length(),
             target_clamp: target_rollback.length(),
-            slop: CCD_CLAMP_SLOP,
+            slop: params.clamp_slop,
             toi_point: hit.toi_point,
+            method: hit_method(&hit),
+            angular_sweep: hit.rigid.map_or(0.0, |poses| {
+                poses.moving_end.angle() - poses.moving_start.angle()
+            }),
+            target_angular_sweep: hit.rigid.map_or(0.0, |poses| {
+                poses.target_end.angle() - poses.target_start.angle()
+            }),
+            resolution: CcdResolution::PoseClamp,
         },
         clamp_count,
     ))
@@ -1009,6 +1886,7 @@ mod tests {
         body::Pose,
         collider::SharedShape,
         math::{point::Point, vector::Vector},
+        pipeline::CcdParams,
     };
 
     #[test]
@@ -1019,6 +1897,7 @@ mod tests {
             Point::new(2.3333333, 0.0),
             0.05,
             &wall,
+            &CcdParams::default(),
         )
         .expect("sweep should hit the expanded thin wall");
 
@@ -1037,6 +1916,7 @@ mod tests {
                 Point::new(2.3333333, 6.0),
                 0.05,
                 &wall,
+                &CcdParams::default(),
             ),
             None
         );
@@ -1047,8 +1927,13 @@ mod tests {
         let moving =
             SharedShape::rect(0.1, 0.1).world_vertices(Pose::from_xy_angle(-1.0, 0.0, 0.0));
         let wall = SharedShape::rect(0.1, 10.0).world_vertices(Pose::default());
-        let hit = swept_convex_convex_toi(&moving, Vector::new(3.3333333, 0.0), &wall)
-            .expect("dynamic convex sweep should hit the thin wall");
+        let hit = swept_convex_convex_toi(
+            &moving,
+            Vector::new(3.3333333, 0.0),
+            &wall,
+            &CcdParams::default(),
+        )
+        .expect("dynamic convex sweep should hit the thin wall");
 
         assert!(hit.toi > 0.0 && hit.toi < 1.0);
         assert!(hit.exit > hit.toi);
@@ -1070,7 +1955,12 @@ mod tests {
         .world_vertices(Pose::default());
 
         assert_eq!(
-            swept_convex_convex_toi(&moving, Vector::new(0.0, -1.1), &diamond),
+            swept_convex_convex_toi(
+                &moving,
+                Vector::new(0.0, -1.1),
+                &diamond,
+                &CcdParams::default(),
+            ),
             None
         );
     }
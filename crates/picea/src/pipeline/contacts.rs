@@ -12,8 +12,10 @@ use crate::{
     pipeline::{
         broadphase::{BroadphaseStats, ColliderProxy},
         island::SolverStepStats,
-        narrowphase::contact_from_shapes_with_cached_vertices,
-        StepConfig,
+        narrowphase::{
+            contact_from_shapes_with_cached_vertices, speculative_contact, ContactManifoldGeometry,
+        },
+        SolverParams, StepConfig,
     },
     world::{
         contact_state::{ContactKey, ContactPairKey, ContactRecord, WarmStartStats},
@@ -21,8 +23,9 @@ use crate::{
     },
 };
 
-const WARM_START_NORMAL_DOT_THRESHOLD: FloatNum = 0.98;
-const WARM_START_POINT_DRIFT_THRESHOLD: FloatNum = 0.05;
+/// Smallest run of candidate pairs worth handing to a worker thread.
+#[cfg(feature = "parallel")]
+const PARALLEL_NARROWPHASE_MIN_PAIRS: usize = 32;
 
 #[derive(Clone, Debug)]
 pub(crate) struct ContactObservation {
@@ -52,6 +55,9 @@ pub(crate) struct ContactObservation {
     pub(crate) restitution_applied: bool,
     pub(crate) generic_convex_trace: Option<GenericConvexTrace>,
     pub(crate) ccd_trace: Option<CcdTrace>,
+    /// Predictive contact for a separated pair; `depth` is the negated gap.
+    /// Solved like any other row but never reported as a contact event.
+    pub(crate) speculative: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -65,6 +71,9 @@ struct ColliderSnapshot {
     material: Material,
     filter: CollisionFilter,
     is_sensor: bool,
+    /// Upper bound on how far the collider can move during the next step;
+    /// zero unless speculative contacts are enabled.
+    speculative_margin: FloatNum,
 }
 
 pub(crate) fn run_contact_phases(
@@ -72,6 +81,7 @@ pub(crate) fn run_contact_phases(
     config: &StepConfig,
     wake_reasons: &mut BTreeMap<BodyHandle, SleepTransitionReason>,
     ccd_traces: &[CcdTrace],
+    substep_origins: &BTreeMap<BodyHandle, Pose>,
 ) -> (
     Vec<WorldEvent>,
     usize,
@@ -80,15 +90,16 @@ pub(crate) fn run_contact_phases(
     WarmStartStats,
     SolverStepStats,
 ) {
-    let mut contacts = world.collect_contact_observations(ccd_traces);
+    let mut contacts = world.collect_contact_observations(ccd_traces, config);
     let broadphase_stats = contacts.broadphase_stats;
     let previous_contacts = world.take_active_contacts();
-    world.prepare_contact_warm_start(&mut contacts.observations, &previous_contacts);
+    world.prepare_contact_warm_start(&mut contacts.observations, &previous_contacts, config);
     let solver_stats = crate::solver::contact::resolve_contacts(
         world,
         &mut contacts.observations,
         config,
         wake_reasons,
+        substep_origins,
     );
     let (events, contact_count, manifold_count, warm_start_stats) =
         world.refresh_contact_events(contacts.observations, previous_contacts);
@@ -111,42 +122,46 @@ impl World {
     fn collect_contact_observations(
         &mut self,
         ccd_traces: &[CcdTrace],
+        config: &StepConfig,
     ) -> ContactPhaseObservations {
         let ccd_traces = ccd_trace_map(ccd_traces);
-        let colliders = self.live_collider_snapshots();
+        let colliders = self.live_collider_snapshots(config);
+        // Speculative pairs must reach the narrowphase while still apart, so
+        // their proxies cover the next step's motion.
         let proxies = colliders
             .iter()
             .map(|collider| ColliderProxy {
                 handle: collider.handle,
-                aabb: collider.aabb,
+                aabb: expanded_aabb(collider.aabb, collider.speculative_margin),
             })
             .collect::<Vec<_>>();
         let mut broadphase = self.update_broadphase(&proxies);
         let mut observations = Vec::with_capacity(broadphase.candidate_pairs.len());
 
-        for (index, other_index) in broadphase.candidate_pairs {
+        let outcomes = narrowphase_pairs(&colliders, &broadphase.candidate_pairs);
+
+        // Outcomes arrive in candidate pair order whether or not they were
+        // computed in parallel, so observation order (and with it contact id
+        // allocation and event order) matches the serial pass exactly.
+        for ((index, other_index), outcome) in broadphase.candidate_pairs.into_iter().zip(outcomes)
+        {
             let collider_a = &colliders[index];
             let collider_b = &colliders[other_index];
-            if collider_a.body == collider_b.body {
-                broadphase.stats.same_body_drop_count += 1;
-                continue;
-            }
-            if !collider_a.filter.allows(&collider_b.filter) {
-                broadphase.stats.filter_drop_count += 1;
-                continue;
-            }
-            let Some(contact) = contact_from_shapes_with_cached_vertices(
-                &collider_a.shape,
-                collider_a.world_pose,
-                collider_a.aabb,
-                collider_a.convex_vertices.as_deref(),
-                &collider_b.shape,
-                collider_b.world_pose,
-                collider_b.aabb,
-                collider_b.convex_vertices.as_deref(),
-            ) else {
-                broadphase.stats.narrowphase_drop_count += 1;
-                continue;
+            let (contact, speculative) = match outcome {
+                PairOutcome::SameBody => {
+                    broadphase.stats.same_body_drop_count += 1;
+                    continue;
+                }
+                PairOutcome::Filtered => {
+                    broadphase.stats.filter_drop_count += 1;
+                    continue;
+                }
+                PairOutcome::Separated => {
+                    broadphase.stats.narrowphase_drop_count += 1;
+                    continue;
+                }
+                PairOutcome::Touching(contact) => (contact, false),
+                PairOutcome::Speculative(contact) => (contact, true),
             };
 
             let (
@@ -208,6 +223,7 @@ impl World {
                     restitution_applied: false,
                     generic_convex_trace: contact.generic_convex_trace,
                     ccd_trace: ccd_traces.get(&(ordered_a, ordered_b)).copied(),
+                    speculative,
                 });
             }
         }
@@ -222,17 +238,19 @@ impl World {
         &self,
         contacts: &mut [ContactObservation],
         previous_contacts: &BTreeMap<ContactKey, ContactRecord>,
+        config: &StepConfig,
     ) {
         let previous_pairs = previous_contacts
             .values()
             .map(|record| ContactPairKey::new(record.contact.collider_a, record.contact.collider_b))
             .collect::<BTreeSet<_>>();
 
-        for contact in contacts {
+        for contact in contacts.iter_mut().filter(|contact| !contact.speculative) {
             let (reason, normal_impulse, tangent_impulse) = warm_start_transfer(
                 previous_contacts.get(&contact.key),
                 contact,
                 previous_pairs.contains(&contact.pair_key),
+                &config.solver,
             );
             contact.warm_start_reason = reason;
             contact.warm_start_normal_impulse = normal_impulse;
@@ -260,7 +278,7 @@ impl World {
         let mut events = Vec::new();
         let mut warm_start_stats = WarmStartStats::default();
 
-        for contact in contacts {
+        for contact in contacts.into_iter().filter(|contact| !contact.speculative) {
             let existing = previous.remove(&contact.key);
             let is_persisted = existing.is_some();
             warm_start_stats.record(contact.warm_start_reason);
@@ -351,12 +369,30 @@ impl World {
         (events, contact_count, manifold_count, warm_start_stats)
     }
 
-    fn live_collider_snapshots(&self) -> Vec<ColliderSnapshot> {
+    fn live_collider_snapshots(&self, config: &StepConfig) -> Vec<ColliderSnapshot> {
+        let speculative = config.ccd.mode.speculative();
         self.collider_records()
             .filter_map(|(handle, record)| {
                 let body = self.body_record(record.body).ok()?;
                 let world_pose = body.pose.compose(record.local_pose);
                 let geometry = record.derived_geometry(body.pose);
+                let speculative_margin = if speculative && !record.is_sensor {
+                    // Every collider point stays within `reach` of the body
+                    // origin, so this bounds linear plus rotational travel.
+                    let half_extent = (geometry.aabb.max - geometry.aabb.min) * 0.5;
+                    let center = geometry.aabb.min + half_extent;
+                    let reach = (center - body.pose.point()).length() + half_extent.length();
+                    let margin = (body.linear_velocity.length()
+                        + body.angular_velocity.abs() * reach)
+                        * config.dt;
+                    if margin.is_finite() {
+                        margin
+                    } else {
+                        0.0
+                    }
+                } else {
+                    0.0
+                };
                 Some(ColliderSnapshot {
                     handle,
                     body: record.body,
@@ -367,16 +403,104 @@ impl World {
                     material: record.material,
                     filter: record.filter,
                     is_sensor: record.is_sensor,
+                    speculative_margin,
                 })
             })
             .collect()
     }
 }
 
+enum PairOutcome {
+    SameBody,
+    Filtered,
+    Separated,
+    Touching(ContactManifoldGeometry),
+    Speculative(ContactManifoldGeometry),
+}
+
+fn narrowphase_pair(
+    colliders: &[ColliderSnapshot],
+    (index, other_index): (usize, usize),
+) -> PairOutcome {
+    let collider_a = &colliders[index];
+    let collider_b = &colliders[other_index];
+    if collider_a.body == collider_b.body {
+        return PairOutcome::SameBody;
+    }
+    if !collider_a.filter.allows(&collider_b.filter) {
+        return PairOutcome::Filtered;
+    }
+    match contact_from_shapes_with_cached_vertices(
+        &collider_a.shape,
+        collider_a.world_pose,
+        collider_a.aabb,
+        collider_a.convex_vertices.as_deref(),
+        &collider_b.shape,
+        collider_b.world_pose,
+        collider_b.aabb,
+        collider_b.convex_vertices.as_deref(),
+    ) {
+        Some(contact) => PairOutcome::Touching(contact),
+        None => {
+            let max_distance = collider_a.speculative_margin + collider_b.speculative_margin;
+            if max_distance <= 0.0 || collider_a.is_sensor || collider_b.is_sensor {
+                return PairOutcome::Separated;
+            }
+            speculative_contact(
+                &collider_a.shape,
+                collider_a.world_pose,
+                &collider_b.shape,
+                collider_b.world_pose,
+                max_distance,
+            )
+            .map_or(PairOutcome::Separated, PairOutcome::Speculative)
+        }
+    }
+}
+
+fn expanded_aabb(aabb: ShapeAabb, margin: FloatNum) -> ShapeAabb {
+    if margin <= 0.0 {
+        return aabb;
+    }
+    let margin = Vector::new(margin, margin);
+    ShapeAabb {
+        min: aabb.min - margin,
+        max: aabb.max + margin,
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn narrowphase_pairs(colliders: &[ColliderSnapshot], pairs: &[(usize, usize)]) -> Vec<PairOutcome> {
+    pairs
+        .iter()
+        .map(|&pair| narrowphase_pair(colliders, pair))
+        .collect()
+}
+
+#[cfg(feature = "parallel")]
+fn narrowphase_pairs(colliders: &[ColliderSnapshot], pairs: &[(usize, usize)]) -> Vec<PairOutcome> {
+    use rayon::prelude::*;
+
+    if pairs.len() < PARALLEL_NARROWPHASE_MIN_PAIRS || rayon::current_num_threads() < 2 {
+        return pairs
+            .iter()
+            .map(|&pair| narrowphase_pair(colliders, pair))
+            .collect();
+    }
+    // Each pair only reads the collider snapshots; the indexed collect keeps
+    // candidate order for the serial merge.
+    pairs
+        .par_iter()
+        .with_min_len(PARALLEL_NARROWPHASE_MIN_PAIRS)
+        .map(|&pair| narrowphase_pair(colliders, pair))
+        .collect()
+}
+
 fn warm_start_transfer(
     previous: Option<&ContactRecord>,
     contact: &ContactObservation,
     had_previous_pair: bool,
+    params: &SolverParams,
 ) -> (WarmStartCacheReason, FloatNum, FloatNum) {
     if contact.is_sensor {
         return (WarmStartCacheReason::SkippedSensor, 0.0, 0.0);
@@ -404,7 +528,7 @@ fn warm_start_transfer(
     // constraint row. Feature ids alone are not enough after a normal flip.
     if previous_normal.length() <= FloatNum::EPSILON
         || current_normal.length() <= FloatNum::EPSILON
-        || previous_normal.dot(current_normal) < WARM_START_NORMAL_DOT_THRESHOLD
+        || previous_normal.dot(current_normal) < params.warm_start_normal_dot_threshold
     {
         return (WarmStartCacheReason::DroppedNormalMismatch, 0.0, 0.0);
     }
@@ -415,7 +539,7 @@ fn warm_start_transfer(
     let drift_a = (contact.anchor_a - previous.anchor_a).length();
     let drift_b = (contact.anchor_b - previous.anchor_b).length();
     let drift = drift_a.max(drift_b);
-    if !drift.is_finite() || drift > WARM_START_POINT_DRIFT_THRESHOLD {
+    if !drift.is_finite() || drift > params.warm_start_point_drift_threshold {
         return (WarmStartCacheReason::DroppedPointDrift, 0.0, 0.0);
     }
 
@@ -426,7 +550,7 @@ fn warm_start_transfer(
     )
 }
 
-fn combine_materials(a: Material, b: Material) -> Material {
+pub(crate) fn combine_materials(a: Material, b: Material) -> Material {
     Material {
         friction: (a.friction.max(0.0) * b.friction.max(0.0)).sqrt(),
         restitution: a.restitution.max(b.restitution).max(0.0),
@@ -451,3 +575,95 @@ fn ordered_pair(a: ColliderHandle, b: ColliderHandle) -> (ColliderHandle, Collid
         (b, a)
     }
 }
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use crate::{
+        body::{BodyDesc, BodyType, Pose},
+        collider::{ColliderDesc, SharedShape},
+        events::WorldEvent,
+        math::FloatNum,
+        pipeline::{SimulationPipeline, StepConfig, StepStats},
+        world::{World, WorldDesc},
+    };
+
+    fn dense_pile_world() -> World {
+        let mut world = World::new(WorldDesc {
+            enable_sleep: false,
+            ..WorldDesc::default()
+        });
+        let ground = world
+            .create_body(BodyDesc {
+                body_type: BodyType::Static,
+                pose: Pose::from_xy_angle(0.0, 12.0, 0.0),
+                ..BodyDesc::default()
+            })
+            .expect("ground should be created");
+        world
+            .create_collider(
+                ground,
+                ColliderDesc {
+                    shape: SharedShape::rect(40.0, 1.0),
+                    ..ColliderDesc::default()
+                },
+            )
+            .expect("ground collider should be created");
+        for row in 0..8 {
+            for column in 0..12 {
+                let body = world
+                    .create_body(BodyDesc {
+                        pose: Pose::from_xy_angle(
+                            column as FloatNum * 0.95 - 5.0 + row as FloatNum * 0.1,
+                            11.0 - row as FloatNum * 0.95,
+                            0.0,
+                        ),
+                        ..BodyDesc::default()
+                    })
+                    .expect("pile body should be created");
+                let shape = if (row + column) % 2 == 0 {
+                    SharedShape::circle(0.5)
+                } else {
+                    SharedShape::rect(1.0, 1.0)
+                };
+                world
+                    .create_collider(
+                        body,
+                        ColliderDesc {
+                            shape,
+                            ..ColliderDesc::default()
+                        },
+                    )
+                    .expect("pile collider should be created");
+            }
+        }
+        world
+    }
+
+    fn reports_with_threads(threads: usize) -> Vec<(StepStats, Vec<WorldEvent>, u64)> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("rayon pool should build");
+        pool.install(|| {
+            let mut world = dense_pile_world();
+            let mut pipeline = SimulationPipeline::new(StepConfig::default());
+            (0..30)
+                .map(|_| {
+                    let report = pipeline.step(&mut world);
+                    (report.stats, report.events, world.state_hash())
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn parallel_pair_processing_keeps_contact_ids_and_event_order() {
+        let serial = reports_with_threads(1);
+        let parallel = reports_with_threads(4);
+
+        assert!(serial
+            .iter()
+            .any(|(stats, _, _)| stats.broadphase_candidate_count > 32));
+        assert_eq!(serial, parallel);
+    }
+}
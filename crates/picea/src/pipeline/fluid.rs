@@ -0,0 +1,177 @@
+//! Buoyancy and drag applied by fluid-volume colliders before integration.
+
+use crate::{
+    body::BodyType,
+    collider::{ColliderRecord, FluidVolume, ShapeAabb, SharedShape},
+    handles::{BodyHandle, ColliderHandle},
+    math::{point::Point, vector::Vector, FloatNum},
+    pipeline::{
+        broadphase::aabb_overlaps_inclusive,
+        narrowphase::{clip_polygon_to_convex, polygon_area, polygon_centroid},
+        StepConfig,
+    },
+    solver::body_state::{angular_point_velocity, inverse_mass_along, linear_response},
+    world::World,
+};
+
+/// Segment count used when a circle takes part in submersion clipping.
+const CIRCLE_SEGMENTS: usize = 32;
+const MIN_SUBMERGED_AREA: FloatNum = 1.0e-6;
+
+/// Overlap between one fluid collider and one submerged collider.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct FluidSubmersion {
+    pub(crate) fluid_collider: ColliderHandle,
+    pub(crate) collider: ColliderHandle,
+    pub(crate) body: BodyHandle,
+    pub(crate) polygon: Vec<Point>,
+    pub(crate) area: FloatNum,
+    pub(crate) centroid: Point,
+}
+
+pub(crate) fn run_fluid_phase(world: &mut World, config: &StepConfig) {
+    let submersions = collect_submersions(world);
+    for submersion in &submersions {
+        let fluid = world
+            .collider_record(submersion.fluid_collider)
+            .ok()
+            .and_then(|record| record.fluid)
+            .expect("fluid submersions reference live fluid colliders");
+        apply_fluid_impulses(world, submersion, fluid, config.dt);
+    }
+    world.set_fluid_submersions(submersions);
+}
+
+fn collect_submersions(world: &World) -> Vec<FluidSubmersion> {
+    let fluids = world
+        .collider_records()
+        .filter(|(_, record)| record.fluid.is_some())
+        .filter_map(|(handle, record)| {
+            let body_pose = world.body_record(record.body).ok()?.pose;
+            let polygon = counter_clockwise(world_polygon(record, body_pose)?);
+            Some((
+                handle,
+                record,
+                ShapeAabb::from_points(polygon.clone()),
+                polygon,
+            ))
+        })
+        .collect::<Vec<_>>();
+    if fluids.is_empty() {
+        return Vec::new();
+    }
+
+    let mut submersions = Vec::new();
+    for (collider_handle, collider) in world.collider_records() {
+        if collider.is_sensor {
+            continue;
+        }
+        let Ok(body) = world.body_record(collider.body) else {
+            continue;
+        };
+        if body.body_type != BodyType::Dynamic || body.sleeping {
+            continue;
+        }
+        let Some(polygon) = world_polygon(collider, body.pose) else {
+            continue;
+        };
+        let bounds = ShapeAabb::from_points(polygon.clone());
+        for (fluid_handle, fluid, fluid_bounds, fluid_polygon) in &fluids {
+            if fluid.body == collider.body
+                || !fluid.filter.allows(&collider.filter)
+                || !aabb_overlaps_inclusive(bounds, *fluid_bounds)
+            {
+                continue;
+            }
+            let submerged = clip_polygon_to_convex(&polygon, fluid_polygon);
+            let area = polygon_area(&submerged).abs();
+            if area <= MIN_SUBMERGED_AREA {
+                continue;
+            }
+            submersions.push(FluidSubmersion {
+                fluid_collider: *fluid_handle,
+                collider: collider_handle,
+                body: collider.body,
+                centroid: polygon_centroid(&submerged),
+                polygon: submerged,
+                area,
+            });
+        }
+    }
+    submersions
+}
+
+/// Applies buoyancy at the submerged centroid, then drag that never reverses
+/// the motion it opposes.
+fn apply_fluid_impulses(
+    world: &mut World,
+    submersion: &FluidSubmersion,
+    fluid: FluidVolume,
+    dt: FloatNum,
+) {
+    let gravity = world.body_gravity(
+        world
+            .body_record(submersion.body)
+            .expect("fluid submersions reference live bodies"),
+    );
+    let record = world
+        .body_record_mut(submersion.body)
+        .expect("fluid submersions reference live bodies");
+    let inverse_mass = record.solver_inverse_mass();
+    let inverse_inertia = record.solver_inverse_inertia();
+    let center = record
+        .pose
+        .transform_point(record.mass_properties.local_center_of_mass);
+    let anchor = submersion.centroid - center;
+    let apply = |record: &mut crate::body::BodyRecord, impulse: Vector| {
+        record.linear_velocity += linear_response(inverse_mass, impulse);
+        record.angular_velocity -= anchor.cross(impulse) * inverse_inertia;
+    };
+
+    apply(record, -gravity * (fluid.density * submersion.area * dt));
+
+    let point_velocity =
+        record.linear_velocity + angular_point_velocity(record.angular_velocity, anchor);
+    let speed = point_velocity.length();
+    if speed > FloatNum::EPSILON {
+        let direction = point_velocity / speed;
+        let arm = anchor.cross(direction);
+        let inverse_effective_mass =
+            inverse_mass_along(inverse_mass, direction) + inverse_inertia * arm * arm;
+        if inverse_effective_mass > FloatNum::EPSILON {
+            let magnitude = (fluid.linear_drag * submersion.area * dt * speed)
+                .min(speed / inverse_effective_mass);
+            apply(record, -direction * magnitude);
+        }
+    }
+
+    let angular_scale = (fluid.angular_drag * submersion.area * dt * inverse_inertia).min(1.0);
+    record.angular_velocity -= record.angular_velocity * angular_scale;
+}
+
+/// World-space polygon for submersion clipping; circles become regular polygons.
+fn world_polygon(record: &ColliderRecord, body_pose: crate::body::Pose) -> Option<Vec<Point>> {
+    match record.shape {
+        SharedShape::Circle { radius } => {
+            let center = Point::from(record.world_pose(body_pose).translation());
+            Some(
+                (0..CIRCLE_SEGMENTS)
+                    .map(|index| {
+                        let angle =
+                            index as FloatNum * crate::math::tau() / CIRCLE_SEGMENTS as FloatNum;
+                        center + Vector::new(radius, 0.0).rotated(angle)
+                    })
+                    .collect(),
+            )
+        }
+        SharedShape::ConcavePolygon { .. } | SharedShape::Segment { .. } => None,
+        _ => record.convex_world_vertices(body_pose),
+    }
+}
+
+fn counter_clockwise(mut polygon: Vec<Point>) -> Vec<Point> {
+    if polygon_area(&polygon) < 0.0 {
+        polygon.reverse();
+    }
+    polygon
+}
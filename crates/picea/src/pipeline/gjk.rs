@@ -156,6 +156,136 @@ fn gjk_distance_with_cached_vertices(
     )
 }
 
+/// Separation between two disjoint convex shapes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct GjkSeparation {
+    /// Lower bound on the distance between the shapes; the true distance is
+    /// within `GJK_EPSILON` of it when the query converges.
+    pub(crate) distance: FloatNum,
+    /// Unit direction from shape A toward shape B.
+    pub(crate) normal: Vector,
+    /// Closest point on shape A.
+    pub(crate) point_a: Point,
+}
+
+/// Measures the gap between two convex shapes.
+///
+/// Unlike `gjk_distance`, which stops as soon as separation is proven, this
+/// keeps refining the closest simplex feature so the reported distance is a
+/// usable lower bound for conservative advancement. Returns `None` when the
+/// shapes overlap or touch, or a support query fails.
+pub(crate) fn gjk_separation(
+    shape_a: &SharedShape,
+    pose_a: Pose,
+    shape_b: &SharedShape,
+    pose_b: Pose,
+) -> Option<GjkSeparation> {
+    let first = support(
+        shape_a,
+        pose_a,
+        None,
+        shape_b,
+        pose_b,
+        None,
+        initial_direction(pose_a, pose_b),
+    )?;
+    let mut simplex = vec![(first, 1.0)];
+    let mut closest = first.v;
+    let mut lower_bound: FloatNum = 0.0;
+    for _ in 0..GJK_MAX_ITERATIONS {
+        let closest_length = closest.length();
+        if closest_length <= GJK_EPSILON {
+            return None;
+        }
+        let next = support(shape_a, pose_a, None, shape_b, pose_b, None, -closest)?;
+        // Every Minkowski point lies on the far side of the support plane, so
+        // its offset along the closest direction bounds the distance below.
+        lower_bound = lower_bound.max(next.v.dot(closest) / closest_length);
+        if closest_length - lower_bound <= GJK_EPSILON
+            || simplex
+                .iter()
+                .any(|(point, _)| (point.v - next.v).length() <= GJK_EPSILON)
+        {
+            break;
+        }
+        simplex.push((next, 1.0));
+        simplex = closest_simplex_feature(&simplex)?;
+        closest = simplex
+            .iter()
+            .fold(Vector::default(), |sum, (point, weight)| {
+                sum + point.v * *weight
+            });
+    }
+    if lower_bound <= 0.0 {
+        return None;
+    }
+    let point_a = simplex
+        .iter()
+        .fold(Vector::default(), |sum, (point, weight)| {
+            sum + Vector::from(point.point_a) * *weight
+        });
+    Some(GjkSeparation {
+        distance: lower_bound,
+        normal: (-closest).normalized_or_zero(),
+        point_a: Point::from(point_a),
+    })
+}
+
+/// Reduces a 1-3 point simplex to the feature closest to the origin, with
+/// barycentric weights. Returns `None` when a triangle contains the origin.
+fn closest_simplex_feature(
+    simplex: &[(SupportPoint, FloatNum)],
+) -> Option<Vec<(SupportPoint, FloatNum)>> {
+    match simplex {
+        [a] => Some(vec![(a.0, 1.0)]),
+        [a, b] => Some(closest_segment_feature(a.0, b.0)),
+        [a, b, c] => {
+            let (a, b, c) = (a.0, b.0, c.0);
+            let area = (b.v - a.v).cross(c.v - a.v);
+            let contains_origin = [(a, b), (b, c), (c, a)]
+                .iter()
+                .all(|(start, end)| (end.v - start.v).cross(-start.v) * area >= 0.0);
+            if contains_origin {
+                return None;
+            }
+            [(a, b), (b, c), (c, a)]
+                .into_iter()
+                .map(|(start, end)| closest_segment_feature(start, end))
+                .min_by(|left, right| {
+                    feature_length(left)
+                        .partial_cmp(&feature_length(right))
+                        .unwrap_or(Ordering::Equal)
+                })
+        }
+        _ => None,
+    }
+}
+
+fn closest_segment_feature(a: SupportPoint, b: SupportPoint) -> Vec<(SupportPoint, FloatNum)> {
+    let ab = b.v - a.v;
+    let denom = ab.length_squared();
+    if denom <= GJK_EPSILON * GJK_EPSILON {
+        return vec![(a, 1.0)];
+    }
+    let t = (-a.v).dot(ab) / denom;
+    if t <= 0.0 {
+        vec![(a, 1.0)]
+    } else if t >= 1.0 {
+        vec![(b, 1.0)]
+    } else {
+        vec![(a, 1.0 - t), (b, t)]
+    }
+}
+
+fn feature_length(feature: &[(SupportPoint, FloatNum)]) -> FloatNum {
+    feature
+        .iter()
+        .fold(Vector::default(), |sum, (point, weight)| {
+            sum + point.v * *weight
+        })
+        .length()
+}
+
 #[allow(dead_code)]
 pub(crate) fn epa_penetration(
     shape_a: &SharedShape,
@@ -601,7 +731,7 @@ fn epa_failure(
 
 #[cfg(test)]
 mod tests {
-    use super::{epa_penetration, generic_convex_contact, gjk_distance};
+    use super::{epa_penetration, generic_convex_contact, gjk_distance, gjk_separation};
     use crate::{
         body::Pose,
         collider::SharedShape,
@@ -755,4 +885,24 @@ mod tests {
         assert!(contact.point.x().is_finite());
         assert!(contact.point.y().is_finite());
     }
+
+    #[test]
+    fn gjk_separation_reports_exact_gap_normal_and_witness_for_rotated_shapes() {
+        let rect = SharedShape::rect(2.0, 2.0);
+        let segment = SharedShape::segment(Point::new(0.0, -1.0), Point::new(0.0, 1.0));
+        let diamond = Pose::from_xy_angle(0.0, 0.0, crate::math::tau() / 8.0);
+
+        let separation =
+            gjk_separation(&rect, diamond, &segment, Pose::from_xy_angle(3.0, 0.0, 0.0))
+                .expect("shapes are apart");
+        let corner = crate::math::FloatNum::sqrt(2.0);
+        assert!((separation.distance - (3.0 - corner)).abs() < 1.0e-4);
+        assert!((separation.normal.x() - 1.0).abs() < 1.0e-4);
+        assert!(separation.normal.y().abs() < 1.0e-4);
+        assert!((separation.point_a.x() - corner).abs() < 1.0e-4);
+
+        assert!(
+            gjk_separation(&rect, diamond, &segment, Pose::from_xy_angle(1.0, 0.0, 0.0)).is_none()
+        );
+    }
 }
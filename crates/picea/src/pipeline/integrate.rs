@@ -22,8 +22,16 @@ impl World {
     ) {
         let body_handles = self.bodies().collect::<Vec<_>>();
         let enable_sleep = self.desc().enable_sleep;
-        let gravity = self.desc().gravity;
         for handle in body_handles {
+            let record = self
+                .body_record(handle)
+                .expect("live body handles must resolve during step");
+            // Only dynamic bodies integrate gravity; skip the field lookup for the rest.
+            let gravity = if record.body_type.is_dynamic() {
+                self.body_gravity(record)
+            } else {
+                Vector::default()
+            };
             let record = self
                 .body_record_mut(handle)
                 .expect("live body handles must resolve during step");
@@ -41,11 +49,14 @@ impl World {
                         continue;
                     }
 
-                    let linear_velocity = (record.linear_velocity
-                        + gravity * config.dt * record.gravity_scale)
-                        * (1.0 - record.linear_damping * config.dt).max(0.0);
-                    let angular_velocity = record.angular_velocity
-                        * (1.0 - record.angular_damping * config.dt).max(0.0);
+                    let linear_velocity = record.locked_axes.mask_linear(
+                        (record.linear_velocity + gravity * config.dt)
+                            * (1.0 - record.linear_damping * config.dt).max(0.0),
+                    );
+                    let angular_velocity = record.locked_axes.mask_angular(
+                        record.angular_velocity
+                            * (1.0 - record.angular_damping * config.dt).max(0.0),
+                    );
                     let pose = translated_pose(
                         record.pose,
                         linear_velocity * config.dt,
@@ -73,6 +84,18 @@ impl World {
                 BodyType::Kinematic => {
                     record.sleeping = false;
                     record.sleep_idle_time = 0.0;
+                    if let Some(target) = record.kinematic_target {
+                        // Solvers read linear velocity at the center of mass,
+                        // so derive it from the center's displacement.
+                        let local_center = record.mass_properties.local_center_of_mass;
+                        let displacement = target.transform_point(local_center)
+                            - record.pose.transform_point(local_center);
+                        record.linear_velocity = displacement / config.dt;
+                        record.angular_velocity =
+                            (target.angle() - record.pose.angle()) / config.dt;
+                        record.pose = target;
+                        continue;
+                    }
                     let pose = translated_pose(
                         record.pose,
                         record.linear_velocity * config.dt,
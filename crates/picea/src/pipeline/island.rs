@@ -28,6 +28,8 @@ pub(crate) struct SolverStepStats {
     pub(crate) body_slot_count: usize,
     pub(crate) contact_row_count: usize,
     pub(crate) joint_row_count: usize,
+    pub(crate) speculative_row_count: usize,
+    pub(crate) speculative_enforced_count: usize,
 }
 
 impl SolverStepStats {
@@ -38,6 +40,8 @@ impl SolverStepStats {
         self.body_slot_count += other.body_slot_count;
         self.contact_row_count += other.contact_row_count;
         self.joint_row_count += other.joint_row_count;
+        self.speculative_row_count += other.speculative_row_count;
+        self.speculative_enforced_count += other.speculative_enforced_count;
     }
 }
 
@@ -76,7 +76,7 @@ impl World {
                         let distance = delta.length();
                         let direction = normalized_or_x_axis(delta);
                         let error = distance - desc.rest_length;
-                        if error.abs() <= f32::EPSILON {
+                        if error.abs() <= FloatNum::EPSILON {
                             continue;
                         }
                         let correction = direction * error * desc.stiffness.max(0.0) * dt;
@@ -170,7 +170,7 @@ fn joint_solve_batches(
 }
 
 fn normalized_or_x_axis(vector: Vector) -> Vector {
-    if vector.length() <= f32::EPSILON {
+    if vector.length() <= FloatNum::EPSILON {
         Vector::new(1.0, 0.0)
     } else {
         vector.normalized_or_zero()
@@ -44,6 +44,7 @@ pub(crate) fn contact_from_shapes(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn contact_from_shapes_with_cached_vertices(
     shape_a: &SharedShape,
     pose_a: Pose,
@@ -130,6 +131,42 @@ pub(crate) fn contact_from_shapes_with_cached_vertices(
     }
 }
 
+/// Builds a single-point speculative contact for a separated convex pair
+/// whose gap is below `max_distance`.
+///
+/// The normal follows the overlap convention (from B toward A), the point sits
+/// halfway across the gap, and the depth is the negated gap.
+pub(crate) fn speculative_contact(
+    shape_a: &SharedShape,
+    pose_a: Pose,
+    shape_b: &SharedShape,
+    pose_b: Pose,
+    max_distance: FloatNum,
+) -> Option<ContactManifoldGeometry> {
+    if matches!(shape_a, SharedShape::ConcavePolygon { .. })
+        || matches!(shape_b, SharedShape::ConcavePolygon { .. })
+    {
+        return None;
+    }
+    let separation = gjk::gjk_separation(shape_a, pose_a, shape_b, pose_b)?;
+    if !separation.distance.is_finite() || separation.distance >= max_distance {
+        return None;
+    }
+    let point = separation.point_a + separation.normal * (separation.distance * 0.5);
+    Some(single_point_manifold(
+        point,
+        -separation.normal,
+        -separation.distance,
+        feature_id(
+            shape_feature_kind(shape_a),
+            0,
+            shape_feature_kind(shape_b),
+            0,
+        ),
+        ContactReductionReason::SinglePoint,
+    ))
+}
+
 fn contact_from_circles(
     center_a: Point,
     radius_a: FloatNum,
@@ -635,6 +672,33 @@ fn clip_to_plane(
     output
 }
 
+/// Clips `subject` against every edge plane of the convex `clip` polygon,
+/// returning the overlap region as a polygon (empty when they do not overlap).
+pub(crate) fn clip_polygon_to_convex(subject: &[Point], clip: &[Point]) -> Vec<Point> {
+    let mut points = subject
+        .iter()
+        .enumerate()
+        .map(|(index, point)| ClippedPoint {
+            point: *point,
+            feature_id: feature_id(0, 0, 0, index),
+        })
+        .collect::<Vec<_>>();
+    for edge_index in 0..clip.len() {
+        if points.len() < 3 {
+            return Vec::new();
+        }
+        points = clip_to_plane(
+            points,
+            clip[edge_index],
+            -edge_outward_normal(clip, edge_index),
+        );
+    }
+    if points.len() < 3 {
+        return Vec::new();
+    }
+    points.into_iter().map(|clipped| clipped.point).collect()
+}
+
 fn interpolate_clip(
     a: ClippedPoint,
     b: ClippedPoint,
@@ -774,7 +838,7 @@ fn support_midpoint(a: &[Point], b: &[Point], normal: Vector) -> Point {
     Point::from((Vector::from(support_a) + Vector::from(support_b)) * 0.5)
 }
 
-fn polygon_area(vertices: &[Point]) -> FloatNum {
+pub(crate) fn polygon_area(vertices: &[Point]) -> FloatNum {
     let mut area = 0.0;
     for index in 0..vertices.len() {
         let current = vertices[index];
@@ -794,6 +858,22 @@ fn polygon_center(vertices: &[Point]) -> Point {
     Point::from(sum / vertices.len() as FloatNum)
 }
 
+/// Area-weighted centroid; degenerate polygons fall back to the vertex average.
+pub(crate) fn polygon_centroid(vertices: &[Point]) -> Point {
+    let area = polygon_area(vertices);
+    if area.abs() <= FloatNum::EPSILON {
+        return polygon_center(vertices);
+    }
+    let origin = vertices[0];
+    let mut weighted = Vector::default();
+    for index in 1..vertices.len() - 1 {
+        let a = vertices[index] - origin;
+        let b = vertices[index + 1] - origin;
+        weighted += (a + b) * (a.cross(b) / 6.0);
+    }
+    origin + weighted / area
+}
+
 #[derive(Clone, Copy, Debug)]
 struct ClosestSegmentPoint {
     point: Point,
@@ -885,10 +965,10 @@ mod tests {
         body::Pose,
         collider::{ShapeAabb, SharedShape},
         events::ContactReductionReason,
-        math::{point::Point, vector::Vector},
+        math::{point::Point, vector::Vector, FloatNum},
     };
 
-    fn aabb(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> ShapeAabb {
+    fn aabb(min_x: FloatNum, min_y: FloatNum, max_x: FloatNum, max_y: FloatNum) -> ShapeAabb {
         ShapeAabb {
             min: Point::new(min_x, min_y),
             max: Point::new(max_x, max_y),
@@ -924,10 +1004,10 @@ mod tests {
         .expect("overlapping circles should contact");
 
         assert_eq!(contact.normal, Vector::new(-1.0, 0.0));
-        assert!((contact.depth - 0.5).abs() < f32::EPSILON);
+        assert!((contact.depth - 0.5).abs() < FloatNum::EPSILON);
         assert_eq!(contact.points.len(), 1);
-        assert!((contact.points[0].point.x() - 0.75).abs() < f32::EPSILON);
-        assert!((contact.points[0].point.y() - 0.0).abs() < f32::EPSILON);
+        assert!((contact.points[0].point.x() - 0.75).abs() < FloatNum::EPSILON);
+        assert!((contact.points[0].point.y() - 0.0).abs() < FloatNum::EPSILON);
     }
 
     #[test]
@@ -0,0 +1,233 @@
+//! Tunable solver, sleep, and CCD parameters carried by `StepConfig`.
+//!
+//! Every field has a default matching the engine's historical constants, and
+//! each section deserializes field-by-field so configs can override only the
+//! knobs they care about.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{math::FloatNum, world::ValidationError};
+
+/// Contact solver tuning shared by the sequential-impulse and soft-step modes.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SolverParams {
+    /// Penetration depth tolerated before velocity bias or position correction applies.
+    pub contact_slop: FloatNum,
+    /// Fraction of the remaining penetration removed by all position iterations together.
+    pub position_correction_percent: FloatNum,
+    /// Fraction of the penetration beyond `contact_slop` turned into separating
+    /// velocity during the sequential-impulse velocity solve.
+    pub velocity_bias: FloatNum,
+    /// Minimum dot product between cached and current contact normals for a
+    /// warm-start hit.
+    pub warm_start_normal_dot_threshold: FloatNum,
+    /// Maximum collider-local anchor drift for a warm-start hit.
+    pub warm_start_point_drift_threshold: FloatNum,
+    /// Soft-step contact stiffness; capped at a quarter of the sub-step rate.
+    pub soft_contact_hertz: FloatNum,
+    /// Soft-step contact damping ratio.
+    pub soft_contact_damping_ratio: FloatNum,
+    /// Largest separating speed the soft-step solver may add to resolve penetration.
+    pub soft_contact_max_push_velocity: FloatNum,
+}
+
+impl Default for SolverParams {
+    fn default() -> Self {
+        Self {
+            contact_slop: 0.005,
+            position_correction_percent: 0.8,
+            velocity_bias: 0.5,
+            warm_start_normal_dot_threshold: 0.98,
+            warm_start_point_drift_threshold: 0.05,
+            soft_contact_hertz: 30.0,
+            soft_contact_damping_ratio: 10.0,
+            soft_contact_max_push_velocity: 3.0,
+        }
+    }
+}
+
+impl SolverParams {
+    /// Checks that every parameter is finite and inside its usable range.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        check(non_negative(self.contact_slop), "solver.contact_slop")?;
+        check(
+            unit_interval(self.position_correction_percent),
+            "solver.position_correction_percent",
+        )?;
+        check(unit_interval(self.velocity_bias), "solver.velocity_bias")?;
+        check(
+            self.warm_start_normal_dot_threshold.is_finite()
+                && (-1.0..=1.0).contains(&self.warm_start_normal_dot_threshold),
+            "solver.warm_start_normal_dot_threshold",
+        )?;
+        check(
+            non_negative(self.warm_start_point_drift_threshold),
+            "solver.warm_start_point_drift_threshold",
+        )?;
+        check(
+            positive(self.soft_contact_hertz),
+            "solver.soft_contact_hertz",
+        )?;
+        check(
+            non_negative(self.soft_contact_damping_ratio),
+            "solver.soft_contact_damping_ratio",
+        )?;
+        check(
+            positive(self.soft_contact_max_push_velocity),
+            "solver.soft_contact_max_push_velocity",
+        )
+    }
+}
+
+/// Island sleep thresholds.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SleepParams {
+    /// Seconds an island must stay below both motion thresholds before it sleeps.
+    pub stability_seconds: FloatNum,
+    /// Linear speed below which a body counts as resting.
+    pub linear_threshold: FloatNum,
+    /// Angular speed below which a body counts as resting.
+    pub angular_threshold: FloatNum,
+}
+
+impl Default for SleepParams {
+    fn default() -> Self {
+        Self {
+            stability_seconds: 0.5,
+            linear_threshold: 0.0001,
+            angular_threshold: 0.0001,
+        }
+    }
+}
+
+impl SleepParams {
+    /// Checks that every threshold is finite and non-negative.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        check(
+            non_negative(self.stability_seconds),
+            "sleep.stability_seconds",
+        )?;
+        check(
+            non_negative(self.linear_threshold),
+            "sleep.linear_threshold",
+        )?;
+        check(
+            non_negative(self.angular_threshold),
+            "sleep.angular_threshold",
+        )
+    }
+}
+
+/// Which continuous collision strategies run each step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CcdMode {
+    /// Sweeps fast bodies between poses and resolves hits per `resolution`.
+    #[default]
+    Sweep,
+    /// Skips sweeps and adds speculative contacts for pairs closer than their
+    /// next-step motion. Cheaper, but a body can still pass through geometry
+    /// it reaches within a single step before any speculative contact exists.
+    Speculative,
+    /// Runs both sweeps and speculative contacts.
+    SweepAndSpeculative,
+}
+
+impl CcdMode {
+    /// Whether swept time-of-impact queries run.
+    pub fn sweeps(self) -> bool {
+        matches!(self, Self::Sweep | Self::SweepAndSpeculative)
+    }
+
+    /// Whether speculative contacts are generated.
+    pub fn speculative(self) -> bool {
+        matches!(self, Self::Speculative | Self::SweepAndSpeculative)
+    }
+}
+
+/// How CCD responds once a sweep finds a time of impact.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CcdResolution {
+    /// Pulls each hit body back to its time of impact and drops the rest of its motion.
+    #[default]
+    PoseClamp,
+    /// Advances the world to the earliest time of impact, resolves that contact
+    /// with a normal impulse, and integrates the pair over the remaining time.
+    ///
+    /// Hits left once `max_toi_substeps` or `max_body_toi_substeps` is spent
+    /// fall back to pose clamping.
+    SubStep,
+}
+
+/// Continuous collision detection tolerances.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CcdParams {
+    /// Sweeps, times of impact, and axis speeds at or below this are treated as zero.
+    pub toi_epsilon: FloatNum,
+    /// Distance a clamped body is allowed to advance past its time of impact so
+    /// the next narrowphase pass still sees the contact.
+    pub clamp_slop: FloatNum,
+    /// Projection tolerance used to group support vertices into one feature.
+    pub support_epsilon: FloatNum,
+    /// Continuous collision strategies to run.
+    pub mode: CcdMode,
+    /// Strategy used to resolve CCD hits.
+    pub resolution: CcdResolution,
+    /// Maximum number of time-of-impact sub-steps resolved per step.
+    pub max_toi_substeps: u32,
+    /// Maximum number of time-of-impact sub-steps any one body takes part in per step.
+    pub max_body_toi_substeps: u32,
+}
+
+impl Default for CcdParams {
+    fn default() -> Self {
+        Self {
+            toi_epsilon: 1.0e-5,
+            clamp_slop: 1.0e-3,
+            support_epsilon: 1.0e-4,
+            mode: CcdMode::Sweep,
+            resolution: CcdResolution::PoseClamp,
+            max_toi_substeps: 8,
+            max_body_toi_substeps: 2,
+        }
+    }
+}
+
+impl CcdParams {
+    /// Checks that every tolerance is finite and in range and both sub-step
+    /// budgets are positive.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        check(
+            positive(self.toi_epsilon) && self.toi_epsilon < 1.0,
+            "ccd.toi_epsilon",
+        )?;
+        check(non_negative(self.clamp_slop), "ccd.clamp_slop")?;
+        check(positive(self.support_epsilon), "ccd.support_epsilon")?;
+        check(self.max_toi_substeps > 0, "ccd.max_toi_substeps")?;
+        check(self.max_body_toi_substeps > 0, "ccd.max_body_toi_substeps")
+    }
+}
+
+fn check(valid: bool, field: &'static str) -> Result<(), ValidationError> {
+    if valid {
+        Ok(())
+    } else {
+        Err(ValidationError::StepConfig { field })
+    }
+}
+
+fn non_negative(value: FloatNum) -> bool {
+    value.is_finite() && value >= 0.0
+}
+
+fn positive(value: FloatNum) -> bool {
+    value.is_finite() && value > 0.0
+}
+
+fn unit_interval(value: FloatNum) -> bool {
+    value.is_finite() && (0.0..=1.0).contains(&value)
+}
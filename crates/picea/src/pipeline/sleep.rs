@@ -4,15 +4,10 @@ use crate::{
     body::BodyType,
     events::{SleepEvent, SleepTransitionReason, WorldEvent},
     handles::BodyHandle,
-    math::FloatNum,
-    pipeline::StepConfig,
+    pipeline::{SleepParams, StepConfig},
     world::World,
 };
 
-const SLEEP_STABILITY_SECONDS: FloatNum = 0.5;
-const SLEEP_LINEAR_THRESHOLD: FloatNum = 0.0001;
-const SLEEP_ANGULAR_THRESHOLD: FloatNum = 0.0001;
-
 #[derive(Clone, Debug)]
 struct Island {
     id: u32,
@@ -46,6 +41,16 @@ pub(crate) fn record_wake_reason(
         .or_insert(reason);
 }
 
+/// Non-static bodies sharing an island with `body`, resolved from the
+/// committed contact cache and joints. Static bodies belong to no island.
+pub(crate) fn island_bodies_containing(world: &World, body: BodyHandle) -> Vec<BodyHandle> {
+    build_islands_from_pairs(world, world.active_contact_body_pairs())
+        .into_iter()
+        .find(|island| island.bodies.contains(&body))
+        .map(|island| island.bodies)
+        .unwrap_or_default()
+}
+
 pub(crate) fn refresh_sleep_phase(
     world: &mut World,
     config: &StepConfig,
@@ -117,7 +122,7 @@ impl World {
 
             if let Some(reason) = island_wake_reasons.get(&island.id).copied() {
                 active_body_count +=
-                    self.wake_island(&island, previous_sleep_states, reason, &mut events);
+                    self.wake_sleeping_island(&island, previous_sleep_states, reason, &mut events);
                 continue;
             }
 
@@ -145,9 +150,10 @@ impl World {
                 self.body_record(*body)
                     .map(|record| {
                         record.body_type.is_dynamic()
-                            && is_low_motion(record)
+                            && is_low_motion(record, &config.sleep)
                             && (previous_sleep_states.get(body).copied().unwrap_or(false)
-                                || record.sleep_idle_time + config.dt >= SLEEP_STABILITY_SECONDS)
+                                || record.sleep_idle_time + config.dt
+                                    >= sleep_params(record, &config.sleep).stability_seconds)
                     })
                     .unwrap_or(false)
             });
@@ -159,11 +165,12 @@ impl World {
                     .expect("island bodies must remain live during sleep refresh");
 
                 if should_sleep {
-                    record.sleep_idle_time = SLEEP_STABILITY_SECONDS;
+                    record.sleep_idle_time = sleep_params(record, &config.sleep).stability_seconds;
                     record.sleeping = true;
                 } else {
-                    record.sleep_idle_time = if is_low_motion(record) {
-                        (record.sleep_idle_time + config.dt).min(SLEEP_STABILITY_SECONDS)
+                    record.sleep_idle_time = if is_low_motion(record, &config.sleep) {
+                        (record.sleep_idle_time + config.dt)
+                            .min(sleep_params(record, &config.sleep).stability_seconds)
                     } else {
                         0.0
                     };
@@ -190,7 +197,7 @@ impl World {
         (events, transitions, active_body_count)
     }
 
-    fn wake_island(
+    fn wake_sleeping_island(
         &mut self,
         island: &Island,
         previous_sleep_states: &BTreeMap<BodyHandle, bool>,
@@ -343,9 +350,18 @@ fn expand_wake_reasons(
     island_reasons
 }
 
-fn is_low_motion(record: &crate::body::BodyRecord) -> bool {
-    record.linear_velocity.length() < SLEEP_LINEAR_THRESHOLD
-        && record.angular_velocity.abs() < SLEEP_ANGULAR_THRESHOLD
+/// Per-body thresholds win over the step-wide defaults.
+fn sleep_params<'a>(
+    record: &'a crate::body::BodyRecord,
+    defaults: &'a SleepParams,
+) -> &'a SleepParams {
+    record.sleep_thresholds.as_ref().unwrap_or(defaults)
+}
+
+fn is_low_motion(record: &crate::body::BodyRecord, defaults: &SleepParams) -> bool {
+    let params = sleep_params(record, defaults);
+    record.linear_velocity.length() < params.linear_threshold
+        && record.angular_velocity.abs() < params.angular_threshold
 }
 
 fn wake_reason_priority(reason: SleepTransitionReason) -> u8 {
@@ -356,9 +372,11 @@ fn wake_reason_priority(reason: SleepTransitionReason) -> u8 {
         SleepTransitionReason::JointCorrection => 3,
         SleepTransitionReason::ContactImpulse => 4,
         SleepTransitionReason::Impact => 5,
-        SleepTransitionReason::UserPatch => 6,
-        SleepTransitionReason::TransformEdit => 7,
-        SleepTransitionReason::VelocityEdit => 8,
+        SleepTransitionReason::ManualSleep => 6,
+        SleepTransitionReason::UserPatch => 7,
+        SleepTransitionReason::ManualWake => 8,
+        SleepTransitionReason::TransformEdit => 9,
+        SleepTransitionReason::VelocityEdit => 10,
     }
 }
 
@@ -418,6 +436,7 @@ mod tests {
         let mut world = World::new(WorldDesc {
             gravity: Vector::default(),
             enable_sleep: true,
+            ..WorldDesc::default()
         });
         let static_body = world
             .create_body(BodyDesc {
@@ -13,8 +13,10 @@ use crate::{
 };
 
 pub(crate) fn simulate_world_step(world: &mut World, config: &StepConfig) -> StepOutcome {
+    world.store_previous_poses();
     let mut step = StepContext::new(world);
 
+    crate::pipeline::fluid::run_fluid_phase(world, config);
     crate::pipeline::integrate::run_integration_phase(world, config, &mut step.numeric_warnings);
     let joint_solver_stats = crate::pipeline::joints::solve_joint_phase(
         world,
@@ -23,7 +25,8 @@ pub(crate) fn simulate_world_step(world: &mut World, config: &StepConfig) -> Ste
         &mut step.numeric_warnings,
     );
     step.record_solver_stats(joint_solver_stats);
-    step.pose_clamp = crate::pipeline::ccd::run_pose_clamp_phase(world, &step.previous_body_poses);
+    let substep_origins = crate::solver::contact::soft_step_origins(world, config);
+    step.pose_clamp = crate::pipeline::ccd::run_ccd_phase(world, &step.previous_body_poses, config);
     let (
         contact_events,
         contact_count,
@@ -36,6 +39,7 @@ pub(crate) fn simulate_world_step(world: &mut World, config: &StepConfig) -> Ste
         config,
         &mut step.wake_reasons,
         &step.pose_clamp.traces,
+        &substep_origins,
     );
     step.record_contacts(
         contact_events,
@@ -55,6 +59,9 @@ pub(crate) fn simulate_world_step(world: &mut World, config: &StepConfig) -> Ste
         );
     step.record_sleep(sleep_events, sleep_transition_count, active_body_count);
     step.record_numeric_warnings();
+    if config.deterministic || world.desc().deterministic {
+        world.canonicalize_float_state();
+    }
 
     let stats = step.stats(world, config);
     world.commit_step(config, stats, step.events)
@@ -185,6 +192,10 @@ impl StepContext {
             ccd_hit_count: self.pose_clamp.stats.hit_count,
             ccd_miss_count: self.pose_clamp.stats.miss_count,
             ccd_clamp_count: self.pose_clamp.stats.clamp_count,
+            ccd_substep_count: self.pose_clamp.stats.substep_count,
+            ccd_substep_fallback_count: self.pose_clamp.stats.substep_fallback_count,
+            ccd_speculative_contact_count: self.solver_stats.speculative_row_count,
+            ccd_speculative_enforced_count: self.solver_stats.speculative_enforced_count,
             velocity_iterations: config.velocity_iterations,
             position_iterations: config.position_iterations,
             sleep_transition_count: self.sleep_transition_count,
@@ -2,7 +2,9 @@
 //!
 //! The query layer consumes stable debug facts instead of borrowing world
 //! internals directly. That keeps spatial queries portable across native,
-//! wasm, and future client/server consumers.
+//! wasm, and future client/server consumers. World-side features that query
+//! inside a single call read the world's query broadphase through the
+//! crate-internal `WorldQueries` instead.
 
 use std::cmp::Ordering;
 use std::sync::Mutex;
@@ -11,8 +13,8 @@ use crate::{
     body::Pose,
     collider::{CollisionFilter, ShapeAabb, SharedShape},
     debug::{
-        sanitize_point, sanitize_scalar, sanitize_vector, DebugAabb, DebugCollider, DebugShape,
-        DebugSnapshot, DebugSnapshotOptions,
+        debug_shape_from_shared_shape, sanitize_point, sanitize_scalar, sanitize_vector, DebugAabb,
+        DebugCollider, DebugShape, DebugSnapshot, DebugSnapshotOptions,
     },
     handles::{BodyHandle, ColliderHandle, WorldRevision},
     math::{point::Point, vector::Vector, FloatNum},
@@ -436,17 +438,7 @@ impl QueryPipeline {
                 stats.filter_drop_count += 1;
                 continue;
             }
-            let Some(hit) =
-                ray_cast_shape(origin, direction, max_toi, &collider.shape, collider.bounds).map(
-                    |(toi, point, normal)| RayHit {
-                        body: collider.body,
-                        collider: collider.handle,
-                        toi,
-                        point,
-                        normal,
-                    },
-                )
-            else {
+            let Some(hit) = collider.ray_hit(origin, direction, max_toi) else {
                 continue;
             };
             match &best_hit {
@@ -570,33 +562,16 @@ impl QueryPipeline {
                 stats.filter_drop_count += 1;
                 continue;
             }
-            let Some((distance, query_point, collider_point, normal)) =
-                distance_between_shapes(shape.shape(), &collider.shape)
-            else {
+            let Some(hit) = collider.shape_hit(shape, max_distance) else {
                 continue;
             };
-            if distance > max_distance {
-                continue;
-            }
             hits.push(OrderedShapeHit {
                 snapshot_index: collider.snapshot_index,
-                hit: ShapeHit {
-                    body: collider.body,
-                    collider: collider.handle,
-                    distance,
-                    query_point,
-                    collider_point,
-                    normal,
-                },
+                hit,
             });
         }
 
-        hits.sort_by(|lhs, rhs| {
-            lhs.hit
-                .distance
-                .total_cmp(&rhs.hit.distance)
-                .then_with(|| lhs.snapshot_index.cmp(&rhs.snapshot_index))
-        });
+        sort_shape_hits(&mut hits);
         if first_only {
             hits.truncate(1);
         }
@@ -607,6 +582,121 @@ impl QueryPipeline {
     }
 }
 
+/// Exact queries answered from the world's query broadphase.
+///
+/// World-side features that query inside one call (explosions, controllers)
+/// use this instead of syncing a [`QueryPipeline`] each time: candidates come
+/// from the world's incrementally refreshed query tree and only candidates are
+/// turned into query shapes. Call `World::sync_query_broadphase` first so
+/// colliders created or moved since the last refresh are indexed.
+pub(crate) struct WorldQueries<'a> {
+    world: &'a World,
+}
+
+impl<'a> WorldQueries<'a> {
+    pub(crate) fn new(world: &'a World) -> Self {
+        Self { world }
+    }
+
+    /// Same contract as [`QueryPipeline::cast_ray`].
+    pub(crate) fn cast_ray(
+        &self,
+        origin: Point,
+        direction: Vector,
+        max_toi: FloatNum,
+        filter: QueryFilter,
+    ) -> Option<RayHit> {
+        let origin = sanitize_point(origin);
+        let direction = sanitize_vector(direction);
+        let max_toi = sanitize_scalar(max_toi).max(0.0);
+        let mut best_hit: Option<RayHit> = None;
+        for collider in self.candidates(
+            self.world
+                .query_broadphase()
+                .query_ray(origin, direction, max_toi),
+            filter,
+        ) {
+            let Some(hit) = collider.ray_hit(origin, direction, max_toi) else {
+                continue;
+            };
+            match &best_hit {
+                Some(current) if hit.toi >= current.toi => {}
+                _ => best_hit = Some(hit),
+            }
+        }
+        best_hit
+    }
+
+    /// Same contract as [`QueryPipeline::intersect_aabb`].
+    pub(crate) fn intersect_aabb(&self, aabb: DebugAabb, filter: QueryFilter) -> Vec<AabbHit> {
+        let query_bounds = DebugAabb::new(aabb.min, aabb.max);
+        self.candidates(
+            self.world
+                .query_broadphase()
+                .query_aabb(query_bounds.into()),
+            filter,
+        )
+        .filter(|collider| collider.bounds.overlaps(&query_bounds))
+        .map(|collider| AabbHit {
+            body: collider.body,
+            collider: collider.handle,
+            bounds: collider.bounds,
+        })
+        .collect()
+    }
+
+    /// Same contract as [`QueryPipeline::intersect_shape`].
+    pub(crate) fn intersect_shape(
+        &self,
+        shape: &QueryShape,
+        max_distance: FloatNum,
+        filter: QueryFilter,
+    ) -> Result<Vec<ShapeHit>, QueryShapeError> {
+        let max_distance = validate_non_negative_scalar(max_distance, "max_distance")?;
+        let query_bounds = expand_aabb(shape.bounds(), max_distance);
+        let mut hits = self
+            .candidates(
+                self.world
+                    .query_broadphase()
+                    .query_aabb(query_bounds.into()),
+                filter,
+            )
+            .filter_map(|collider| {
+                Some(OrderedShapeHit {
+                    snapshot_index: collider.snapshot_index,
+                    hit: collider.shape_hit(shape, max_distance)?,
+                })
+            })
+            .collect::<Vec<_>>();
+        sort_shape_hits(&mut hits);
+        Ok(hits.into_iter().map(|entry| entry.hit).collect())
+    }
+
+    /// Live query records for broadphase candidates that pass `filter`.
+    fn candidates(
+        &self,
+        handles: Vec<ColliderHandle>,
+        filter: QueryFilter,
+    ) -> impl Iterator<Item = QueryColliderRecord> + '_ {
+        handles
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, handle)| {
+                QueryColliderRecord::from_world(self.world, index, handle)
+            })
+            .filter(move |collider| filter.matches(collider))
+    }
+}
+
+fn sort_shape_hits(hits: &mut [OrderedShapeHit]) {
+    hits.sort_by(|lhs, rhs| {
+        lhs.hit
+            .distance
+            .total_cmp(&rhs.hit.distance)
+            .then_with(|| lhs.snapshot_index.cmp(&rhs.snapshot_index))
+    });
+}
+
 fn query_stats_from_tree(stats: TreeQueryStats) -> QueryStats {
     QueryStats {
         traversal_count: stats.traversal_count,
@@ -1042,8 +1132,26 @@ fn compare_point_pair(lhs: (Point, Point), rhs: (Point, Point)) -> Ordering {
         .then_with(|| point_order(lhs.1).cmp(&point_order(rhs.1)))
 }
 
-fn point_order(point: Point) -> (u32, u32) {
-    (point.x().to_bits(), point.y().to_bits())
+fn point_order(point: Point) -> (TotalOrder, TotalOrder) {
+    (TotalOrder(point.x()), TotalOrder(point.y()))
+}
+
+/// IEEE total order key so tie-breaks are identical in `f32` and `f64` builds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TotalOrder(FloatNum);
+
+impl Eq for TotalOrder {}
+
+impl PartialOrd for TotalOrder {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalOrder {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
 }
 
 fn finalize_shape_distance(
@@ -1169,10 +1277,64 @@ fn closest_points_between_segments(a0: Point, a1: Point, b0: Point, b1: Point) -
         s = ((b - c) / a).clamp(0.0, 1.0);
     }
 
-    (a0 + d1 * s, b0 + d2 * t)
+    (point_on_segment(a0, a1, s), point_on_segment(b0, b1, t))
+}
+
+/// Interpolates along a segment, returning clamped endpoints exactly so that
+/// equal-distance candidates compare equal regardless of float width.
+fn point_on_segment(start: Point, end: Point, t: FloatNum) -> Point {
+    if t <= 0.0 {
+        start
+    } else if t >= 1.0 {
+        end
+    } else {
+        start + (end - start) * t
+    }
 }
 
 impl QueryColliderRecord {
+    /// Builds a record straight from world state; `order` ranks ties the way
+    /// `snapshot_index` does for cached records.
+    fn from_world(world: &World, order: usize, handle: ColliderHandle) -> Option<Self> {
+        let collider = world.collider_record(handle).ok()?;
+        let body_pose = world.body_record(collider.body).ok()?.pose;
+        let aabb = collider.derived_geometry(body_pose).aabb;
+        Some(Self {
+            snapshot_index: order,
+            handle,
+            body: collider.body,
+            is_sensor: collider.is_sensor,
+            filter: collider.filter,
+            bounds: DebugAabb::new(aabb.min, aabb.max),
+            shape: debug_shape_from_shared_shape(&collider.shape, collider.world_pose(body_pose)),
+        })
+    }
+
+    fn ray_hit(&self, origin: Point, direction: Vector, max_toi: FloatNum) -> Option<RayHit> {
+        ray_cast_shape(origin, direction, max_toi, &self.shape, self.bounds).map(
+            |(toi, point, normal)| RayHit {
+                body: self.body,
+                collider: self.handle,
+                toi,
+                point,
+                normal,
+            },
+        )
+    }
+
+    fn shape_hit(&self, shape: &QueryShape, max_distance: FloatNum) -> Option<ShapeHit> {
+        let (distance, query_point, collider_point, normal) =
+            distance_between_shapes(shape.shape(), &self.shape)?;
+        (distance <= max_distance).then_some(ShapeHit {
+            body: self.body,
+            collider: self.handle,
+            distance,
+            query_point,
+            collider_point,
+            normal,
+        })
+    }
+
     fn from_debug_collider(snapshot_index: usize, collider: DebugCollider) -> Option<Self> {
         let bounds = collider.aabb.or_else(|| collider.shape.aabb())?;
         Some(Self {
@@ -1305,7 +1467,7 @@ fn ray_cast_polygon(
         let candidate = (toi, point, normal);
         if best
             .as_ref()
-            .map_or(true, |(current_toi, _, _)| toi < *current_toi)
+            .is_none_or(|(current_toi, _, _)| toi < *current_toi)
         {
             best = Some(candidate);
         }
@@ -8,8 +8,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    body::{BodyDesc, BodyPatch, BodyType, Pose},
-    collider::{ColliderDesc, ColliderPatch, CollisionFilter, Material, SharedShape},
+    body::{BodyDesc, BodyPatch, BodyType, MassProperties, Pose},
+    collider::{ColliderDesc, ColliderPatch, CollisionFilter, FluidVolume, Material, SharedShape},
     handles::{BodyHandle, ColliderHandle, JointHandle},
     joint::{DistanceJointDesc, JointDesc, JointPatch, WorldAnchorJointDesc},
     math::{point::Point, FloatNum},
@@ -152,7 +152,7 @@ impl From<CollisionLayerPreset> for CollisionFilter {
 }
 
 /// Collider creation bundle used by recipes and batch commands.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ColliderBundle {
     /// Low-level collider descriptor passed through unchanged on success.
     pub desc: ColliderDesc,
@@ -196,6 +196,12 @@ impl ColliderBundle {
         self
     }
 
+    /// Sets an explicit collider mass that replaces the density-derived mass.
+    pub fn with_mass(mut self, mass: FloatNum) -> Self {
+        self.desc.mass = Some(mass);
+        self
+    }
+
     /// Sets material coefficients or a named material preset.
     pub fn with_material(mut self, material: impl Into<Material>) -> Self {
         self.desc.material = material.into();
@@ -213,13 +219,11 @@ impl ColliderBundle {
         self.desc.is_sensor = is_sensor;
         self
     }
-}
 
-impl Default for ColliderBundle {
-    fn default() -> Self {
-        Self {
-            desc: ColliderDesc::default(),
-        }
+    /// Turns this collider into a buoyancy and drag volume.
+    pub fn with_fluid(mut self, fluid: FluidVolume) -> Self {
+        self.desc.fluid = Some(fluid);
+        self
     }
 }
 
@@ -277,6 +281,12 @@ impl BodyBundle {
         self
     }
 
+    /// Replaces the collider-derived mass properties of this body.
+    pub fn with_mass_override(mut self, mass_override: MassProperties) -> Self {
+        self.desc.mass_override = Some(mass_override);
+        self
+    }
+
     /// Adds one collider to this body bundle.
     pub fn with_collider(mut self, collider: impl Into<ColliderBundle>) -> Self {
         self.colliders.push(collider.into());
@@ -521,6 +531,78 @@ impl WorldRecipe {
         self
     }
 
+    /// Captures a world's current bodies, colliders and joints as a recipe.
+    ///
+    /// Bodies keep the world's iteration order and colliders keep their
+    /// attachment order, so instantiating the recipe rebuilds the same scene
+    /// with joints pointing at recipe indices. Only descriptor state is
+    /// captured: contact warm starts, sleep timers and kinematic targets start
+    /// fresh in the rebuilt world.
+    pub fn from_world(world: &World) -> Self {
+        let body_handles: Vec<_> = world.bodies().collect();
+        let body_index = |handle: BodyHandle| {
+            body_handles
+                .iter()
+                .position(|candidate| *candidate == handle)
+                .expect("joint endpoints are live world bodies")
+        };
+        let bodies = body_handles
+            .iter()
+            .map(|handle| {
+                let colliders = world
+                    .colliders_for_body(*handle)
+                    .expect("world body handles resolve")
+                    .map(|collider| {
+                        ColliderBundle::from_desc(
+                            world
+                                .collider(collider)
+                                .expect("attached colliders resolve")
+                                .desc(),
+                        )
+                    });
+                BodyBundle::new(
+                    world
+                        .body(*handle)
+                        .expect("world body handles resolve")
+                        .desc(),
+                )
+                .with_colliders(colliders)
+            })
+            .collect();
+        let joints = world
+            .joints()
+            .map(|handle| {
+                match world
+                    .joint(handle)
+                    .expect("world joint handles resolve")
+                    .desc()
+                {
+                    JointDesc::Distance(desc) => JointBundle::Distance {
+                        body_a: body_index(desc.body_a),
+                        body_b: body_index(desc.body_b),
+                        desc: DistanceJointDesc {
+                            body_a: BodyHandle::default(),
+                            body_b: BodyHandle::default(),
+                            ..desc.clone()
+                        },
+                    },
+                    JointDesc::WorldAnchor(desc) => JointBundle::WorldAnchor {
+                        body: body_index(desc.body),
+                        desc: WorldAnchorJointDesc {
+                            body: BodyHandle::default(),
+                            ..desc.clone()
+                        },
+                    },
+                }
+            })
+            .collect();
+        Self {
+            desc: world.desc().clone(),
+            bodies,
+            joints,
+        }
+    }
+
     /// Instantiates the recipe into a concrete world.
     pub fn instantiate(self) -> Result<WorldRecipeResult, WorldCommandError> {
         self.instantiate_with_context().map_err(|error| error.error)
@@ -528,7 +610,10 @@ impl WorldRecipe {
 
     /// Instantiates the recipe and returns a nested recipe path when setup fails.
     pub fn instantiate_with_context(self) -> Result<WorldRecipeResult, WorldCommandContextError> {
-        let mut world = World::new(self.desc);
+        let mut world = World::try_new(self.desc).map_err(|error| {
+            let path = validation_path("recipe.desc", &error);
+            command_context_error(0, None, WorldCommandKind::CreateWorld, error, path)
+        })?;
         let created = world
             .commands()
             .create_recipe_with_context(self.bodies, self.joints)?;
@@ -786,6 +871,8 @@ pub enum WorldCommand {
 /// Stable command kind attached to command errors.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WorldCommandKind {
+    /// World descriptor validation failed.
+    CreateWorld,
     /// Body creation failed.
     CreateBody,
     /// Nested collider creation failed.
@@ -1131,7 +1218,12 @@ fn validation_field(error: &WorldError) -> Option<&'static str> {
         | WorldError::Validation(ValidationError::ColliderDesc { field })
         | WorldError::Validation(ValidationError::ColliderPatch { field })
         | WorldError::Validation(ValidationError::JointDesc { field })
-        | WorldError::Validation(ValidationError::JointPatch { field }) => Some(field),
+        | WorldError::Validation(ValidationError::JointPatch { field })
+        | WorldError::Validation(ValidationError::StepConfig { field })
+        | WorldError::Validation(ValidationError::WorldDesc { field })
+        | WorldError::Validation(ValidationError::RadialImpulse { field })
+        | WorldError::Validation(ValidationError::CharacterController { field })
+        | WorldError::Validation(ValidationError::VehicleController { field }) => Some(field),
         WorldError::Handle(_) | WorldError::Topology(_) => None,
     }
 }
@@ -16,39 +16,49 @@ impl World {
         correction_toward_a: Vector,
         wake_reasons: &mut BTreeMap<BodyHandle, SleepTransitionReason>,
     ) {
-        let body_a_dynamic = self
-            .body_record(body_a)
-            .expect("live body handles must resolve")
-            .body_type
-            .is_dynamic();
-        let body_b_dynamic = self
-            .body_record(body_b)
-            .expect("live body handles must resolve")
-            .body_type
-            .is_dynamic();
+        let free_a = self.correction_axes(body_a);
+        let free_b = self.correction_axes(body_b);
 
-        match (body_a_dynamic, body_b_dynamic) {
-            (true, true) => {
-                self.apply_single_body_correction(body_a, correction_toward_a, wake_reasons);
-                self.apply_single_body_correction(body_b, -correction_toward_a, wake_reasons);
-            }
-            (true, false) => {
-                self.apply_single_body_correction(body_a, correction_toward_a * 2.0, wake_reasons);
-            }
-            (false, true) => {
-                self.apply_single_body_correction(body_b, -correction_toward_a * 2.0, wake_reasons);
-            }
-            (false, false) => {}
-        }
+        // Each axis is split on its own so a body locked along x still lets
+        // its partner absorb the whole x correction, like a static body would.
+        let share = |free_self: bool, free_other: bool, component: FloatNum| match (
+            free_self, free_other,
+        ) {
+            (true, true) => component,
+            (true, false) => component * 2.0,
+            (false, _) => 0.0,
+        };
+        let correction_a = Vector::new(
+            share(free_a.0, free_b.0, correction_toward_a.x()),
+            share(free_a.1, free_b.1, correction_toward_a.y()),
+        );
+        let correction_b = Vector::new(
+            share(free_b.0, free_a.0, -correction_toward_a.x()),
+            share(free_b.1, free_a.1, -correction_toward_a.y()),
+        );
+        self.apply_single_body_correction(body_a, correction_a, wake_reasons);
+        self.apply_single_body_correction(body_b, correction_b, wake_reasons);
+    }
+
+    /// Which world translation axes a position correction may move `body` along.
+    fn correction_axes(&self, body: BodyHandle) -> (bool, bool) {
+        let record = self
+            .body_record(body)
+            .expect("live body handles must resolve");
+        let dynamic = record.body_type.is_dynamic();
+        (
+            dynamic && !record.locked_axes.translation_x,
+            dynamic && !record.locked_axes.translation_y,
+        )
     }
 
     pub(crate) fn apply_single_body_correction(
         &mut self,
         body: BodyHandle,
-        translation: Vector,
+        mut translation: Vector,
         wake_reasons: &mut BTreeMap<BodyHandle, SleepTransitionReason>,
     ) {
-        if translation.length() <= f32::EPSILON {
+        if translation.length() <= FloatNum::EPSILON {
             return;
         }
         let record = self
@@ -57,6 +67,14 @@ impl World {
         if !record.body_type.is_dynamic() {
             return;
         }
+        if record.locked_axes.translation_x || record.locked_axes.translation_y {
+            // A correction entirely along locked axes leaves the body, its
+            // velocity and its sleep state alone.
+            translation = record.locked_axes.mask_linear(translation);
+            if translation.length() <= FloatNum::EPSILON {
+                return;
+            }
+        }
         let was_sleeping = record.sleeping;
         translate_pose(&mut record.pose, translation, 0.0);
         record.linear_velocity = Vector::default();
@@ -73,6 +91,29 @@ impl World {
     }
 }
 
+/// Inverse mass a per-axis inverse mass presents along `direction`.
+pub(crate) fn inverse_mass_along(inverse_mass: Vector, direction: Vector) -> FloatNum {
+    inverse_mass.x() * direction.x() * direction.x()
+        + inverse_mass.y() * direction.y() * direction.y()
+}
+
+/// Velocity change from `impulse` for a per-axis inverse mass.
+pub(crate) fn linear_response(inverse_mass: Vector, impulse: Vector) -> Vector {
+    Vector::new(
+        impulse.x() * inverse_mass.x(),
+        impulse.y() * inverse_mass.y(),
+    )
+}
+
+/// Velocity that `angular_velocity` adds at `anchor`, measured from the center
+/// of mass, in the solver's angular sign convention.
+pub(crate) fn angular_point_velocity(angular_velocity: FloatNum, anchor: Vector) -> Vector {
+    Vector::new(
+        angular_velocity * anchor.y(),
+        -angular_velocity * anchor.x(),
+    )
+}
+
 pub(crate) fn translate_pose(pose: &mut Pose, translation: Vector, angle_delta: FloatNum) {
     *pose = crate::pipeline::integrate::translated_pose(*pose, translation, angle_delta);
 }
@@ -1,25 +1,28 @@
 use std::collections::BTreeMap;
 
 use crate::{
+    body::Pose,
     events::SleepTransitionReason,
     handles::BodyHandle,
     math::{point::Point, vector::Vector, FloatNum},
-    pipeline::{contacts::ContactObservation, island, sleep, StepConfig},
+    pipeline::{contacts::ContactObservation, island, sleep, SolverMode, StepConfig},
+    solver::body_state::{angular_point_velocity, inverse_mass_along, linear_response},
     world::World,
 };
 
-const POSITION_CORRECTION_PERCENT: FloatNum = 0.8;
-const POSITION_CORRECTION_SLOP: FloatNum = 0.005;
-const CONTACT_VELOCITY_BIAS: FloatNum = 0.5;
-
 #[derive(Clone, Copy, Debug)]
 struct SolverBody {
     dynamic: bool,
-    inverse_mass: FloatNum,
+    /// Per-axis inverse mass; locked translation axes are zero.
+    inverse_mass: Vector,
     inverse_inertia: FloatNum,
     center: Point,
     linear_velocity: Vector,
     angular_velocity: FloatNum,
+    /// Pose change accumulated by soft-step sub-steps relative to the
+    /// integrated pose the contacts were measured at.
+    delta_position: Vector,
+    delta_angle: FloatNum,
 }
 
 #[derive(Clone, Debug)]
@@ -36,12 +39,15 @@ struct ContactSolverRow {
     friction: FloatNum,
     restitution_bias: FloatNum,
     position_bias: FloatNum,
+    separation: FloatNum,
+    peak_normal_impulse: FloatNum,
     normal_impulse: FloatNum,
     tangent_impulse: FloatNum,
     normal_impulse_clamped: bool,
     tangent_impulse_clamped: bool,
     restitution_velocity_threshold: FloatNum,
     restitution_applied: bool,
+    speculative: bool,
 }
 
 struct ContactSolveBatch {
@@ -49,11 +55,18 @@ struct ContactSolveBatch {
     rows: Vec<ContactSolverRow>,
 }
 
+struct SolvedContactBatch {
+    body_slots: Vec<BodyHandle>,
+    rows: Vec<ContactSolverRow>,
+    bodies: Vec<SolverBody>,
+}
+
 pub(crate) fn resolve_contacts(
     world: &mut World,
     contacts: &mut [ContactObservation],
     config: &StepConfig,
     wake_reasons: &mut BTreeMap<BodyHandle, SleepTransitionReason>,
+    substep_origins: &BTreeMap<BodyHandle, Pose>,
 ) -> island::SolverStepStats {
     let islands = sleep::build_active_solver_islands(
         world,
@@ -81,24 +94,13 @@ pub(crate) fn resolve_contacts(
         contact.restitution_applied = false;
     }
 
-    let (mut batches, stats) = contact_solver_row_batches(world, contacts, &islands, plan, config);
+    let (batches, mut stats) = contact_solver_row_batches(world, contacts, &islands, plan, config);
 
-    for batch in &mut batches {
-        let mut solver_bodies = solver_body_cache(world, &batch.body_slots);
+    for batch in solve_contact_batches(world, batches, config, substep_origins) {
         for row in &batch.rows {
-            let warm_start_impulse =
-                row.normal * row.normal_impulse + row.tangent * row.tangent_impulse;
-            apply_solver_impulse(&mut solver_bodies, row, warm_start_impulse);
-        }
-
-        for _ in 0..config.velocity_iterations {
-            for row in &mut batch.rows {
-                solve_normal_impulse(&mut solver_bodies, row);
-                solve_tangent_impulse(&mut solver_bodies, row);
+            if row.speculative && row.normal_impulse > FloatNum::EPSILON {
+                stats.speculative_enforced_count += 1;
             }
-        }
-
-        for row in &batch.rows {
             let contact = &mut contacts[row.contact_index];
             contact.normal_impulse = row.normal_impulse.max(0.0);
             contact.tangent_impulse = row.tangent_impulse;
@@ -108,18 +110,236 @@ pub(crate) fn resolve_contacts(
             contact.restitution_applied = row.restitution_applied;
         }
         record_contact_impulse_wakes_for_rows(world, contacts, &batch.rows, wake_reasons);
-        write_solver_velocities(world, &batch.body_slots, &solver_bodies, wake_reasons);
+        write_solver_velocities(world, &batch.body_slots, &batch.bodies, wake_reasons);
+        write_solver_pose_corrections(world, &batch.body_slots, &batch.bodies, wake_reasons);
     }
 
-    apply_residual_contact_position_correction(
-        world,
-        contacts,
-        config.position_iterations,
-        wake_reasons,
-    );
+    // Soft-step sub-steps already integrate positions against the contact
+    // separation, so only the sequential solver needs a residual push-out.
+    if config.solver_mode == SolverMode::SequentialImpulse {
+        apply_residual_contact_position_correction(world, contacts, config, wake_reasons);
+    }
     stats
 }
 
+#[cfg(not(feature = "parallel"))]
+fn solve_contact_batches(
+    world: &World,
+    batches: Vec<ContactSolveBatch>,
+    config: &StepConfig,
+    substep_origins: &BTreeMap<BodyHandle, Pose>,
+) -> Vec<SolvedContactBatch> {
+    batches
+        .into_iter()
+        .map(|batch| solve_contact_batch(world, batch, config, substep_origins))
+        .collect()
+}
+
+#[cfg(feature = "parallel")]
+fn solve_contact_batches(
+    world: &World,
+    batches: Vec<ContactSolveBatch>,
+    config: &StepConfig,
+    substep_origins: &BTreeMap<BodyHandle, Pose>,
+) -> Vec<SolvedContactBatch> {
+    use rayon::prelude::*;
+
+    if batches.len() < 2 || rayon::current_num_threads() < 2 {
+        return batches
+            .into_iter()
+            .map(|batch| solve_contact_batch(world, batch, config, substep_origins))
+            .collect();
+    }
+    // Islands never share a dynamic body, so each batch only reads world state
+    // that no other batch writes. The indexed collect keeps plan order, and the
+    // caller writes results back serially, so output is bit-identical to the
+    // serial path.
+    batches
+        .into_par_iter()
+        .map(|batch| solve_contact_batch(world, batch, config, substep_origins))
+        .collect()
+}
+
+fn solve_contact_batch(
+    world: &World,
+    batch: ContactSolveBatch,
+    config: &StepConfig,
+    substep_origins: &BTreeMap<BodyHandle, Pose>,
+) -> SolvedContactBatch {
+    match config.solver_mode {
+        SolverMode::SequentialImpulse => solve_sequential_impulse_batch(world, batch, config),
+        SolverMode::SoftStep { substeps } => {
+            solve_soft_step_batch(world, batch, config, substeps.max(1), substep_origins)
+        }
+    }
+}
+
+fn solve_sequential_impulse_batch(
+    world: &World,
+    mut batch: ContactSolveBatch,
+    config: &StepConfig,
+) -> SolvedContactBatch {
+    let mut bodies = solver_body_cache(world, &batch.body_slots);
+    for row in &batch.rows {
+        let warm_start_impulse =
+            row.normal * row.normal_impulse + row.tangent * row.tangent_impulse;
+        apply_solver_impulse(&mut bodies, row, warm_start_impulse);
+    }
+
+    for _ in 0..config.velocity_iterations {
+        for row in &mut batch.rows {
+            solve_normal_impulse(&mut bodies, row);
+            solve_tangent_impulse(&mut bodies, row);
+        }
+    }
+
+    SolvedContactBatch {
+        body_slots: batch.body_slots,
+        rows: batch.rows,
+        bodies,
+    }
+}
+
+fn solve_soft_step_batch(
+    world: &World,
+    mut batch: ContactSolveBatch,
+    config: &StepConfig,
+    substeps: u16,
+    substep_origins: &BTreeMap<BodyHandle, Pose>,
+) -> SolvedContactBatch {
+    let mut bodies = solver_body_cache(world, &batch.body_slots);
+    let substep_dt = config.dt / FloatNum::from(substeps);
+    let inverse_substep_dt = 1.0 / substep_dt;
+    let softness = Softness::new(
+        config
+            .solver
+            .soft_contact_hertz
+            .min(0.25 * inverse_substep_dt),
+        config.solver.soft_contact_damping_ratio,
+        config.solver.soft_contact_max_push_velocity,
+        substep_dt,
+    );
+
+    // Integration already moved every body by a full step of velocity and
+    // applied a full step of gravity. Rewind both so the sub-steps can replay
+    // the step in slices; kinematic and static bodies replay to zero change.
+    let substep_gravity = batch
+        .body_slots
+        .iter()
+        .zip(bodies.iter_mut())
+        .map(|(handle, body)| {
+            let record = world
+                .body_record(*handle)
+                .expect("live contact body handles must resolve");
+            if let Some(origin) = substep_origins.get(handle) {
+                let local_center = record.mass_properties.local_center_of_mass;
+                body.delta_position = origin.transform_point(local_center) - body.center;
+                body.delta_angle = origin.angle() - record.pose.angle();
+            }
+            if !body.dynamic {
+                return Vector::default();
+            }
+            let step_gravity = record
+                .locked_axes
+                .mask_linear(world.body_gravity(record) * config.dt);
+            body.linear_velocity -= step_gravity;
+            step_gravity / FloatNum::from(substeps)
+        })
+        .collect::<Vec<_>>();
+
+    for _ in 0..substeps {
+        for (body, gravity) in bodies.iter_mut().zip(&substep_gravity) {
+            body.linear_velocity += *gravity;
+        }
+        for row in &batch.rows {
+            let warm_start_impulse =
+                row.normal * row.normal_impulse + row.tangent * row.tangent_impulse;
+            apply_solver_impulse(&mut bodies, row, warm_start_impulse);
+        }
+        for row in &mut batch.rows {
+            solve_soft_normal_impulse(&mut bodies, row, softness, inverse_substep_dt, true);
+            solve_tangent_impulse(&mut bodies, row);
+        }
+        for body in &mut bodies {
+            body.delta_position += body.linear_velocity * substep_dt;
+            body.delta_angle += body.angular_velocity * substep_dt;
+        }
+        for row in &mut batch.rows {
+            solve_soft_normal_impulse(&mut bodies, row, softness, inverse_substep_dt, false);
+            solve_tangent_impulse(&mut bodies, row);
+        }
+    }
+    for row in &mut batch.rows {
+        apply_soft_restitution(&mut bodies, row);
+    }
+
+    SolvedContactBatch {
+        body_slots: batch.body_slots,
+        rows: batch.rows,
+        bodies,
+    }
+}
+
+/// Returns the pose each body's soft-step sub-steps replay from: the pose
+/// after joint correction, rewound by one step of integrated velocity.
+///
+/// Captured before the CCD clamp so a clamped body still replays its full
+/// approach and lets the speculative contact stop it.
+pub(crate) fn soft_step_origins(world: &World, config: &StepConfig) -> BTreeMap<BodyHandle, Pose> {
+    if config.solver_mode == SolverMode::SequentialImpulse {
+        return BTreeMap::new();
+    }
+    world
+        .body_records()
+        .filter(|(_, record)| !record.sleeping)
+        .map(|(handle, record)| {
+            let origin = crate::pipeline::integrate::translated_pose(
+                record.pose,
+                -record.linear_velocity * config.dt,
+                -record.angular_velocity * config.dt,
+            );
+            (handle, origin)
+        })
+        .collect()
+}
+
+/// Spring-damper softening for a constraint solved at a fixed sub-step.
+#[derive(Clone, Copy, Debug)]
+struct Softness {
+    bias_rate: FloatNum,
+    mass_scale: FloatNum,
+    impulse_scale: FloatNum,
+    max_push_velocity: FloatNum,
+}
+
+impl Softness {
+    fn new(
+        hertz: FloatNum,
+        damping_ratio: FloatNum,
+        max_push_velocity: FloatNum,
+        dt: FloatNum,
+    ) -> Self {
+        if hertz <= 0.0 {
+            return Self {
+                bias_rate: 0.0,
+                mass_scale: 1.0,
+                impulse_scale: 0.0,
+                max_push_velocity,
+            };
+        }
+        let omega = crate::math::tau() * hertz;
+        let a1 = 2.0 * damping_ratio + dt * omega;
+        let a2 = dt * omega * a1;
+        let a3 = 1.0 / (1.0 + a2);
+        Self {
+            bias_rate: omega / a1,
+            mass_scale: a2 * a3,
+            impulse_scale: a3,
+            max_push_velocity,
+        }
+    }
+}
+
 fn contact_solver_row_batches(
     world: &World,
     contacts: &[ContactObservation],
@@ -159,6 +379,12 @@ fn contact_solver_row_batches(
         body_slot_count: batches.iter().map(|batch| batch.body_slots.len()).sum(),
         contact_row_count: batches.iter().map(|batch| batch.rows.len()).sum(),
         joint_row_count: 0,
+        speculative_row_count: batches
+            .iter()
+            .flat_map(|batch| &batch.rows)
+            .filter(|row| row.speculative)
+            .count(),
+        speculative_enforced_count: 0,
     };
     (batches, stats)
 }
@@ -173,11 +399,13 @@ fn solver_body_cache(world: &World, body_slots: &[BodyHandle]) -> Vec<SolverBody
             let mass = record.mass_properties;
             SolverBody {
                 dynamic: record.body_type.is_dynamic(),
-                inverse_mass: mass.inverse_mass,
-                inverse_inertia: mass.inverse_inertia,
+                inverse_mass: record.solver_inverse_mass(),
+                inverse_inertia: record.solver_inverse_inertia(),
                 center: record.pose.transform_point(mass.local_center_of_mass),
                 linear_velocity: record.linear_velocity,
                 angular_velocity: record.angular_velocity,
+                delta_position: Vector::default(),
+                delta_angle: 0.0,
             }
         })
         .collect()
@@ -212,8 +440,9 @@ fn contact_solver_row(
     let relative_normal_speed =
         relative_contact_velocity(body_a, body_b, anchor_a, anchor_b).dot(normal);
     let restitution_threshold = config.restitution_velocity_threshold;
-    let restitution_applied =
-        -relative_normal_speed > restitution_threshold && contact.material.restitution > 0.0;
+    let restitution_applied = !contact.speculative
+        && -relative_normal_speed > restitution_threshold
+        && contact.material.restitution > 0.0;
     let restitution_bias = if restitution_applied {
         contact.material.restitution.clamp(0.0, 1.0) * -relative_normal_speed
     } else {
@@ -221,13 +450,23 @@ fn contact_solver_row(
     };
     // Penetration velocity bias gives resting overlap a support impulse during the
     // velocity solve, so Coulomb friction has a real normal budget to clamp against.
-    let position_bias = if relative_normal_speed.abs() <= 1.0e-4 {
-        (contact.depth - POSITION_CORRECTION_SLOP).max(0.0) * CONTACT_VELOCITY_BIAS / config.dt
+    let position_bias = if contact.speculative {
+        // Speculative rows let the pair close the gap, plus the slop so it lands
+        // in regular contact, over the next step and only push back on
+        // approach speed beyond that.
+        (contact.depth - config.solver.contact_slop) / config.dt
+    } else if relative_normal_speed.abs() <= 1.0e-4 {
+        (contact.depth - config.solver.contact_slop).max(0.0) * config.solver.velocity_bias
+            / config.dt
     } else {
         0.0
     };
     let friction = contact.material.friction.max(0.0);
-    let normal_impulse = contact.warm_start_normal_impulse.max(0.0);
+    let normal_impulse = if contact.speculative {
+        0.0
+    } else {
+        contact.warm_start_normal_impulse.max(0.0)
+    };
     let max_friction = friction * normal_impulse;
     let tangent_impulse = contact
         .warm_start_tangent_impulse
@@ -246,6 +485,8 @@ fn contact_solver_row(
         friction,
         restitution_bias,
         position_bias,
+        separation: config.solver.contact_slop - contact.depth,
+        peak_normal_impulse: 0.0,
         normal_impulse,
         tangent_impulse,
         normal_impulse_clamped: false,
@@ -253,6 +494,7 @@ fn contact_solver_row(
             > FloatNum::EPSILON,
         restitution_velocity_threshold: restitution_threshold,
         restitution_applied,
+        speculative: contact.speculative,
     })
 }
 
@@ -277,6 +519,32 @@ fn write_solver_velocities(
     }
 }
 
+fn write_solver_pose_corrections(
+    world: &mut World,
+    body_slots: &[BodyHandle],
+    bodies: &[SolverBody],
+    wake_reasons: &BTreeMap<BodyHandle, SleepTransitionReason>,
+) {
+    for (handle, body) in body_slots.iter().zip(bodies.iter()) {
+        if !body.dynamic || (body.delta_position == Vector::default() && body.delta_angle == 0.0) {
+            continue;
+        }
+        let Ok(record) = world.body_record_mut(*handle) else {
+            continue;
+        };
+        if record.sleeping && !wake_reasons.contains_key(handle) {
+            continue;
+        }
+        // Sub-steps move the center of mass; keep it fixed under the rotation
+        // when converting back to the body origin.
+        let local_center = record.mass_properties.local_center_of_mass;
+        let angle = record.pose.angle() + body.delta_angle;
+        let center = record.pose.transform_point(local_center) + body.delta_position;
+        let origin = center - Vector::from(local_center).rotated(angle);
+        record.pose = Pose::from_xy_angle(origin.x(), origin.y(), angle);
+    }
+}
+
 fn record_contact_impulse_wakes_for_rows(
     world: &World,
     contacts: &[ContactObservation],
@@ -319,9 +587,10 @@ fn contact_counterpart_can_wake(world: &World, other: BodyHandle) -> bool {
 fn apply_residual_contact_position_correction(
     world: &mut World,
     contacts: &[ContactObservation],
-    iterations: u16,
+    config: &StepConfig,
     wake_reasons: &mut BTreeMap<BodyHandle, SleepTransitionReason>,
 ) {
+    let iterations = config.position_iterations;
     if iterations == 0 {
         return;
     }
@@ -329,25 +598,27 @@ fn apply_residual_contact_position_correction(
     for _ in 0..iterations {
         for contact in contacts.iter().filter(|contact| !contact.is_sensor) {
             let normal = contact.normal.normalized_or_zero();
-            let depth = (contact.depth - POSITION_CORRECTION_SLOP).max(0.0);
+            let depth = (contact.depth - config.solver.contact_slop).max(0.0);
             if normal.length() <= FloatNum::EPSILON || depth <= FloatNum::EPSILON {
                 continue;
             }
             let inv_mass_a = world
                 .body_record(contact.body_a)
-                .map(|record| record.mass_properties.inverse_mass)
-                .unwrap_or(0.0);
+                .map(|record| record.solver_inverse_mass())
+                .unwrap_or_default();
             let inv_mass_b = world
                 .body_record(contact.body_b)
-                .map(|record| record.mass_properties.inverse_mass)
-                .unwrap_or(0.0);
-            let inv_mass_sum = inv_mass_a + inv_mass_b;
+                .map(|record| record.solver_inverse_mass())
+                .unwrap_or_default();
+            let inv_mass_sum =
+                inverse_mass_along(inv_mass_a, normal) + inverse_mass_along(inv_mass_b, normal);
             if inv_mass_sum <= FloatNum::EPSILON {
                 continue;
             }
-            let correction = depth * POSITION_CORRECTION_PERCENT / FloatNum::from(iterations);
-            let correction_a = normal * (correction * inv_mass_a / inv_mass_sum);
-            let correction_b = -normal * (correction * inv_mass_b / inv_mass_sum);
+            let correction =
+                depth * config.solver.position_correction_percent / FloatNum::from(iterations);
+            let correction_a = linear_response(inv_mass_a, normal * (correction / inv_mass_sum));
+            let correction_b = linear_response(inv_mass_b, -normal * (correction / inv_mass_sum));
             let wake_a = contact_counterpart_can_wake(world, contact.body_b);
             let wake_b = contact_counterpart_can_wake(world, contact.body_a);
             apply_position_translation(world, contact.body_a, correction_a, wake_a, wake_reasons);
@@ -404,6 +675,70 @@ fn solve_normal_impulse(bodies: &mut [SolverBody], row: &mut ContactSolverRow) {
     apply_solver_impulse(bodies, row, row.normal * delta);
 }
 
+fn solve_soft_normal_impulse(
+    bodies: &mut [SolverBody],
+    row: &mut ContactSolverRow,
+    softness: Softness,
+    inverse_substep_dt: FloatNum,
+    use_bias: bool,
+) {
+    if row.normal_mass <= 0.0 {
+        return;
+    }
+    let Some((body_a, body_b)) = solver_pair(bodies, row) else {
+        return;
+    };
+    // Track separation through the sub-steps by projecting how far the two
+    // anchors have moved since the contact was measured.
+    let separation = row.separation
+        + (point_displacement(body_a, row.anchor_a) - point_displacement(body_b, row.anchor_b))
+            .dot(row.normal);
+    let (bias, mass_scale, impulse_scale) = if separation > 0.0 {
+        // Speculative: allow the gap to close within this sub-step.
+        (separation * inverse_substep_dt, 1.0, 0.0)
+    } else if use_bias {
+        (
+            (softness.bias_rate * separation).max(-softness.max_push_velocity),
+            softness.mass_scale,
+            softness.impulse_scale,
+        )
+    } else {
+        (0.0, 1.0, 0.0)
+    };
+    let normal_speed =
+        relative_contact_velocity(body_a, body_b, row.anchor_a, row.anchor_b).dot(row.normal);
+    let previous = row.normal_impulse;
+    let candidate =
+        previous - row.normal_mass * mass_scale * (normal_speed + bias) - impulse_scale * previous;
+    row.normal_impulse = candidate.max(0.0);
+    row.normal_impulse_clamped |= candidate < 0.0;
+    row.peak_normal_impulse = row.peak_normal_impulse.max(row.normal_impulse);
+    let delta = row.normal_impulse - previous;
+    apply_solver_impulse(bodies, row, row.normal * delta);
+}
+
+fn apply_soft_restitution(bodies: &mut [SolverBody], row: &mut ContactSolverRow) {
+    // Only rows that actually pushed during the sub-steps bounce; a
+    // speculative row that never closed must not launch the bodies.
+    if !row.restitution_applied || row.normal_mass <= 0.0 || row.peak_normal_impulse <= 0.0 {
+        return;
+    }
+    let Some((body_a, body_b)) = solver_pair(bodies, row) else {
+        return;
+    };
+    let normal_speed =
+        relative_contact_velocity(body_a, body_b, row.anchor_a, row.anchor_b).dot(row.normal);
+    let previous = row.normal_impulse;
+    let candidate = previous - (normal_speed - row.restitution_bias) * row.normal_mass;
+    row.normal_impulse = candidate.max(0.0);
+    let delta = row.normal_impulse - previous;
+    apply_solver_impulse(bodies, row, row.normal * delta);
+}
+
+fn point_displacement(body: SolverBody, anchor: Vector) -> Vector {
+    body.delta_position + anchor.rotated(body.delta_angle) - anchor
+}
+
 fn solve_tangent_impulse(bodies: &mut [SolverBody], row: &mut ContactSolverRow) {
     if row.tangent_mass <= 0.0 {
         return;
@@ -440,7 +775,7 @@ fn apply_impulse_to_body(bodies: &mut [SolverBody], slot: usize, anchor: Vector,
     if !body.dynamic {
         return;
     }
-    body.linear_velocity += impulse * body.inverse_mass;
+    body.linear_velocity += linear_response(body.inverse_mass, impulse);
     body.angular_velocity -= anchor.cross(impulse) * body.inverse_inertia;
 }
 
@@ -455,8 +790,8 @@ fn effective_mass(
     let anchor_b_cross = anchor_b.cross(direction);
     // Effective mass is the scalar inverse of "how hard is it to change
     // relative velocity along this row", including angular inertia through r x n.
-    let denominator = body_a.inverse_mass
-        + body_b.inverse_mass
+    let denominator = inverse_mass_along(body_a.inverse_mass, direction)
+        + inverse_mass_along(body_b.inverse_mass, direction)
         + body_a.inverse_inertia * anchor_a_cross * anchor_a_cross
         + body_b.inverse_inertia * anchor_b_cross * anchor_b_cross;
     if denominator.is_finite() && denominator > FloatNum::EPSILON {
@@ -479,9 +814,86 @@ fn contact_point_velocity(body: SolverBody, anchor: Vector) -> Vector {
     body.linear_velocity + angular_point_velocity(body.angular_velocity, anchor)
 }
 
-fn angular_point_velocity(angular_velocity: FloatNum, anchor: Vector) -> Vector {
-    Vector::new(
-        angular_velocity * anchor.y(),
-        -angular_velocity * anchor.x(),
-    )
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use crate::{
+        body::{BodyDesc, BodyType, Pose},
+        collider::{ColliderDesc, SharedShape},
+        math::FloatNum,
+        pipeline::{SimulationPipeline, StepConfig},
+        world::{World, WorldDesc},
+    };
+
+    fn stacked_islands_world() -> World {
+        let mut world = World::new(WorldDesc {
+            enable_sleep: false,
+            ..WorldDesc::default()
+        });
+        for island in 0..12 {
+            let x = island as FloatNum * 4.0;
+            let platform = world
+                .create_body(BodyDesc {
+                    body_type: BodyType::Static,
+                    pose: Pose::from_xy_angle(x, 2.0, 0.0),
+                    ..BodyDesc::default()
+                })
+                .expect("platform should be created");
+            world
+                .create_collider(
+                    platform,
+                    ColliderDesc {
+                        shape: SharedShape::rect(2.0, 1.0),
+                        ..ColliderDesc::default()
+                    },
+                )
+                .expect("platform collider should be created");
+            for level in 0..3 {
+                let body = world
+                    .create_body(BodyDesc {
+                        pose: Pose::from_xy_angle(
+                            x + island as FloatNum * 0.01,
+                            1.0 - level as FloatNum * 1.02,
+                            0.05 * level as FloatNum,
+                        ),
+                        ..BodyDesc::default()
+                    })
+                    .expect("stack body should be created");
+                world
+                    .create_collider(
+                        body,
+                        ColliderDesc {
+                            shape: SharedShape::rect(1.0, 1.0),
+                            ..ColliderDesc::default()
+                        },
+                    )
+                    .expect("stack collider should be created");
+            }
+        }
+        world
+    }
+
+    fn state_hashes_with_threads(threads: usize) -> Vec<u64> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("rayon pool should build");
+        pool.install(|| {
+            let mut world = stacked_islands_world();
+            let mut pipeline = SimulationPipeline::new(StepConfig::default());
+            (0..60)
+                .map(|_| {
+                    pipeline.step(&mut world);
+                    world.state_hash()
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn parallel_island_solve_is_bit_identical_to_single_threaded_solve() {
+        let serial = state_hashes_with_threads(1);
+        let parallel = state_hashes_with_threads(4);
+
+        assert_eq!(serial, parallel);
+    }
 }
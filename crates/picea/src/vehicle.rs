@@ -0,0 +1,405 @@
+//! Ray-cast vehicle controller for arcade cars.
+//!
+//! A vehicle is one dynamic chassis body plus virtual wheels. Each wheel casts
+//! a ray along its suspension axis against the world broadphase; a hit
+//! compresses a spring-damper that holds the chassis up, and tire friction
+//! along the ground turns wheel spin into traction. Wheels have no colliders,
+//! so only the chassis collides with the world, and fast motion never snags a
+//! wheel on a polygon seam the way a joint-based car can.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    body::{BodyPatch, BodyType},
+    handles::{BodyHandle, ColliderHandle},
+    math::{point::Point, vector::Vector, FloatNum},
+    query::{QueryFilter, WorldQueries},
+    solver::body_state::{angular_point_velocity, inverse_mass_along, linear_response},
+    world::{HandleError, ValidationError, World, WorldError},
+};
+
+/// One virtual wheel, described in the chassis frame.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WheelDesc {
+    /// Suspension mount point in chassis-local coordinates.
+    pub local_anchor: Point,
+    /// Chassis-local direction the suspension extends in. Gravity points along
+    /// +y by default, so the suspension defaults to +y.
+    pub suspension_direction: Vector,
+    /// Suspension length with no load.
+    pub rest_length: FloatNum,
+    /// Wheel radius; the ray reaches `rest_length + radius` past the mount.
+    pub radius: FloatNum,
+    /// Spring force per unit of suspension compression.
+    pub stiffness: FloatNum,
+    /// Damper force per unit of suspension compression speed.
+    pub damping: FloatNum,
+    /// Tire grip: the largest tire impulse per unit of suspension impulse.
+    pub friction: FloatNum,
+    /// Wheel mass, which sets the spin inertia of a solid disc.
+    pub mass: FloatNum,
+    /// Whether engine torque drives this wheel.
+    pub driven: bool,
+}
+
+impl Default for WheelDesc {
+    fn default() -> Self {
+        Self {
+            local_anchor: Point::default(),
+            suspension_direction: Vector::new(0.0, 1.0),
+            rest_length: 0.5,
+            radius: 0.3,
+            stiffness: 50.0,
+            damping: 5.0,
+            friction: 1.0,
+            mass: 0.2,
+            driven: true,
+        }
+    }
+}
+
+impl WheelDesc {
+    fn spin_inertia(&self) -> FloatNum {
+        0.5 * self.mass * self.radius * self.radius
+    }
+}
+
+/// Ground touched by a wheel ray.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WheelContact {
+    /// Body owning the ground collider.
+    pub body: BodyHandle,
+    /// Ground collider.
+    pub collider: ColliderHandle,
+    /// World-space point the ray hit.
+    pub point: Point,
+    /// Ground normal at the hit point.
+    pub normal: Vector,
+}
+
+/// Per-wheel state after the latest [`VehicleController::update`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WheelState {
+    /// World-space wheel center at the current suspension length.
+    pub hub: Point,
+    /// Current suspension length, between zero and the rest length.
+    pub suspension_length: FloatNum,
+    /// Ground under the wheel, or `None` while the wheel is in the air.
+    pub contact: Option<WheelContact>,
+    /// Suspension impulse pushing the chassis away from the ground.
+    pub suspension_impulse: FloatNum,
+    /// Tire impulse along the ground; positive drives the chassis forward.
+    pub tire_impulse: FloatNum,
+    /// Whether the tire impulse hit the grip limit, so the wheel slips.
+    pub slipping: bool,
+    /// Wheel spin in radians per second; positive rolls forward.
+    pub spin: FloatNum,
+    /// Accumulated wheel rotation in radians, for rendering.
+    pub rotation: FloatNum,
+}
+
+/// Ray-cast vehicle attached to a dynamic chassis body.
+///
+/// The chassis-local +x axis is forward. Set `engine_torque` and
+/// `brake_torque` from player input, then call [`VehicleController::update`]
+/// once before each step.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VehicleController {
+    body: BodyHandle,
+    wheels: Vec<WheelDesc>,
+    states: Vec<WheelState>,
+    /// Torque applied to every driven wheel; negative values reverse.
+    pub engine_torque: FloatNum,
+    /// Torque slowing every wheel's spin toward zero.
+    pub brake_torque: FloatNum,
+}
+
+impl VehicleController {
+    /// Creates a vehicle without wheels for the given chassis body.
+    pub fn new(body: BodyHandle) -> Self {
+        Self {
+            body,
+            wheels: Vec::new(),
+            states: Vec::new(),
+            engine_torque: 0.0,
+            brake_torque: 0.0,
+        }
+    }
+
+    /// Adds a wheel; wheel states keep the order wheels were added in.
+    pub fn with_wheel(mut self, wheel: WheelDesc) -> Self {
+        self.wheels.push(wheel);
+        self.states.push(WheelState {
+            suspension_length: wheel.rest_length,
+            ..WheelState::default()
+        });
+        self
+    }
+
+    /// Chassis body driven by this vehicle.
+    pub fn body(&self) -> BodyHandle {
+        self.body
+    }
+
+    /// Wheel descriptors, in the order they were added.
+    pub fn wheels(&self) -> &[WheelDesc] {
+        &self.wheels
+    }
+
+    /// Wheel states from the latest update, parallel to [`Self::wheels`].
+    pub fn wheel_states(&self) -> &[WheelState] {
+        &self.states
+    }
+
+    /// Checks that the inputs and every wheel are finite and in range.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let invalid = |field| Err(ValidationError::VehicleController { field });
+        if !self.engine_torque.is_finite() {
+            return invalid("engine_torque");
+        }
+        if !self.brake_torque.is_finite() || self.brake_torque < 0.0 {
+            return invalid("brake_torque");
+        }
+        for wheel in &self.wheels {
+            if !wheel.local_anchor.x().is_finite() || !wheel.local_anchor.y().is_finite() {
+                return invalid("wheel.local_anchor");
+            }
+            if !wheel.suspension_direction.x().is_finite()
+                || !wheel.suspension_direction.y().is_finite()
+                || wheel.suspension_direction.length() <= FloatNum::EPSILON
+            {
+                return invalid("wheel.suspension_direction");
+            }
+            for (field, value) in [
+                ("wheel.rest_length", wheel.rest_length),
+                ("wheel.stiffness", wheel.stiffness),
+                ("wheel.damping", wheel.damping),
+                ("wheel.friction", wheel.friction),
+            ] {
+                if !value.is_finite() || value < 0.0 {
+                    return invalid(field);
+                }
+            }
+            for (field, value) in [("wheel.radius", wheel.radius), ("wheel.mass", wheel.mass)] {
+                if !value.is_finite() || value <= 0.0 {
+                    return invalid(field);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Casts every wheel and applies suspension and tire impulses to the
+    /// chassis for a step of `dt`.
+    ///
+    /// Engine and brake torque change wheel spin first. A wheel whose ray hits
+    /// ground within `rest_length + radius` pushes the chassis along the
+    /// suspension axis with its spring-damper, then tire friction removes the
+    /// slip between wheel spin and ground speed, limited by `friction` times
+    /// the suspension impulse. Dynamic ground receives the opposite impulses.
+    pub fn update(&mut self, world: &mut World, dt: FloatNum) -> Result<&[WheelState], WorldError> {
+        self.validate().map_err(WorldError::Validation)?;
+        if !dt.is_finite() || dt <= 0.0 {
+            return Err(WorldError::Validation(ValidationError::VehicleController {
+                field: "dt",
+            }));
+        }
+        let record = world.body_record(self.body)?;
+        if !record.body_type.is_dynamic() {
+            return Err(WorldError::Handle(HandleError::WrongBodyType {
+                handle: self.body,
+                expected: BodyType::Dynamic,
+                actual: record.body_type,
+            }));
+        }
+        let pose = record.pose;
+        let center = pose.transform_point(record.mass_properties.local_center_of_mass);
+        let inverse_mass = record.solver_inverse_mass();
+        let inverse_inertia = record.solver_inverse_inertia();
+        let forward = Vector::new(1.0, 0.0).rotated(pose.angle());
+        let mut chassis = Velocity {
+            linear: record.linear_velocity,
+            angular: record.angular_velocity,
+        };
+
+        // Wheels only ride on what the chassis itself may collide with.
+        let chassis_filter = world
+            .try_colliders_for_body(self.body)?
+            .filter_map(|handle| world.collider_record(handle).ok())
+            .find(|collider| !collider.is_sensor)
+            .map(|collider| collider.filter);
+        let mut filter = QueryFilter::default().excluding_body(self.body);
+        if let Some(chassis_filter) = chassis_filter {
+            filter = filter.colliding_with(chassis_filter);
+        }
+
+        world.sync_query_broadphase();
+        let queries = WorldQueries::new(world);
+        // Suspension impulses all read the incoming chassis velocity, so one
+        // wheel's spring never skews the next wheel's damper or tire.
+        let mut contacts = Vec::with_capacity(self.wheels.len());
+        for (wheel, state) in self.wheels.iter().zip(&mut self.states) {
+            let inertia = wheel.spin_inertia();
+            if wheel.driven {
+                state.spin += self.engine_torque / inertia * dt;
+            }
+            let braking = (self.brake_torque / inertia * dt).min(state.spin.abs());
+            state.spin -= braking.copysign(state.spin);
+
+            let anchor = pose.transform_point(wheel.local_anchor);
+            let axis = wheel
+                .suspension_direction
+                .normalized()
+                .rotated(pose.angle());
+            let hit = queries.cast_ray(anchor, axis, wheel.rest_length + wheel.radius, filter);
+            state.contact = None;
+            state.suspension_length = wheel.rest_length;
+            state.suspension_impulse = 0.0;
+            state.tire_impulse = 0.0;
+            state.slipping = false;
+            let contact = hit.map(|hit| {
+                let ground = world
+                    .body_record(hit.body)
+                    .ok()
+                    .filter(|ground| !ground.body_type.is_static())
+                    .map(|ground| {
+                        let offset = hit.point
+                            - ground
+                                .pose
+                                .transform_point(ground.mass_properties.local_center_of_mass);
+                        ground.linear_velocity
+                            + angular_point_velocity(ground.angular_velocity, offset)
+                    })
+                    .unwrap_or_default();
+                let offset = hit.point - center;
+                state.suspension_length = (hit.toi - wheel.radius).max(0.0);
+                let compression = wheel.rest_length - state.suspension_length;
+                let force = (wheel.stiffness * compression
+                    + wheel.damping * (chassis.point_velocity(offset) - ground).dot(axis))
+                .max(0.0);
+                state.suspension_impulse = force * dt;
+                state.contact = Some(WheelContact {
+                    body: hit.body,
+                    collider: hit.collider,
+                    point: hit.point,
+                    normal: hit.normal,
+                });
+                let mut tangent = hit.normal.perp();
+                if tangent.dot(forward) < 0.0 {
+                    tangent = -tangent;
+                }
+                (offset, ground, tangent)
+            });
+            state.hub = anchor + axis * state.suspension_length;
+            contacts.push((axis, contact));
+        }
+        for (state, (axis, contact)) in self.states.iter().zip(&contacts) {
+            if let Some((offset, _, _)) = contact {
+                chassis.apply(
+                    inverse_mass,
+                    inverse_inertia,
+                    *offset,
+                    -*axis * state.suspension_impulse,
+                );
+            }
+        }
+
+        let mut reactions = Vec::new();
+        for ((wheel, state), (axis, contact)) in
+            self.wheels.iter().zip(&mut self.states).zip(contacts)
+        {
+            if let (Some((offset, ground, tangent)), Some(hit)) = (contact, state.contact) {
+                let inertia = wheel.spin_inertia();
+                let slip = (chassis.point_velocity(offset) - ground).dot(tangent)
+                    - state.spin * wheel.radius;
+                let arm = offset.cross(tangent);
+                let chassis_response =
+                    inverse_mass_along(inverse_mass, tangent) + arm * arm * inverse_inertia;
+                let response = chassis_response + wheel.radius * wheel.radius / inertia;
+                let grip = wheel.friction * state.suspension_impulse;
+                let tire = (-slip / response).clamp(-grip, grip);
+                chassis.apply(inverse_mass, inverse_inertia, offset, tangent * tire);
+                state.spin -= tire * wheel.radius / inertia;
+                state.tire_impulse = tire;
+                state.slipping = tire.abs() >= grip && grip > 0.0;
+                reactions.push((
+                    hit.body,
+                    hit.point,
+                    axis * state.suspension_impulse - tangent * tire,
+                ));
+            }
+            state.rotation += state.spin * dt;
+        }
+
+        world.apply_body_patch(
+            self.body,
+            BodyPatch {
+                linear_velocity: Some(chassis.linear),
+                angular_velocity: Some(chassis.angular),
+                ..BodyPatch::default()
+            },
+        )?;
+        for (body, point, impulse) in reactions {
+            apply_ground_reaction(world, body, point, impulse)?;
+        }
+        Ok(&self.states)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Velocity {
+    linear: Vector,
+    angular: FloatNum,
+}
+
+impl Velocity {
+    fn point_velocity(&self, offset: Vector) -> Vector {
+        self.linear + angular_point_velocity(self.angular, offset)
+    }
+
+    fn apply(
+        &mut self,
+        inverse_mass: Vector,
+        inverse_inertia: FloatNum,
+        offset: Vector,
+        impulse: Vector,
+    ) {
+        self.linear += linear_response(inverse_mass, impulse);
+        self.angular -= offset.cross(impulse) * inverse_inertia;
+    }
+}
+
+fn apply_ground_reaction(
+    world: &mut World,
+    body: BodyHandle,
+    point: Point,
+    impulse: Vector,
+) -> Result<(), WorldError> {
+    let record = world.body_record(body)?;
+    if !record.body_type.is_dynamic() {
+        return Ok(());
+    }
+    let mut velocity = Velocity {
+        linear: record.linear_velocity,
+        angular: record.angular_velocity,
+    };
+    let offset = point
+        - record
+            .pose
+            .transform_point(record.mass_properties.local_center_of_mass);
+    velocity.apply(
+        record.solver_inverse_mass(),
+        record.solver_inverse_inertia(),
+        offset,
+        impulse,
+    );
+    world.apply_body_patch(
+        body,
+        BodyPatch {
+            linear_velocity: Some(velocity.linear),
+            angular_velocity: Some(velocity.angular),
+            ..BodyPatch::default()
+        },
+    )
+}
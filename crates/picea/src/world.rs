@@ -4,7 +4,10 @@ use std::collections::BTreeMap;
 
 mod api;
 pub(crate) mod contact_state;
+mod determinism;
 pub mod error;
+pub mod gravity;
+pub mod impulse;
 pub(crate) mod runtime;
 pub mod store;
 
@@ -15,11 +18,16 @@ use crate::{
     handles::{BodyHandle, ColliderHandle, JointHandle, WorldRevision},
     joint::JointRecord,
     math::{vector::Vector, FloatNum},
-    pipeline::{broadphase::Broadphase, SimulationWorld, StepConfig, StepOutcome, StepStats},
+    pipeline::{
+        broadphase::Broadphase, fluid::FluidSubmersion, SimulationWorld, StepConfig, StepOutcome,
+        StepStats,
+    },
 };
 
 use contact_state::{ContactKey, ContactRecord};
 pub use error::{HandleError, TopologyError, ValidationError, WorldError};
+pub use gravity::{GravityFalloff, GravityField};
+pub use impulse::{ImpulseFalloff, RadialImpulseFilter, RadialImpulseHit};
 use store::Slot;
 
 /// World-level immutable configuration.
@@ -27,15 +35,35 @@ use store::Slot;
 pub struct WorldDesc {
     /// Default gravity vector consumed by the simulation pipeline.
     pub gravity: Vector,
+    /// Extra gravity sources summed with `gravity`; the one part of the
+    /// descriptor that [`World::set_gravity_fields`] may replace later.
+    pub gravity_fields: Vec<GravityField>,
     /// Enables body sleeping when the simulation pipeline supports it.
     pub enable_sleep: bool,
+    /// Canonicalizes committed float state after every step so lockstep peers
+    /// can compare [`World::state_hash`] values tick by tick.
+    pub deterministic: bool,
+}
+
+impl WorldDesc {
+    /// Checks that gravity and every gravity field are finite and well formed.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if !self.gravity.x().is_finite() || !self.gravity.y().is_finite() {
+            return Err(ValidationError::WorldDesc { field: "gravity" });
+        }
+        self.gravity_fields
+            .iter()
+            .try_for_each(GravityField::validate)
+    }
 }
 
 impl Default for WorldDesc {
     fn default() -> Self {
         Self {
             gravity: (0.0, 9.8).into(),
+            gravity_fields: Vec::new(),
             enable_sleep: true,
+            deterministic: false,
         }
     }
 }
@@ -61,9 +89,13 @@ pub struct World {
     #[allow(dead_code)]
     last_step_events: Vec<WorldEvent>,
     broadphase: Broadphase,
+    /// Index for queries between steps, kept apart from `broadphase` so
+    /// gameplay queries never change what the next step's tree does.
+    query_broadphase: Broadphase,
     active_contacts: BTreeMap<ContactKey, ContactRecord>,
     next_contact_raw: u32,
     next_manifold_raw: u32,
+    fluid_submersions: Vec<FluidSubmersion>,
 }
 
 impl SimulationWorld for World {
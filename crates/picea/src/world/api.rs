@@ -1,5 +1,5 @@
 use crate::{
-    body::{BodyDesc, BodyPatch, BodyRecord, BodyView, MassProperties},
+    body::{BodyDesc, BodyPatch, BodyRecord, BodyType, BodyView, MassProperties, Pose},
     collider::{ColliderDesc, ColliderPatch, ColliderRecord, ColliderView, SharedShape},
     debug::{DebugSnapshot, DebugSnapshotOptions},
     events::{SleepTransitionReason, WorldEvent},
@@ -11,12 +11,34 @@ use crate::{
 
 use super::{
     store::{allocate_slot, remove_slot},
-    HandleError, TopologyError, WorldDesc, WorldError,
+    GravityField, HandleError, TopologyError, ValidationError, WorldDesc, WorldError,
 };
 
 impl World {
+    /// Creates a world after [`WorldDesc::validate`] accepts `desc`.
+    pub fn try_new(desc: WorldDesc) -> Result<Self, WorldError> {
+        desc.validate().map_err(WorldError::Validation)?;
+        Ok(Self::from_desc(desc))
+    }
+
     /// Creates a new world using the provided immutable configuration.
+    ///
+    /// Panics when a gravity field is malformed; use [`World::try_new`] for
+    /// descriptors built from user input. The global gravity vector is
+    /// trusted as given, and non-finite values surface as step numeric
+    /// warnings.
     pub fn new(desc: WorldDesc) -> Self {
+        if let Err(error) = desc
+            .gravity_fields
+            .iter()
+            .try_for_each(GravityField::validate)
+        {
+            panic!("{error}");
+        }
+        Self::from_desc(desc)
+    }
+
+    fn from_desc(desc: WorldDesc) -> Self {
         Self {
             desc,
             revision: WorldRevision::default(),
@@ -33,9 +55,11 @@ impl World {
             pending_wake_reasons: Default::default(),
             last_step_events: Vec::new(),
             broadphase: Default::default(),
+            query_broadphase: Default::default(),
             active_contacts: Default::default(),
             next_contact_raw: 0,
             next_manifold_raw: 0,
+            fluid_submersions: Vec::new(),
         }
     }
 
@@ -107,6 +131,7 @@ impl World {
                 shape: &desc.shape,
                 local_pose: desc.local_pose,
                 density: desc.density,
+                mass: desc.mass,
                 field_scope: "desc",
             }),
             None,
@@ -184,8 +209,18 @@ impl World {
         patch.validate().map_err(WorldError::Validation)?;
         let wake_reason = body_patch_wake_reason(&patch);
         let was_sleeping = self.body_record(handle)?.sleeping;
-        let mass_properties = if let Some(body_type) = patch.body_type {
-            Some(self.compute_body_mass_properties(handle, body_type, None, None)?)
+        let mass_properties = if patch.body_type.is_some() || patch.mass_override.is_some() {
+            let record = self.body_record(handle)?;
+            let body_type = patch.body_type.unwrap_or(record.body_type);
+            let mass_override = patch.mass_override.unwrap_or(record.mass_override);
+            if body_type.is_dynamic()
+                && mass_override.is_some_and(|mass_override| mass_override.mass <= 0.0)
+            {
+                return Err(WorldError::Validation(ValidationError::BodyPatch {
+                    field: "mass_override.mass",
+                }));
+            }
+            Some(self.resolve_body_mass_properties(handle, body_type, mass_override, None, None)?)
         } else {
             None
         };
@@ -210,6 +245,130 @@ impl World {
         Ok(())
     }
 
+    /// Re-derives a body's mass properties from its colliders and override.
+    ///
+    /// Patches and collider edits already keep mass facts current; this is for
+    /// callers that want to re-sync explicitly, for example after a batch of
+    /// edits. Invalid collider mass is rejected with the same validation errors
+    /// as collider creation, leaving the stored facts untouched.
+    pub fn recompute_mass_properties(
+        &mut self,
+        body: BodyHandle,
+    ) -> Result<MassProperties, WorldError> {
+        let body_type = self.body_record(body)?.body_type;
+        let mass_properties = self.compute_body_mass_properties(body, body_type, None, None)?;
+        let record = self.body_record_mut(body)?;
+        record.set_mass_properties(mass_properties);
+        let mass_properties = record.mass_properties;
+        self.bump_revision();
+        Ok(mass_properties)
+    }
+
+    /// Drives a kinematic body to `pose` over the next step.
+    ///
+    /// The step derives the body's linear and angular velocity from the pose
+    /// change, so contacts and CCD see the motion instead of a teleport. The
+    /// body then holds `pose` until a new target or a pose/velocity patch.
+    pub fn set_kinematic_target(&mut self, body: BodyHandle, pose: Pose) -> Result<(), WorldError> {
+        let record = self.body_record(body)?;
+        if !record.body_type.is_kinematic() {
+            return Err(WorldError::Handle(HandleError::WrongBodyType {
+                handle: body,
+                expected: BodyType::Kinematic,
+                actual: record.body_type,
+            }));
+        }
+        for (value, field) in [
+            (pose.translation().x(), "kinematic_target.translation.x"),
+            (pose.translation().y(), "kinematic_target.translation.y"),
+            (pose.angle(), "kinematic_target.angle"),
+        ] {
+            if !value.is_finite() {
+                return Err(WorldError::Validation(ValidationError::BodyPatch { field }));
+            }
+        }
+        self.body_record_mut(body)?.kinematic_target = Some(pose);
+        self.bump_revision();
+        Ok(())
+    }
+
+    /// Wakes every body in the island containing `body`.
+    ///
+    /// Islands are resolved from the last step's contacts plus joints. Each
+    /// woken body reports `SleepTransitionReason::ManualWake` on the next step.
+    /// Returns whether any body was asleep.
+    pub fn wake_island(&mut self, body: BodyHandle) -> Result<bool, WorldError> {
+        self.wake_island_with_reason(body, SleepTransitionReason::ManualWake)
+    }
+
+    pub(crate) fn wake_island_with_reason(
+        &mut self,
+        body: BodyHandle,
+        reason: SleepTransitionReason,
+    ) -> Result<bool, WorldError> {
+        self.body_record(body)?;
+        let mut woke = false;
+        for handle in crate::pipeline::sleep::island_bodies_containing(self, body) {
+            let record = self.body_record_mut(handle)?;
+            if !record.sleeping {
+                continue;
+            }
+            record.sleeping = false;
+            record.sleep_idle_time = 0.0;
+            crate::pipeline::sleep::record_wake_reason(
+                &mut self.pending_wake_reasons,
+                handle,
+                reason,
+            );
+            woke = true;
+        }
+        if woke {
+            self.bump_revision();
+        }
+        Ok(woke)
+    }
+
+    /// Puts every body in the island containing `body` to sleep immediately.
+    ///
+    /// Velocities are cleared, pending wake requests for the island are
+    /// dropped, and each body that was awake reports
+    /// `SleepTransitionReason::ManualSleep` in the next step's events. Islands
+    /// with a body that may not sleep (kinematic, or `can_sleep` off) are left
+    /// untouched. Returns whether the island was put to sleep.
+    pub fn sleep_island(&mut self, body: BodyHandle) -> Result<bool, WorldError> {
+        self.body_record(body)?;
+        let island = crate::pipeline::sleep::island_bodies_containing(self, body);
+        let island_can_sleep = !island.is_empty()
+            && island.iter().all(|handle| {
+                self.body_record(*handle)
+                    .map(|record| record.body_type.is_dynamic() && record.can_sleep)
+                    .unwrap_or(false)
+            });
+        if !island_can_sleep {
+            return Ok(false);
+        }
+        for handle in island {
+            self.pending_wake_reasons.remove(&handle);
+            let record = self.body_record_mut(handle)?;
+            record.linear_velocity = Vector::default();
+            record.angular_velocity = 0.0;
+            if record.sleeping {
+                continue;
+            }
+            record.sleeping = true;
+            let island_id = record.island_id.unwrap_or(0);
+            self.pending_events
+                .push(WorldEvent::SleepChanged(crate::events::SleepEvent {
+                    body: handle,
+                    is_sleeping: true,
+                    island_id,
+                    reason: SleepTransitionReason::ManualSleep,
+                }));
+        }
+        self.bump_revision();
+        Ok(true)
+    }
+
     /// Applies a partial update to an existing collider.
     pub fn apply_collider_patch(
         &mut self,
@@ -217,16 +376,26 @@ impl World {
         patch: ColliderPatch,
     ) -> Result<(), WorldError> {
         patch.validate().map_err(WorldError::Validation)?;
-        let mass_inputs_changed =
-            patch.shape.is_some() || patch.local_pose.is_some() || patch.density.is_some();
+        let mass_inputs_changed = patch.shape.is_some()
+            || patch.local_pose.is_some()
+            || patch.density.is_some()
+            || patch.mass.is_some();
         let (body, mass_properties) = {
             let collider = self.collider_record(handle)?;
             let body = collider.body;
+            let fluid = patch.fluid.unwrap_or(collider.fluid);
+            let shape = patch.shape.as_ref().unwrap_or(&collider.shape);
+            if fluid.is_some() && !shape.supports_fluid() {
+                return Err(WorldError::Validation(ValidationError::ColliderPatch {
+                    field: "fluid",
+                }));
+            }
             let mass_properties = if mass_inputs_changed {
                 let body_type = self.body_record(body)?.body_type;
                 let shape = patch.shape.as_ref().unwrap_or(&collider.shape);
                 let local_pose = patch.local_pose.unwrap_or(collider.local_pose);
                 let density = patch.density.unwrap_or(collider.density);
+                let mass = patch.mass.unwrap_or(collider.mass);
                 Some(self.compute_body_mass_properties(
                     body,
                     body_type,
@@ -235,6 +404,7 @@ impl World {
                         shape,
                         local_pose,
                         density,
+                        mass,
                         field_scope: "patch",
                     }),
                     None,
@@ -287,6 +457,24 @@ impl World {
         self.try_body(handle)
     }
 
+    /// Blends a body's pose between the start and end of the last step.
+    ///
+    /// `alpha` is usually `AdvanceReport::alpha`; it is clamped to `[0, 1]` and
+    /// a non-finite value yields the current pose.
+    pub fn interpolated_pose(
+        &self,
+        handle: BodyHandle,
+        alpha: FloatNum,
+    ) -> Result<Pose, WorldError> {
+        let record = self.body_record(handle)?;
+        let alpha = if alpha.is_finite() {
+            alpha.clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        Ok(record.previous_pose.lerp(record.pose, alpha))
+    }
+
     /// Resolves a collider handle into a read-only collider view.
     #[track_caller]
     pub fn collider(&self, handle: ColliderHandle) -> Result<ColliderView, WorldError> {
@@ -403,6 +591,18 @@ impl World {
         body_type: crate::body::BodyType,
         prospective: Option<ProspectiveColliderMass<'_>>,
         excluded: Option<ColliderHandle>,
+    ) -> Result<MassProperties, WorldError> {
+        let mass_override = self.body_record(body)?.mass_override;
+        self.resolve_body_mass_properties(body, body_type, mass_override, prospective, excluded)
+    }
+
+    fn resolve_body_mass_properties(
+        &self,
+        body: BodyHandle,
+        body_type: crate::body::BodyType,
+        mass_override: Option<MassProperties>,
+        prospective: Option<ProspectiveColliderMass<'_>>,
+        excluded: Option<ColliderHandle>,
     ) -> Result<MassProperties, WorldError> {
         let colliders = {
             let record = self.body_record(body)?;
@@ -427,7 +627,12 @@ impl World {
             let collider = self.collider_record(handle)?;
             let mass_properties = collider
                 .shape
-                .validate_mass_properties(collider.density, collider.local_pose, "desc")
+                .validate_mass_properties(
+                    collider.density,
+                    collider.mass,
+                    collider.local_pose,
+                    "desc",
+                )
                 .map_err(WorldError::Validation)?;
             if mass_properties.mass > 0.0 {
                 contributions.push(mass_properties);
@@ -438,6 +643,7 @@ impl World {
                 .shape
                 .validate_mass_properties(
                     prospective.density,
+                    prospective.mass,
                     prospective.local_pose,
                     prospective.field_scope,
                 )
@@ -450,7 +656,11 @@ impl World {
             aggregate_mass_properties(&contributions, body_type).ok_or_else(|| {
                 WorldError::Validation(collider_mass_properties_error(aggregate_field_scope))
             })?;
-        Ok(mass_properties)
+        // Colliders are still validated above so an override never hides bad
+        // collider input; it only replaces the aggregate.
+        Ok(mass_override
+            .map(|mass_override| mass_override.with_body_type(body_type))
+            .unwrap_or(mass_properties))
     }
 }
 
@@ -464,6 +674,9 @@ fn body_patch_wake_reason(patch: &BodyPatch) -> Option<SleepTransitionReason> {
         || patch.linear_damping.is_some()
         || patch.angular_damping.is_some()
         || patch.gravity_scale.is_some()
+        || patch.gravity_override.is_some()
+        || patch.mass_override.is_some()
+        || patch.locked_axes.is_some()
         || patch.can_sleep == Some(false)
         || patch.sleeping == Some(false)
         || patch.user_data.is_some()
@@ -479,6 +692,7 @@ struct ProspectiveColliderMass<'a> {
     shape: &'a SharedShape,
     local_pose: crate::body::Pose,
     density: FloatNum,
+    mass: Option<FloatNum>,
     field_scope: &'static str,
 }
 
@@ -543,7 +757,7 @@ fn collider_mass_properties_error(field_scope: &'static str) -> crate::world::Va
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::world::ValidationError;
+    use crate::{math::FloatNum, world::ValidationError};
 
     #[test]
     fn destroy_collider_validates_remaining_mass_before_mutating_slots() {
@@ -568,7 +782,7 @@ mod tests {
         world
             .collider_record_mut(survivor)
             .expect("survivor collider should resolve")
-            .shape = SharedShape::circle(1.0e20);
+            .shape = SharedShape::circle(FloatNum::MAX.sqrt());
 
         let error = world
             .destroy_collider(target)
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use crate::{
     events::{ContactEvent, WarmStartCacheReason},
-    handles::{ColliderHandle, ContactFeatureId, ContactId, ManifoldId},
+    handles::{BodyHandle, ColliderHandle, ContactFeatureId, ContactId, ManifoldId},
     math::FloatNum,
     world::World,
 };
@@ -74,6 +74,14 @@ impl World {
         std::mem::take(&mut self.active_contacts)
     }
 
+    pub(crate) fn active_contact_body_pairs(
+        &self,
+    ) -> impl Iterator<Item = (BodyHandle, BodyHandle)> + '_ {
+        self.active_contacts
+            .values()
+            .map(|record| (record.contact.body_a, record.contact.body_b))
+    }
+
     pub(crate) fn replace_active_contacts(
         &mut self,
         active_contacts: BTreeMap<ContactKey, ContactRecord>,
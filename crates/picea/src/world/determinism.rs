@@ -0,0 +1,342 @@
+//! Cross-platform determinism helpers: committed-state canonicalization and
+//! the stable world state hash used for lockstep comparisons.
+
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    body::{BodyType, Pose},
+    collider::SharedShape,
+    joint::JointDesc,
+    math::{point::Point, vector::Vector, FloatNum},
+    world::{GravityField, World},
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl World {
+    /// Returns a platform-independent FNV-1a hash of the simulation state.
+    ///
+    /// The hash covers the world settings (gravity, gravity fields, sleep and
+    /// determinism flags), body motion and mass state, collider shape,
+    /// attachment, material, filter and user data, joint descriptors, and the
+    /// persistent contact cache including warm-start impulses. Two worlds that
+    /// produce the same hash will step identically under the same `StepConfig`.
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = StateHasher::default();
+
+        hasher.vector(self.desc.gravity);
+        self.desc.enable_sleep.hash(&mut hasher);
+        self.desc.deterministic.hash(&mut hasher);
+
+        for (handle, record) in self.body_records() {
+            handle.hash(&mut hasher);
+            hasher.write_u8(body_type_tag(record.body_type));
+            hasher.pose(record.pose);
+            hasher.float(record.mass_properties.mass);
+            hasher.float(record.mass_properties.inverse_mass);
+            hasher.point(record.mass_properties.local_center_of_mass);
+            hasher.float(record.mass_properties.inertia);
+            hasher.float(record.mass_properties.inverse_inertia);
+            hasher.vector(record.linear_velocity);
+            hasher.float(record.angular_velocity);
+            hasher.float(record.linear_damping);
+            hasher.float(record.angular_damping);
+            hasher.float(record.gravity_scale);
+            match record.gravity_override {
+                Some(gravity) => {
+                    hasher.write_u8(1);
+                    hasher.vector(gravity);
+                }
+                None => hasher.write_u8(0),
+            }
+            record.can_sleep.hash(&mut hasher);
+            record.sleeping.hash(&mut hasher);
+            hasher.float(record.sleep_idle_time);
+            match record.sleep_thresholds {
+                Some(thresholds) => {
+                    hasher.write_u8(1);
+                    hasher.float(thresholds.stability_seconds);
+                    hasher.float(thresholds.linear_threshold);
+                    hasher.float(thresholds.angular_threshold);
+                }
+                None => hasher.write_u8(0),
+            }
+            record.ccd_enabled.hash(&mut hasher);
+            record.bullet.hash(&mut hasher);
+            hasher.float(record.ccd_motion_threshold);
+            record.locked_axes.hash(&mut hasher);
+            match record.mass_override {
+                Some(mass_override) => {
+                    hasher.write_u8(1);
+                    hasher.float(mass_override.mass);
+                    hasher.point(mass_override.local_center_of_mass);
+                    hasher.float(mass_override.inertia);
+                }
+                None => hasher.write_u8(0),
+            }
+            match record.kinematic_target {
+                Some(target) => {
+                    hasher.write_u8(1);
+                    hasher.pose(target);
+                }
+                None => hasher.write_u8(0),
+            }
+            hasher.write_u64(record.user_data);
+        }
+
+        for (handle, record) in self.collider_records() {
+            handle.hash(&mut hasher);
+            record.body.hash(&mut hasher);
+            hasher.shape(&record.shape);
+            hasher.pose(record.local_pose);
+            hasher.float(record.density);
+            match record.mass {
+                Some(mass) => {
+                    hasher.write_u8(1);
+                    hasher.float(mass);
+                }
+                None => hasher.write_u8(0),
+            }
+            hasher.float(record.material.friction);
+            hasher.float(record.material.restitution);
+            hasher.write_u64(record.filter.memberships);
+            hasher.write_u64(record.filter.collides_with);
+            record.is_sensor.hash(&mut hasher);
+            match record.fluid {
+                Some(fluid) => {
+                    hasher.write_u8(1);
+                    hasher.float(fluid.density);
+                    hasher.float(fluid.linear_drag);
+                    hasher.float(fluid.angular_drag);
+                }
+                None => hasher.write_u8(0),
+            }
+            hasher.write_u64(record.user_data);
+        }
+
+        for (handle, record) in self.joint_records() {
+            handle.hash(&mut hasher);
+            match &record.desc {
+                JointDesc::Distance(desc) => {
+                    hasher.write_u8(0);
+                    desc.body_a.hash(&mut hasher);
+                    desc.body_b.hash(&mut hasher);
+                    hasher.point(desc.local_anchor_a);
+                    hasher.point(desc.local_anchor_b);
+                    hasher.float(desc.rest_length);
+                    hasher.float(desc.stiffness);
+                    hasher.float(desc.damping);
+                }
+                JointDesc::WorldAnchor(desc) => {
+                    hasher.write_u8(1);
+                    desc.body.hash(&mut hasher);
+                    hasher.point(desc.local_anchor);
+                    hasher.point(desc.world_anchor);
+                    hasher.float(desc.stiffness);
+                    hasher.float(desc.damping);
+                }
+            }
+        }
+
+        for field in self.gravity_fields() {
+            match *field {
+                GravityField::Uniform {
+                    min,
+                    max,
+                    acceleration,
+                } => {
+                    hasher.write_u8(0);
+                    hasher.point(min);
+                    hasher.point(max);
+                    hasher.vector(acceleration);
+                }
+                GravityField::Radial {
+                    center,
+                    strength,
+                    inner_radius,
+                    outer_radius,
+                    falloff,
+                } => {
+                    hasher.write_u8(1);
+                    hasher.point(center);
+                    hasher.float(strength);
+                    hasher.float(inner_radius);
+                    hasher.float(outer_radius);
+                    hasher.write_u8(falloff as u8);
+                }
+            }
+        }
+
+        for record in self.active_contacts.values() {
+            let contact = &record.contact;
+            contact.contact_id.hash(&mut hasher);
+            contact.manifold_id.hash(&mut hasher);
+            contact.collider_a.hash(&mut hasher);
+            contact.collider_b.hash(&mut hasher);
+            contact.feature_id.hash(&mut hasher);
+            hasher.point(contact.point);
+            hasher.vector(contact.normal);
+            hasher.float(contact.depth);
+            hasher.vector(record.anchor_a);
+            hasher.vector(record.anchor_b);
+            hasher.float(record.normal_impulse);
+            hasher.float(record.tangent_impulse);
+        }
+        hasher.write_u32(self.next_contact_raw);
+        hasher.write_u32(self.next_manifold_raw);
+
+        hasher.finish()
+    }
+
+    /// Flushes negative zero and subnormal values out of committed float state.
+    ///
+    /// Flush-to-zero CPU modes treat subnormals differently, so deterministic
+    /// worlds never carry them from one step into the next.
+    pub(crate) fn canonicalize_float_state(&mut self) {
+        for slot in &mut self.bodies {
+            let Some(record) = slot.value.as_mut() else {
+                continue;
+            };
+            record.pose = canonical_pose(record.pose);
+            record.linear_velocity = canonical_vector(record.linear_velocity);
+            record.angular_velocity = canonical_float(record.angular_velocity);
+            record.sleep_idle_time = canonical_float(record.sleep_idle_time);
+        }
+        for record in self.active_contacts.values_mut() {
+            record.anchor_a = canonical_vector(record.anchor_a);
+            record.anchor_b = canonical_vector(record.anchor_b);
+            record.normal_impulse = canonical_float(record.normal_impulse);
+            record.tangent_impulse = canonical_float(record.tangent_impulse);
+        }
+    }
+}
+
+fn body_type_tag(body_type: BodyType) -> u8 {
+    match body_type {
+        BodyType::Static => 0,
+        BodyType::Dynamic => 1,
+        BodyType::Kinematic => 2,
+    }
+}
+
+fn canonical_float(value: FloatNum) -> FloatNum {
+    if value == 0.0 || value.is_subnormal() {
+        0.0
+    } else {
+        value
+    }
+}
+
+fn canonical_vector(value: Vector) -> Vector {
+    Vector::new(canonical_float(value.x()), canonical_float(value.y()))
+}
+
+fn canonical_pose(pose: Pose) -> Pose {
+    let translation = canonical_vector(pose.translation());
+    Pose::from_xy_angle(
+        translation.x(),
+        translation.y(),
+        canonical_float(pose.angle()),
+    )
+}
+
+/// FNV-1a over little-endian encodings so the hash does not depend on host
+/// endianness or `usize` width.
+struct StateHasher(u64);
+
+impl Default for StateHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl StateHasher {
+    fn float(&mut self, value: FloatNum) {
+        // Every NaN payload hashes the same; the engine never relies on payloads.
+        let value = if value.is_nan() { FloatNum::NAN } else { value };
+        self.write(&value.to_le_bytes());
+    }
+
+    fn vector(&mut self, value: Vector) {
+        self.float(value.x());
+        self.float(value.y());
+    }
+
+    fn point(&mut self, value: Point) {
+        self.float(value.x());
+        self.float(value.y());
+    }
+
+    fn pose(&mut self, value: Pose) {
+        self.vector(value.translation());
+        self.float(value.angle());
+    }
+
+    fn shape(&mut self, value: &SharedShape) {
+        match value {
+            SharedShape::Circle { radius } => {
+                self.write_u8(0);
+                self.float(*radius);
+            }
+            SharedShape::Rect { half_extents } => {
+                self.write_u8(1);
+                self.vector(*half_extents);
+            }
+            SharedShape::RegularPolygon { sides, radius } => {
+                self.write_u8(2);
+                self.write_usize(*sides);
+                self.float(*radius);
+            }
+            SharedShape::ConvexPolygon { vertices } => {
+                self.write_u8(3);
+                self.points(vertices);
+            }
+            SharedShape::ConcavePolygon { vertices } => {
+                self.write_u8(4);
+                self.points(vertices);
+            }
+            SharedShape::Segment { start, end } => {
+                self.write_u8(5);
+                self.point(*start);
+                self.point(*end);
+            }
+        }
+    }
+
+    fn points(&mut self, values: &[Point]) {
+        self.write_usize(values.len());
+        for value in values {
+            self.point(*value);
+        }
+    }
+}
+
+impl Hasher for StateHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write(&[value]);
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+}
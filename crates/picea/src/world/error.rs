@@ -1,6 +1,7 @@
 use std::{error::Error, fmt};
 
 use crate::{
+    body::BodyType,
     handles::{BodyHandle, ColliderHandle, JointHandle},
     joint::JointKind,
 };
@@ -13,6 +14,11 @@ pub enum ValidationError {
     ColliderPatch { field: &'static str },
     JointDesc { field: &'static str },
     JointPatch { field: &'static str },
+    StepConfig { field: &'static str },
+    WorldDesc { field: &'static str },
+    RadialImpulse { field: &'static str },
+    CharacterController { field: &'static str },
+    VehicleController { field: &'static str },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -40,6 +46,11 @@ pub enum HandleError {
         expected: JointKind,
         actual: JointKind,
     },
+    WrongBodyType {
+        handle: BodyHandle,
+        expected: BodyType,
+        actual: BodyType,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -63,6 +74,11 @@ impl fmt::Display for ValidationError {
             Self::ColliderPatch { field } => ("collider patch", field),
             Self::JointDesc { field } => ("joint descriptor", field),
             Self::JointPatch { field } => ("joint patch", field),
+            Self::StepConfig { field } => ("step config", field),
+            Self::WorldDesc { field } => ("world descriptor", field),
+            Self::RadialImpulse { field } => ("radial impulse", field),
+            Self::CharacterController { field } => ("character controller", field),
+            Self::VehicleController { field } => ("vehicle controller", field),
         };
         write!(f, "{scope} contains an invalid `{field}` value")
     }
@@ -84,6 +100,9 @@ impl fmt::Display for HandleError {
             Self::WrongJointKind { .. } => {
                 f.write_str("joint patch kind does not match the stored joint")
             }
+            Self::WrongBodyType { .. } => {
+                f.write_str("body handle does not refer to a body of the required type")
+            }
         }
     }
 }
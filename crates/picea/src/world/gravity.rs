@@ -0,0 +1,192 @@
+//! World gravity fields layered on top of `WorldDesc::gravity`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    body::BodyRecord,
+    math::{point::Point, vector::Vector, FloatNum},
+    world::{ValidationError, World, WorldError},
+};
+
+/// How a radial field's pull weakens between its inner and outer radius.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GravityFalloff {
+    /// Full strength everywhere inside the outer radius.
+    #[default]
+    Constant,
+    /// Fades linearly to zero at the outer radius.
+    Linear,
+    /// Scales with `(inner_radius / distance)^2`, like a planet seen from orbit.
+    InverseSquare,
+}
+
+/// Extra gravity source added to the world gravity during integration.
+///
+/// Fields are sampled at each dynamic body's center of mass and summed with
+/// `WorldDesc::gravity`; set that to zero for levels driven purely by fields.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GravityField {
+    /// Constant acceleration inside an axis-aligned region.
+    Uniform {
+        /// Lower region corner.
+        min: Point,
+        /// Upper region corner.
+        max: Point,
+        /// Acceleration applied inside the region.
+        acceleration: Vector,
+    },
+    /// Pull toward a point; a negative strength pushes away instead.
+    Radial {
+        /// Attracting point.
+        center: Point,
+        /// Acceleration magnitude at or inside `inner_radius`.
+        strength: FloatNum,
+        /// Radius inside which the full strength applies.
+        inner_radius: FloatNum,
+        /// Radius beyond which the field has no effect.
+        outer_radius: FloatNum,
+        /// Attenuation between the two radii.
+        falloff: GravityFalloff,
+    },
+}
+
+impl GravityField {
+    /// Acceleration this field applies at `point`.
+    pub fn acceleration_at(&self, point: Point) -> Vector {
+        match *self {
+            Self::Uniform {
+                min,
+                max,
+                acceleration,
+            } => {
+                let inside = point.x() >= min.x()
+                    && point.x() <= max.x()
+                    && point.y() >= min.y()
+                    && point.y() <= max.y();
+                if inside {
+                    acceleration
+                } else {
+                    Vector::default()
+                }
+            }
+            Self::Radial {
+                center,
+                strength,
+                inner_radius,
+                outer_radius,
+                falloff,
+            } => {
+                let offset = center - point;
+                let distance = offset.length();
+                if distance <= FloatNum::EPSILON || distance > outer_radius {
+                    return Vector::default();
+                }
+                let scale = if distance <= inner_radius {
+                    1.0
+                } else {
+                    match falloff {
+                        GravityFalloff::Constant => 1.0,
+                        GravityFalloff::Linear => {
+                            (outer_radius - distance) / (outer_radius - inner_radius)
+                        }
+                        GravityFalloff::InverseSquare => {
+                            let ratio = inner_radius / distance;
+                            ratio * ratio
+                        }
+                    }
+                };
+                offset / distance * (strength * scale)
+            }
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), ValidationError> {
+        let invalid = |field| Err(ValidationError::WorldDesc { field });
+        match *self {
+            Self::Uniform {
+                min,
+                max,
+                acceleration,
+            } => {
+                if !(min.x().is_finite()
+                    && min.y().is_finite()
+                    && max.x().is_finite()
+                    && max.y().is_finite())
+                    || min.x() > max.x()
+                    || min.y() > max.y()
+                {
+                    return invalid("gravity_fields.region");
+                }
+                if !acceleration.x().is_finite() || !acceleration.y().is_finite() {
+                    return invalid("gravity_fields.acceleration");
+                }
+            }
+            Self::Radial {
+                center,
+                strength,
+                inner_radius,
+                outer_radius,
+                ..
+            } => {
+                if !center.x().is_finite() || !center.y().is_finite() {
+                    return invalid("gravity_fields.center");
+                }
+                if !strength.is_finite() {
+                    return invalid("gravity_fields.strength");
+                }
+                if !inner_radius.is_finite()
+                    || !outer_radius.is_finite()
+                    || inner_radius < 0.0
+                    || outer_radius < inner_radius
+                {
+                    return invalid("gravity_fields.radius");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl World {
+    /// Returns the gravity fields currently layered on top of world gravity.
+    pub fn gravity_fields(&self) -> &[GravityField] {
+        &self.desc.gravity_fields
+    }
+
+    /// Replaces the world's gravity fields.
+    ///
+    /// Every field is validated before any is applied. Sleeping bodies keep
+    /// sleeping until something else wakes them.
+    pub fn set_gravity_fields(&mut self, fields: Vec<GravityField>) -> Result<(), WorldError> {
+        for field in &fields {
+            field.validate().map_err(WorldError::Validation)?;
+        }
+        self.desc.gravity_fields = fields;
+        self.bump_revision();
+        Ok(())
+    }
+
+    /// World gravity plus field contributions at `point`.
+    pub fn gravity_at(&self, point: Point) -> Vector {
+        self.desc
+            .gravity_fields
+            .iter()
+            .fold(self.desc.gravity, |sum, field| {
+                sum + field.acceleration_at(point)
+            })
+    }
+
+    /// Gravity acceleration for a dynamic body, including its override and scale.
+    pub(crate) fn body_gravity(&self, record: &BodyRecord) -> Vector {
+        let gravity = record.gravity_override.unwrap_or_else(|| {
+            self.gravity_at(
+                record
+                    .pose
+                    .transform_point(record.mass_properties.local_center_of_mass),
+            )
+        });
+        gravity * record.gravity_scale
+    }
+}
@@ -0,0 +1,204 @@
+//! One-shot radial impulses such as explosions and shockwaves.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    events::SleepTransitionReason,
+    handles::{BodyHandle, ColliderHandle},
+    math::{point::Point, vector::Vector, FloatNum},
+    query::{QueryFilter, QueryShape, WorldQueries},
+    solver::body_state::linear_response,
+    world::{ValidationError, World, WorldError},
+};
+
+/// Radius of the probe circle used to find the nearest surface point.
+const PROBE_RADIUS: FloatNum = 1.0e-4;
+/// Occluders closer than this to the target surface do not block it.
+const OCCLUSION_SLOP: FloatNum = 1.0e-3;
+
+/// How a radial impulse weakens between its center and its radius.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImpulseFalloff {
+    /// Full strength everywhere inside the radius.
+    Constant,
+    /// Fades linearly to zero at the radius.
+    #[default]
+    Linear,
+    /// Fades with the square of the remaining distance, for a sharp core.
+    Quadratic,
+}
+
+impl ImpulseFalloff {
+    fn scale(self, distance: FloatNum, radius: FloatNum) -> FloatNum {
+        let remaining = (1.0 - distance / radius).clamp(0.0, 1.0);
+        match self {
+            Self::Constant => 1.0,
+            Self::Linear => remaining,
+            Self::Quadratic => remaining * remaining,
+        }
+    }
+}
+
+/// Selects which colliders a radial impulse affects and what blocks it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RadialImpulseFilter {
+    /// Colliders that may receive the impulse.
+    pub query: QueryFilter,
+    /// When set, colliders matching this filter shadow anything behind them
+    /// along the ray from the center to the target's nearest surface point.
+    pub occlusion: Option<QueryFilter>,
+}
+
+impl RadialImpulseFilter {
+    /// Blocks the impulse with colliders matching `filter`.
+    pub fn occluded_by(mut self, filter: QueryFilter) -> Self {
+        self.occlusion = Some(filter);
+        self
+    }
+}
+
+impl From<QueryFilter> for RadialImpulseFilter {
+    fn from(query: QueryFilter) -> Self {
+        Self {
+            query,
+            occlusion: None,
+        }
+    }
+}
+
+/// Impulse applied to one body by [`World::apply_radial_impulse`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RadialImpulseHit {
+    /// Body that received the impulse.
+    pub body: BodyHandle,
+    /// Collider nearest to the center on that body.
+    pub collider: ColliderHandle,
+    /// World-space point where the impulse was applied.
+    pub point: Point,
+    /// Distance from the center to `point`; zero when the center is inside.
+    pub distance: FloatNum,
+    /// Applied impulse.
+    pub impulse: Vector,
+}
+
+impl World {
+    /// Pushes dynamic bodies away from `center`, like an explosion.
+    ///
+    /// Each dynamic body with a collider within `radius` receives one impulse
+    /// of `strength` scaled by `falloff`, applied at the nearest surface point
+    /// of its closest collider and directed away from the center. A negative
+    /// strength pulls toward the center instead. A sleeping body wakes with
+    /// its whole island, reporting `SleepTransitionReason::VelocityEdit`, so
+    /// jointed or resting neighbours respond too. Returns the applied impulses
+    /// in body-handle order, for effects and gameplay reactions.
+    pub fn apply_radial_impulse(
+        &mut self,
+        center: Point,
+        radius: FloatNum,
+        strength: FloatNum,
+        falloff: ImpulseFalloff,
+        filter: impl Into<RadialImpulseFilter>,
+    ) -> Result<Vec<RadialImpulseHit>, WorldError> {
+        let invalid = |field| {
+            Err(WorldError::Validation(ValidationError::RadialImpulse {
+                field,
+            }))
+        };
+        if !center.x().is_finite() || !center.y().is_finite() {
+            return invalid("center");
+        }
+        if !radius.is_finite() || radius <= 0.0 {
+            return invalid("radius");
+        }
+        if !strength.is_finite() {
+            return invalid("strength");
+        }
+        let filter = filter.into();
+
+        self.sync_query_broadphase();
+        let queries = WorldQueries::new(self);
+        let probe = QueryShape::circle(center, PROBE_RADIUS)
+            .expect("validated centers build a probe circle");
+        let candidates = queries
+            .intersect_shape(&probe, radius, filter.query)
+            .expect("validated radii are non-negative");
+
+        let mut nearest = BTreeMap::new();
+        for hit in candidates {
+            let is_dynamic = self
+                .body_record(hit.body)
+                .is_ok_and(|record| record.body_type.is_dynamic());
+            if !is_dynamic {
+                continue;
+            }
+            let distance = if hit.distance <= 0.0 {
+                0.0
+            } else {
+                (hit.collider_point - center).length()
+            };
+            if distance > radius {
+                continue;
+            }
+            if let Some(occlusion) = filter.occlusion {
+                if distance > OCCLUSION_SLOP {
+                    let direction = (hit.collider_point - center) / distance;
+                    let blocked = queries
+                        .cast_ray(
+                            center,
+                            direction,
+                            distance,
+                            occlusion.excluding_body(hit.body),
+                        )
+                        .is_some_and(|blocker| blocker.toi < distance - OCCLUSION_SLOP);
+                    if blocked {
+                        continue;
+                    }
+                }
+            }
+            nearest
+                .entry(hit.body)
+                .and_modify(|current: &mut (ColliderHandle, Point, FloatNum)| {
+                    if distance < current.2 {
+                        *current = (hit.collider, hit.collider_point, distance);
+                    }
+                })
+                .or_insert((hit.collider, hit.collider_point, distance));
+        }
+
+        let mut hits = Vec::with_capacity(nearest.len());
+        for (body, (collider, surface_point, distance)) in nearest {
+            let record = self.body_record_mut(body)?;
+            let center_of_mass = record
+                .pose
+                .transform_point(record.mass_properties.local_center_of_mass);
+            // A center inside the collider has no outward surface direction,
+            // so push from the center through the body's center of mass.
+            let (point, direction) = if distance > 0.0 {
+                (surface_point, (surface_point - center) / distance)
+            } else {
+                (center, (center_of_mass - center).normalized_or_zero())
+            };
+            let impulse = direction * (strength * falloff.scale(distance, radius));
+            let anchor = point - center_of_mass;
+            record.linear_velocity += linear_response(record.solver_inverse_mass(), impulse);
+            record.angular_velocity -= anchor.cross(impulse) * record.solver_inverse_inertia();
+            if record.sleeping {
+                self.wake_island_with_reason(body, SleepTransitionReason::VelocityEdit)?;
+            }
+            hits.push(RadialImpulseHit {
+                body,
+                collider,
+                point,
+                distance,
+                impulse,
+            });
+        }
+        if !hits.is_empty() {
+            self.bump_revision();
+        }
+        Ok(hits)
+    }
+}
@@ -6,7 +6,8 @@ use crate::{
     handles::BodyHandle,
     math::FloatNum,
     pipeline::{
-        broadphase::{BroadphaseOutput, ColliderProxy},
+        broadphase::{Broadphase, BroadphaseOutput, ColliderProxy},
+        fluid::FluidSubmersion,
         StepConfig, StepOutcome, StepStats,
     },
     world::World,
@@ -39,10 +40,47 @@ impl World {
         std::mem::take(&mut self.pending_wake_reasons)
     }
 
+    pub(crate) fn store_previous_poses(&mut self) {
+        for slot in &mut self.bodies {
+            if let Some(record) = slot.value.as_mut() {
+                record.previous_pose = record.pose;
+            }
+        }
+    }
+
+    pub(crate) fn fluid_submersions(&self) -> &[FluidSubmersion] {
+        &self.fluid_submersions
+    }
+
+    pub(crate) fn set_fluid_submersions(&mut self, submersions: Vec<FluidSubmersion>) {
+        self.fluid_submersions = submersions;
+    }
+
     pub(crate) fn update_broadphase(&mut self, proxies: &[ColliderProxy]) -> BroadphaseOutput {
         self.broadphase.update(proxies)
     }
 
+    /// Brings the query broadphase up to the current collider bounds so
+    /// queries made outside a step can read it through
+    /// [`World::query_broadphase`]. The step's own tree is left untouched.
+    pub(crate) fn sync_query_broadphase(&mut self) {
+        let proxies = self
+            .collider_records()
+            .filter_map(|(handle, record)| {
+                let body = self.body_record(record.body).ok()?;
+                Some(ColliderProxy {
+                    handle,
+                    aabb: record.derived_geometry(body.pose).aabb,
+                })
+            })
+            .collect::<Vec<_>>();
+        self.query_broadphase.refresh(&proxies);
+    }
+
+    pub(crate) fn query_broadphase(&self) -> &Broadphase {
+        &self.query_broadphase
+    }
+
     pub(crate) fn debug_broadphase_tree(&self) -> DebugBroadphaseTree {
         self.broadphase.debug_tree()
     }
@@ -55,7 +93,7 @@ impl World {
     ) -> StepOutcome {
         self.last_step_stats = stats;
         self.last_step_dt = config.dt;
-        self.simulated_time += f64::from(config.dt);
+        self.simulated_time += crate::math::num::widen(config.dt);
         self.last_step_events = events.clone();
         self.bump_revision();
 
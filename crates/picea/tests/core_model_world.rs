@@ -1,15 +1,15 @@
 use picea::prelude::{
     BodyAsset, BodyBundle, BodyDesc, BodyHandle, BodyPatch, BodyType, ColliderBundle, ColliderDesc,
-    ColliderPatch, CollisionFilter, DistanceJointDesc, DistanceJointPatch, JointBundle, JointDesc,
-    JointPatch, Material, Pose, SharedShape, SimulationPipeline, StepConfig, ValidationError,
-    World, WorldAnchorJointDesc, WorldCommand, WorldCommandError, WorldCommandEvent,
-    WorldCommandKind, WorldDesc, WorldError, WorldRecipe,
+    ColliderPatch, CollisionFilter, DistanceJointDesc, DistanceJointPatch, FloatNum, JointBundle,
+    JointDesc, JointPatch, MassProperties, Material, Point, Pose, SharedShape, SimulationPipeline,
+    StepConfig, ValidationError, World, WorldAnchorJointDesc, WorldCommand, WorldCommandError,
+    WorldCommandEvent, WorldCommandKind, WorldDesc, WorldError, WorldRecipe,
 };
 use picea::world::HandleError;
 
-const MASS_EPSILON: f32 = 1e-4;
+const MASS_EPSILON: FloatNum = 1e-4;
 
-fn assert_near(actual: f32, expected: f32) {
+fn assert_near(actual: FloatNum, expected: FloatNum) {
     assert!(
         (actual - expected).abs() <= MASS_EPSILON,
         "expected {actual} to be within {MASS_EPSILON} of {expected}"
@@ -309,10 +309,10 @@ fn mass_properties_are_density_derived_and_body_type_controls_inverses() {
         .body(dynamic)
         .expect("dynamic body should resolve")
         .mass_properties();
-    assert_near(dynamic_mass.mass, 2.0 * std::f32::consts::PI);
+    assert_near(dynamic_mass.mass, 2.0 * picea::math::pi());
     assert_near(dynamic_mass.inverse_mass, 1.0 / dynamic_mass.mass);
     assert_eq!(dynamic_mass.local_center_of_mass, (0.0, 0.0).into());
-    assert_near(dynamic_mass.inertia, std::f32::consts::PI);
+    assert_near(dynamic_mass.inertia, picea::math::pi());
     assert_near(dynamic_mass.inverse_inertia, 1.0 / dynamic_mass.inertia);
 
     for body in [static_body, kinematic] {
@@ -320,9 +320,9 @@ fn mass_properties_are_density_derived_and_body_type_controls_inverses() {
             .body(body)
             .expect("body should resolve")
             .mass_properties();
-        assert_near(mass.mass, 2.0 * std::f32::consts::PI);
+        assert_near(mass.mass, 2.0 * picea::math::pi());
         assert_eq!(mass.inverse_mass, 0.0);
-        assert_near(mass.inertia, std::f32::consts::PI);
+        assert_near(mass.inertia, picea::math::pi());
         assert_eq!(mass.inverse_inertia, 0.0);
     }
 }
@@ -370,9 +370,9 @@ fn mass_properties_recompute_after_collider_and_body_mutations() {
         .body(body)
         .expect("body should resolve")
         .mass_properties();
-    assert_near(balanced.mass, 2.0 * std::f32::consts::PI);
+    assert_near(balanced.mass, 2.0 * picea::math::pi());
     assert_eq!(balanced.local_center_of_mass, (0.0, 0.0).into());
-    assert_near(balanced.inertia, 3.0 * std::f32::consts::PI);
+    assert_near(balanced.inertia, 3.0 * picea::math::pi());
 
     world
         .apply_collider_patch(
@@ -387,9 +387,9 @@ fn mass_properties_recompute_after_collider_and_body_mutations() {
         .body(body)
         .expect("body should resolve")
         .mass_properties();
-    assert_near(after_density_patch.mass, std::f32::consts::PI);
+    assert_near(after_density_patch.mass, picea::math::pi());
     assert_eq!(after_density_patch.local_center_of_mass, (-1.0, 0.0).into());
-    assert_near(after_density_patch.inertia, 0.5 * std::f32::consts::PI);
+    assert_near(after_density_patch.inertia, 0.5 * picea::math::pi());
 
     world
         .apply_body_patch(
@@ -404,7 +404,7 @@ fn mass_properties_recompute_after_collider_and_body_mutations() {
         .body(body)
         .expect("body should resolve")
         .mass_properties();
-    assert_near(static_mass.mass, std::f32::consts::PI);
+    assert_near(static_mass.mass, picea::math::pi());
     assert_eq!(static_mass.inverse_mass, 0.0);
 
     world
@@ -452,6 +452,254 @@ fn compound_convex_pieces_add_mass_properties_without_concave_decomposition() {
     assert_near(mass.inverse_inertia, 2.0 / 3.0);
 }
 
+#[test]
+fn collider_mass_and_body_mass_override_replace_density_derived_facts() {
+    let mut world = World::new(WorldDesc::default());
+    let body = world
+        .create_body(BodyDesc::default())
+        .expect("body should be created");
+    world
+        .create_collider(
+            body,
+            ColliderDesc {
+                shape: SharedShape::circle(1.0),
+                local_pose: Pose::from_xy_angle(-1.0, 0.0, 0.0),
+                mass: Some(3.0),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("left collider should be created");
+    let right = world
+        .create_collider(
+            body,
+            ColliderDesc {
+                shape: SharedShape::circle(1.0),
+                local_pose: Pose::from_xy_angle(1.0, 0.0, 0.0),
+                mass: Some(1.0),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("right collider should be created");
+    assert_eq!(
+        world.collider(right).expect("collider should exist").mass(),
+        Some(1.0)
+    );
+
+    // Explicit masses ignore density but keep the disk inertia distribution:
+    // 0.5 * 3 + 0.5 * 1 local inertia plus 3 * 0.5^2 + 1 * 1.5^2 parallel-axis terms.
+    let compound = world
+        .body(body)
+        .expect("body should exist")
+        .mass_properties();
+    assert_near(compound.mass, 4.0);
+    assert_near(compound.local_center_of_mass.x(), -0.5);
+    assert_near(compound.inertia, 5.0);
+
+    world
+        .apply_body_patch(
+            body,
+            BodyPatch {
+                mass_override: Some(Some(MassProperties {
+                    mass: 10.0,
+                    local_center_of_mass: (0.25, 0.0).into(),
+                    inertia: 2.0,
+                    ..MassProperties::default()
+                })),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("mass override patch should apply");
+    let overridden = world
+        .body(body)
+        .expect("body should exist")
+        .mass_properties();
+    assert_near(overridden.mass, 10.0);
+    assert_near(overridden.inverse_mass, 0.1);
+    assert_eq!(overridden.local_center_of_mass, (0.25, 0.0).into());
+    assert_near(overridden.inverse_inertia, 0.5);
+
+    // Collider edits still validate, but the override keeps owning the aggregate.
+    world
+        .apply_collider_patch(
+            right,
+            ColliderPatch {
+                mass: Some(None),
+                ..ColliderPatch::default()
+            },
+        )
+        .expect("collider mass can revert to density");
+    assert_eq!(
+        world
+            .body(body)
+            .expect("body should exist")
+            .mass_properties(),
+        overridden
+    );
+
+    world
+        .apply_body_patch(
+            body,
+            BodyPatch {
+                mass_override: Some(None),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("mass override can be cleared");
+    assert_near(
+        world
+            .body(body)
+            .expect("body should exist")
+            .mass_properties()
+            .mass,
+        3.0 + picea::math::pi(),
+    );
+
+    let revision = world.revision();
+    let recomputed = world
+        .recompute_mass_properties(body)
+        .expect("mass properties should recompute");
+    assert_eq!(
+        recomputed,
+        world
+            .body(body)
+            .expect("body should exist")
+            .mass_properties()
+    );
+    assert_ne!(world.revision(), revision);
+}
+
+#[test]
+fn zero_area_colliders_carry_explicit_mass_at_their_centroid() {
+    let recipe =
+        WorldRecipe::new(WorldDesc::default()).with_body(BodyBundle::dynamic().with_collider(
+            ColliderBundle::new(SharedShape::segment((0.0, 0.0), (2.0, 0.0))).with_mass(2.0),
+        ));
+    let created = recipe.instantiate().expect("segment recipe should build");
+    let mass = created
+        .world
+        .body(created.created.body_handles[0])
+        .expect("body should exist")
+        .mass_properties();
+
+    assert_near(mass.mass, 2.0);
+    assert_eq!(mass.local_center_of_mass, (1.0, 0.0).into());
+    assert_eq!(mass.inertia, 0.0);
+    assert_eq!(mass.inverse_inertia, 0.0);
+}
+
+#[test]
+fn invalid_mass_overrides_are_rejected_without_mutation() {
+    let mut world = World::new(WorldDesc::default());
+    let error = world
+        .create_body(BodyDesc {
+            mass_override: Some(MassProperties {
+                mass: FloatNum::NAN,
+                ..MassProperties::default()
+            }),
+            ..BodyDesc::default()
+        })
+        .expect_err("non-finite override must be rejected");
+    assert_eq!(
+        error,
+        WorldError::Validation(ValidationError::BodyDesc {
+            field: "mass_override"
+        })
+    );
+
+    let body = world
+        .create_body(BodyDesc::default())
+        .expect("body should be created");
+    let error = world
+        .create_collider(
+            body,
+            ColliderDesc {
+                mass: Some(-1.0),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect_err("negative collider mass must be rejected");
+    assert_eq!(
+        error,
+        WorldError::Validation(ValidationError::ColliderDesc { field: "mass" })
+    );
+
+    let revision = world.revision();
+    let error = world
+        .apply_body_patch(
+            body,
+            BodyPatch {
+                mass_override: Some(Some(MassProperties {
+                    inertia: -1.0,
+                    ..MassProperties::default()
+                })),
+                ..BodyPatch::default()
+            },
+        )
+        .expect_err("negative override inertia must be rejected");
+    assert_eq!(
+        error,
+        WorldError::Validation(ValidationError::BodyPatch {
+            field: "mass_override"
+        })
+    );
+    assert_eq!(world.revision(), revision);
+    assert_eq!(
+        world.body(body).expect("body should exist").mass_override(),
+        None
+    );
+
+    // A zero override mass would make a dynamic body silently immovable.
+    let zero_mass = MassProperties {
+        mass: 0.0,
+        inertia: 1.0,
+        ..MassProperties::default()
+    };
+    assert_eq!(
+        world.create_body(BodyDesc {
+            mass_override: Some(zero_mass),
+            ..BodyDesc::default()
+        }),
+        Err(WorldError::Validation(ValidationError::BodyDesc {
+            field: "mass_override.mass"
+        }))
+    );
+    assert_eq!(
+        world.apply_body_patch(
+            body,
+            BodyPatch {
+                mass_override: Some(Some(zero_mass)),
+                ..BodyPatch::default()
+            },
+        ),
+        Err(WorldError::Validation(ValidationError::BodyPatch {
+            field: "mass_override.mass"
+        }))
+    );
+    let anchor = world
+        .create_body(BodyDesc {
+            body_type: BodyType::Static,
+            mass_override: Some(zero_mass),
+            ..BodyDesc::default()
+        })
+        .expect("static bodies may carry a zero override mass");
+    assert_eq!(
+        world.apply_body_patch(
+            anchor,
+            BodyPatch {
+                body_type: Some(BodyType::Dynamic),
+                ..BodyPatch::default()
+            },
+        ),
+        Err(WorldError::Validation(ValidationError::BodyPatch {
+            field: "mass_override.mass"
+        }))
+    );
+    assert_eq!(
+        world.body(anchor).expect("anchor should exist").body_type(),
+        BodyType::Static
+    );
+}
+
 #[test]
 fn world_commands_create_bundles_with_structured_handles_and_events() {
     let mut world = World::new(WorldDesc::default());
@@ -604,6 +852,77 @@ fn world_commands_reject_invalid_recipe_joint_without_partial_bodies() {
     );
 }
 
+#[test]
+fn world_recipe_from_world_rebuilds_the_current_scene() {
+    let created = WorldRecipe::new(WorldDesc::default())
+        .with_body(BodyBundle::static_body().with_collider(ColliderBundle::rect(8.0, 0.5)))
+        .with_body(BodyBundle::dynamic().with_collider(ColliderBundle::circle(0.25)))
+        .with_body(
+            BodyBundle::dynamic()
+                .with_pose(Pose::from_xy_angle(1.0, -2.0, 0.3))
+                .with_colliders([
+                    ColliderBundle::rect(1.0, 0.5).with_density(2.0),
+                    ColliderBundle::circle(0.2)
+                        .with_local_pose(Pose::from_xy_angle(0.6, 0.0, 0.0))
+                        .with_sensor(true),
+                ]),
+        )
+        .with_joint(JointBundle::world_anchor(2).with_world_anchor(Point::new(1.0, -3.0)))
+        .instantiate()
+        .expect("source recipe should instantiate");
+    let mut world = created.world;
+    // Free the second slot so recipe indices no longer match raw handle indices.
+    world
+        .destroy_body(created.created.body_handles[1])
+        .expect("middle body should be destroyed");
+    let mut pipeline = SimulationPipeline::new(StepConfig::default());
+    for _ in 0..10 {
+        pipeline.step(&mut world);
+    }
+
+    let recipe = WorldRecipe::from_world(&world);
+    assert_eq!(&recipe.desc, world.desc());
+    assert_eq!(recipe.bodies.len(), 2);
+    assert_eq!(recipe.bodies[1].colliders.len(), 2);
+    assert!(matches!(
+        recipe.joints.as_slice(),
+        [JointBundle::WorldAnchor { body: 1, .. }]
+    ));
+
+    let rebuilt = recipe
+        .clone()
+        .instantiate()
+        .expect("exported recipe should instantiate");
+    for (source, copy) in world.bodies().zip(rebuilt.world.bodies()) {
+        let source_view = world.body(source).expect("source body resolves");
+        let copy_view = rebuilt.world.body(copy).expect("rebuilt body resolves");
+        assert_eq!(copy_view.desc(), source_view.desc());
+        let source_colliders: Vec<_> = world
+            .colliders_for_body(source)
+            .expect("source colliders resolve")
+            .map(|handle| world.collider(handle).expect("collider resolves").desc())
+            .collect();
+        let copy_colliders: Vec<_> = rebuilt
+            .world
+            .colliders_for_body(copy)
+            .expect("rebuilt colliders resolve")
+            .map(|handle| {
+                rebuilt
+                    .world
+                    .collider(handle)
+                    .expect("collider resolves")
+                    .desc()
+            })
+            .collect();
+        assert_eq!(copy_colliders, source_colliders);
+    }
+    assert_eq!(
+        WorldRecipe::from_world(&rebuilt.world),
+        recipe,
+        "exporting the rebuilt world should reproduce the same recipe"
+    );
+}
+
 #[test]
 fn world_recipe_errors_include_nested_body_collider_and_joint_paths() {
     let collider_error = WorldRecipe::new(WorldDesc::default())
@@ -1,7 +1,8 @@
-use picea::events::CcdTargetKind;
+use picea::events::{CcdMethod, CcdTargetKind};
+use picea::math::pi;
 use picea::prelude::*;
 
-const DT: f32 = 1.0 / 60.0;
+const DT: FloatNum = 1.0 / 60.0;
 
 fn fixed_step_config() -> StepConfig {
     StepConfig {
@@ -20,8 +21,8 @@ fn no_gravity_world() -> World {
 fn create_body(
     world: &mut World,
     body_type: BodyType,
-    x: f32,
-    y: f32,
+    x: FloatNum,
+    y: FloatNum,
     linear_velocity: Vector,
 ) -> BodyHandle {
     world
@@ -48,7 +49,7 @@ fn attach_shape_with_density(
     world: &mut World,
     body: BodyHandle,
     shape: SharedShape,
-    density: f32,
+    density: FloatNum,
     material: Material,
 ) -> ColliderHandle {
     world
@@ -92,7 +93,7 @@ fn body_position(world: &World, body: BodyHandle) -> Vector {
         .translation()
 }
 
-fn body_angular_velocity(world: &World, body: BodyHandle) -> f32 {
+fn body_angular_velocity(world: &World, body: BodyHandle) -> FloatNum {
     world
         .try_body(body)
         .expect("body should still exist")
@@ -905,7 +906,7 @@ fn restitution_changes_post_impact_bounce_velocity() {
 fn friction_changes_tangential_sliding_speed() {
     // Physical behavior: lower friction should preserve more tangential velocity than high
     // friction while sliding on the same surface.
-    fn sliding_speed_after_contact(friction: f32) -> f32 {
+    fn sliding_speed_after_contact(friction: FloatNum) -> FloatNum {
         let mut world = no_gravity_world();
         let floor = create_body(&mut world, BodyType::Static, 0.0, 0.5, Vector::default());
         let slider = create_body(
@@ -939,12 +940,13 @@ fn friction_changes_tangential_sliding_speed() {
 fn ramp_friction_suppresses_downhill_sliding_more_than_low_friction() {
     // In this setup the actual downhill motion projects onto the ramp's negative tangent, so we
     // lock assertions to that signed downhill vector instead of erasing direction with `abs()`.
-    fn downhill_progress_after_contact(friction: f32) -> (f32, f32) {
+    fn downhill_progress_after_contact(friction: FloatNum) -> (FloatNum, FloatNum) {
         let mut world = World::new(WorldDesc {
             gravity: Vector::new(0.0, 9.81),
             enable_sleep: false,
+            ..WorldDesc::default()
         });
-        let ramp_angle: f32 = 0.4;
+        let ramp_angle: FloatNum = 0.4;
         let ramp_tangent = Vector::new(ramp_angle.cos(), ramp_angle.sin());
         let downhill_tangent = -ramp_tangent;
         let surface_normal = Vector::new(ramp_angle.sin(), -ramp_angle.cos());
@@ -1016,6 +1018,7 @@ fn sequential_impulse_solves_all_manifold_rows_for_stacked_contact() {
     let mut world = World::new(WorldDesc {
         gravity: Vector::default(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let floor = create_body(&mut world, BodyType::Static, 0.0, 0.5, Vector::default());
     let box_body = create_body(
@@ -1073,6 +1076,7 @@ fn tangent_impulse_is_clamped_by_coulomb_friction_budget() {
     let mut world = World::new(WorldDesc {
         gravity: Vector::default(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let floor = create_body(&mut world, BodyType::Static, 0.0, 0.5, Vector::default());
     let slider = create_body(
@@ -1125,10 +1129,11 @@ fn tangent_impulse_is_clamped_by_coulomb_friction_budget() {
 
 #[test]
 fn restitution_uses_configurable_velocity_threshold() {
-    fn impact(speed: f32, threshold: f32) -> (Vector, ContactEvent) {
+    fn impact(speed: FloatNum, threshold: FloatNum) -> (Vector, ContactEvent) {
         let mut world = World::new(WorldDesc {
             gravity: Vector::default(),
             enable_sleep: false,
+            ..WorldDesc::default()
         });
         let floor = create_body(&mut world, BodyType::Static, 0.0, 0.5, Vector::default());
         let ball = create_body(
@@ -1190,6 +1195,7 @@ fn off_center_contact_produces_angular_velocity() {
     let mut world = World::new(WorldDesc {
         gravity: Vector::default(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let obstacle = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
     let striking_box = world
@@ -1239,7 +1245,10 @@ fn off_center_contact_produces_angular_velocity() {
 
 #[test]
 fn collider_density_changes_sequential_impulse_response_through_inverse_mass() {
-    fn post_contact_velocity(left_density: f32, right_density: f32) -> (Vector, Vector, f32, f32) {
+    fn post_contact_velocity(
+        left_density: FloatNum,
+        right_density: FloatNum,
+    ) -> (Vector, Vector, FloatNum, FloatNum) {
         let mut world = no_gravity_world();
         let left = create_body(
             &mut world,
@@ -1374,210 +1383,1836 @@ fn contact_position_correction_preserves_spin_after_velocity_solve() {
         .expect("spinner should still exist")
         .angular_velocity();
     assert!(
-        (angular_velocity - 5.0).abs() < f32::EPSILON,
+        (angular_velocity - 5.0).abs() < FloatNum::EPSILON,
         "contact correction should not silently clear angular velocity; got {angular_velocity}"
     );
 }
 
 #[test]
-fn sleep_requires_a_stability_window_before_a_body_sleeps() {
-    // Physical behavior: sleeping should require sustained low motion over a stability window,
-    // so bodies do not sleep after one quiet frame and miss near-future wake interactions.
+fn locked_rotation_keeps_off_center_impact_from_spinning() {
     let mut world = no_gravity_world();
-    let body = create_body(&mut world, BodyType::Dynamic, 0.0, 0.0, Vector::default());
-    world
-        .apply_body_patch(
-            body,
-            BodyPatch {
-                can_sleep: Some(true),
-                ..BodyPatch::default()
-            },
-        )
-        .expect("body patch should apply");
-
-    step_world(&mut world, 1);
+    let obstacle = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
+    let striking_box = world
+        .create_body(BodyDesc {
+            pose: Pose::from_xy_angle(-0.8, -0.35, 0.0),
+            linear_velocity: Vector::new(4.0, 0.0),
+            angular_velocity: 2.0,
+            locked_axes: LockedAxes::ROTATION,
+            can_sleep: false,
+            ..BodyDesc::default()
+        })
+        .expect("striking box should be created");
+    let material = Material {
+        friction: 0.0,
+        restitution: 0.0,
+    };
+    attach_shape(&mut world, obstacle, SharedShape::circle(0.5), material);
+    attach_shape(
+        &mut world,
+        striking_box,
+        SharedShape::rect(1.0, 1.0),
+        material,
+    );
+    assert_eq!(
+        body_angular_velocity(&world, striking_box),
+        0.0,
+        "locked rotation must discard authored spin"
+    );
 
+    let report = step_world(&mut world, 1);
     assert!(
-        !world.try_body(body).expect("body should exist").sleeping(),
-        "a single quiet step should not be enough to put a dynamic body to sleep"
+        active_contact_events(&report)
+            .iter()
+            .any(|contact| contact.solver_normal_impulse > 0.0),
+        "the locked box should still be stopped by the obstacle"
     );
+    step_world(&mut world, 10);
 
-    step_world(&mut world, 31);
+    assert_eq!(body_angular_velocity(&world, striking_box), 0.0);
+    assert_eq!(world.body(striking_box).unwrap().pose().angle(), 0.0);
+    assert!(body_velocity(&world, striking_box).x() < 4.0);
+}
+
+#[test]
+fn locked_translation_axis_acts_as_infinite_mass_for_contacts() {
+    let run = |gate_type: BodyType, locked_axes: LockedAxes| {
+        let mut world = no_gravity_world();
+        let ball = create_body(
+            &mut world,
+            BodyType::Dynamic,
+            -1.0,
+            0.0,
+            Vector::new(6.0, 0.0),
+        );
+        // CCD only sweeps moving dynamic targets, so keep both runs on the
+        // plain contact solver path.
+        world
+            .apply_body_patch(
+                ball,
+                BodyPatch {
+                    ccd_enabled: Some(false),
+                    ..BodyPatch::default()
+                },
+            )
+            .expect("ball patch should apply");
+        let gate = world
+            .create_body(BodyDesc {
+                body_type: gate_type,
+                locked_axes,
+                can_sleep: false,
+                ..BodyDesc::default()
+            })
+            .expect("gate should be created");
+        let material = Material {
+            friction: 0.0,
+            restitution: 0.0,
+        };
+        attach_shape(&mut world, ball, SharedShape::circle(0.25), material);
+        attach_shape(&mut world, gate, SharedShape::rect(1.0, 1.0), material);
+        step_world(&mut world, 30);
+        (
+            body_position(&world, ball),
+            body_velocity(&world, ball),
+            body_position(&world, gate),
+            body_velocity(&world, gate),
+        )
+    };
+
+    let (wall_ball_position, wall_ball_velocity, _, _) = run(BodyType::Static, LockedAxes::NONE);
+    let (ball_position, ball_velocity, gate_position, gate_velocity) = run(
+        BodyType::Dynamic,
+        LockedAxes {
+            translation_x: true,
+            ..LockedAxes::NONE
+        },
+    );
 
+    assert_eq!(gate_position.x(), 0.0);
+    assert_eq!(gate_velocity.x(), 0.0);
     assert!(
-        world.try_body(body).expect("body should exist").sleeping(),
-        "a body should sleep after remaining quiet for the stability window"
+        (ball_velocity - wall_ball_velocity).length() < 1e-3
+            && (ball_position - wall_ball_position).length() < 1e-3,
+        "an x-locked gate must stop the ball like a static wall: \
+         {ball_position:?} {ball_velocity:?} vs {wall_ball_position:?} {wall_ball_velocity:?}"
     );
 }
 
 #[test]
-fn jointed_island_sleeps_together_with_island_reason_facts() {
-    let mut world = no_gravity_world();
-    let left = world
+fn locked_axes_limit_gravity_and_joint_corrections() {
+    let mut world = World::new(WorldDesc::default());
+    let rail_car = world
         .create_body(BodyDesc {
-            body_type: BodyType::Dynamic,
-            pose: Pose::from_xy_angle(-0.5, 0.0, 0.0),
-            can_sleep: true,
-            ..BodyDesc::default()
-        })
-        .expect("left body should be created");
-    let right = world
-        .create_body(BodyDesc {
-            body_type: BodyType::Dynamic,
-            pose: Pose::from_xy_angle(0.5, 0.0, 0.0),
-            can_sleep: true,
+            locked_axes: LockedAxes {
+                translation_y: true,
+                ..LockedAxes::NONE
+            },
+            can_sleep: false,
             ..BodyDesc::default()
         })
-        .expect("right body should be created");
+        .expect("rail car should be created");
+    attach_shape(
+        &mut world,
+        rail_car,
+        SharedShape::circle(0.25),
+        Material::default(),
+    );
     world
-        .create_joint(JointDesc::Distance(DistanceJointDesc {
-            body_a: left,
-            body_b: right,
-            rest_length: 1.0,
-            ..DistanceJointDesc::default()
+        .create_joint(JointDesc::WorldAnchor(WorldAnchorJointDesc {
+            body: rail_car,
+            world_anchor: Point::new(1.0, 1.0),
+            stiffness: 30.0,
+            ..WorldAnchorJointDesc::default()
         }))
-        .expect("resting distance joint should be created");
-
-    let mut pipeline = SimulationPipeline::new(fixed_step_config());
-    let mut reports = Vec::new();
-    for _ in 0..31 {
-        reports.push(pipeline.step(&mut world));
-    }
-    let sleep_events = reports
-        .iter()
-        .flat_map(|report| report.events.iter())
-        .filter_map(|event| match event {
-            WorldEvent::SleepChanged(event) => Some(event),
-            _ => None,
-        })
-        .collect::<Vec<_>>();
+        .expect("anchor joint should be created");
 
-    assert_eq!(sleep_events.len(), 2);
-    assert!(sleep_events.iter().all(|event| event.is_sleeping));
-    assert!(sleep_events
-        .iter()
-        .all(|event| event.reason == picea::events::SleepTransitionReason::StabilityWindow));
-    assert_eq!(sleep_events[0].island_id, sleep_events[1].island_id);
+    step_world(&mut world, 120);
 
-    let snapshot = world.debug_snapshot(&DebugSnapshotOptions::default());
-    let left_debug = snapshot
-        .bodies
-        .iter()
-        .find(|body| body.handle == left)
-        .expect("left body should be in debug snapshot");
-    let right_debug = snapshot
-        .bodies
-        .iter()
-        .find(|body| body.handle == right)
-        .expect("right body should be in debug snapshot");
-    assert_eq!(left_debug.island_id, right_debug.island_id);
-    let island_id = left_debug
-        .island_id
-        .expect("dynamic bodies should have an island");
-    let island = snapshot
-        .islands
-        .iter()
-        .find(|island| island.id == island_id)
-        .expect("debug snapshot should expose the sleeping island");
-    assert!(island.sleeping);
-    assert_eq!(island.bodies, vec![left, right]);
-}
+    assert_eq!(body_position(&world, rail_car).y(), 0.0);
+    assert!(
+        (body_position(&world, rail_car).x() - 1.0).abs() < 0.05,
+        "the free x axis should still follow the joint: {:?}",
+        body_position(&world, rail_car)
+    );
 
-#[test]
-fn transform_patch_wakes_the_touched_sleeping_island_only() {
+    // A distance joint hands the whole correction to the partner along the locked axis.
     let mut world = no_gravity_world();
-    let first = world
-        .create_body(BodyDesc {
-            body_type: BodyType::Dynamic,
-            pose: Pose::from_xy_angle(-2.0, 0.0, 0.0),
-            can_sleep: true,
-            ..BodyDesc::default()
-        })
-        .expect("first body should be created");
-    let partner = world
+    let pinned = world
         .create_body(BodyDesc {
-            body_type: BodyType::Dynamic,
-            pose: Pose::from_xy_angle(-1.0, 0.0, 0.0),
-            can_sleep: true,
-            ..BodyDesc::default()
-        })
-        .expect("partner body should be created");
-    let unrelated = world
-        .create_body(BodyDesc {
-            body_type: BodyType::Dynamic,
-            pose: Pose::from_xy_angle(2.0, 0.0, 0.0),
-            can_sleep: true,
+            locked_axes: LockedAxes::TRANSLATION,
+            can_sleep: false,
             ..BodyDesc::default()
         })
-        .expect("unrelated body should be created");
+        .expect("pinned body should be created");
+    let free = create_body(&mut world, BodyType::Dynamic, 2.0, 0.0, Vector::default());
+    for body in [pinned, free] {
+        attach_shape(
+            &mut world,
+            body,
+            SharedShape::circle(0.25),
+            Material::default(),
+        );
+    }
     world
         .create_joint(JointDesc::Distance(DistanceJointDesc {
-            body_a: first,
-            body_b: partner,
+            body_a: pinned,
+            body_b: free,
             rest_length: 1.0,
+            stiffness: 30.0,
             ..DistanceJointDesc::default()
         }))
-        .expect("joint should connect the first island");
+        .expect("distance joint should be created");
 
-    step_world(&mut world, 31);
-    assert!(world.try_body(first).expect("first exists").sleeping());
-    assert!(world.try_body(partner).expect("partner exists").sleeping());
-    assert!(world
-        .try_body(unrelated)
-        .expect("unrelated exists")
-        .sleeping());
+    step_world(&mut world, 120);
 
-    world
-        .apply_body_patch(
-            first,
-            BodyPatch {
-                pose: Some(Pose::from_xy_angle(-1.5, 0.0, 0.0)),
-                ..BodyPatch::default()
-            },
-        )
-        .expect("pose patch should apply");
+    assert_eq!(body_position(&world, pinned), Vector::default());
+    assert!(
+        (body_position(&world, free).x() - 1.0).abs() < 0.05,
+        "the free body should close the whole gap: {:?}",
+        body_position(&world, free)
+    );
+}
 
-    let report = step_world(&mut world, 1);
-    let wake_events = report
-        .events
-        .iter()
-        .filter_map(|event| match event {
-            WorldEvent::SleepChanged(event) if !event.is_sleeping => Some(event),
-            _ => None,
+#[test]
+fn radial_gravity_field_pulls_toward_its_center_with_falloff() {
+    let mut world = World::new(WorldDesc {
+        gravity: Vector::default(),
+        gravity_fields: vec![GravityField::Radial {
+            center: Point::new(0.0, 0.0),
+            strength: 10.0,
+            inner_radius: 1.0,
+            outer_radius: 20.0,
+            falloff: GravityFalloff::InverseSquare,
+        }],
+        ..WorldDesc::default()
+    });
+    let orbiting = create_body(&mut world, BodyType::Dynamic, 4.0, 0.0, Vector::default());
+    let surface = create_body(&mut world, BodyType::Dynamic, 0.0, -0.5, Vector::default());
+    let distant = create_body(&mut world, BodyType::Dynamic, 30.0, 0.0, Vector::default());
+    let overridden = world
+        .create_body(BodyDesc {
+            pose: Pose::from_xy_angle(4.0, 0.0, 0.0),
+            gravity_override: Some(Vector::new(0.0, -2.0)),
+            gravity_scale: 0.5,
+            can_sleep: false,
+            ..BodyDesc::default()
         })
-        .collect::<Vec<_>>();
+        .expect("overridden body should be created");
 
-    assert_eq!(wake_events.len(), 2);
-    assert!(wake_events.iter().any(|event| event.body == first));
-    assert!(wake_events.iter().any(|event| event.body == partner));
-    assert!(wake_events
-        .iter()
-        .all(|event| event.reason == picea::events::SleepTransitionReason::TransformEdit));
-    assert_eq!(wake_events[0].island_id, wake_events[1].island_id);
-    assert!(!world.try_body(first).expect("first exists").sleeping());
-    assert!(!world.try_body(partner).expect("partner exists").sleeping());
+    step_world(&mut world, 1);
+
+    let orbiting_velocity = body_velocity(&world, orbiting);
+    assert!((orbiting_velocity.x() + 10.0 / 16.0 * DT).abs() < 1e-5);
+    assert!(orbiting_velocity.y().abs() < 1e-6);
+    let surface_velocity = body_velocity(&world, surface);
     assert!(
-        world
-            .try_body(unrelated)
-            .expect("unrelated exists")
-            .sleeping(),
-        "an unrelated sleeping island should stay asleep"
+        (surface_velocity.y() - 10.0 * DT).abs() < 1e-5,
+        "inside the inner radius the field pulls at full strength: {surface_velocity:?}"
     );
+    assert_eq!(body_velocity(&world, distant), Vector::default());
+    assert!((body_velocity(&world, overridden).y() + DT).abs() < 1e-6);
+    assert_eq!(body_velocity(&world, overridden).x(), 0.0);
 }
 
 #[test]
-fn static_contacts_do_not_bridge_dynamic_sleep_islands() {
-    let mut world = no_gravity_world();
-    let floor = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
-    let left = create_body(&mut world, BodyType::Dynamic, -2.0, 0.0, Vector::default());
-    let right = create_body(&mut world, BodyType::Dynamic, 2.0, 0.0, Vector::default());
-    attach_shape(
-        &mut world,
-        floor,
-        SharedShape::rect(8.0, 1.0),
-        Material::default(),
+fn uniform_gravity_zone_only_applies_inside_its_region() {
+    let mut world = World::new(WorldDesc {
+        gravity_fields: vec![GravityField::Uniform {
+            min: Point::new(-1.0, -1.0),
+            max: Point::new(1.0, 1.0),
+            acceleration: Vector::new(0.0, -19.6),
+        }],
+        ..WorldDesc::default()
+    });
+    let inside = create_body(&mut world, BodyType::Dynamic, 0.0, 0.0, Vector::default());
+    let outside = create_body(&mut world, BodyType::Dynamic, 3.0, 0.0, Vector::default());
+
+    step_world(&mut world, 1);
+
+    assert!((body_velocity(&world, inside).y() + 9.8 * DT).abs() < 1e-5);
+    assert!((body_velocity(&world, outside).y() - 9.8 * DT).abs() < 1e-5);
+
+    world
+        .set_gravity_fields(Vec::new())
+        .expect("clearing fields should succeed");
+    step_world(&mut world, 1);
+    assert!(body_velocity(&world, inside).y().abs() < 1e-5);
+
+    let revision = world.revision();
+    let error = world
+        .set_gravity_fields(vec![GravityField::Radial {
+            center: Point::new(0.0, 0.0),
+            strength: 1.0,
+            inner_radius: 2.0,
+            outer_radius: 1.0,
+            falloff: GravityFalloff::Linear,
+        }])
+        .expect_err("outer radius below inner radius must be rejected");
+    assert_eq!(
+        error,
+        WorldError::Validation(ValidationError::WorldDesc {
+            field: "gravity_fields.radius"
+        })
     );
-    attach_shape(
+    assert!(world.gravity_fields().is_empty());
+    assert_eq!(world.revision(), revision);
+
+    // Descriptors are checked the same way when a world is built from them.
+    let desc = WorldDesc {
+        gravity_fields: vec![GravityField::Radial {
+            center: Point::new(FloatNum::NAN, 0.0),
+            strength: 1.0,
+            inner_radius: 0.0,
+            outer_radius: 1.0,
+            falloff: GravityFalloff::Linear,
+        }],
+        ..WorldDesc::default()
+    };
+    assert_eq!(
+        World::try_new(desc.clone()).err(),
+        Some(WorldError::Validation(ValidationError::WorldDesc {
+            field: "gravity_fields.center"
+        }))
+    );
+    let unchecked = desc.clone();
+    assert!(
+        std::panic::catch_unwind(move || World::new(unchecked)).is_err(),
+        "World::new must not accept what try_new rejects"
+    );
+    let error = WorldRecipe::new(desc)
+        .instantiate_with_context()
+        .expect_err("recipes must validate their world descriptor");
+    assert_eq!(error.path, "recipe.desc.gravity_fields.center");
+    assert_eq!(error.error.kind, WorldCommandKind::CreateWorld);
+}
+
+fn create_fluid_pool(world: &mut World, fluid: FluidVolume) -> ColliderHandle {
+    // Gravity points toward +y, so the pool surface sits at y = 0 and the
+    // water fills y in [0, 10].
+    let pool = create_body(world, BodyType::Static, 0.0, 5.0, Vector::default());
+    world
+        .create_collider(
+            pool,
+            ColliderDesc {
+                shape: SharedShape::rect(20.0, 10.0),
+                fluid: Some(fluid),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("fluid collider should be created")
+}
+
+#[test]
+fn fluid_volume_floats_a_box_at_its_density_ratio_depth() {
+    let mut world = World::new(WorldDesc::default());
+    let pool = create_fluid_pool(
+        &mut world,
+        FluidVolume {
+            density: 1.0,
+            linear_drag: 2.0,
+            angular_drag: 2.0,
+        },
+    );
+    assert!(
+        world
+            .collider(pool)
+            .expect("pool should resolve")
+            .is_sensor(),
+        "fluid volumes never push bodies out with contact impulses"
+    );
+    let floater = create_body(&mut world, BodyType::Dynamic, 0.0, -2.0, Vector::default());
+    attach_shape_with_density(
+        &mut world,
+        floater,
+        SharedShape::rect(1.0, 1.0),
+        0.5,
+        Material::default(),
+    );
+    let sinker = create_body(&mut world, BodyType::Dynamic, 4.0, 2.0, Vector::default());
+    attach_shape_with_density(
+        &mut world,
+        sinker,
+        SharedShape::rect(1.0, 1.0),
+        2.0,
+        Material::default(),
+    );
+
+    step_world(&mut world, 600);
+
+    let floating = body_position(&world, floater);
+    assert!(
+        floating.y().abs() < 0.05,
+        "a half-density box floats half submerged: {floating:?}"
+    );
+    assert!(body_velocity(&world, floater).length() < 0.05);
+    assert!(
+        body_position(&world, sinker).y() > 9.0,
+        "a denser box sinks through the pool: {:?}",
+        body_position(&world, sinker)
+    );
+}
+
+#[test]
+fn fluid_buoyancy_follows_the_body_gravity_scale() {
+    let mut world = World::new(WorldDesc::default());
+    create_fluid_pool(
+        &mut world,
+        FluidVolume {
+            density: 1.0,
+            linear_drag: 0.0,
+            angular_drag: 0.0,
+        },
+    );
+    let weightless = create_body(&mut world, BodyType::Dynamic, 0.0, 5.0, Vector::default());
+    attach_shape_with_density(
+        &mut world,
+        weightless,
+        SharedShape::rect(1.0, 1.0),
+        0.5,
+        Material::default(),
+    );
+    world
+        .apply_body_patch(
+            weightless,
+            BodyPatch {
+                gravity_scale: Some(0.0),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("gravity scale should apply");
+
+    step_world(&mut world, 60);
+
+    assert!(
+        body_velocity(&world, weightless).length() < 1e-5,
+        "a body that ignores gravity feels no buoyancy: {:?}",
+        body_velocity(&world, weightless)
+    );
+}
+
+#[test]
+fn fluid_drag_slows_bodies_without_reversing_them() {
+    let mut world = no_gravity_world();
+    create_fluid_pool(
+        &mut world,
+        FluidVolume {
+            density: 1.0,
+            linear_drag: 0.5,
+            angular_drag: 0.5,
+        },
+    );
+    let gentle = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        0.0,
+        5.0,
+        Vector::new(4.0, 0.0),
+    );
+    attach_shape(
+        &mut world,
+        gentle,
+        SharedShape::circle(0.5),
+        Material::default(),
+    );
+    let heavy_drag_pool = create_body(&mut world, BodyType::Static, 0.0, 30.0, Vector::default());
+    world
+        .create_collider(
+            heavy_drag_pool,
+            ColliderDesc {
+                shape: SharedShape::rect(20.0, 10.0),
+                fluid: Some(FluidVolume {
+                    density: 1.0,
+                    linear_drag: 1.0e4,
+                    angular_drag: 1.0e4,
+                }),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("fluid collider should be created");
+    let stopped = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        0.0,
+        30.0,
+        Vector::new(4.0, 0.0),
+    );
+    attach_shape(
+        &mut world,
+        stopped,
+        SharedShape::rect(1.0, 1.0),
+        Material::default(),
+    );
+    world
+        .apply_body_patch(
+            stopped,
+            BodyPatch {
+                angular_velocity: Some(3.0),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("spin should apply");
+
+    let mut previous = body_velocity(&world, gentle).x();
+    for _ in 0..30 {
+        step_world(&mut world, 1);
+        let current = body_velocity(&world, gentle).x();
+        assert!(
+            current < previous && current > 0.0,
+            "{previous} -> {current}"
+        );
+        previous = current;
+        let stopped_velocity = body_velocity(&world, stopped);
+        assert!(
+            stopped_velocity.x() >= -1e-5,
+            "drag must not reverse motion: {stopped_velocity:?}"
+        );
+        assert!(body_angular_velocity(&world, stopped).abs() <= 3.0);
+    }
+    assert!(body_velocity(&world, stopped).length() < 1e-3);
+    assert!(body_angular_velocity(&world, stopped).abs() < 1e-3);
+}
+
+#[test]
+fn fluid_volumes_require_convex_shapes_and_valid_coefficients() {
+    let mut world = no_gravity_world();
+    let body = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
+    let concave = world
+        .create_collider(
+            body,
+            ColliderDesc {
+                shape: SharedShape::concave_polygon(vec![
+                    Point::new(0.0, 0.0),
+                    Point::new(2.0, 0.0),
+                    Point::new(1.0, 1.0),
+                    Point::new(2.0, 2.0),
+                    Point::new(0.0, 2.0),
+                ]),
+                fluid: Some(FluidVolume::default()),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect_err("concave fluids are rejected");
+    assert_eq!(
+        concave,
+        WorldError::Validation(ValidationError::ColliderDesc { field: "fluid" })
+    );
+    let negative = world
+        .create_collider(
+            body,
+            ColliderDesc {
+                fluid: Some(FluidVolume {
+                    density: -1.0,
+                    ..FluidVolume::default()
+                }),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect_err("negative fluid density is rejected");
+    assert_eq!(
+        negative,
+        WorldError::Validation(ValidationError::ColliderDesc { field: "fluid" })
+    );
+
+    let pool = world
+        .create_collider(
+            body,
+            ColliderDesc {
+                fluid: Some(FluidVolume::default()),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("circle fluids are supported");
+    let revision = world.revision();
+    let error = world
+        .apply_collider_patch(
+            pool,
+            ColliderPatch {
+                shape: Some(SharedShape::segment(
+                    Point::new(0.0, 0.0),
+                    Point::new(1.0, 0.0),
+                )),
+                ..ColliderPatch::default()
+            },
+        )
+        .expect_err("segments cannot bound a fluid");
+    assert_eq!(
+        error,
+        WorldError::Validation(ValidationError::ColliderPatch { field: "fluid" })
+    );
+    assert_eq!(world.revision(), revision);
+
+    world
+        .apply_collider_patch(
+            pool,
+            ColliderPatch {
+                fluid: Some(None),
+                ..ColliderPatch::default()
+            },
+        )
+        .expect("fluids can be turned off");
+    let view = world.collider(pool).expect("pool should resolve");
+    assert_eq!(view.fluid(), None);
+    assert!(
+        view.is_sensor(),
+        "turning the fluid off keeps the sensor flag"
+    );
+}
+
+fn create_box(world: &mut World, x: FloatNum, y: FloatNum) -> BodyHandle {
+    let body = create_body(world, BodyType::Dynamic, x, y, Vector::default());
+    attach_shape(
+        world,
+        body,
+        SharedShape::rect(1.0, 1.0),
+        Material::default(),
+    );
+    body
+}
+
+#[test]
+fn radial_impulse_pushes_nearby_bodies_away_with_falloff() {
+    let mut world = no_gravity_world();
+    let near = create_box(&mut world, 3.0, 0.0);
+    let far = create_box(&mut world, 6.0, 0.0);
+    let offset = create_box(&mut world, 0.0, -3.0);
+    let outside = create_box(&mut world, 20.0, 0.0);
+    let wall = create_body(&mut world, BodyType::Static, -3.0, 0.0, Vector::default());
+    attach_shape(
+        &mut world,
+        wall,
+        SharedShape::rect(1.0, 1.0),
+        Material::default(),
+    );
+    world
+        .apply_body_patch(
+            near,
+            BodyPatch {
+                can_sleep: Some(true),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("near box may sleep");
+    assert!(world.sleep_island(near).expect("near box should resolve"));
+    let revision = world.revision();
+
+    let hits = world
+        .apply_radial_impulse(
+            Point::new(0.0, 0.0),
+            10.0,
+            10.0,
+            ImpulseFalloff::Linear,
+            QueryFilter::default(),
+        )
+        .expect("radial impulse should apply");
+
+    assert_eq!(
+        hits.iter().map(|hit| hit.body).collect::<Vec<_>>(),
+        vec![near, far, offset]
+    );
+    // Impulses land on the nearest surface point: 2.5 and 5.5 units away.
+    assert!((hits[0].point.x() - 2.5).abs() < 1e-3);
+    assert!((hits[0].distance - 2.5).abs() < 1e-3);
+    assert!((body_velocity(&world, near).x() - 7.5).abs() < 1e-2);
+    assert!((body_velocity(&world, far).x() - 4.5).abs() < 1e-2);
+    assert!(body_velocity(&world, near).y().abs() < 1e-4);
+    assert!((body_velocity(&world, offset).y() + 7.5).abs() < 1e-2);
+    assert_eq!(body_velocity(&world, outside), Vector::default());
+    assert!(!world
+        .body(near)
+        .expect("near box should resolve")
+        .sleeping());
+    assert!(world.revision() > revision);
+
+    let report = step_world(&mut world, 1);
+    assert!(report.events.iter().any(|event| matches!(
+        event,
+        WorldEvent::SleepChanged(SleepEvent {
+            body,
+            is_sleeping: false,
+            reason: SleepTransitionReason::VelocityEdit,
+            ..
+        }) if *body == near
+    )));
+}
+
+#[test]
+fn radial_impulse_wakes_the_island_and_sees_bodies_moved_since_the_last_step() {
+    let mut world = no_gravity_world();
+    let hit = create_box(&mut world, 30.0, 0.0);
+    let neighbour = create_box(&mut world, 50.0, 0.0);
+    world
+        .create_joint(JointDesc::Distance(DistanceJointDesc {
+            body_a: hit,
+            body_b: neighbour,
+            rest_length: 20.0,
+            ..DistanceJointDesc::default()
+        }))
+        .expect("distance joint should be created");
+    step_world(&mut world, 1);
+    world
+        .apply_body_patch(
+            hit,
+            BodyPatch {
+                pose: Some(Pose::from_xy_angle(3.0, 0.0, 0.0)),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("pose patch should apply");
+    for body in [hit, neighbour] {
+        world
+            .apply_body_patch(
+                body,
+                BodyPatch {
+                    can_sleep: Some(true),
+                    ..BodyPatch::default()
+                },
+            )
+            .expect("bodies may sleep");
+    }
+    assert!(world.sleep_island(hit).expect("island should resolve"));
+
+    let hits = world
+        .apply_radial_impulse(
+            Point::new(0.0, 0.0),
+            10.0,
+            10.0,
+            ImpulseFalloff::Constant,
+            QueryFilter::default(),
+        )
+        .expect("radial impulse should apply");
+
+    assert_eq!(
+        hits.iter().map(|hit| hit.body).collect::<Vec<_>>(),
+        vec![hit]
+    );
+    for body in [hit, neighbour] {
+        assert!(
+            !world.body(body).expect("body should resolve").sleeping(),
+            "the whole island wakes"
+        );
+    }
+}
+
+#[test]
+fn radial_impulse_queries_leave_the_next_step_broadphase_stats_alone() {
+    let mut world = no_gravity_world();
+    let body = create_box(&mut world, 0.0, 0.0);
+    step_world(&mut world, 1);
+    world
+        .apply_body_patch(
+            body,
+            BodyPatch {
+                pose: Some(Pose::from_xy_angle(40.0, 0.0, 0.0)),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("pose patch should apply");
+    let mut queried = world.clone();
+    let hits = queried
+        .apply_radial_impulse(
+            Point::new(-100.0, 0.0),
+            1.0,
+            10.0,
+            ImpulseFalloff::Constant,
+            QueryFilter::default(),
+        )
+        .expect("radial impulse should apply");
+    assert!(hits.is_empty());
+
+    let mut pipeline = SimulationPipeline::new(fixed_step_config());
+    let expected = pipeline.clone().step(&mut world).stats;
+    let stats = pipeline.step(&mut queried).stats;
+    assert!(expected.broadphase_update_count > 0);
+    assert_eq!(
+        stats.broadphase_update_count,
+        expected.broadphase_update_count
+    );
+    assert_eq!(
+        stats.broadphase_rebuild_count,
+        expected.broadphase_rebuild_count
+    );
+}
+
+#[test]
+fn radial_impulse_spins_off_center_bodies_and_can_pull() {
+    let mut world = no_gravity_world();
+    let body = create_box(&mut world, 3.0, 1.0);
+
+    let hits = world
+        .apply_radial_impulse(
+            Point::new(0.0, 0.0),
+            10.0,
+            -5.0,
+            ImpulseFalloff::Constant,
+            QueryFilter::default(),
+        )
+        .expect("radial impulse should apply");
+
+    let [hit] = hits.as_slice() else {
+        panic!("expected one hit: {hits:?}");
+    };
+    assert!((hit.point.x() - 2.5).abs() < 1e-3 && (hit.point.y() - 0.5).abs() < 1e-3);
+    assert!((hit.impulse.length() - 5.0).abs() < 1e-3);
+    assert!(
+        body_velocity(&world, body).x() < 0.0,
+        "negative strength pulls"
+    );
+    assert!(
+        body_angular_velocity(&world, body).abs() > 0.1,
+        "an impulse at a corner spins the body"
+    );
+}
+
+#[test]
+fn radial_impulse_occlusion_shadows_bodies_behind_blockers() {
+    let mut world = no_gravity_world();
+    let shielded = create_box(&mut world, 4.0, 0.0);
+    let exposed = create_box(&mut world, 0.0, 4.0);
+    let wall = create_body(&mut world, BodyType::Static, 2.0, 0.0, Vector::default());
+    attach_shape(
+        &mut world,
+        wall,
+        SharedShape::rect(0.5, 4.0),
+        Material::default(),
+    );
+
+    let hits = world
+        .apply_radial_impulse(
+            Point::new(0.0, 0.0),
+            10.0,
+            10.0,
+            ImpulseFalloff::Quadratic,
+            RadialImpulseFilter::from(QueryFilter::default()).occluded_by(QueryFilter::default()),
+        )
+        .expect("occluded radial impulse should apply");
+
+    assert_eq!(
+        hits.iter().map(|hit| hit.body).collect::<Vec<_>>(),
+        vec![exposed]
+    );
+    assert_eq!(body_velocity(&world, shielded), Vector::default());
+    // Quadratic falloff at 3.5 of 10 units keeps 0.65^2 of the strength.
+    assert!((body_velocity(&world, exposed).y() - 10.0 * 0.65 * 0.65).abs() < 1e-2);
+
+    let unoccluded = world
+        .apply_radial_impulse(
+            Point::new(0.0, 0.0),
+            10.0,
+            10.0,
+            ImpulseFalloff::Quadratic,
+            QueryFilter::default(),
+        )
+        .expect("radial impulse should apply");
+    assert!(unoccluded.iter().any(|hit| hit.body == shielded));
+
+    let revision = world.revision();
+    let error = world
+        .apply_radial_impulse(
+            Point::new(0.0, 0.0),
+            0.0,
+            10.0,
+            ImpulseFalloff::Linear,
+            QueryFilter::default(),
+        )
+        .expect_err("zero radius is rejected");
+    assert_eq!(
+        error,
+        WorldError::Validation(ValidationError::RadialImpulse { field: "radius" })
+    );
+    assert_eq!(world.revision(), revision);
+}
+
+fn create_static_box(
+    world: &mut World,
+    x: FloatNum,
+    y: FloatNum,
+    width: FloatNum,
+    height: FloatNum,
+) -> ColliderHandle {
+    let body = create_body(world, BodyType::Static, x, y, Vector::default());
+    attach_shape(
+        world,
+        body,
+        SharedShape::rect(width, height),
+        Material::default(),
+    )
+}
+
+/// Kinematic 0.5 x 1 box whose bottom rests `offset` above y = 0.5.
+fn create_character(world: &mut World, x: FloatNum) -> BodyHandle {
+    let body = create_body(world, BodyType::Kinematic, x, -0.01, Vector::default());
+    attach_shape(
+        world,
+        body,
+        SharedShape::rect(0.5, 1.0),
+        Material::default(),
+    );
+    body
+}
+
+/// World with a floor whose top face is y = 0.5; gravity points toward +y.
+fn character_world() -> World {
+    let mut world = World::new(WorldDesc::default());
+    create_static_box(&mut world, 0.0, 1.0, 40.0, 1.0);
+    world
+}
+
+fn drive_character(
+    world: &mut World,
+    controller: &CharacterController,
+    character: BodyHandle,
+    desired: Vector,
+    frames: usize,
+) -> Vec<CharacterMovement> {
+    (0..frames)
+        .map(|_| {
+            let movement = controller
+                .move_body(world, character, desired, DT, &fixed_step_config().ccd)
+                .expect("character move should succeed");
+            step_world(world, 1);
+            movement
+        })
+        .collect()
+}
+
+#[test]
+fn character_controller_walks_on_ground_and_stops_at_walls() {
+    let mut world = character_world();
+    let wall = create_static_box(&mut world, 3.0, -1.0, 1.0, 4.0);
+    let character = create_character(&mut world, 0.0);
+    let controller = CharacterController::default();
+
+    let moves = drive_character(
+        &mut world,
+        &controller,
+        character,
+        Vector::new(0.1, 0.05),
+        40,
+    );
+
+    assert!(moves.iter().all(|movement| movement.grounded));
+    let position = body_position(&world, character);
+    assert!(
+        (position.x() - (2.5 - 0.25 - controller.offset)).abs() < 0.02,
+        "the character stops just short of the wall: {position:?}"
+    );
+    assert!(
+        position.y().abs() < 0.02,
+        "the floor holds the character: {position:?}"
+    );
+    assert!(moves
+        .last()
+        .expect("moves were recorded")
+        .collisions
+        .iter()
+        .any(|collision| collision.collider == wall && collision.normal.x() < -0.9));
+}
+
+#[test]
+fn character_controller_climbs_walkable_slopes_but_not_steep_ones() {
+    let mut world = character_world();
+    // A 30 degree ramp rising from x = 1 and a 60 degree ramp rising from x = -1.
+    let gentle_run = 2.0;
+    let gentle_rise = gentle_run * (pi() / 6.0).tan();
+    let steep_rise = 2.0 * (pi() / 3.0).tan();
+    for vertices in [
+        vec![
+            Point::new(1.0, 0.5),
+            Point::new(1.0 + gentle_run, 0.5),
+            Point::new(1.0 + gentle_run, 0.5 - gentle_rise),
+        ],
+        vec![
+            Point::new(-1.0, 0.5),
+            Point::new(-3.0, 0.5 - steep_rise),
+            Point::new(-3.0, 0.5),
+        ],
+    ] {
+        let ramp = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
+        attach_shape(
+            &mut world,
+            ramp,
+            SharedShape::convex_polygon(vertices),
+            Material::default(),
+        );
+    }
+    let climber = create_character(&mut world, 0.5);
+    let blocked = create_character(&mut world, -0.5);
+    let controller = CharacterController {
+        max_step_height: 0.0,
+        ..CharacterController::default()
+    };
+
+    drive_character(
+        &mut world,
+        &controller,
+        climber,
+        Vector::new(0.05, 0.02),
+        40,
+    );
+    drive_character(
+        &mut world,
+        &controller,
+        blocked,
+        Vector::new(-0.05, 0.02),
+        40,
+    );
+
+    let climbed = body_position(&world, climber);
+    assert!(
+        climbed.x() > 1.5 && climbed.y() < -0.3,
+        "a 30 degree slope is walkable: {climbed:?}"
+    );
+    let stopped = body_position(&world, blocked);
+    assert!(
+        stopped.x() > -1.5 && stopped.y() > -0.1,
+        "a 60 degree slope acts as a wall: {stopped:?}"
+    );
+}
+
+#[test]
+fn character_controller_steps_up_ledges_and_snaps_down_drops() {
+    let mut world = character_world();
+    create_static_box(&mut world, 3.0, 0.4, 2.0, 0.2);
+    create_static_box(&mut world, 7.0, 0.0, 2.0, 2.0);
+    let character = create_character(&mut world, 0.0);
+    let controller = CharacterController::default();
+
+    let moves = drive_character(
+        &mut world,
+        &controller,
+        character,
+        Vector::new(0.08, 0.0),
+        90,
+    );
+
+    assert!(moves.iter().any(|movement| movement.stepped));
+    assert!(
+        moves.iter().any(|movement| movement.snapped),
+        "walking off the 0.2 ledge snaps back down"
+    );
+    assert!(moves.iter().all(|movement| movement.grounded));
+    let position = body_position(&world, character);
+    assert!(
+        position.x() < 6.0 - 0.25 && position.x() > 5.5,
+        "a full-height wall is not a step: {position:?}"
+    );
+    assert!(position.y().abs() < 0.02);
+
+    let mut world = character_world();
+    create_static_box(&mut world, 3.0, 0.4, 2.0, 0.2);
+    let character = create_character(&mut world, 0.0);
+    world
+        .apply_body_patch(
+            character,
+            BodyPatch {
+                pose: Some(Pose::from_xy_angle(3.5, -0.21, 0.0)),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("character should move");
+    let no_snap = CharacterController {
+        snap_to_ground: 0.0,
+        ..CharacterController::default()
+    };
+    let moves = drive_character(&mut world, &no_snap, character, Vector::new(0.2, 0.0), 5);
+    assert!(moves[0].grounded);
+    assert!(!moves.last().expect("moves were recorded").grounded);
+    assert!(body_position(&world, character).y() < -0.2);
+}
+
+#[test]
+fn character_controller_passes_up_through_one_way_platforms() {
+    let mut world = character_world();
+    let platform_body = create_body(&mut world, BodyType::Static, 0.0, -1.2, Vector::default());
+    let platform = world
+        .create_collider(
+            platform_body,
+            ColliderDesc {
+                shape: SharedShape::rect(4.0, 0.2),
+                filter: CollisionFilter {
+                    memberships: 0b10,
+                    collides_with: u64::MAX,
+                },
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("platform should be created");
+    let character = create_character(&mut world, 0.0);
+    let controller = CharacterController {
+        one_way_platforms: 0b10,
+        ..CharacterController::default()
+    };
+
+    drive_character(
+        &mut world,
+        &controller,
+        character,
+        Vector::new(0.0, -0.2),
+        12,
+    );
+    assert!(
+        body_position(&world, character).y() < -2.0,
+        "jumping up passes through the platform: {:?}",
+        body_position(&world, character)
+    );
+
+    let moves = drive_character(
+        &mut world,
+        &controller,
+        character,
+        Vector::new(0.0, 0.1),
+        20,
+    );
+    let landed = moves.last().expect("moves were recorded");
+    assert_eq!(landed.ground.map(|ground| ground.collider), Some(platform));
+    assert!((body_position(&world, character).y() + 1.8).abs() < 0.03);
+}
+
+#[test]
+fn character_controller_rides_moving_platforms_and_pushes_dynamic_bodies() {
+    let mut world = character_world();
+    let platform = create_body(
+        &mut world,
+        BodyType::Kinematic,
+        0.0,
+        -1.2,
+        Vector::default(),
+    );
+    attach_shape(
+        &mut world,
+        platform,
+        SharedShape::rect(4.0, 0.2),
+        Material::default(),
+    );
+    let rider = create_character(&mut world, 0.0);
+    world
+        .apply_body_patch(
+            rider,
+            BodyPatch {
+                pose: Some(Pose::from_xy_angle(0.0, -1.81, 0.0)),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("rider should move onto the platform");
+    let controller = CharacterController::default();
+
+    for frame in 1..=30 {
+        world
+            .set_kinematic_target(
+                platform,
+                Pose::from_xy_angle(frame as FloatNum * 0.02, -1.2, 0.0),
+            )
+            .expect("platform target should apply");
+        controller
+            .move_body(
+                &mut world,
+                rider,
+                Vector::new(0.0, 0.02),
+                DT,
+                &fixed_step_config().ccd,
+            )
+            .expect("rider move should succeed");
+        step_world(&mut world, 1);
+    }
+    let platform_x = body_position(&world, platform).x();
+    assert!(
+        (body_position(&world, rider).x() - platform_x).abs() < 0.05,
+        "the rider keeps up with the platform: {:?} vs {platform_x}",
+        body_position(&world, rider)
+    );
+
+    let mut world = character_world();
+    let crate_body = create_box(&mut world, 1.5, 0.0);
+    let character = create_character(&mut world, 0.0);
+    let moves = drive_character(
+        &mut world,
+        &controller,
+        character,
+        Vector::new(0.1, 0.02),
+        10,
+    );
+    assert!(moves
+        .iter()
+        .flat_map(|movement| &movement.collisions)
+        .any(|collision| collision.body == crate_body && collision.impulse.x() > 0.0));
+    assert!(body_position(&world, crate_body).x() > 1.6);
+
+    // A crate locked along the push sees the character's full mass, not the
+    // reduced mass of a free 1 kg crate (0.5 kg against a 1 kg character).
+    let first_push = |locked_axes: LockedAxes| {
+        let mut world = character_world();
+        let crate_body = create_box(&mut world, 1.5, 0.0);
+        world
+            .apply_body_patch(
+                crate_body,
+                BodyPatch {
+                    locked_axes: Some(locked_axes),
+                    ..BodyPatch::default()
+                },
+            )
+            .expect("locked axes should apply");
+        let character = create_character(&mut world, 0.0);
+        drive_character(
+            &mut world,
+            &controller,
+            character,
+            Vector::new(0.1, 0.02),
+            10,
+        )
+        .iter()
+        .flat_map(|movement| &movement.collisions)
+        .find(|collision| collision.body == crate_body)
+        .expect("the character reaches the crate")
+        .impulse
+    };
+    let free = first_push(LockedAxes::default());
+    let locked = first_push(LockedAxes {
+        translation_x: true,
+        ..LockedAxes::default()
+    });
+    assert!(
+        (locked.x() / free.x() - 2.0).abs() < 1e-3,
+        "{locked:?} vs {free:?}"
+    );
+
+    let mut world = character_world();
+    let crate_body = create_box(&mut world, 1.5, 0.0);
+    let character = create_character(&mut world, 0.0);
+    let immovable = CharacterController {
+        character_mass: 0.0,
+        ..CharacterController::default()
+    };
+    drive_character(
+        &mut world,
+        &immovable,
+        character,
+        Vector::new(0.1, 0.02),
+        10,
+    );
+    assert!((body_position(&world, crate_body).x() - 1.5).abs() < 1e-3);
+}
+
+#[test]
+fn character_controller_rejects_invalid_input() {
+    let mut world = character_world();
+    let dynamic = create_box(&mut world, 0.0, -2.0);
+    let character = create_character(&mut world, 3.0);
+
+    let error = CharacterController::default()
+        .move_body(
+            &mut world,
+            dynamic,
+            Vector::new(1.0, 0.0),
+            DT,
+            &fixed_step_config().ccd,
+        )
+        .expect_err("dynamic bodies are not characters");
+    assert!(matches!(
+        error,
+        WorldError::Handle(HandleError::WrongBodyType {
+            expected: BodyType::Kinematic,
+            ..
+        })
+    ));
+    let error = CharacterController {
+        offset: -1.0,
+        ..CharacterController::default()
+    }
+    .move_body(
+        &mut world,
+        character,
+        Vector::new(1.0, 0.0),
+        DT,
+        &fixed_step_config().ccd,
+    )
+    .expect_err("negative offsets are rejected");
+    assert_eq!(
+        error,
+        WorldError::Validation(ValidationError::CharacterController { field: "offset" })
+    );
+    let error = CharacterController::default()
+        .move_body(
+            &mut world,
+            character,
+            Vector::new(1.0, 0.0),
+            DT,
+            &CcdParams {
+                toi_epsilon: 0.0,
+                ..CcdParams::default()
+            },
+        )
+        .expect_err("sweeps need a positive time-of-impact epsilon");
+    assert_eq!(
+        error,
+        WorldError::Validation(ValidationError::StepConfig {
+            field: "ccd.toi_epsilon"
+        })
+    );
+}
+
+/// 2 x 0.5 chassis of mass 1 on two default wheels mounted at its bottom
+/// corners, above a floor whose top face is y = 0.5.
+fn create_vehicle(world: &mut World, x: FloatNum, y: FloatNum) -> VehicleController {
+    let chassis = create_body(world, BodyType::Dynamic, x, y, Vector::default());
+    attach_shape(
+        world,
+        chassis,
+        SharedShape::rect(2.0, 0.5),
+        Material::default(),
+    );
+    [-0.8, 0.8]
+        .into_iter()
+        .fold(VehicleController::new(chassis), |vehicle, anchor_x| {
+            vehicle.with_wheel(WheelDesc {
+                local_anchor: Point::new(anchor_x, 0.25),
+                ..WheelDesc::default()
+            })
+        })
+}
+
+fn drive_vehicle(world: &mut World, vehicle: &mut VehicleController, frames: usize) {
+    for _ in 0..frames {
+        vehicle
+            .update(world, DT)
+            .expect("vehicle update should succeed");
+        step_world(world, 1);
+    }
+}
+
+#[test]
+fn vehicle_suspension_holds_the_chassis_at_its_spring_equilibrium() {
+    let mut world = character_world();
+    let mut vehicle = create_vehicle(&mut world, 0.0, -0.4);
+    let wheel = WheelDesc::default();
+    let states = vehicle
+        .update(&mut world, DT)
+        .expect("vehicle update should succeed");
+    assert!(
+        states.iter().all(|state| state.contact.is_some()),
+        "wheels find ground created before the first step"
+    );
+
+    drive_vehicle(&mut world, &mut vehicle, 180);
+
+    // Each wheel carries half the chassis weight.
+    let compression = 0.5 * 9.8 / wheel.stiffness;
+    let expected_y = 0.5 - wheel.radius - (wheel.rest_length - compression) - 0.25;
+    let position = body_position(&world, vehicle.body());
+    assert!(
+        (position.y() - expected_y).abs() < 0.02,
+        "springs settle at {expected_y}: {position:?}"
+    );
+    assert!(body_velocity(&world, vehicle.body()).length() < 0.05);
+    for state in vehicle.wheel_states() {
+        assert!(state.contact.is_some());
+        assert!((state.suspension_length - (wheel.rest_length - compression)).abs() < 0.02);
+        assert!((state.suspension_impulse - 0.5 * 9.8 * DT).abs() < 0.01);
+        assert!((state.hub.y() - (0.5 - wheel.radius)).abs() < 1.0e-3);
+    }
+}
+
+#[test]
+fn vehicle_engine_torque_drives_and_brake_torque_stops_the_chassis() {
+    let mut world = character_world();
+    let mut vehicle = create_vehicle(&mut world, 0.0, -0.45);
+    drive_vehicle(&mut world, &mut vehicle, 30);
+
+    vehicle.engine_torque = 0.3;
+    drive_vehicle(&mut world, &mut vehicle, 120);
+    let velocity = body_velocity(&world, vehicle.body());
+    assert!(velocity.x() > 2.0, "the engine accelerates: {velocity:?}");
+    for (state, wheel) in vehicle.wheel_states().iter().zip(vehicle.wheels()) {
+        assert!(state.tire_impulse > 0.0);
+        assert!(
+            (state.spin * wheel.radius - velocity.x()).abs() < 0.1 * velocity.x(),
+            "gripping wheels roll with the chassis"
+        );
+        assert!(state.rotation > 0.0);
+    }
+
+    vehicle.engine_torque = 0.0;
+    vehicle.brake_torque = 1.0;
+    drive_vehicle(&mut world, &mut vehicle, 120);
+    assert!(body_velocity(&world, vehicle.body()).x().abs() < 0.05);
+    assert!(vehicle
+        .wheel_states()
+        .iter()
+        .all(|state| state.spin.abs() < 0.2));
+}
+
+#[test]
+fn vehicle_reports_airborne_wheels_and_rejects_invalid_setup() {
+    let mut world = character_world();
+    let mut vehicle = create_vehicle(&mut world, 0.0, -5.0);
+    vehicle.engine_torque = 0.3;
+    drive_vehicle(&mut world, &mut vehicle, 1);
+    for (state, wheel) in vehicle.wheel_states().iter().zip(vehicle.wheels()) {
+        assert_eq!(state.contact, None);
+        assert_eq!(state.suspension_length, wheel.rest_length);
+        assert_eq!(state.suspension_impulse, 0.0);
+        assert!(state.spin > 0.0, "free wheels still spin up");
+    }
+
+    let kinematic = create_body(&mut world, BodyType::Kinematic, 5.0, 0.0, Vector::default());
+    let error = VehicleController::new(kinematic)
+        .update(&mut world, DT)
+        .expect_err("a vehicle needs a dynamic chassis");
+    assert!(matches!(
+        error,
+        WorldError::Handle(HandleError::WrongBodyType {
+            expected: BodyType::Dynamic,
+            ..
+        })
+    ));
+    let error = VehicleController::new(vehicle.body())
+        .with_wheel(WheelDesc {
+            radius: 0.0,
+            ..WheelDesc::default()
+        })
+        .update(&mut world, DT)
+        .expect_err("wheels need a radius");
+    assert_eq!(
+        error,
+        WorldError::Validation(ValidationError::VehicleController {
+            field: "wheel.radius"
+        })
+    );
+
+    // Ground the chassis cannot collide with does not hold the wheels up.
+    let mut world = World::new(WorldDesc::default());
+    let debris = create_static_box(&mut world, 0.0, 1.0, 40.0, 1.0);
+    world
+        .apply_collider_patch(
+            debris,
+            ColliderPatch {
+                filter: Some(CollisionFilter {
+                    memberships: 0b10,
+                    collides_with: u64::MAX,
+                }),
+                ..ColliderPatch::default()
+            },
+        )
+        .expect("debris filter should apply");
+    let mut vehicle = create_vehicle(&mut world, 0.0, -0.4);
+    let chassis = world
+        .try_colliders_for_body(vehicle.body())
+        .expect("chassis should resolve")
+        .next()
+        .expect("chassis should have a collider");
+    world
+        .apply_collider_patch(
+            chassis,
+            ColliderPatch {
+                filter: Some(CollisionFilter {
+                    memberships: 0b01,
+                    collides_with: !0b10,
+                }),
+                ..ColliderPatch::default()
+            },
+        )
+        .expect("chassis filter should apply");
+    let states = vehicle
+        .update(&mut world, DT)
+        .expect("vehicle update should succeed");
+    assert!(states.iter().all(|state| state.contact.is_none()));
+}
+
+#[test]
+fn sleep_requires_a_stability_window_before_a_body_sleeps() {
+    // Physical behavior: sleeping should require sustained low motion over a stability window,
+    // so bodies do not sleep after one quiet frame and miss near-future wake interactions.
+    let mut world = no_gravity_world();
+    let body = create_body(&mut world, BodyType::Dynamic, 0.0, 0.0, Vector::default());
+    world
+        .apply_body_patch(
+            body,
+            BodyPatch {
+                can_sleep: Some(true),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("body patch should apply");
+
+    step_world(&mut world, 1);
+
+    assert!(
+        !world.try_body(body).expect("body should exist").sleeping(),
+        "a single quiet step should not be enough to put a dynamic body to sleep"
+    );
+
+    step_world(&mut world, 31);
+
+    assert!(
+        world.try_body(body).expect("body should exist").sleeping(),
+        "a body should sleep after remaining quiet for the stability window"
+    );
+}
+
+#[test]
+fn sleep_params_shorten_the_stability_window() {
+    // Physical behavior: the sleep stability window is a tuning knob, so a
+    // shorter window puts a quiet body to sleep in fewer steps.
+    let mut world = no_gravity_world();
+    let body = create_body(&mut world, BodyType::Dynamic, 0.0, 0.0, Vector::default());
+    world
+        .apply_body_patch(
+            body,
+            BodyPatch {
+                can_sleep: Some(true),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("body patch should apply");
+    let mut pipeline = SimulationPipeline::new(StepConfig {
+        sleep: SleepParams {
+            stability_seconds: 4.0 * DT,
+            ..SleepParams::default()
+        },
+        ..fixed_step_config()
+    });
+
+    for _ in 0..2 {
+        pipeline.step(&mut world);
+    }
+    assert!(!world.try_body(body).expect("body should exist").sleeping());
+
+    for _ in 0..3 {
+        pipeline.step(&mut world);
+    }
+    assert!(
+        world.try_body(body).expect("body should exist").sleeping(),
+        "a body should sleep once the configured stability window has passed"
+    );
+}
+
+#[test]
+fn jointed_island_sleeps_together_with_island_reason_facts() {
+    let mut world = no_gravity_world();
+    let left = world
+        .create_body(BodyDesc {
+            body_type: BodyType::Dynamic,
+            pose: Pose::from_xy_angle(-0.5, 0.0, 0.0),
+            can_sleep: true,
+            ..BodyDesc::default()
+        })
+        .expect("left body should be created");
+    let right = world
+        .create_body(BodyDesc {
+            body_type: BodyType::Dynamic,
+            pose: Pose::from_xy_angle(0.5, 0.0, 0.0),
+            can_sleep: true,
+            ..BodyDesc::default()
+        })
+        .expect("right body should be created");
+    world
+        .create_joint(JointDesc::Distance(DistanceJointDesc {
+            body_a: left,
+            body_b: right,
+            rest_length: 1.0,
+            ..DistanceJointDesc::default()
+        }))
+        .expect("resting distance joint should be created");
+
+    let mut pipeline = SimulationPipeline::new(fixed_step_config());
+    let mut reports = Vec::new();
+    for _ in 0..31 {
+        reports.push(pipeline.step(&mut world));
+    }
+    let sleep_events = reports
+        .iter()
+        .flat_map(|report| report.events.iter())
+        .filter_map(|event| match event {
+            WorldEvent::SleepChanged(event) => Some(event),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(sleep_events.len(), 2);
+    assert!(sleep_events.iter().all(|event| event.is_sleeping));
+    assert!(sleep_events
+        .iter()
+        .all(|event| event.reason == picea::events::SleepTransitionReason::StabilityWindow));
+    assert_eq!(sleep_events[0].island_id, sleep_events[1].island_id);
+
+    let snapshot = world.debug_snapshot(&DebugSnapshotOptions::default());
+    let left_debug = snapshot
+        .bodies
+        .iter()
+        .find(|body| body.handle == left)
+        .expect("left body should be in debug snapshot");
+    let right_debug = snapshot
+        .bodies
+        .iter()
+        .find(|body| body.handle == right)
+        .expect("right body should be in debug snapshot");
+    assert_eq!(left_debug.island_id, right_debug.island_id);
+    let island_id = left_debug
+        .island_id
+        .expect("dynamic bodies should have an island");
+    let island = snapshot
+        .islands
+        .iter()
+        .find(|island| island.id == island_id)
+        .expect("debug snapshot should expose the sleeping island");
+    assert!(island.sleeping);
+    assert_eq!(island.bodies, vec![left, right]);
+}
+
+#[test]
+fn transform_patch_wakes_the_touched_sleeping_island_only() {
+    let mut world = no_gravity_world();
+    let first = world
+        .create_body(BodyDesc {
+            body_type: BodyType::Dynamic,
+            pose: Pose::from_xy_angle(-2.0, 0.0, 0.0),
+            can_sleep: true,
+            ..BodyDesc::default()
+        })
+        .expect("first body should be created");
+    let partner = world
+        .create_body(BodyDesc {
+            body_type: BodyType::Dynamic,
+            pose: Pose::from_xy_angle(-1.0, 0.0, 0.0),
+            can_sleep: true,
+            ..BodyDesc::default()
+        })
+        .expect("partner body should be created");
+    let unrelated = world
+        .create_body(BodyDesc {
+            body_type: BodyType::Dynamic,
+            pose: Pose::from_xy_angle(2.0, 0.0, 0.0),
+            can_sleep: true,
+            ..BodyDesc::default()
+        })
+        .expect("unrelated body should be created");
+    world
+        .create_joint(JointDesc::Distance(DistanceJointDesc {
+            body_a: first,
+            body_b: partner,
+            rest_length: 1.0,
+            ..DistanceJointDesc::default()
+        }))
+        .expect("joint should connect the first island");
+
+    step_world(&mut world, 31);
+    assert!(world.try_body(first).expect("first exists").sleeping());
+    assert!(world.try_body(partner).expect("partner exists").sleeping());
+    assert!(world
+        .try_body(unrelated)
+        .expect("unrelated exists")
+        .sleeping());
+
+    world
+        .apply_body_patch(
+            first,
+            BodyPatch {
+                pose: Some(Pose::from_xy_angle(-1.5, 0.0, 0.0)),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("pose patch should apply");
+
+    let report = step_world(&mut world, 1);
+    let wake_events = report
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            WorldEvent::SleepChanged(event) if !event.is_sleeping => Some(event),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(wake_events.len(), 2);
+    assert!(wake_events.iter().any(|event| event.body == first));
+    assert!(wake_events.iter().any(|event| event.body == partner));
+    assert!(wake_events
+        .iter()
+        .all(|event| event.reason == picea::events::SleepTransitionReason::TransformEdit));
+    assert_eq!(wake_events[0].island_id, wake_events[1].island_id);
+    assert!(!world.try_body(first).expect("first exists").sleeping());
+    assert!(!world.try_body(partner).expect("partner exists").sleeping());
+    assert!(
+        world
+            .try_body(unrelated)
+            .expect("unrelated exists")
+            .sleeping(),
+        "an unrelated sleeping island should stay asleep"
+    );
+}
+
+#[test]
+fn per_body_sleep_thresholds_override_step_defaults() {
+    // Physical behavior: a slow gameplay object stays awake under the default
+    // thresholds, while debris with looser per-body thresholds settles.
+    let mut world = no_gravity_world();
+    let drifting = |thresholds| BodyDesc {
+        body_type: BodyType::Dynamic,
+        linear_velocity: Vector::new(0.01, 0.0),
+        can_sleep: true,
+        sleep_thresholds: thresholds,
+        ..BodyDesc::default()
+    };
+    let gameplay = world
+        .create_body(drifting(None))
+        .expect("gameplay body should be created");
+    let debris = world
+        .create_body(BodyDesc {
+            pose: Pose::from_xy_angle(0.0, 5.0, 0.0),
+            ..drifting(Some(SleepParams {
+                stability_seconds: 0.1,
+                linear_threshold: 0.05,
+                angular_threshold: 0.05,
+            }))
+        })
+        .expect("debris body should be created");
+
+    step_world(&mut world, 10);
+
+    assert!(!world
+        .try_body(gameplay)
+        .expect("gameplay exists")
+        .sleeping());
+    assert!(world.try_body(debris).expect("debris exists").sleeping());
+    assert_eq!(
+        world
+            .try_body(debris)
+            .expect("debris exists")
+            .sleep_thresholds()
+            .map(|thresholds| thresholds.linear_threshold),
+        Some(0.05)
+    );
+
+    world
+        .apply_body_patch(
+            gameplay,
+            BodyPatch {
+                sleep_thresholds: Some(Some(SleepParams {
+                    linear_threshold: 0.05,
+                    ..SleepParams::default()
+                })),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("threshold patch should apply");
+    step_world(&mut world, 31);
+    assert!(world
+        .try_body(gameplay)
+        .expect("gameplay exists")
+        .sleeping());
+
+    let invalid = world.apply_body_patch(
+        gameplay,
+        BodyPatch {
+            sleep_thresholds: Some(Some(SleepParams {
+                angular_threshold: FloatNum::NAN,
+                ..SleepParams::default()
+            })),
+            ..BodyPatch::default()
+        },
+    );
+    assert_eq!(
+        invalid,
+        Err(WorldError::Validation(ValidationError::BodyPatch {
+            field: "sleep_thresholds.angular_threshold"
+        }))
+    );
+}
+
+#[test]
+fn manual_island_sleep_and_wake_report_matching_reasons() {
+    let mut world = no_gravity_world();
+    let body_at = |world: &mut World, x: FloatNum| {
+        world
+            .create_body(BodyDesc {
+                body_type: BodyType::Dynamic,
+                pose: Pose::from_xy_angle(x, 0.0, 0.0),
+                linear_velocity: Vector::new(0.5, 0.0),
+                can_sleep: true,
+                ..BodyDesc::default()
+            })
+            .expect("body should be created")
+    };
+    let first = body_at(&mut world, -2.0);
+    let partner = body_at(&mut world, -1.0);
+    let unrelated = body_at(&mut world, 2.0);
+    world
+        .create_joint(JointDesc::Distance(DistanceJointDesc {
+            body_a: first,
+            body_b: partner,
+            rest_length: 1.0,
+            ..DistanceJointDesc::default()
+        }))
+        .expect("joint should connect the first island");
+    let mut pipeline = SimulationPipeline::new(fixed_step_config());
+    pipeline.step(&mut world);
+
+    let sleep_changes = |report: &StepReport| {
+        report
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                WorldEvent::SleepChanged(event) => Some(*event),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(world.sleep_island(partner), Ok(true));
+    assert!(world.try_body(first).expect("first exists").sleeping());
+    assert_eq!(body_velocity(&world, first), Vector::default());
+    let report = pipeline.step(&mut world);
+    let events = sleep_changes(&report);
+    assert_eq!(
+        events.iter().map(|event| event.body).collect::<Vec<_>>(),
+        vec![first, partner]
+    );
+    assert!(events.iter().all(|event| event.is_sleeping
+        && event.reason == picea::events::SleepTransitionReason::ManualSleep));
+    assert!(world.try_body(partner).expect("partner exists").sleeping());
+    assert!(!world
+        .try_body(unrelated)
+        .expect("unrelated exists")
+        .sleeping());
+
+    assert_eq!(world.wake_island(first), Ok(true));
+    assert_eq!(world.wake_island(first), Ok(false));
+    let report = pipeline.step(&mut world);
+    let events = sleep_changes(&report);
+    assert_eq!(
+        events.iter().map(|event| event.body).collect::<Vec<_>>(),
+        vec![first, partner]
+    );
+    assert!(events.iter().all(|event| !event.is_sleeping
+        && event.reason == picea::events::SleepTransitionReason::ManualWake));
+
+    world
+        .apply_body_patch(
+            unrelated,
+            BodyPatch {
+                can_sleep: Some(false),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("patch should apply");
+    assert_eq!(world.sleep_island(unrelated), Ok(false));
+    assert!(!world
+        .try_body(unrelated)
+        .expect("unrelated exists")
+        .sleeping());
+}
+
+#[test]
+fn static_contacts_do_not_bridge_dynamic_sleep_islands() {
+    let mut world = no_gravity_world();
+    let floor = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
+    let left = create_body(&mut world, BodyType::Dynamic, -2.0, 0.0, Vector::default());
+    let right = create_body(&mut world, BodyType::Dynamic, 2.0, 0.0, Vector::default());
+    attach_shape(
+        &mut world,
+        floor,
+        SharedShape::rect(8.0, 1.0),
+        Material::default(),
+    );
+    attach_shape(
         &mut world,
         left,
         SharedShape::rect(0.5, 0.5),
@@ -2323,20 +3958,255 @@ fn ccd_dynamic_convex_multi_hit_budget_selects_earliest_static_hit() {
     let bullet = create_body(
         &mut world,
         BodyType::Dynamic,
-        -1.0,
+        -1.0,
+        0.0,
+        Vector::new(200.0, 0.0),
+    );
+    let near_collider = attach_shape(
+        &mut world,
+        near_wall,
+        SharedShape::rect(0.1, 10.0),
+        Material::default(),
+    );
+    let far_collider = attach_shape(
+        &mut world,
+        far_wall,
+        SharedShape::rect(0.1, 10.0),
+        Material::default(),
+    );
+    attach_shape(
+        &mut world,
+        bullet,
+        SharedShape::rect(0.1, 0.1),
+        Material::default(),
+    );
+
+    let report = step_world(&mut world, 1);
+    let contact = active_contact_events(&report)
+        .into_iter()
+        .find(|contact| contact.ccd_trace.is_some())
+        .expect("CCD should emit the selected earliest hit");
+    let trace = contact.ccd_trace.expect("contact should carry CCD trace");
+
+    assert_eq!(report.stats.ccd_candidate_count, 2);
+    assert_eq!(report.stats.ccd_hit_count, 2);
+    assert_eq!(report.stats.ccd_miss_count, 0);
+    assert_eq!(report.stats.ccd_clamp_count, 1);
+    assert_eq!(trace.static_body, near_wall);
+    assert_eq!(trace.static_collider, near_collider);
+    assert_ne!(trace.static_collider, far_collider);
+    assert!(
+        body_position(&world, bullet).x() <= -0.09,
+        "CCD budget should clamp at the first static hit before reaching the farther wall"
+    );
+}
+
+#[test]
+fn ccd_dynamic_convex_pair_clamps_both_bodies_without_tunneling() {
+    let mut world = no_gravity_world();
+    let left = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        -1.0,
+        0.0,
+        Vector::new(200.0, 0.0),
+    );
+    let right = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        1.0,
+        0.0,
+        Vector::new(-200.0, 0.0),
+    );
+    let left_collider = attach_shape(
+        &mut world,
+        left,
+        SharedShape::rect(0.1, 0.1),
+        Material::default(),
+    );
+    let right_collider = attach_shape(
+        &mut world,
+        right,
+        SharedShape::rect(0.1, 0.1),
+        Material::default(),
+    );
+
+    let report = step_world(&mut world, 1);
+    let left_position = body_position(&world, left);
+    let right_position = body_position(&world, right);
+    let contact = active_contact_events(&report)
+        .into_iter()
+        .find(|contact| contact.ccd_trace.is_some())
+        .expect("dynamic-vs-dynamic CCD should emit the selected swept contact");
+    let trace = contact.ccd_trace.expect("contact should carry CCD trace");
+
+    assert_eq!(report.stats.ccd_candidate_count, 1);
+    assert_eq!(report.stats.ccd_hit_count, 1);
+    assert_eq!(report.stats.ccd_miss_count, 0);
+    assert_eq!(report.stats.ccd_clamp_count, 2);
+    assert_eq!(trace.moving_body, left);
+    assert_eq!(trace.static_body, right);
+    assert_eq!(trace.moving_collider, left_collider);
+    assert_eq!(trace.static_collider, right_collider);
+    assert_eq!(trace.target_kind, CcdTargetKind::Dynamic);
+    assert_eq!(trace.target_swept_start, Point::new(1.0, 0.0));
+    assert!(trace.target_swept_end.x() < -2.0);
+    assert!(trace.toi > 0.0 && trace.toi < 1.0);
+    assert!(trace.clamp > 0.0);
+    assert!(trace.target_clamp > 0.0);
+    assert!(
+        left_position.x() < right_position.x(),
+        "dynamic CCD should stop the bodies before they exchange sides; left_x={}, right_x={}",
+        left_position.x(),
+        right_position.x()
+    );
+}
+
+#[test]
+fn ccd_dynamic_convex_hits_stationary_dynamic_convex_target() {
+    let mut world = no_gravity_world();
+    let bullet = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        -1.0,
+        0.0,
+        Vector::new(200.0, 0.0),
+    );
+    let target = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        0.0,
+        0.0,
+        Vector::new(0.0, 0.0),
+    );
+    let bullet_collider = attach_shape(
+        &mut world,
+        bullet,
+        SharedShape::rect(0.1, 0.1),
+        Material::default(),
+    );
+    let target_collider = attach_shape(
+        &mut world,
+        target,
+        SharedShape::rect(0.1, 0.1),
+        Material::default(),
+    );
+
+    let report = step_world(&mut world, 1);
+    let bullet_position = body_position(&world, bullet);
+    let target_position = body_position(&world, target);
+    let contact = active_contact_events(&report)
+        .into_iter()
+        .find(|contact| contact.ccd_trace.is_some())
+        .expect("dynamic CCD should include stationary dynamic convex targets");
+    let trace = contact.ccd_trace.expect("contact should carry CCD trace");
+
+    assert_eq!(report.stats.ccd_candidate_count, 1);
+    assert_eq!(report.stats.ccd_hit_count, 1);
+    assert_eq!(report.stats.ccd_miss_count, 0);
+    assert_eq!(report.stats.ccd_clamp_count, 2);
+    assert_eq!(trace.moving_body, bullet);
+    assert_eq!(trace.static_body, target);
+    assert_eq!(trace.moving_collider, bullet_collider);
+    assert_eq!(trace.static_collider, target_collider);
+    assert_eq!(trace.target_kind, CcdTargetKind::Dynamic);
+    assert_eq!(trace.target_swept_start, Point::new(0.0, 0.0));
+    assert_eq!(trace.target_swept_end, Point::new(0.0, 0.0));
+    assert!(trace.toi > 0.0 && trace.toi < 1.0);
+    assert!(trace.clamp > 0.0);
+    assert_eq!(trace.target_clamp, 0.0);
+    assert!(
+        bullet_position.x() < target_position.x(),
+        "dynamic CCD should stop the bullet before it tunnels through a stationary dynamic target; bullet_x={}, target_x={}",
+        bullet_position.x(),
+        target_position.x()
+    );
+}
+
+#[test]
+fn ccd_dynamic_convex_pair_missed_sweep_does_not_false_positive_or_clamp() {
+    let mut world = no_gravity_world();
+    let diamond = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        0.0,
+        0.0,
+        Vector::new(0.1, 0.0),
+    );
+    let bullet = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        -0.9,
+        2.0,
+        Vector::new(0.0, -66.0),
+    );
+    attach_shape(
+        &mut world,
+        diamond,
+        SharedShape::convex_polygon(vec![
+            Point::new(0.0, -1.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(-1.0, 0.0),
+        ]),
+        Material::default(),
+    );
+    attach_shape(
+        &mut world,
+        bullet,
+        SharedShape::rect(0.1, 0.1),
+        Material::default(),
+    );
+
+    let report = step_world(&mut world, 1);
+    let bullet_position = body_position(&world, bullet);
+
+    assert_eq!(report.stats.contact_count, 0);
+    assert_eq!(report.stats.ccd_candidate_count, 1);
+    assert_eq!(report.stats.ccd_hit_count, 0);
+    assert_eq!(report.stats.ccd_miss_count, 1);
+    assert_eq!(report.stats.ccd_clamp_count, 0);
+    assert!(
+        (bullet_position.y() - 0.9).abs() < 1.0e-4,
+        "missed dynamic sweep should keep the integrated end pose; y={}",
+        bullet_position.y()
+    );
+}
+
+#[test]
+fn ccd_dynamic_convex_multi_hit_budget_selects_earliest_dynamic_hit() {
+    let mut world = no_gravity_world();
+    let bullet = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        -1.0,
+        0.0,
+        Vector::new(200.0, 0.0),
+    );
+    let near = create_body(
+        &mut world,
+        BodyType::Dynamic,
         0.0,
-        Vector::new(200.0, 0.0),
+        0.0,
+        Vector::new(0.1, 0.0),
+    );
+    let far = create_body(
+        &mut world,
+        BodyType::Dynamic,
+        0.8,
+        0.0,
+        Vector::new(0.1, 0.0),
     );
     let near_collider = attach_shape(
         &mut world,
-        near_wall,
-        SharedShape::rect(0.1, 10.0),
+        near,
+        SharedShape::rect(0.1, 0.1),
         Material::default(),
     );
     let far_collider = attach_shape(
         &mut world,
-        far_wall,
-        SharedShape::rect(0.1, 10.0),
+        far,
+        SharedShape::rect(0.1, 0.1),
         Material::default(),
     );
     attach_shape(
@@ -2350,303 +4220,824 @@ fn ccd_dynamic_convex_multi_hit_budget_selects_earliest_static_hit() {
     let contact = active_contact_events(&report)
         .into_iter()
         .find(|contact| contact.ccd_trace.is_some())
-        .expect("CCD should emit the selected earliest hit");
+        .expect("CCD should emit the selected earliest dynamic hit");
     let trace = contact.ccd_trace.expect("contact should carry CCD trace");
 
     assert_eq!(report.stats.ccd_candidate_count, 2);
     assert_eq!(report.stats.ccd_hit_count, 2);
     assert_eq!(report.stats.ccd_miss_count, 0);
-    assert_eq!(report.stats.ccd_clamp_count, 1);
-    assert_eq!(trace.static_body, near_wall);
+    assert_eq!(report.stats.ccd_clamp_count, 2);
+    assert_eq!(trace.static_body, near);
     assert_eq!(trace.static_collider, near_collider);
     assert_ne!(trace.static_collider, far_collider);
+    assert_eq!(trace.target_kind, CcdTargetKind::Dynamic);
+    assert_eq!(trace.target_swept_start, Point::new(0.0, 0.0));
+    assert!(trace.target_swept_end.x() > trace.target_swept_start.x());
+    assert!(trace.target_clamp > 0.0);
     assert!(
-        body_position(&world, bullet).x() <= -0.09,
-        "CCD budget should clamp at the first static hit before reaching the farther wall"
+        body_position(&world, bullet).x() < body_position(&world, far).x(),
+        "CCD budget should select the nearer dynamic target before the farther hit"
     );
 }
 
 #[test]
-fn ccd_dynamic_convex_pair_clamps_both_bodies_without_tunneling() {
+fn ccd_rotating_convex_pair_uses_conservative_advancement() {
+    let spinning_body = |world: &mut World, x: FloatNum, speed: FloatNum, spin: FloatNum| {
+        world
+            .create_body(BodyDesc {
+                body_type: BodyType::Dynamic,
+                pose: Pose::from_xy_angle(x, 0.0, 0.0),
+                linear_velocity: Vector::new(speed, 0.0),
+                angular_velocity: spin,
+                can_sleep: false,
+                ..BodyDesc::default()
+            })
+            .expect("rotating body should be created")
+    };
     let mut world = no_gravity_world();
-    let left = create_body(
-        &mut world,
-        BodyType::Dynamic,
-        -1.0,
-        0.0,
-        Vector::new(200.0, 0.0),
-    );
-    let right = create_body(
-        &mut world,
-        BodyType::Dynamic,
-        1.0,
-        0.0,
-        Vector::new(-200.0, 0.0),
-    );
-    let left_collider = attach_shape(
+    let rotating = spinning_body(&mut world, -1.0, 200.0, 1.0);
+    let target = spinning_body(&mut world, 1.0, -200.0, -2.0);
+    attach_shape(
         &mut world,
-        left,
+        rotating,
         SharedShape::rect(0.1, 0.1),
         Material::default(),
     );
-    let right_collider = attach_shape(
+    attach_shape(
         &mut world,
-        right,
+        target,
         SharedShape::rect(0.1, 0.1),
         Material::default(),
     );
 
     let report = step_world(&mut world, 1);
-    let left_position = body_position(&world, left);
-    let right_position = body_position(&world, right);
-    let contact = active_contact_events(&report)
+    let trace = active_contact_events(&report)
         .into_iter()
-        .find(|contact| contact.ccd_trace.is_some())
-        .expect("dynamic-vs-dynamic CCD should emit the selected swept contact");
-    let trace = contact.ccd_trace.expect("contact should carry CCD trace");
+        .find_map(|contact| contact.ccd_trace)
+        .expect("rotating pair should emit a CCD contact");
 
     assert_eq!(report.stats.ccd_candidate_count, 1);
     assert_eq!(report.stats.ccd_hit_count, 1);
-    assert_eq!(report.stats.ccd_miss_count, 0);
     assert_eq!(report.stats.ccd_clamp_count, 2);
-    assert_eq!(trace.moving_body, left);
-    assert_eq!(trace.static_body, right);
-    assert_eq!(trace.moving_collider, left_collider);
-    assert_eq!(trace.static_collider, right_collider);
+    assert_eq!(trace.method, CcdMethod::ConservativeAdvancement);
     assert_eq!(trace.target_kind, CcdTargetKind::Dynamic);
-    assert_eq!(trace.target_swept_start, Point::new(1.0, 0.0));
-    assert!(trace.target_swept_end.x() < -2.0);
+    assert_eq!(trace.moving_body, rotating);
+    assert!((trace.angular_sweep - DT).abs() < 1.0e-5);
+    assert!((trace.target_angular_sweep + 2.0 * DT).abs() < 1.0e-5);
     assert!(trace.toi > 0.0 && trace.toi < 1.0);
-    assert!(trace.clamp > 0.0);
-    assert!(trace.target_clamp > 0.0);
+    assert!(trace.clamp > 0.0 && trace.target_clamp > 0.0);
     assert!(
-        left_position.x() < right_position.x(),
-        "dynamic CCD should stop the bodies before they exchange sides; left_x={}, right_x={}",
-        left_position.x(),
-        right_position.x()
+        body_position(&world, rotating).x() < body_position(&world, target).x(),
+        "rotating CCD should stop the bodies before they exchange sides"
     );
 }
 
 #[test]
-fn ccd_dynamic_convex_hits_stationary_dynamic_convex_target() {
+fn ccd_spinning_plank_and_segment_do_not_tunnel_through_thin_wall() {
+    for shape in [
+        SharedShape::rect(1.0, 0.05),
+        SharedShape::segment(Point::new(-0.5, 0.0), Point::new(0.5, 0.0)),
+    ] {
+        let mut world = no_gravity_world();
+        let wall = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
+        attach_shape(
+            &mut world,
+            wall,
+            SharedShape::rect(0.05, 10.0),
+            Material::default(),
+        );
+        let plank = world
+            .create_body(BodyDesc {
+                pose: Pose::from_xy_angle(-1.5, 0.0, 0.0),
+                linear_velocity: Vector::new(200.0, 0.0),
+                angular_velocity: 30.0,
+                can_sleep: false,
+                ..BodyDesc::default()
+            })
+            .expect("plank should be created");
+        attach_shape(&mut world, plank, shape.clone(), Material::default());
+
+        let report = step_world(&mut world, 1);
+        let trace = active_contact_events(&report)
+            .into_iter()
+            .find_map(|contact| contact.ccd_trace)
+            .expect("spinning plank should emit a CCD contact");
+        let pose = world.try_body(plank).expect("plank exists").pose();
+
+        assert_eq!(report.stats.ccd_hit_count, 1, "{shape:?}");
+        assert_eq!(trace.method, CcdMethod::ConservativeAdvancement);
+        assert_eq!(trace.target_kind, CcdTargetKind::Static);
+        assert_eq!(trace.static_body, wall);
+        assert!((trace.angular_sweep - 30.0 * DT).abs() < 1.0e-4);
+        assert!(trace.toi > 0.0 && trace.toi < 1.0);
+        assert!(
+            trace.toi_point.x().abs() < 0.05,
+            "impact point should sit on the wall face; {shape:?} {trace:?}"
+        );
+        assert!(
+            pose.translation().x() < 0.0,
+            "the plank should stay on its side of the wall; {shape:?} {pose:?}"
+        );
+        assert!(pose.angle() > 0.0 && pose.angle() < 30.0 * DT);
+    }
+}
+
+fn substep_ccd_config(max_toi_substeps: u32, max_body_toi_substeps: u32) -> StepConfig {
+    StepConfig {
+        ccd: CcdParams {
+            resolution: CcdResolution::SubStep,
+            max_toi_substeps,
+            max_body_toi_substeps,
+            ..CcdParams::default()
+        },
+        ..fixed_step_config()
+    }
+}
+
+#[test]
+fn ccd_substep_resolution_keeps_motion_after_impact() {
+    // Pose clamping drops the rest of the step; sub-stepping resolves the hit
+    // and spends the remaining time sliding along or bouncing off the wall.
+    for restitution in [0.0, 1.0] {
+        let mut world = no_gravity_world();
+        let wall = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
+        attach_shape(
+            &mut world,
+            wall,
+            SharedShape::rect(0.1, 10.0),
+            Material::default(),
+        );
+        let ball = create_body(
+            &mut world,
+            BodyType::Dynamic,
+            -1.0,
+            0.0,
+            Vector::new(200.0, 30.0),
+        );
+        attach_shape(
+            &mut world,
+            ball,
+            SharedShape::circle(0.05),
+            Material {
+                restitution,
+                friction: 0.0,
+            },
+        );
+
+        let report = step_world_with_config(&mut world, substep_ccd_config(8, 2), 1);
+        let trace = active_contact_events(&report)
+            .into_iter()
+            .find_map(|contact| contact.ccd_trace);
+        let position = body_position(&world, ball);
+        let velocity = world.try_body(ball).expect("ball exists").linear_velocity();
+
+        assert_eq!(report.stats.ccd_substep_count, 1, "e={restitution}");
+        assert_eq!(report.stats.ccd_substep_fallback_count, 0);
+        assert_eq!(report.stats.ccd_clamp_count, 0);
+        let toi = (0.9 / (200.0 * DT)) as FloatNum;
+        let remaining = (1.0 - toi) * DT;
+        assert!(
+            (position.y() - 30.0 * DT).abs() < 1.0e-3,
+            "tangential motion should last the whole step; e={restitution} {position:?}"
+        );
+        if restitution == 0.0 {
+            let trace = trace.expect("sliding ball should keep its CCD contact");
+            assert_eq!(trace.resolution, CcdResolution::SubStep);
+            assert!((trace.toi - toi).abs() < 1.0e-3, "{trace:?}");
+            assert!((trace.toi_point.x() + 0.05).abs() < 1.0e-3);
+            assert!(velocity.x().abs() < 1.0e-3, "{velocity:?}");
+            assert!(
+                position.x() >= -0.1 && position.x() < -0.095,
+                "the ball should rest against the wall face; {position:?}"
+            );
+        } else {
+            assert!((velocity.x() + 200.0).abs() < 0.1, "{velocity:?}");
+            assert!(
+                (position.x() - (-0.1 - 200.0 * remaining)).abs() < 5.0e-3,
+                "the ball should bounce for the rest of the step; {position:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn ccd_substep_budgets_fall_back_to_pose_clamping() {
+    // Three balls reach one wall in the same step; a single-sub-step budget
+    // resolves the earliest hit and pose-clamps the others.
     let mut world = no_gravity_world();
-    let bullet = create_body(
+    let wall = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
+    attach_shape(
         &mut world,
-        BodyType::Dynamic,
-        -1.0,
-        0.0,
-        Vector::new(200.0, 0.0),
+        wall,
+        SharedShape::rect(0.1, 10.0),
+        Material::default(),
     );
-    let target = create_body(
+    let balls = [(-1.0, 200.0), (-1.2, 200.0), (-1.4, 200.0)]
+        .into_iter()
+        .enumerate()
+        .map(|(index, (x, speed))| {
+            let ball = create_body(
+                &mut world,
+                BodyType::Dynamic,
+                x,
+                index as FloatNum - 1.0,
+                Vector::new(speed, 0.0),
+            );
+            attach_shape(
+                &mut world,
+                ball,
+                SharedShape::circle(0.05),
+                Material::default(),
+            );
+            ball
+        })
+        .collect::<Vec<_>>();
+
+    let report = step_world_with_config(&mut world, substep_ccd_config(1, 2), 1);
+    assert_eq!(report.stats.ccd_substep_count, 1);
+    assert_eq!(
+        (report.stats.ccd_candidate_count, report.stats.ccd_hit_count),
+        (3, 3),
+        "the re-sweep after the sub-step must not count the pairs again"
+    );
+    assert_eq!(report.stats.ccd_miss_count, 0);
+    assert_eq!(report.stats.ccd_substep_fallback_count, 2);
+    assert_eq!(report.stats.ccd_clamp_count, 2);
+    for ball in &balls {
+        assert!(body_position(&world, *ball).x() <= -0.05);
+    }
+
+    // A ball bouncing between two walls spends its per-body budget on the
+    // first wall; the second wall pose-clamps it instead.
+    let mut world = no_gravity_world();
+    for x in [-0.5, 0.5] {
+        let wall = create_body(&mut world, BodyType::Static, x, 0.0, Vector::default());
+        attach_shape(
+            &mut world,
+            wall,
+            SharedShape::rect(0.1, 10.0),
+            Material::default(),
+        );
+    }
+    let ball = create_body(
         &mut world,
         BodyType::Dynamic,
         0.0,
         0.0,
-        Vector::new(0.0, 0.0),
+        Vector::new(200.0, 0.0),
     );
-    let bullet_collider = attach_shape(
+    attach_shape(
         &mut world,
-        bullet,
-        SharedShape::rect(0.1, 0.1),
+        ball,
+        SharedShape::circle(0.05),
+        Material {
+            restitution: 1.0,
+            friction: 0.0,
+        },
+    );
+
+    let report = step_world_with_config(&mut world, substep_ccd_config(8, 1), 1);
+    let position = body_position(&world, ball);
+    assert_eq!(report.stats.ccd_substep_count, 1);
+    assert_eq!(report.stats.ccd_substep_fallback_count, 1);
+    assert_eq!(report.stats.ccd_clamp_count, 1);
+    assert!(
+        position.x() > -0.45 && position.x() < 0.0,
+        "the ball should stop at the second wall; {position:?}"
+    );
+}
+
+fn speculative_ccd_config(mode: CcdMode) -> StepConfig {
+    StepConfig {
+        ccd: CcdParams {
+            mode,
+            ..CcdParams::default()
+        },
+        ..fixed_step_config()
+    }
+}
+
+fn ball_and_wall_world(x: FloatNum, velocity: Vector) -> (World, BodyHandle) {
+    let mut world = no_gravity_world();
+    let wall = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
+    attach_shape(
+        &mut world,
+        wall,
+        SharedShape::rect(0.1, 10.0),
         Material::default(),
     );
-    let target_collider = attach_shape(
+    let ball = create_body(&mut world, BodyType::Dynamic, x, 0.0, velocity);
+    attach_shape(
         &mut world,
-        target,
-        SharedShape::rect(0.1, 0.1),
+        ball,
+        SharedShape::circle(0.05),
+        Material::default(),
+    );
+    (world, ball)
+}
+
+#[test]
+fn speculative_contacts_stop_an_approaching_bullet() {
+    for mode in [CcdMode::Speculative, CcdMode::SweepAndSpeculative] {
+        // The bullet covers 3.3 units per step, so after the first step its
+        // gap to the wall is inside the speculative margin.
+        let (mut world, ball) = ball_and_wall_world(-5.0, Vector::new(200.0, 0.0));
+        let mut pipeline = SimulationPipeline::new(speculative_ccd_config(mode));
+
+        let first = pipeline.step(&mut world);
+        let velocity = world.try_body(ball).expect("ball exists").linear_velocity();
+        assert_eq!(first.stats.ccd_candidate_count, 0, "{mode:?}");
+        assert_eq!(first.stats.ccd_speculative_contact_count, 1);
+        assert_eq!(first.stats.ccd_speculative_enforced_count, 1);
+        assert_eq!(first.stats.contact_count, 0);
+        assert!(active_contact_events(&first).is_empty());
+        let gap = 5.0 - 200.0 * DT - 0.1;
+        let slop = StepConfig::default().solver.contact_slop;
+        assert!(
+            (velocity.x() - (gap + slop) / DT).abs() < 0.1,
+            "the bullet should slow to close its gap plus the contact slop; {velocity:?}"
+        );
+
+        for _ in 0..4 {
+            pipeline.step(&mut world);
+        }
+        let position = body_position(&world, ball);
+        assert!(
+            position.x() <= -0.05 && position.x() > -0.2,
+            "the bullet should come to rest against the wall; {mode:?} {position:?}"
+        );
+    }
+
+    // Without sweeps a bullet that starts within one step of the wall still
+    // tunnels; running both strategies catches it.
+    let (mut world, ball) = ball_and_wall_world(-1.0, Vector::new(200.0, 0.0));
+    step_world_with_config(&mut world, speculative_ccd_config(CcdMode::Speculative), 1);
+    assert!(body_position(&world, ball).x() > 0.05);
+    let (mut world, ball) = ball_and_wall_world(-1.0, Vector::new(200.0, 0.0));
+    let report = step_world_with_config(
+        &mut world,
+        speculative_ccd_config(CcdMode::SweepAndSpeculative),
+        1,
+    );
+    assert_eq!(report.stats.ccd_clamp_count, 1);
+    assert!(body_position(&world, ball).x() <= -0.05);
+}
+
+#[test]
+fn speculative_contacts_only_push_when_the_gap_would_close() {
+    // A ball sliding past the wall is within its speculative margin but never
+    // approaches it, so the row exists without applying any impulse.
+    let (mut world, ball) = ball_and_wall_world(-0.2, Vector::new(0.0, 60.0));
+
+    let report =
+        step_world_with_config(&mut world, speculative_ccd_config(CcdMode::Speculative), 1);
+    let body = world.try_body(ball).expect("ball exists");
+
+    assert_eq!(report.stats.ccd_speculative_contact_count, 1);
+    assert_eq!(report.stats.ccd_speculative_enforced_count, 0);
+    assert_eq!(report.stats.contact_count, 0);
+    assert!(report.events.iter().all(|event| !matches!(
+        event,
+        WorldEvent::ContactStarted(_) | WorldEvent::ContactPersisted(_)
+    )));
+    assert_eq!(body.linear_velocity(), Vector::new(0.0, 60.0));
+    assert!((body_position(&world, ball).x() + 0.2).abs() < 1.0e-6);
+}
+
+fn box_stack_world(height: usize, top_density: FloatNum) -> (World, Vec<BodyHandle>) {
+    let mut world = World::new(WorldDesc {
+        enable_sleep: false,
+        ..WorldDesc::default()
+    });
+    let ground = create_body(&mut world, BodyType::Static, 0.0, 6.0, Vector::default());
+    attach_shape(
+        &mut world,
+        ground,
+        SharedShape::rect(12.0, 1.0),
         Material::default(),
     );
+    let boxes = (0..height)
+        .map(|level| {
+            let body = create_body(
+                &mut world,
+                BodyType::Dynamic,
+                0.0,
+                5.0 - level as FloatNum * 1.05,
+                Vector::default(),
+            );
+            let density = if level + 1 == height {
+                top_density
+            } else {
+                1.0
+            };
+            attach_shape_with_density(
+                &mut world,
+                body,
+                SharedShape::rect(1.0, 1.0),
+                density,
+                Material::default(),
+            );
+            body
+        })
+        .collect::<Vec<_>>();
+    (world, boxes)
+}
+
+/// Steps a box stack and returns the fastest body speed seen over the last
+/// half of the run.
+fn settle_box_stack(world: &mut World, boxes: &[BodyHandle], config: StepConfig) -> FloatNum {
+    let steps = 600;
+    let mut pipeline = SimulationPipeline::new(config);
+    let mut max_late_speed: FloatNum = 0.0;
+    for step in 0..steps {
+        pipeline.step(world);
+        if step >= steps / 2 {
+            for body in boxes {
+                max_late_speed = max_late_speed.max(body_velocity(world, *body).length());
+            }
+        }
+    }
+    max_late_speed
+}
+
+fn assert_box_stack_upright(world: &World, boxes: &[BodyHandle], height_tolerance: FloatNum) {
+    for (level, body) in boxes.iter().enumerate() {
+        let view = world.try_body(*body).expect("stack body should exist");
+        let position = view.pose().translation();
+        let rest_y = 5.0 - level as FloatNum;
+        assert!(
+            position.x().abs() < 0.25 && view.pose().angle().abs() < 0.05,
+            "stack level {level} should stay upright; pose={:?}",
+            view.pose()
+        );
+        assert!(
+            (position.y() - rest_y).abs() < height_tolerance,
+            "stack level {level} should rest near y={rest_y}; got {position:?}"
+        );
+    }
+}
+
+#[test]
+fn soft_step_solver_keeps_tall_box_stack_upright_and_still() {
+    let (mut world, boxes) = box_stack_world(10, 1.0);
+
+    let max_late_speed = settle_box_stack(
+        &mut world,
+        &boxes,
+        StepConfig {
+            solver_mode: SolverMode::SoftStep { substeps: 4 },
+            ..fixed_step_config()
+        },
+    );
 
-    let report = step_world(&mut world, 1);
-    let bullet_position = body_position(&world, bullet);
-    let target_position = body_position(&world, target);
-    let contact = active_contact_events(&report)
-        .into_iter()
-        .find(|contact| contact.ccd_trace.is_some())
-        .expect("dynamic CCD should include stationary dynamic convex targets");
-    let trace = contact.ccd_trace.expect("contact should carry CCD trace");
+    assert_box_stack_upright(&world, &boxes, 0.15);
+    assert!(
+        max_late_speed < 0.05,
+        "settled soft-step stack should not jitter; max speed={max_late_speed}"
+    );
+}
 
-    assert_eq!(report.stats.ccd_candidate_count, 1);
-    assert_eq!(report.stats.ccd_hit_count, 1);
-    assert_eq!(report.stats.ccd_miss_count, 0);
-    assert_eq!(report.stats.ccd_clamp_count, 2);
-    assert_eq!(trace.moving_body, bullet);
-    assert_eq!(trace.static_body, target);
-    assert_eq!(trace.moving_collider, bullet_collider);
-    assert_eq!(trace.static_collider, target_collider);
-    assert_eq!(trace.target_kind, CcdTargetKind::Dynamic);
-    assert_eq!(trace.target_swept_start, Point::new(0.0, 0.0));
-    assert_eq!(trace.target_swept_end, Point::new(0.0, 0.0));
-    assert!(trace.toi > 0.0 && trace.toi < 1.0);
-    assert!(trace.clamp > 0.0);
-    assert_eq!(trace.target_clamp, 0.0);
+#[test]
+fn soft_step_solver_supports_heavy_box_on_light_stack() {
+    let (mut world, boxes) = box_stack_world(3, 30.0);
+
+    let max_late_speed = settle_box_stack(
+        &mut world,
+        &boxes,
+        StepConfig {
+            solver_mode: SolverMode::SoftStep { substeps: 8 },
+            ..fixed_step_config()
+        },
+    );
+
+    assert_box_stack_upright(&world, &boxes, 0.1);
     assert!(
-        bullet_position.x() < target_position.x(),
-        "dynamic CCD should stop the bullet before it tunnels through a stationary dynamic target; bullet_x={}, target_x={}",
-        bullet_position.x(),
-        target_position.x()
+        max_late_speed < 0.05,
+        "30:1 mass ratio stack should settle; max speed={max_late_speed}"
     );
 }
 
 #[test]
-fn ccd_dynamic_convex_pair_missed_sweep_does_not_false_positive_or_clamp() {
+fn soft_step_solver_keeps_restitution_and_resting_contact_semantics() {
     let mut world = no_gravity_world();
-    let diamond = create_body(
+    let floor = create_body(&mut world, BodyType::Static, 0.0, 0.5, Vector::default());
+    let ball = create_body(
         &mut world,
         BodyType::Dynamic,
         0.0,
-        0.0,
-        Vector::new(0.1, 0.0),
+        -2.0,
+        Vector::new(0.0, 6.0),
     );
-    let bullet = create_body(
-        &mut world,
-        BodyType::Dynamic,
-        -0.9,
-        2.0,
-        Vector::new(0.0, -66.0),
+    let bouncy = Material {
+        restitution: 1.0,
+        friction: 0.0,
+    };
+    attach_shape(&mut world, floor, SharedShape::rect(10.0, 1.0), bouncy);
+    attach_shape(&mut world, ball, SharedShape::circle(0.5), bouncy);
+    let config = StepConfig {
+        solver_mode: SolverMode::SoftStep { substeps: 4 },
+        ..fixed_step_config()
+    };
+
+    step_world_with_config(&mut world, config, 30);
+    let velocity = body_velocity(&world, ball);
+    assert!(
+        velocity.y() < -3.0,
+        "elastic impact should reverse vertical velocity; got {velocity:?}"
     );
-    attach_shape(
-        &mut world,
-        diamond,
-        SharedShape::convex_polygon(vec![
-            Point::new(0.0, -1.0),
-            Point::new(1.0, 0.0),
-            Point::new(0.0, 1.0),
-            Point::new(-1.0, 0.0),
-        ]),
-        Material::default(),
+
+    let (mut resting, boxes) = box_stack_world(1, 1.0);
+    let report = step_world_with_config(&mut resting, config, 120);
+    let contacts = active_contact_events(&report);
+    assert_eq!(contacts.len(), 2, "box should rest on a two-point manifold");
+    assert!(contacts
+        .iter()
+        .all(|contact| contact.solver_normal_impulse > 0.0
+            && contact.warm_start_reason == WarmStartCacheReason::Hit));
+    let penetration = body_position(&resting, boxes[0]).y() + 0.5 - 5.5;
+    assert!(
+        (0.0..0.02).contains(&penetration),
+        "resting box should settle just past the contact slop; penetration={penetration}"
     );
+}
+
+#[test]
+fn kinematic_target_derives_velocity_and_carries_resting_box_through_friction() {
+    let mut world = World::new(WorldDesc::default());
+    let platform = create_body(&mut world, BodyType::Kinematic, 0.0, 0.5, Vector::default());
+    let crate_body = create_body(&mut world, BodyType::Dynamic, 0.0, 0.15, Vector::default());
+    let material = Material {
+        friction: 1.0,
+        restitution: 0.0,
+    };
+    attach_shape(&mut world, platform, SharedShape::rect(6.0, 0.2), material);
     attach_shape(
         &mut world,
-        bullet,
-        SharedShape::rect(0.1, 0.1),
-        Material::default(),
+        crate_body,
+        SharedShape::rect(0.5, 0.5),
+        material,
     );
 
-    let report = step_world(&mut world, 1);
-    let bullet_position = body_position(&world, bullet);
+    let mut pipeline = SimulationPipeline::new(fixed_step_config());
+    let speed = 1.0;
+    for step in 1..=60 {
+        let target = Pose::from_xy_angle(speed * DT * step as FloatNum, 0.5, 0.0);
+        world
+            .set_kinematic_target(platform, target)
+            .expect("kinematic platform accepts targets");
+        pipeline.step(&mut world);
+        assert_eq!(
+            world.try_body(platform).expect("platform exists").pose(),
+            target
+        );
+    }
 
-    assert_eq!(report.stats.contact_count, 0);
-    assert_eq!(report.stats.ccd_candidate_count, 1);
-    assert_eq!(report.stats.ccd_hit_count, 0);
-    assert_eq!(report.stats.ccd_miss_count, 1);
-    assert_eq!(report.stats.ccd_clamp_count, 0);
+    let platform_velocity = body_velocity(&world, platform);
+    assert!((platform_velocity.x() - speed).abs() < 1.0e-3);
+    assert!(platform_velocity.y().abs() < 1.0e-3);
+    let crate_x = body_position(&world, crate_body).x();
     assert!(
-        (bullet_position.y() - 0.9).abs() < 1.0e-4,
-        "missed dynamic sweep should keep the integrated end pose; y={}",
-        bullet_position.y()
+        crate_x > 0.8,
+        "friction against the driven platform should carry the crate; crate_x={crate_x}"
     );
+
+    // Once the target is reached the platform holds it with zero velocity.
+    pipeline.step(&mut world);
+    assert_eq!(body_velocity(&world, platform), Vector::default());
 }
 
 #[test]
-fn ccd_dynamic_convex_multi_hit_budget_selects_earliest_dynamic_hit() {
+fn set_kinematic_target_rejects_non_kinematic_bodies_and_non_finite_poses() {
     let mut world = no_gravity_world();
-    let bullet = create_body(
+    let dynamic = create_body(&mut world, BodyType::Dynamic, 0.0, 0.0, Vector::default());
+    let kinematic = create_body(&mut world, BodyType::Kinematic, 0.0, 0.0, Vector::default());
+
+    assert!(matches!(
+        world.set_kinematic_target(dynamic, Pose::from_xy_angle(1.0, 0.0, 0.0)),
+        Err(WorldError::Handle(HandleError::WrongBodyType {
+            expected: BodyType::Kinematic,
+            actual: BodyType::Dynamic,
+            ..
+        }))
+    ));
+    assert!(matches!(
+        world.set_kinematic_target(kinematic, Pose::from_xy_angle(FloatNum::NAN, 0.0, 0.0)),
+        Err(WorldError::Validation(ValidationError::BodyPatch {
+            field: "kinematic_target.translation.x"
+        }))
+    ));
+
+    world
+        .set_kinematic_target(kinematic, Pose::from_xy_angle(1.0, 0.0, 0.0))
+        .expect("kinematic body accepts a target");
+    world
+        .apply_body_patch(
+            kinematic,
+            BodyPatch {
+                linear_velocity: Some(Vector::default()),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("velocity patch applies");
+    step_world(&mut world, 1);
+    assert_eq!(body_position(&world, kinematic), Vector::default());
+}
+
+#[test]
+fn ccd_kinematic_sweep_pushes_resting_dynamic_body_without_tunneling() {
+    let mut world = no_gravity_world();
+    let paddle = create_body(
         &mut world,
-        BodyType::Dynamic,
+        BodyType::Kinematic,
         -1.0,
         0.0,
-        Vector::new(200.0, 0.0),
-    );
-    let near = create_body(
-        &mut world,
-        BodyType::Dynamic,
-        0.0,
-        0.0,
-        Vector::new(0.1, 0.0),
-    );
-    let far = create_body(
-        &mut world,
-        BodyType::Dynamic,
-        0.8,
-        0.0,
-        Vector::new(0.1, 0.0),
-    );
-    let near_collider = attach_shape(
-        &mut world,
-        near,
-        SharedShape::rect(0.1, 0.1),
-        Material::default(),
+        Vector::default(),
     );
-    let far_collider = attach_shape(
+    let resting = create_body(&mut world, BodyType::Dynamic, 0.0, 0.0, Vector::default());
+    let paddle_collider = attach_shape(
         &mut world,
-        far,
-        SharedShape::rect(0.1, 0.1),
+        paddle,
+        SharedShape::rect(0.1, 1.0),
         Material::default(),
     );
-    attach_shape(
+    let resting_collider = attach_shape(
         &mut world,
-        bullet,
-        SharedShape::rect(0.1, 0.1),
+        resting,
+        SharedShape::rect(0.2, 0.2),
         Material::default(),
     );
+    world
+        .set_kinematic_target(paddle, Pose::from_xy_angle(1.0, 0.0, 0.0))
+        .expect("kinematic paddle accepts a target");
 
     let report = step_world(&mut world, 1);
     let contact = active_contact_events(&report)
         .into_iter()
         .find(|contact| contact.ccd_trace.is_some())
-        .expect("CCD should emit the selected earliest dynamic hit");
+        .expect("kinematic sweep should emit a CCD contact");
     let trace = contact.ccd_trace.expect("contact should carry CCD trace");
 
-    assert_eq!(report.stats.ccd_candidate_count, 2);
-    assert_eq!(report.stats.ccd_hit_count, 2);
-    assert_eq!(report.stats.ccd_miss_count, 0);
-    assert_eq!(report.stats.ccd_clamp_count, 2);
-    assert_eq!(trace.static_body, near);
-    assert_eq!(trace.static_collider, near_collider);
-    assert_ne!(trace.static_collider, far_collider);
-    assert_eq!(trace.target_kind, CcdTargetKind::Dynamic);
-    assert_eq!(trace.target_swept_start, Point::new(0.0, 0.0));
-    assert!(trace.target_swept_end.x() > trace.target_swept_start.x());
-    assert!(trace.target_clamp > 0.0);
+    assert_eq!(report.stats.ccd_hit_count, 1);
+    assert_eq!(report.stats.ccd_clamp_count, 1);
+    assert_eq!(trace.moving_body, resting);
+    assert_eq!(trace.static_body, paddle);
+    assert_eq!(trace.moving_collider, resting_collider);
+    assert_eq!(trace.static_collider, paddle_collider);
+    assert_eq!(trace.target_kind, CcdTargetKind::Kinematic);
+    assert_eq!(trace.target_swept_start, Point::new(-1.0, 0.0));
+    assert_eq!(trace.target_swept_end, Point::new(1.0, 0.0));
+    assert!(trace.toi > 0.0 && trace.toi < 1.0);
+    assert!(trace.clamp > 0.0);
+    assert_eq!(trace.target_clamp, 0.0);
+    assert_eq!(body_position(&world, paddle), Vector::new(1.0, 0.0));
+    let resting_x = body_position(&world, resting).x();
     assert!(
-        body_position(&world, bullet).x() < body_position(&world, far).x(),
-        "CCD budget should select the nearer dynamic target before the farther hit"
+        resting_x > 1.0,
+        "the driven paddle should push the body ahead instead of passing through; resting_x={resting_x}"
     );
 }
 
 #[test]
-fn ccd_dynamic_convex_pair_skips_rotating_bodies() {
-    let mut world = no_gravity_world();
-    let rotating = world
-        .create_body(BodyDesc {
-            body_type: BodyType::Dynamic,
-            pose: Pose::from_xy_angle(-1.0, 0.0, 0.0),
-            linear_velocity: Vector::new(200.0, 0.0),
-            angular_velocity: 1.0,
-            can_sleep: false,
-            ..BodyDesc::default()
-        })
-        .expect("rotating body should be created");
-    let target = create_body(
-        &mut world,
-        BodyType::Dynamic,
-        1.0,
-        0.0,
-        Vector::new(-200.0, 0.0),
-    );
-    attach_shape(
-        &mut world,
-        rotating,
-        SharedShape::rect(0.1, 0.1),
-        Material::default(),
-    );
-    attach_shape(
-        &mut world,
-        target,
-        SharedShape::rect(0.1, 0.1),
-        Material::default(),
-    );
+fn ccd_opt_out_and_motion_threshold_skip_sweeps_against_static_walls() {
+    // Sweep length at 200 m/s for one step is about 3.33 m.
+    fn wall_stats(desc: BodyDesc) -> (StepStats, FloatNum) {
+        let mut world = no_gravity_world();
+        let wall = create_body(&mut world, BodyType::Static, 0.0, 0.0, Vector::default());
+        let body = world
+            .create_body(BodyDesc {
+                pose: Pose::from_xy_angle(-1.0, 0.0, 0.0),
+                linear_velocity: Vector::new(200.0, 0.0),
+                can_sleep: false,
+                ..desc
+            })
+            .expect("body should be created");
+        attach_shape(
+            &mut world,
+            wall,
+            SharedShape::rect(0.1, 10.0),
+            Material::default(),
+        );
+        attach_shape(
+            &mut world,
+            body,
+            SharedShape::circle(0.05),
+            Material::default(),
+        );
+        let report = step_world(&mut world, 1);
+        (report.stats, body_position(&world, body).x())
+    }
 
-    let report = step_world(&mut world, 1);
+    let (enabled, enabled_x) = wall_stats(BodyDesc::default());
+    assert_eq!(enabled.ccd_candidate_count, 1);
+    assert_eq!(enabled.ccd_hit_count, 1);
+    assert!(enabled_x <= -0.05);
 
-    assert_eq!(report.stats.ccd_candidate_count, 0);
-    assert_eq!(report.stats.ccd_hit_count, 0);
-    assert_eq!(report.stats.ccd_clamp_count, 0);
+    let (disabled, disabled_x) = wall_stats(BodyDesc {
+        ccd_enabled: false,
+        ..BodyDesc::default()
+    });
+    assert_eq!(disabled.ccd_candidate_count, 0);
+    assert_eq!(disabled.ccd_hit_count, 0);
     assert!(
-        active_contact_events(&report)
-            .iter()
-            .all(|contact| contact.ccd_trace.is_none()),
-        "rotational CCD is outside the M19 translational slice"
+        disabled_x > 2.0,
+        "an opted-out body should not be swept; x={disabled_x}"
     );
+
+    let (slow_for_threshold, _) = wall_stats(BodyDesc {
+        ccd_motion_threshold: 5.0,
+        ..BodyDesc::default()
+    });
+    assert_eq!(slow_for_threshold.ccd_candidate_count, 0);
+
+    let (bullet, bullet_x) = wall_stats(BodyDesc {
+        ccd_enabled: false,
+        bullet: true,
+        ccd_motion_threshold: 5.0,
+        ..BodyDesc::default()
+    });
+    assert_eq!(bullet.ccd_candidate_count, 1);
+    assert_eq!(bullet.ccd_hit_count, 1);
+    assert!(bullet_x <= -0.05);
+}
+
+#[test]
+fn ccd_bullet_sweeps_against_dynamic_bodies_that_opted_out() {
+    fn debris_stats(bullet: bool) -> StepStats {
+        let mut world = no_gravity_world();
+        let projectile = world
+            .create_body(BodyDesc {
+                pose: Pose::from_xy_angle(-1.0, 0.0, 0.0),
+                linear_velocity: Vector::new(200.0, 0.0),
+                can_sleep: false,
+                bullet,
+                ..BodyDesc::default()
+            })
+            .expect("projectile should be created");
+        let debris = world
+            .create_body(BodyDesc {
+                can_sleep: false,
+                ccd_enabled: false,
+                ..BodyDesc::default()
+            })
+            .expect("debris should be created");
+        for body in [projectile, debris] {
+            attach_shape(
+                &mut world,
+                body,
+                SharedShape::rect(0.1, 0.1),
+                Material::default(),
+            );
+        }
+        step_world(&mut world, 1).stats
+    }
+
+    let plain = debris_stats(false);
+    assert_eq!(plain.ccd_candidate_count, 0);
+    assert_eq!(plain.ccd_hit_count, 0);
+
+    let bullet = debris_stats(true);
+    assert_eq!(bullet.ccd_candidate_count, 1);
+    assert_eq!(bullet.ccd_hit_count, 1);
+}
+
+#[test]
+fn ccd_body_settings_round_trip_through_patches_and_validation() {
+    let mut world = no_gravity_world();
+    assert!(matches!(
+        world.create_body(BodyDesc {
+            ccd_motion_threshold: -1.0,
+            ..BodyDesc::default()
+        }),
+        Err(WorldError::Validation(ValidationError::BodyDesc {
+            field: "ccd_motion_threshold"
+        }))
+    ));
+    let body = create_body(&mut world, BodyType::Dynamic, 0.0, 0.0, Vector::default());
+    world
+        .apply_body_patch(
+            body,
+            BodyPatch {
+                ccd_enabled: Some(false),
+                bullet: Some(true),
+                ccd_motion_threshold: Some(0.25),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("ccd patch applies");
+    let view = world.try_body(body).expect("body exists");
+    assert!(!view.ccd_enabled());
+    assert!(view.bullet());
+    assert_eq!(view.ccd_motion_threshold(), 0.25);
+    assert!(matches!(
+        world.apply_body_patch(
+            body,
+            BodyPatch {
+                ccd_motion_threshold: Some(FloatNum::NAN),
+                ..BodyPatch::default()
+            },
+        ),
+        Err(WorldError::Validation(ValidationError::BodyPatch {
+            field: "ccd_motion_threshold"
+        }))
+    ));
 }
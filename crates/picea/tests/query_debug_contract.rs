@@ -1,11 +1,12 @@
 use picea::debug::{DebugShape, DebugStats, DebugTransform};
-use picea::math::{point::Point, vector::Vector};
+use picea::math::{point::Point, vector::Vector, FloatNum};
 use picea::prelude::{
     BodyDesc, BodyHandle, BodyPatch, BodyType, ColliderDesc, ColliderHandle, ColliderPatch,
     CollisionFilter, ContactEvent, ContactFeatureId, ContactId, ContactReductionReason, DebugBody,
-    DebugCollider, DebugContact, DebugIsland, DebugManifold, DebugManifoldPoint, DebugSnapshot,
-    DebugSnapshotOptions, EpaTerminationReason, GenericConvexFallbackReason, GenericConvexTrace,
-    GjkTerminationReason, ManifoldId, Material, Pose, QueryFilter, QueryPipeline, QueryShape,
+    DebugCollider, DebugContact, DebugIsland, DebugManifold, DebugManifoldPoint, DebugPrimitive,
+    DebugSnapshot, DebugSnapshotOptions, EpaTerminationReason, FluidVolume,
+    GenericConvexFallbackReason, GenericConvexTrace, GjkTerminationReason, GravityFalloff,
+    GravityField, ManifoldId, Material, Pose, QueryFilter, QueryPipeline, QueryShape,
     QueryShapeError, QueryStats, SharedShape, SimulationPipeline, SleepEvent,
     SleepTransitionReason, StepConfig, StepReport, StepStats, WarmStartCacheReason, World,
     WorldDesc, WorldEvent,
@@ -136,6 +137,131 @@ fn debug_snapshot_defaults_to_empty_stable_fact_layers() {
     assert!(snapshot.primitives.is_empty());
 }
 
+#[test]
+fn debug_snapshot_draws_gravity_fields_as_primitives() {
+    let world = World::new(WorldDesc {
+        gravity_fields: vec![
+            GravityField::Uniform {
+                min: Point::new(-1.0, -2.0),
+                max: Point::new(3.0, 2.0),
+                acceleration: Vector::new(0.0, -5.0),
+            },
+            GravityField::Radial {
+                center: Point::new(10.0, 0.0),
+                strength: 9.8,
+                inner_radius: 2.0,
+                outer_radius: 6.0,
+                falloff: GravityFalloff::Linear,
+            },
+        ],
+        ..WorldDesc::default()
+    });
+
+    let snapshot = world.debug_snapshot(&DebugSnapshotOptions::default());
+
+    assert!(snapshot.primitives.iter().any(|primitive| matches!(
+        primitive,
+        DebugPrimitive::Polygon { points, fill: Some(_), .. }
+            if points.first() == Some(&Point::new(-1.0, -2.0))
+    )));
+    assert!(snapshot.primitives.iter().any(|primitive| matches!(
+        primitive,
+        DebugPrimitive::Arrow { origin, direction, .. }
+            if *origin == Point::new(1.0, 0.0) && *direction == Vector::new(0.0, -1.0)
+    )));
+    for radius in [2.0, 6.0] {
+        assert!(snapshot.primitives.iter().any(|primitive| matches!(
+            primitive,
+            DebugPrimitive::Circle { center, radius: drawn, .. }
+                if *center == Point::new(10.0, 0.0) && *drawn == radius
+        )));
+    }
+    // Ring arrows point inward for an attracting field.
+    assert!(snapshot.primitives.iter().any(|primitive| matches!(
+        primitive,
+        DebugPrimitive::Arrow { origin, direction, .. }
+            if *origin == Point::new(16.0, 0.0) && direction.x() < 0.0
+    )));
+
+    let without_primitives = world.debug_snapshot(&DebugSnapshotOptions {
+        include_primitives: false,
+        ..DebugSnapshotOptions::default()
+    });
+    assert!(without_primitives.primitives.is_empty());
+}
+
+#[test]
+fn debug_snapshot_reports_fluid_submersion_area_and_centroid() {
+    let mut world = World::new(WorldDesc {
+        gravity: Vector::default(),
+        ..WorldDesc::default()
+    });
+    let pool_body = world
+        .create_body(BodyDesc {
+            body_type: BodyType::Static,
+            pose: Pose::from_xy_angle(0.0, 5.0, 0.0),
+            ..BodyDesc::default()
+        })
+        .expect("pool body should be created");
+    let pool = world
+        .create_collider(
+            pool_body,
+            ColliderDesc {
+                shape: SharedShape::rect(20.0, 10.0),
+                fluid: Some(FluidVolume::default()),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("pool should be created");
+    let body = world
+        .create_body(BodyDesc {
+            pose: Pose::from_xy_angle(0.0, 0.5, 0.0),
+            can_sleep: false,
+            ..BodyDesc::default()
+        })
+        .expect("box body should be created");
+    let collider = world
+        .create_collider(
+            body,
+            ColliderDesc {
+                shape: SharedShape::rect(2.0, 2.0),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("box should be created");
+
+    assert!(world
+        .debug_snapshot(&DebugSnapshotOptions::default())
+        .fluid_submersions
+        .is_empty());
+    step_once(&mut world);
+    let snapshot = world.debug_snapshot(&DebugSnapshotOptions::default());
+
+    let [submersion] = snapshot.fluid_submersions.as_slice() else {
+        panic!("expected one submersion: {:?}", snapshot.fluid_submersions);
+    };
+    assert_eq!(submersion.fluid_collider, pool);
+    assert_eq!(submersion.collider, collider);
+    assert_eq!(submersion.body, body);
+    // The box spans y in [-0.5, 1.5]; the pool surface is y = 0.
+    assert!((submersion.submerged_area - 3.0).abs() < 1e-3);
+    assert!(submersion.centroid.x().abs() < 1e-3);
+    assert!((submersion.centroid.y() - 0.75).abs() < 1e-3);
+    assert_eq!(submersion.polygon.len(), 4);
+    assert!(snapshot.primitives.iter().any(|primitive| matches!(
+        primitive,
+        DebugPrimitive::Polygon { points, fill: Some(_), .. } if *points == submersion.polygon
+    )));
+
+    let encoded = serde_json::to_value(&snapshot).expect("snapshot should serialize");
+    assert_eq!(
+        encoded["fluid_submersions"][0]["submerged_area"]
+            .as_f64()
+            .map(|area| (area - 3.0).abs() < 1e-3),
+        Some(true)
+    );
+}
+
 #[test]
 fn debug_snapshot_serializes_broadphase_tree_without_proxy_or_leaf_ids() {
     let mut world = World::new(WorldDesc::default());
@@ -540,7 +666,7 @@ fn debug_snapshot_with_step_report_preserves_step_facts_and_collider_semantics()
         .iter()
         .find(|body| body.handle == body_a)
         .expect("snapshot should contain body mass properties");
-    assert!((body.mass_properties.mass - 2.5 * std::f32::consts::PI).abs() <= 1e-4);
+    assert!((body.mass_properties.mass - 2.5 * picea::math::pi()).abs() <= 1e-4);
     assert!(body.mass_properties.inverse_mass > 0.0);
 
     let collider = snapshot
@@ -594,6 +720,7 @@ fn warm_start_debug_snapshot_exposes_cache_facts() {
     let mut world = World::new(WorldDesc {
         gravity: Vector::default(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let left = world
         .create_body(BodyDesc {
@@ -719,6 +846,10 @@ fn warm_start_new_picea_payload_fields_default_when_deserializing_older_json() {
             "ccd_hit_count",
             "ccd_miss_count",
             "ccd_clamp_count",
+            "ccd_substep_count",
+            "ccd_substep_fallback_count",
+            "ccd_speculative_contact_count",
+            "ccd_speculative_enforced_count",
         ],
     );
     let stats: StepStats =
@@ -738,6 +869,10 @@ fn warm_start_new_picea_payload_fields_default_when_deserializing_older_json() {
     assert_eq!(stats.ccd_hit_count, 0);
     assert_eq!(stats.ccd_miss_count, 0);
     assert_eq!(stats.ccd_clamp_count, 0);
+    assert_eq!(stats.ccd_substep_count, 0);
+    assert_eq!(stats.ccd_substep_fallback_count, 0);
+    assert_eq!(stats.ccd_speculative_contact_count, 0);
+    assert_eq!(stats.ccd_speculative_enforced_count, 0);
 
     let mut debug_stats_value =
         serde_json::to_value(DebugStats::default()).expect("debug stats should serialize");
@@ -759,6 +894,10 @@ fn warm_start_new_picea_payload_fields_default_when_deserializing_older_json() {
             "ccd_hit_count",
             "ccd_miss_count",
             "ccd_clamp_count",
+            "ccd_substep_count",
+            "ccd_substep_fallback_count",
+            "ccd_speculative_contact_count",
+            "ccd_speculative_enforced_count",
         ],
     );
     let debug_stats: DebugStats =
@@ -778,6 +917,10 @@ fn warm_start_new_picea_payload_fields_default_when_deserializing_older_json() {
     assert_eq!(debug_stats.ccd_hit_count, 0);
     assert_eq!(debug_stats.ccd_miss_count, 0);
     assert_eq!(debug_stats.ccd_clamp_count, 0);
+    assert_eq!(debug_stats.ccd_substep_count, 0);
+    assert_eq!(debug_stats.ccd_substep_fallback_count, 0);
+    assert_eq!(debug_stats.ccd_speculative_contact_count, 0);
+    assert_eq!(debug_stats.ccd_speculative_enforced_count, 0);
 
     let debug_contact = DebugContact {
         id: ContactId::default(),
@@ -931,10 +1074,14 @@ fn warm_start_new_picea_payload_fields_default_when_deserializing_older_json() {
 
     let mut snapshot_value =
         serde_json::to_value(DebugSnapshot::default()).expect("debug snapshot should serialize");
-    remove_json_fields(&mut snapshot_value, &["islands", "broadphase_tree"]);
+    remove_json_fields(
+        &mut snapshot_value,
+        &["islands", "broadphase_tree", "fluid_submersions"],
+    );
     let decoded_snapshot: DebugSnapshot =
         serde_json::from_value(snapshot_value).expect("older debug snapshot should deserialize");
     assert!(decoded_snapshot.islands.is_empty());
+    assert!(decoded_snapshot.fluid_submersions.is_empty());
     assert!(decoded_snapshot.broadphase_tree.nodes.is_empty());
 }
 
@@ -2021,7 +2168,7 @@ fn query_shape_rejects_degenerate_and_unsupported_inputs() {
     ));
     assert!(matches!(
         QueryShape::polygon(vec![
-            Point::new(f32::NAN, 0.0),
+            Point::new(FloatNum::NAN, 0.0),
             Point::new(1.0, 0.0),
             Point::new(0.0, 1.0),
         ]),
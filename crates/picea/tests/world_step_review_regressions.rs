@@ -1,7 +1,8 @@
 use picea::prelude::{
-    BodyDesc, BodyPatch, BodyType, ColliderDesc, ColliderPatch, DistanceJointDesc,
-    DistanceJointPatch, JointDesc, JointPatch, Pose, SharedShape, SimulationPipeline, StepConfig,
-    World, WorldAnchorJointDesc, WorldAnchorJointPatch, WorldDesc, WorldError, WorldEvent,
+    BodyDesc, BodyPatch, BodyType, ColliderDesc, ColliderPatch, CollisionFilter, DistanceJointDesc,
+    DistanceJointPatch, FloatNum, JointDesc, JointPatch, Pose, SharedShape, SimulationPipeline,
+    StepConfig, World, WorldAnchorJointDesc, WorldAnchorJointPatch, WorldDesc, WorldError,
+    WorldEvent,
 };
 use picea::world::{HandleError, TopologyError, ValidationError};
 
@@ -12,7 +13,7 @@ fn body_inputs_must_be_finite_before_world_state_mutates() {
 
     let create_error = world
         .create_body(BodyDesc {
-            pose: Pose::from_xy_angle(f32::NAN, 0.0, 0.0),
+            pose: Pose::from_xy_angle(FloatNum::NAN, 0.0, 0.0),
             ..BodyDesc::default()
         })
         .expect_err("non-finite creation inputs must be rejected");
@@ -37,7 +38,7 @@ fn body_inputs_must_be_finite_before_world_state_mutates() {
         .apply_body_patch(
             body,
             BodyPatch {
-                gravity_scale: Some(f32::INFINITY),
+                gravity_scale: Some(FloatNum::INFINITY),
                 ..BodyPatch::default()
             },
         )
@@ -70,7 +71,7 @@ fn collider_and_joint_inputs_are_validated_before_revision_bump() {
         .create_collider(
             body_a,
             ColliderDesc {
-                density: f32::INFINITY,
+                density: FloatNum::INFINITY,
                 ..ColliderDesc::default()
             },
         )
@@ -160,7 +161,7 @@ fn collider_and_joint_inputs_are_validated_before_revision_bump() {
         .apply_joint_patch(
             joint,
             JointPatch::Distance(DistanceJointPatch {
-                rest_length: Some(f32::NAN),
+                rest_length: Some(FloatNum::NAN),
                 ..DistanceJointPatch::default()
             }),
         )
@@ -188,7 +189,7 @@ fn collider_and_joint_inputs_are_validated_before_revision_bump() {
         .apply_joint_patch(
             world_anchor_joint,
             JointPatch::WorldAnchor(WorldAnchorJointPatch {
-                world_anchor: Some((f32::INFINITY, 0.0).into()),
+                world_anchor: Some((FloatNum::INFINITY, 0.0).into()),
                 ..WorldAnchorJointPatch::default()
             }),
         )
@@ -218,7 +219,7 @@ fn derived_mass_properties_are_validated_before_world_state_mutates() {
         .create_collider(
             body,
             ColliderDesc {
-                shape: SharedShape::circle(1.0e20),
+                shape: SharedShape::circle(FloatNum::MAX.sqrt()),
                 density: 1.0,
                 ..ColliderDesc::default()
             },
@@ -264,7 +265,7 @@ fn derived_mass_properties_are_validated_before_world_state_mutates() {
             body,
             ColliderDesc {
                 shape: SharedShape::circle(1.0),
-                local_pose: Pose::from_xy_angle(1.0e20, 0.0, 0.0),
+                local_pose: Pose::from_xy_angle(FloatNum::MAX.sqrt(), 0.0, 0.0),
                 density: 1.0,
                 ..ColliderDesc::default()
             },
@@ -323,7 +324,7 @@ fn derived_mass_properties_are_validated_before_world_state_mutates() {
         .apply_collider_patch(
             collider,
             ColliderPatch {
-                local_pose: Some(Pose::from_xy_angle(1.0e20, 0.0, 0.0)),
+                local_pose: Some(Pose::from_xy_angle(FloatNum::MAX.sqrt(), 0.0, 0.0)),
                 ..ColliderPatch::default()
             },
         )
@@ -451,8 +452,9 @@ fn stale_reads_are_explicit_instead_of_collapsing_into_absence() {
 #[test]
 fn step_emits_numeric_warnings_without_committing_non_finite_body_state() {
     let mut world = World::new(WorldDesc {
-        gravity: (f32::NAN, 0.0).into(),
+        gravity: (FloatNum::NAN, 0.0).into(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let body = world
         .create_body(BodyDesc {
@@ -490,6 +492,7 @@ fn simulation_step_emits_lifecycle_and_contact_events_with_nonzero_stats() {
     let mut world = World::new(WorldDesc {
         gravity: (0.0, 0.0).into(),
         enable_sleep: true,
+        ..WorldDesc::default()
     });
     let ground = world
         .create_body(BodyDesc {
@@ -589,6 +592,7 @@ fn circle_contacts_keep_normals_toward_ordered_body_after_collider_slot_reuse()
     let mut world = World::new(WorldDesc {
         gravity: (0.0, 0.0).into(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let temp_body = world
         .create_body(BodyDesc {
@@ -665,7 +669,7 @@ fn circle_contacts_keep_normals_toward_ordered_body_after_collider_slot_reuse()
     assert_eq!(contact.body_a, right_body);
     assert_eq!(contact.body_b, left_body);
     assert!(
-        contact.normal.x() > 0.0 && contact.normal.y().abs() <= f32::EPSILON,
+        contact.normal.x() > 0.0 && contact.normal.y().abs() <= FloatNum::EPSILON,
         "normal should point toward ordered body_a even when handle order diverges; got {:?}",
         contact.normal
     );
@@ -676,6 +680,7 @@ fn contact_gathering_geometry_cache_invalidates_after_body_and_collider_edits()
     let mut world = World::new(WorldDesc {
         gravity: (0.0, 0.0).into(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let left_body = world
         .create_body(BodyDesc {
@@ -769,6 +774,7 @@ fn world_anchor_joints_affect_body_motion_during_step() {
     let mut world = World::new(WorldDesc {
         gravity: (0.0, 0.0).into(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let body = world
         .create_body(BodyDesc {
@@ -813,6 +819,7 @@ fn dynamic_static_distance_joints_solve_through_dense_island_slots() {
     let mut world = World::new(WorldDesc {
         gravity: (0.0, 0.0).into(),
         enable_sleep: false,
+        ..WorldDesc::default()
     });
     let static_right = world
         .create_body(BodyDesc {
@@ -932,3 +939,227 @@ fn dynamic_static_distance_joints_solve_through_dense_island_slots() {
         "static endpoint must remain fixed while the dense island row solves"
     );
 }
+
+fn lockstep_world() -> World {
+    let mut world = World::new(WorldDesc {
+        deterministic: true,
+        ..WorldDesc::default()
+    });
+    let ground = world
+        .create_body(BodyDesc {
+            body_type: BodyType::Static,
+            pose: Pose::from_xy_angle(0.0, 2.0, 0.0),
+            ..BodyDesc::default()
+        })
+        .expect("ground should be created");
+    world
+        .create_collider(
+            ground,
+            ColliderDesc {
+                shape: SharedShape::rect(10.0, 1.0),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("ground collider should be created");
+    let falling = world
+        .create_body(BodyDesc {
+            pose: Pose::from_xy_angle(0.2, 0.0, 0.3),
+            angular_velocity: 0.5,
+            ..BodyDesc::default()
+        })
+        .expect("falling body should be created");
+    world
+        .create_collider(
+            falling,
+            ColliderDesc {
+                shape: SharedShape::rect(1.0, 1.0),
+                ..ColliderDesc::default()
+            },
+        )
+        .expect("falling collider should be created");
+    world
+}
+
+#[test]
+fn state_hash_matches_between_lockstep_worlds_every_tick() {
+    let mut left = lockstep_world();
+    let mut right = lockstep_world();
+    let mut left_pipeline = SimulationPipeline::new(StepConfig::default());
+    let mut right_pipeline = SimulationPipeline::new(StepConfig::default());
+    assert_eq!(left.state_hash(), right.state_hash());
+
+    let initial_hash = left.state_hash();
+    let mut saw_contact = false;
+    for tick in 0..90 {
+        let report = left_pipeline.step(&mut left);
+        right_pipeline.step(&mut right);
+        saw_contact |= report.stats.contact_count > 0;
+        assert_eq!(
+            left.state_hash(),
+            right.state_hash(),
+            "lockstep worlds diverged at tick {tick}"
+        );
+    }
+    assert!(saw_contact, "the hash must cover a populated contact cache");
+    assert_ne!(left.state_hash(), initial_hash);
+
+    let body = left.bodies().last().expect("falling body should exist");
+    left.apply_body_patch(
+        body,
+        BodyPatch {
+            angular_velocity: Some(1.0e-3),
+            ..BodyPatch::default()
+        },
+    )
+    .expect("patch should apply");
+    assert_ne!(left.state_hash(), right.state_hash());
+}
+
+#[test]
+fn state_hash_covers_world_settings_and_collider_facts() {
+    let baseline = lockstep_world().state_hash();
+    assert_ne!(
+        World::new(WorldDesc {
+            enable_sleep: false,
+            ..WorldDesc::default()
+        })
+        .state_hash(),
+        World::new(WorldDesc::default()).state_hash()
+    );
+    assert_ne!(
+        World::new(WorldDesc {
+            gravity: (0.0, 1.0).into(),
+            ..WorldDesc::default()
+        })
+        .state_hash(),
+        World::new(WorldDesc::default()).state_hash()
+    );
+
+    let patches = [
+        ColliderPatch {
+            shape: Some(SharedShape::rect(10.0, 2.0)),
+            ..ColliderPatch::default()
+        },
+        ColliderPatch {
+            filter: Some(CollisionFilter {
+                memberships: 2,
+                ..CollisionFilter::default()
+            }),
+            ..ColliderPatch::default()
+        },
+        ColliderPatch {
+            user_data: Some(7),
+            ..ColliderPatch::default()
+        },
+    ];
+    for patch in patches {
+        let mut world = lockstep_world();
+        let ground = world.bodies().next().expect("ground should exist");
+        let collider = world
+            .colliders_for_body(ground)
+            .expect("ground should be live")
+            .next()
+            .expect("ground collider should exist");
+        world
+            .apply_collider_patch(collider, patch.clone())
+            .expect("patch should apply");
+        assert_ne!(
+            world.state_hash(),
+            baseline,
+            "{patch:?} should change the hash"
+        );
+    }
+}
+
+#[test]
+fn deterministic_step_flushes_subnormal_and_negative_zero_state() {
+    let subnormal = FloatNum::MIN_POSITIVE / 4.0;
+    let make_world = |deterministic| {
+        let mut world = World::new(WorldDesc {
+            gravity: Default::default(),
+            deterministic,
+            ..WorldDesc::default()
+        });
+        let body = world
+            .create_body(BodyDesc {
+                linear_velocity: (-0.0, subnormal).into(),
+                angular_velocity: subnormal,
+                can_sleep: false,
+                ..BodyDesc::default()
+            })
+            .expect("body should be created");
+        (world, body)
+    };
+
+    let (mut plain, plain_body) = make_world(false);
+    SimulationPipeline::new(StepConfig::default()).step(&mut plain);
+    let plain_view = plain.body(plain_body).expect("body should exist");
+    assert!(plain_view.angular_velocity().is_subnormal());
+
+    let (mut world, body) = make_world(true);
+    SimulationPipeline::new(StepConfig::default()).step(&mut world);
+    let view = world.body(body).expect("body should exist");
+    assert_eq!(view.angular_velocity().to_bits(), 0);
+    assert_eq!(view.linear_velocity().x().to_bits(), 0);
+    assert_eq!(view.linear_velocity().y().to_bits(), 0);
+
+    let (mut forced, forced_body) = make_world(false);
+    SimulationPipeline::new(StepConfig {
+        deterministic: true,
+        ..StepConfig::default()
+    })
+    .step(&mut forced);
+    let forced_view = forced.body(forced_body).expect("body should exist");
+    assert_eq!(forced_view.angular_velocity().to_bits(), 0);
+}
+
+#[test]
+fn interpolated_pose_blends_between_last_two_steps_and_snaps_on_teleport() {
+    let mut world = World::new(WorldDesc {
+        gravity: Default::default(),
+        ..WorldDesc::default()
+    });
+    let body = world
+        .create_body(BodyDesc {
+            linear_velocity: (6.0, 0.0).into(),
+            angular_velocity: 1.2,
+            can_sleep: false,
+            ..BodyDesc::default()
+        })
+        .expect("body should be created");
+    let mut pipeline = SimulationPipeline::new(StepConfig::default());
+
+    assert_eq!(
+        world
+            .interpolated_pose(body, 0.5)
+            .expect("body should exist"),
+        Pose::default()
+    );
+
+    let report = pipeline.advance(&mut world, 2.5 / 60.0);
+    assert_eq!(report.steps.len(), 2);
+    let current = world.body(body).expect("body should exist").pose();
+    let start = world
+        .interpolated_pose(body, 0.0)
+        .expect("body should exist");
+    let blended = world
+        .interpolated_pose(body, report.alpha)
+        .expect("body should exist");
+    assert_eq!(world.interpolated_pose(body, 1.0), Ok(current));
+    assert_eq!(world.interpolated_pose(body, FloatNum::NAN), Ok(current));
+    assert!(start.translation().x() < blended.translation().x());
+    assert!(blended.translation().x() < current.translation().x());
+    assert!(start.angle() < blended.angle() && blended.angle() < current.angle());
+
+    let teleport = Pose::from_xy_angle(-4.0, 3.0, 0.5);
+    world
+        .apply_body_patch(
+            body,
+            BodyPatch {
+                pose: Some(teleport),
+                ..BodyPatch::default()
+            },
+        )
+        .expect("teleport should apply");
+    assert_eq!(world.interpolated_pose(body, 0.0), Ok(teleport));
+}